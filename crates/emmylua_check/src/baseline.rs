@@ -0,0 +1,238 @@
+use std::{
+    collections::HashMap,
+    error::Error,
+    path::{Path, PathBuf},
+};
+
+use emmylua_code_analysis::{DbIndex, FileId};
+use lsp_types::{Diagnostic, NumberOrString};
+use serde::{Deserialize, Serialize};
+
+use crate::output::DiagnosticReceiver;
+
+/// 诊断的指纹. 使用所在行的内容而不是行号, 使基线在代码移动或重新格式化后仍然有效
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+struct BaselineKey {
+    path: String,
+    code: String,
+    content: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct BaselineEntry {
+    #[serde(flatten)]
+    key: BaselineKey,
+    count: usize,
+}
+
+/// 已知诊断的集合, 只有不在基线中的诊断才会被报告
+#[derive(Debug, Default)]
+pub struct Baseline {
+    workspace: PathBuf,
+    entries: HashMap<BaselineKey, usize>,
+}
+
+impl Baseline {
+    pub fn new(workspace: PathBuf) -> Self {
+        Self {
+            workspace,
+            entries: HashMap::new(),
+        }
+    }
+
+    pub fn load(workspace: PathBuf, path: &Path) -> Result<Self, Box<dyn Error + Sync + Send>> {
+        let content = std::fs::read_to_string(path)?;
+        let entries: Vec<BaselineEntry> = serde_json::from_str(&content)?;
+        Ok(Self {
+            workspace,
+            entries: entries
+                .into_iter()
+                .map(|entry| (entry.key, entry.count))
+                .collect(),
+        })
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), Box<dyn Error + Sync + Send>> {
+        let mut entries = self
+            .entries
+            .iter()
+            .map(|(key, count)| BaselineEntry {
+                key: key.clone(),
+                count: *count,
+            })
+            .collect::<Vec<_>>();
+        entries.sort_by(|a, b| a.key.cmp(&b.key));
+
+        if let Some(parent) = path.parent()
+            && !parent.as_os_str().is_empty()
+            && !parent.exists()
+        {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(path, serde_json::to_string_pretty(&entries)?)?;
+        Ok(())
+    }
+
+    /// 收集所有文件的诊断作为新的基线
+    pub async fn collect(
+        workspace: PathBuf,
+        total_count: usize,
+        db: &DbIndex,
+        mut receiver: DiagnosticReceiver,
+    ) -> Self {
+        let mut baseline = Self::new(workspace);
+        let mut count = 0;
+        while let Some((file_id, diagnostics)) = receiver.recv().await {
+            count += 1;
+            for diagnostic in diagnostics.iter().flatten() {
+                if let Some(key) = baseline.make_key(db, file_id, diagnostic) {
+                    *baseline.entries.entry(key).or_insert(0) += 1;
+                }
+            }
+
+            if count == total_count {
+                break;
+            }
+        }
+
+        baseline
+    }
+
+    /// 移除基线中已经存在的诊断, 每条基线记录最多抵消对应数量的诊断
+    pub fn filter(
+        &mut self,
+        db: &DbIndex,
+        file_id: FileId,
+        diagnostics: Vec<Diagnostic>,
+    ) -> Vec<Diagnostic> {
+        diagnostics
+            .into_iter()
+            .filter(|diagnostic| {
+                let Some(key) = self.make_key(db, file_id, diagnostic) else {
+                    return true;
+                };
+                match self.entries.get_mut(&key) {
+                    Some(count) if *count > 0 => {
+                        *count -= 1;
+                        false
+                    }
+                    _ => true,
+                }
+            })
+            .collect()
+    }
+
+    fn make_key(
+        &self,
+        db: &DbIndex,
+        file_id: FileId,
+        diagnostic: &Diagnostic,
+    ) -> Option<BaselineKey> {
+        let vfs = db.get_vfs();
+        let file_path = vfs.get_file_path(&file_id)?;
+        let path = file_path
+            .strip_prefix(&self.workspace)
+            .unwrap_or(file_path)
+            .to_string_lossy()
+            .replace('\\', "/");
+        let code = match diagnostic.code.as_ref()? {
+            NumberOrString::Number(n) => n.to_string(),
+            NumberOrString::String(s) => s.clone(),
+        };
+        let document = vfs.get_document(&file_id)?;
+        let line_range = document.get_line_range(diagnostic.range.start.line as usize)?;
+        let content = document
+            .get_text_slice(line_range)
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        Some(BaselineKey {
+            path,
+            code,
+            content,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_lib::{TestWorkspace, diagnose_files, diagnostic_codes};
+
+    const CODE: &str = r#"
+print(undefined_a)
+print(undefined_b)
+print(undefined_b)
+"#;
+
+    async fn filter_file(baseline: &mut Baseline, workspace: &TestWorkspace) -> Vec<Diagnostic> {
+        let (analysis, file_ids) = workspace.load().await;
+        let db = analysis.compilation.get_db();
+        let mut receiver = diagnose_files(&analysis, &file_ids);
+        let (file_id, diagnostics) = receiver.recv().await.unwrap();
+        baseline.filter(db, file_id, diagnostics.unwrap())
+    }
+
+    async fn collect_baseline(workspace: &TestWorkspace) -> Baseline {
+        let (analysis, file_ids) = workspace.load().await;
+        let receiver = diagnose_files(&analysis, &file_ids);
+        Baseline::collect(
+            workspace.root.clone(),
+            file_ids.len(),
+            analysis.compilation.get_db(),
+            receiver,
+        )
+        .await
+    }
+
+    #[tokio::test]
+    async fn test_baseline_suppress_known() {
+        let workspace = TestWorkspace::new("baseline_known", &[("main.lua", CODE)]);
+        let mut baseline = collect_baseline(&workspace).await;
+        assert_eq!(baseline.entries.values().sum::<usize>(), 3);
+
+        // 代码移动或缩进改变后基线仍然有效, 新的诊断会被报告
+        std::fs::write(
+            workspace.root.join("main.lua"),
+            "\n-- moved\n    print(undefined_b)\nprint(undefined_a)\nprint(undefined_c)\n",
+        )
+        .unwrap();
+        let diagnostics = filter_file(&mut baseline, &workspace).await;
+        assert_eq!(diagnostic_codes(&diagnostics), vec!["undefined-global"]);
+        assert_eq!(diagnostics[0].range.start.line, 4);
+    }
+
+    #[tokio::test]
+    async fn test_baseline_count() {
+        let workspace = TestWorkspace::new("baseline_count", &[("main.lua", CODE)]);
+        let mut baseline = collect_baseline(&workspace).await;
+
+        // 每条基线记录只抵消记录的数量
+        std::fs::write(
+            workspace.root.join("main.lua"),
+            format!("{}print(undefined_b)\n", CODE),
+        )
+        .unwrap();
+        let diagnostics = filter_file(&mut baseline, &workspace).await;
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].range.start.line, 4);
+    }
+
+    #[tokio::test]
+    async fn test_baseline_save_load() {
+        let workspace = TestWorkspace::new("baseline_save", &[("src/main.lua", CODE)]);
+        let baseline = collect_baseline(&workspace).await;
+        let baseline_path = workspace.root.join("out/baseline.json");
+        baseline.save(&baseline_path).unwrap();
+
+        let content = std::fs::read_to_string(&baseline_path).unwrap();
+        assert!(content.contains(r#""path": "src/main.lua""#));
+        assert!(content.contains(r#""content": "print(undefined_b)""#));
+        assert!(content.contains(r#""count": 2"#));
+
+        let mut loaded = Baseline::load(workspace.root.clone(), &baseline_path).unwrap();
+        assert_eq!(loaded.entries, baseline.entries);
+        assert!(filter_file(&mut loaded, &workspace).await.is_empty());
+    }
+}
@@ -1,6 +1,7 @@
 #[cfg(feature = "cli")]
 use clap::{Parser, ValueEnum};
 
+use emmylua_code_analysis::DiagnosticCode;
 use std::path::PathBuf;
 
 #[allow(unused)]
@@ -36,14 +37,57 @@ pub struct CmdArgs {
     )]
     pub output_format: OutputFormat,
 
-    /// Specify output destination (stdout or a file path, only used when output_format is json)
+    /// Specify output destination (stdout or a file path, not used when output_format is text)
     #[cfg_attr(feature = "cli", arg(long, default_value = "stdout"))]
     pub output: OutputDestination,
 
+    /// Comma separated list of diagnostic codes to report, all other diagnostics are dropped
+    #[cfg_attr(
+        feature = "cli",
+        arg(long, value_delimiter = ',', value_parser = parse_diagnostic_code, conflicts_with = "exclude_code")
+    )]
+    pub only_code: Option<Vec<DiagnosticCode>>,
+
+    /// Comma separated list of diagnostic codes not to report
+    #[cfg_attr(
+        feature = "cli",
+        arg(long, value_delimiter = ',', value_parser = parse_diagnostic_code)
+    )]
+    pub exclude_code: Option<Vec<DiagnosticCode>>,
+
     /// Treat warnings as errors
     #[cfg_attr(feature = "cli", arg(long))]
     pub warnings_as_errors: bool,
 
+    /// Baseline file of known diagnostics.
+    /// Diagnostics recorded in the baseline are not reported, so only new ones fail the check
+    #[cfg_attr(feature = "cli", arg(long))]
+    pub baseline: Option<PathBuf>,
+
+    /// Write all current diagnostics to the baseline file instead of reporting them
+    #[cfg_attr(feature = "cli", arg(long, requires = "baseline"))]
+    pub write_baseline: bool,
+
+    /// Apply safe automatic fixes to the files, then report the remaining diagnostics.
+    /// Only fixes that do not change the behavior of the code are applied
+    #[cfg_attr(feature = "cli", arg(long, conflicts_with = "fix_dry_run"))]
+    pub fix: bool,
+
+    /// Write the available automatic fixes as a unified diff to the output destination
+    /// instead of reporting diagnostics. Files are not modified
+    #[cfg_attr(feature = "cli", arg(long, conflicts_with = "write_baseline"))]
+    pub fix_dry_run: bool,
+
+    /// Print the time spent in each diagnostic checker to stderr
+    #[cfg_attr(feature = "cli", arg(long, conflicts_with = "fix_dry_run"))]
+    pub stats: bool,
+
+    /// Only validate the configuration files and report problems such as unknown keys,
+    /// invalid values, unknown diagnostic codes and invalid glob patterns.
+    /// The workspace is not analyzed
+    #[cfg_attr(feature = "cli", arg(long))]
+    pub config_check: bool,
+
     /// Verbose output
     #[cfg_attr(feature = "cli", arg(long))]
     pub verbose: bool,
@@ -55,6 +99,8 @@ pub enum OutputFormat {
     Json,
     Text,
     Sarif,
+    Ndjson,
+    GitlabCodeQuality,
 }
 
 #[allow(unused)]
@@ -73,3 +119,11 @@ impl std::str::FromStr for OutputDestination {
         }
     }
 }
+
+#[allow(unused)]
+fn parse_diagnostic_code(s: &str) -> Result<DiagnosticCode, String> {
+    match s.parse::<DiagnosticCode>() {
+        Ok(code) if code != DiagnosticCode::None => Ok(code),
+        _ => Err(format!("unknown diagnostic code `{}`", s)),
+    }
+}
@@ -0,0 +1,531 @@
+use std::{
+    io::Write,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use emmylua_code_analysis::{
+    DiagnosticCode, EmmyLuaAnalysis, FileId, LuaDocument, SemanticModel, build_diagnostic_fix,
+    is_safe_diagnostic_fix, write_file_with_encoding,
+};
+use lsp_types::{Diagnostic, NumberOrString, Position};
+use similar::TextDiff;
+use tokio_util::sync::CancellationToken;
+
+use crate::{CodeFilter, cmd_args::OutputDestination};
+
+/// 修复可能产生新的可修复诊断, 例如删除变量后另一个变量也不再被使用
+const MAX_FIX_PASSES: usize = 10;
+
+/// `--fix`: 在文件中应用安全的自动修复, 返回应用的修复数量
+pub fn fix_files(
+    analysis: &mut EmmyLuaAnalysis,
+    file_ids: &[FileId],
+    code_filter: &CodeFilter,
+) -> std::io::Result<usize> {
+    let encoding = analysis.get_emmyrc().workspace.encoding.clone();
+    let fixed_files = apply_fix_passes(analysis, file_ids, code_filter);
+    for file in &fixed_files.files {
+        write_file_with_encoding(&file.path, &file.fixed, &encoding)?;
+    }
+
+    Ok(fixed_files.fix_count)
+}
+
+/// 修复过的文件, 保留修复前的文本用于生成补丁
+#[derive(Debug)]
+struct FixedFile {
+    path: PathBuf,
+    original: String,
+    fixed: String,
+}
+
+#[derive(Debug, Default)]
+struct FixedFiles {
+    files: Vec<FixedFile>,
+    fix_count: usize,
+}
+
+/// 每一轮只应用互不重叠的安全修复, 然后重新分析修改过的文件, 直到没有可用的修复.
+/// 修复只更新内存中的文件, `--fix` 和 `--fix-dry-run` 共用, 保证预览与写入的结果一致
+fn apply_fix_passes(
+    analysis: &mut EmmyLuaAnalysis,
+    file_ids: &[FileId],
+    code_filter: &CodeFilter,
+) -> FixedFiles {
+    let mut fixed_files = FixedFiles::default();
+    let mut pending_files = file_ids.to_vec();
+    for _ in 0..MAX_FIX_PASSES {
+        let mut updated_files = Vec::new();
+        for file_id in pending_files {
+            let Some(diagnostics) = analysis.diagnose_file(file_id, CancellationToken::new())
+            else {
+                continue;
+            };
+            let Some(semantic_model) = analysis.compilation.get_semantic_model(file_id) else {
+                continue;
+            };
+            let document = semantic_model.get_document();
+            let fixes = collect_fixes(&semantic_model, &document, code_filter.filter(diagnostics));
+            if fixes.is_empty() {
+                continue;
+            }
+
+            let path = document.get_file_path().clone();
+            let fixed = apply_fixes(document.get_text(), &fixes);
+            match fixed_files.files.iter_mut().find(|file| file.path == path) {
+                Some(file) => file.fixed = fixed.clone(),
+                None => fixed_files.files.push(FixedFile {
+                    path: path.clone(),
+                    original: document.get_text().to_string(),
+                    fixed: fixed.clone(),
+                }),
+            }
+            fixed_files.fix_count += fixes.len();
+            updated_files.push((path, Some(fixed)));
+        }
+
+        if updated_files.is_empty() {
+            break;
+        }
+        pending_files = analysis.update_files_by_path(updated_files);
+    }
+
+    fixed_files
+}
+
+/// `--fix-dry-run` 的结果, 自动修复以 unified diff 的形式输出而不写回文件
+#[derive(Debug, Default)]
+pub struct FixDiff {
+    /// 按路径排序的每个文件的补丁
+    patches: Vec<(String, String)>,
+    fix_count: usize,
+}
+
+impl FixDiff {
+    /// 与 `--fix` 相同的方式修复, 只更新内存中的文件
+    pub fn collect(
+        workspace: &Path,
+        analysis: &mut EmmyLuaAnalysis,
+        file_ids: &[FileId],
+        code_filter: &CodeFilter,
+    ) -> Self {
+        let fixed_files = apply_fix_passes(analysis, file_ids, code_filter);
+        let mut patches = fixed_files
+            .files
+            .iter()
+            .map(|file| {
+                let path = get_relative_path(workspace, &file.path);
+                let patch = TextDiff::from_lines(&file.original, &file.fixed)
+                    .unified_diff()
+                    .header(&format!("a/{}", path), &format!("b/{}", path))
+                    .to_string();
+                (path, patch)
+            })
+            .collect::<Vec<_>>();
+        patches.sort_by(|a, b| a.0.cmp(&b.0));
+        Self {
+            patches,
+            fix_count: fixed_files.fix_count,
+        }
+    }
+
+    /// 可以自动修复的诊断数量
+    pub fn fix_count(&self) -> usize {
+        self.fix_count
+    }
+
+    pub fn write(&self, output: &OutputDestination) -> std::io::Result<()> {
+        let patch = self
+            .patches
+            .iter()
+            .map(|(_, patch)| patch.as_str())
+            .collect::<String>();
+        match output {
+            OutputDestination::Stdout => std::io::stdout().write_all(patch.as_bytes()),
+            OutputDestination::File(path) => {
+                if let Some(parent) = path.parent()
+                    && !parent.exists()
+                {
+                    std::fs::create_dir_all(parent)?;
+                }
+                std::fs::write(path, patch)
+            }
+        }
+    }
+}
+
+/// 一条诊断的所有编辑, 以字节偏移表示
+type FixEdits = Vec<(usize, usize, String)>;
+
+/// 只收集不改变代码行为的修复, 与已收集的编辑重叠的修复会被跳过,
+/// 同一诊断的编辑要么全部应用, 要么全部跳过
+fn collect_fixes(
+    semantic_model: &SemanticModel,
+    document: &LuaDocument,
+    diagnostics: Vec<Diagnostic>,
+) -> Vec<FixEdits> {
+    let mut fixes: Vec<FixEdits> = Vec::new();
+    for diagnostic in diagnostics {
+        let Some(NumberOrString::String(name)) = &diagnostic.code else {
+            continue;
+        };
+        let Ok(code) = DiagnosticCode::from_str(name) else {
+            continue;
+        };
+        if !is_safe_diagnostic_fix(code) {
+            continue;
+        }
+        let Some(text_edits) =
+            build_diagnostic_fix(semantic_model, code, diagnostic.range, &diagnostic.data)
+        else {
+            continue;
+        };
+        let Some(edits) = text_edits
+            .into_iter()
+            .map(|edit| {
+                Some((
+                    to_offset(document, edit.range.start)?,
+                    to_offset(document, edit.range.end)?,
+                    edit.new_text,
+                ))
+            })
+            .collect::<Option<Vec<_>>>()
+        else {
+            continue;
+        };
+
+        let is_overlapped = edits.iter().any(|(start, end, _)| {
+            fixes.iter().flatten().any(|(other_start, other_end, _)| {
+                start == other_start || (start < other_end && other_start < end)
+            })
+        });
+        if !is_overlapped {
+            fixes.push(edits);
+        }
+    }
+
+    fixes
+}
+
+fn apply_fixes(text: &str, fixes: &[FixEdits]) -> String {
+    let mut edits = fixes.iter().flatten().collect::<Vec<_>>();
+    // 从后向前应用, 前面的偏移不受影响
+    edits.sort_by_key(|(start, _, _)| std::cmp::Reverse(*start));
+    let mut result = text.to_string();
+    for (start, end, new_text) in edits {
+        result.replace_range(*start..*end, new_text);
+    }
+    result
+}
+
+/// 超出最后一行的位置视为文件末尾, 例如删除最后一行时的结束位置
+fn to_offset(document: &LuaDocument, position: Position) -> Option<usize> {
+    match document.get_offset(position.line as usize, position.character as usize) {
+        Some(offset) => Some(offset.into()),
+        None if position.line as usize >= document.get_line_count() => {
+            Some(document.get_text().len())
+        }
+        None => None,
+    }
+}
+
+fn get_relative_path(workspace: &Path, file_path: &Path) -> String {
+    file_path
+        .strip_prefix(workspace)
+        .unwrap_or(file_path)
+        .to_string_lossy()
+        .replace('\\', "/")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_lib::{TestWorkspace, diagnostic_codes};
+
+    #[test]
+    fn test_apply_fixes() {
+        let fixes = vec![
+            vec![(0, 5, "local".to_string())],
+            vec![(6, 7, String::new()), (10, 10, "!".to_string())],
+        ];
+        assert_eq!(apply_fixes("LOCAL ab = 1", &fixes), "local b =! 1");
+    }
+
+    #[tokio::test]
+    async fn test_fix_dry_run() {
+        let workspace = TestWorkspace::new(
+            "fix_dry_run",
+            &[
+                ("lib.lua", "return {}\n"),
+                (
+                    "main.lua",
+                    "local lib = require(\"lib\")\nlocal unused_value = 1\nprint(\"done\")\n",
+                ),
+            ],
+        );
+        let (mut analysis, file_ids) = workspace.load().await;
+        let code_filter = CodeFilter::default();
+        let fix_diff = FixDiff::collect(&workspace.root, &mut analysis, &file_ids, &code_filter);
+
+        assert_eq!(fix_diff.fix_count(), 2);
+        assert_eq!(fix_diff.patches.len(), 1);
+        let (path, patch) = &fix_diff.patches[0];
+        assert_eq!(path, "main.lua");
+        assert_eq!(
+            patch,
+            "--- a/main.lua\n+++ b/main.lua\n@@ -1,3 +1 @@\n-local lib = require(\"lib\")\n-local unused_value = 1\n print(\"done\")\n"
+        );
+        // 预览不修改文件
+        assert!(
+            std::fs::read_to_string(workspace.root.join("main.lua"))
+                .unwrap()
+                .starts_with("local lib")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_fix_dry_run_safe_only() {
+        let code = r#"---@class FixA
+local A = {}
+function A:method() end
+A.method()
+
+local a = 1
+local b = a
+print(A)
+"#;
+        let workspace = TestWorkspace::new("fix_dry_run_safe", &[("main.lua", code)]);
+        let (mut analysis, file_ids) = workspace.load().await;
+        let code_filter = CodeFilter::default();
+        // 不预览 `A.method()` 等不安全的修复, 删除 `b` 后在下一轮删除 `a`
+        let fix_diff = FixDiff::collect(&workspace.root, &mut analysis, &file_ids, &code_filter);
+        assert_eq!(fix_diff.fix_count(), 2);
+        assert_eq!(
+            fix_diff.patches,
+            vec![(
+                "main.lua".to_string(),
+                "--- a/main.lua\n+++ b/main.lua\n@@ -3,6 +3,4 @@\n function A:method() end\n A.method()\n \n-local a = 1\n-local b = a\n print(A)\n".to_string()
+            )]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_fix_dry_run_matches_fix_files() {
+        let files = [
+            ("lib.lua", "return {}\n"),
+            (
+                "main.lua",
+                "-- header\nlocal lib = require(\"lib\")\nlocal a = 1\nlocal b = a; print(\"done\")\n",
+            ),
+            (
+                "method.lua",
+                "---@class FixB\nlocal B = {}\nfunction B:method() end\nB.method()\nlocal unused_value = 1\n",
+            ),
+        ];
+        let code_filter = CodeFilter::default();
+        let workspace = TestWorkspace::new("fix_dry_run_matches", &files);
+
+        let (mut analysis, file_ids) = workspace.load().await;
+        let fix_diff = FixDiff::collect(&workspace.root, &mut analysis, &file_ids, &code_filter);
+
+        let (mut analysis, file_ids) = workspace.load().await;
+        let fix_count = fix_files(&mut analysis, &file_ids, &code_filter).unwrap();
+        assert_eq!(fix_diff.fix_count(), fix_count);
+
+        // 预览的补丁与 `--fix` 写入文件的修改一致
+        let written = files
+            .iter()
+            .filter_map(|(path, original)| {
+                let fixed = workspace.read(path);
+                (fixed != *original).then(|| {
+                    let patch = TextDiff::from_lines(*original, fixed.as_str())
+                        .unified_diff()
+                        .header(&format!("a/{}", path), &format!("b/{}", path))
+                        .to_string();
+                    (path.to_string(), patch)
+                })
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(written.len(), 2);
+        assert_eq!(fix_diff.patches, written);
+    }
+
+    #[tokio::test]
+    async fn test_fix_files() {
+        let workspace = TestWorkspace::new(
+            "fix_files",
+            &[
+                ("lib.lua", "return {}\n"),
+                (
+                    "main.lua",
+                    "local lib = require(\"lib\")\nlocal a = 1\nlocal b = a\nprint(\"done\")\n",
+                ),
+            ],
+        );
+        let (mut analysis, file_ids) = workspace.load().await;
+        let code_filter = CodeFilter::default();
+        // 删除 `b` 后 `a` 也不再被使用, 在下一轮中删除
+        let fix_count = fix_files(&mut analysis, &file_ids, &code_filter).unwrap();
+        assert_eq!(fix_count, 3);
+        assert_eq!(workspace.read("main.lua"), "print(\"done\")\n");
+        assert_eq!(workspace.read("lib.lua"), "return {}\n");
+    }
+
+    #[tokio::test]
+    async fn test_fix_files_skip_unsafe() {
+        let code = r#"---@class FixA
+local A = {}
+function A:method() end
+A.method()
+
+local obj = setmetatable({}, { __close = function() end })
+local x <close> = obj
+local y = obj
+print(A)
+"#;
+        let workspace = TestWorkspace::new("fix_files_unsafe", &[("main.lua", code)]);
+        let (mut analysis, file_ids) = workspace.load().await;
+        let code_filter = CodeFilter::default();
+        // `A.method()` 改为 `A:method()` 会改变参数, `<close>` 变量离开作用域时有副作用
+        let fix_count = fix_files(&mut analysis, &file_ids, &code_filter).unwrap();
+        assert_eq!(fix_count, 1);
+        assert_eq!(
+            workspace.read("main.lua"),
+            code.replace("local y = obj\n", "")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_fix_files_keep_comments() {
+        let header = "-- Copyright (c) Example\n-- Licensed under the MIT license\nlocal unused_value = 1\nprint(\"done\")\n";
+        let class = r#"---@class FixOptions
+---@field verbose boolean
+local options = {}
+print("done")
+"#;
+        let usage = r#"---@type FixOptions
+local opts = get_options()
+print(opts.verbose)
+"#;
+        let workspace = TestWorkspace::new(
+            "fix_files_comments",
+            &[
+                ("header.lua", header),
+                ("class.lua", class),
+                ("usage.lua", usage),
+            ],
+        );
+        let (mut analysis, file_ids) = workspace.load().await;
+        let code_filter = CodeFilter::default();
+        // 附着在语句上的文件头和类型定义不随语句删除
+        let fix_count = fix_files(&mut analysis, &file_ids, &code_filter).unwrap();
+        assert_eq!(fix_count, 2);
+        assert_eq!(
+            workspace.read("header.lua"),
+            header.replace("local unused_value = 1\n", "")
+        );
+        assert_eq!(
+            workspace.read("class.lua"),
+            class.replace("local options = {}\n", "")
+        );
+        assert_eq!(workspace.read("usage.lua"), usage);
+
+        let usage_id = file_ids
+            .iter()
+            .copied()
+            .find(|file_id| {
+                analysis
+                    .compilation
+                    .get_db()
+                    .get_vfs()
+                    .get_file_path(file_id)
+                    .is_some_and(|path| path.ends_with("usage.lua"))
+            })
+            .unwrap();
+        let diagnostics = analysis
+            .diagnose_file(usage_id, CancellationToken::new())
+            .unwrap_or_default();
+        assert!(
+            !diagnostic_codes(&diagnostics).contains(&"undefined-field".to_string()),
+            "{:?}",
+            diagnostics
+        );
+    }
+
+    #[tokio::test]
+    async fn test_fix_files_code_filter() {
+        let code = "local lib = require(\"lib\")\nlocal a = 1\n";
+        let workspace = TestWorkspace::new(
+            "fix_files_filter",
+            &[("lib.lua", "return {}\n"), ("main.lua", code)],
+        );
+        let (mut analysis, file_ids) = workspace.load().await;
+        let code_filter = CodeFilter {
+            only: Some(vec![DiagnosticCode::UnusedRequire]),
+            exclude: Vec::new(),
+        };
+        let fix_count = fix_files(&mut analysis, &file_ids, &code_filter).unwrap();
+        assert_eq!(fix_count, 1);
+        assert_eq!(workspace.read("main.lua"), "local a = 1\n");
+    }
+
+    #[tokio::test]
+    async fn test_collect_fixes_overlap() {
+        let workspace = TestWorkspace::new(
+            "collect_fixes",
+            &[("main.lua", "local a = 1\nprint(\"done\")\n")],
+        );
+        let (analysis, file_ids) = workspace.load().await;
+        let semantic_model = analysis
+            .compilation
+            .get_semantic_model(file_ids[0])
+            .unwrap();
+        let document = semantic_model.get_document();
+        let diagnostics = analysis
+            .diagnose_file(file_ids[0], CancellationToken::new())
+            .unwrap();
+        assert_eq!(diagnostics.len(), 1);
+
+        // 同一修复重复出现时只保留第一个
+        let fixes = collect_fixes(
+            &semantic_model,
+            &document,
+            vec![diagnostics[0].clone(), diagnostics[0].clone()],
+        );
+        assert_eq!(fixes, vec![vec![(0, 12, String::new())]]);
+    }
+
+    #[tokio::test]
+    async fn test_fix_files_keep_encoding() {
+        let code_filter = CodeFilter::default();
+        // "你好" 的 gbk 编码
+        let gbk_text = [0xc4, 0xe3, 0xba, 0xc3];
+        let workspace = TestWorkspace::new(
+            "fix_files_gbk",
+            &[(".emmyrc.json", r#"{ "workspace": { "encoding": "gbk" } }"#)],
+        );
+        let path = workspace.root.join("main.lua");
+        std::fs::write(
+            &path,
+            [b"local a = 1\nprint(\"".as_slice(), &gbk_text, b"\")\n"].concat(),
+        )
+        .unwrap();
+        let (mut analysis, file_ids) = workspace.load().await;
+        let fix_count = fix_files(&mut analysis, &file_ids, &code_filter).unwrap();
+        assert_eq!(fix_count, 1);
+        assert_eq!(
+            std::fs::read(&path).unwrap(),
+            [b"print(\"".as_slice(), &gbk_text, b"\")\n"].concat()
+        );
+
+        let workspace = TestWorkspace::new(
+            "fix_files_bom",
+            &[("main.lua", "\u{feff}local a = 1\nprint(\"你好\")\n")],
+        );
+        let (mut analysis, file_ids) = workspace.load().await;
+        let fix_count = fix_files(&mut analysis, &file_ids, &code_filter).unwrap();
+        assert_eq!(fix_count, 1);
+        assert_eq!(workspace.read("main.lua"), "\u{feff}print(\"你好\")\n");
+    }
+}
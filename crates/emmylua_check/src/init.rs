@@ -1,9 +1,13 @@
 use emmylua_code_analysis::{
-    EmmyLuaAnalysis, WorkspaceFolder, collect_workspace_files, load_configs, update_code_style,
+    DIRECTORY_CONFIG_FILE_NAME, EmmyLuaAnalysis, WorkspaceFolder, collect_workspace_files,
+    is_directory_config_file, load_configs, load_directory_configs, update_code_style,
 };
 use fern::Dispatch;
 use log::LevelFilter;
-use std::path::{Path, PathBuf};
+use std::{
+    path::{Path, PathBuf},
+    sync::Arc,
+};
 
 fn root_from_configs(config_paths: &[PathBuf], fallback: &Path) -> PathBuf {
     if config_paths.len() != 1 {
@@ -58,30 +62,57 @@ pub fn setup_logger(verbose: bool) {
     }
 }
 
+/// 返回要加载的配置文件以及配置中相对路径的根目录
+pub fn find_config_files(
+    main_path: &Path,
+    config_paths: Option<Vec<PathBuf>>,
+) -> (Vec<PathBuf>, PathBuf) {
+    if let Some(config_paths) = config_paths {
+        let config_root = root_from_configs(&config_paths, main_path);
+        (config_paths, config_root)
+    } else {
+        (
+            vec![
+                main_path.join(".luarc.json"),
+                main_path.join(".emmyrc.json"),
+            ]
+            .into_iter()
+            .filter(|path| path.exists())
+            .collect(),
+            main_path.to_path_buf(),
+        )
+    }
+}
+
+/// 工作区子目录中的 `.emmyrc.json`, 与加载工作区时收集的文件相同
+pub fn find_directory_config_files(
+    main_path: &Path,
+    config_root: &Path,
+    config_files: &[PathBuf],
+) -> Vec<PathBuf> {
+    let mut emmyrc = load_configs(config_files.to_vec(), None);
+    emmyrc.pre_process_emmyrc(config_root);
+    let mut files = collect_workspace_files(
+        &[WorkspaceFolder::new(main_path.to_path_buf(), false)],
+        &emmyrc,
+        Some(vec![format!("**/{}", DIRECTORY_CONFIG_FILE_NAME)]),
+        None,
+    )
+    .into_iter()
+    .map(|file| PathBuf::from(file.path))
+    .filter(|path| is_directory_config_file(path) && path.parent() != Some(main_path))
+    .collect::<Vec<_>>();
+    files.sort();
+    files
+}
+
 pub async fn load_workspace(
     main_path: PathBuf,
     cmd_workspace_folders: Vec<PathBuf>,
     config_paths: Option<Vec<PathBuf>>,
     ignore: Option<Vec<String>>,
 ) -> Option<EmmyLuaAnalysis> {
-    let (config_files, config_root): (Vec<PathBuf>, PathBuf) =
-        if let Some(config_paths) = config_paths {
-            (
-                config_paths.clone(),
-                root_from_configs(&config_paths, &main_path),
-            )
-        } else {
-            (
-                vec![
-                    main_path.join(".luarc.json"),
-                    main_path.join(".emmyrc.json"),
-                ]
-                .into_iter()
-                .filter(|path| path.exists())
-                .collect(),
-                main_path.clone(),
-            )
-        };
+    let (config_files, config_root) = find_config_files(&main_path, config_paths);
 
     let mut emmyrc = load_configs(config_files, None);
     log::info!(
@@ -112,7 +143,35 @@ pub async fn load_workspace(
         analysis.add_main_workspace(PathBuf::from(root));
     }
 
-    let file_infos = collect_workspace_files(&workspace_folders, &analysis.emmyrc, None, ignore);
+    let file_infos = collect_workspace_files(
+        &workspace_folders,
+        &analysis.emmyrc,
+        Some(vec![format!("**/{}", DIRECTORY_CONFIG_FILE_NAME)]),
+        ignore,
+    );
+    let (config_files, file_infos): (Vec<_>, Vec<_>) = file_infos
+        .into_iter()
+        .partition(|file| is_directory_config_file(Path::new(&file.path)));
+
+    // 子目录中的 `.emmyrc.json` 覆盖其下文件的诊断配置
+    let main_roots = workspace_folders
+        .iter()
+        .filter(|workspace| !workspace.is_library)
+        .map(|workspace| workspace.root.clone())
+        .collect::<Vec<_>>();
+    let directory_configs = load_directory_configs(
+        &main_roots,
+        config_files
+            .into_iter()
+            .map(|file| PathBuf::from(file.path))
+            .collect(),
+        &emmyrc,
+    )
+    .into_iter()
+    .map(|(dir, config)| (dir, Arc::new(config)))
+    .collect();
+    analysis.update_directory_configs(directory_configs);
+
     let files = file_infos
         .into_iter()
         .filter_map(|file| {
@@ -1,11 +1,23 @@
+mod baseline;
 pub mod cmd_args;
+mod fix;
 mod init;
 mod output;
 mod terminal_display;
+#[cfg(test)]
+mod test_lib;
 
+use baseline::Baseline;
 pub use cmd_args::*;
+use emmylua_code_analysis::{DiagnosticCode, DiagnosticStats, check_config_files};
+use fix::FixDiff;
+use lsp_types::{Diagnostic, NumberOrString};
 use output::output_result;
-use std::{error::Error, sync::Arc};
+use std::{
+    error::Error,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
 use tokio_util::sync::CancellationToken;
 
 use crate::init::setup_logger;
@@ -30,7 +42,11 @@ pub async fn run_check(cmd_args: CmdArgs) -> Result<(), Box<dyn Error + Sync + S
         .ok_or("Failed to load workspace")?
         .clone();
 
-    let analysis = match init::load_workspace(
+    if cmd_args.config_check {
+        return check_config(&main_path, cmd_args.config);
+    }
+
+    let mut analysis = match init::load_workspace(
         main_path.clone(),
         workspaces.clone(),
         cmd_args.config,
@@ -44,8 +60,35 @@ pub async fn run_check(cmd_args: CmdArgs) -> Result<(), Box<dyn Error + Sync + S
         }
     };
 
+    let code_filter = Arc::new(CodeFilter {
+        only: cmd_args.only_code.clone(),
+        exclude: cmd_args.exclude_code.clone().unwrap_or_default(),
+    });
+
     let db = analysis.compilation.get_db();
-    let need_check_files = db.get_module_index().get_main_workspace_file_ids();
+    let need_check_files = db
+        .get_module_index()
+        .get_main_workspace_file_ids()
+        .into_iter()
+        .filter(|file_id| !db.is_ignored(file_id))
+        .collect::<Vec<_>>();
+
+    if cmd_args.fix {
+        let fix_count = fix::fix_files(&mut analysis, &need_check_files, &code_filter)?;
+        eprintln!("Applied {} fixes", fix_count);
+    }
+
+    if cmd_args.fix_dry_run {
+        let fix_diff = FixDiff::collect(&main_path, &mut analysis, &need_check_files, &code_filter);
+        fix_diff.write(&cmd_args.output)?;
+        eprintln!("{} fixes available", fix_diff.fix_count());
+        return Ok(());
+    }
+
+    // 修复时的诊断不计入统计, 被过滤掉的诊断代码也不统计
+    let stats = cmd_args
+        .stats
+        .then(|| analysis.diagnostic.enable_stats(code_filter.stats()));
 
     let (sender, receiver) = tokio::sync::mpsc::channel(100);
     let analysis = Arc::new(analysis);
@@ -53,13 +96,39 @@ pub async fn run_check(cmd_args: CmdArgs) -> Result<(), Box<dyn Error + Sync + S
     for file_id in need_check_files.clone() {
         let sender = sender.clone();
         let analysis = analysis.clone();
+        let code_filter = code_filter.clone();
         tokio::spawn(async move {
             let cancel_token = CancellationToken::new();
-            let diagnostics = analysis.diagnose_file(file_id, cancel_token);
+            let diagnostics = analysis
+                .diagnose_file(file_id, cancel_token)
+                .map(|diagnostics| code_filter.filter(diagnostics));
             sender.send((file_id, diagnostics)).await.unwrap();
         });
     }
 
+    if cmd_args.write_baseline {
+        let baseline_path = cmd_args
+            .baseline
+            .ok_or("--write-baseline requires --baseline <file>")?;
+        let baseline = Baseline::collect(main_path, need_check_files.len(), db, receiver).await;
+        print_stats(stats.as_deref());
+        baseline.save(&baseline_path)?;
+        eprintln!("Baseline written to {}", baseline_path.display());
+        return Ok(());
+    }
+
+    let baseline = match &cmd_args.baseline {
+        Some(path) if path.exists() => Some(Baseline::load(main_path.clone(), path)?),
+        Some(path) => {
+            eprintln!(
+                "Baseline file {} not found, all diagnostics will be reported",
+                path.display()
+            );
+            None
+        }
+        None => None,
+    };
+
     let exit_code = output_result(
         need_check_files.len(),
         db,
@@ -68,8 +137,10 @@ pub async fn run_check(cmd_args: CmdArgs) -> Result<(), Box<dyn Error + Sync + S
         cmd_args.output_format,
         cmd_args.output,
         cmd_args.warnings_as_errors,
+        baseline,
     )
     .await;
+    print_stats(stats.as_deref());
 
     if exit_code != 0 {
         return Err(format!("exit code: {}", exit_code).into());
@@ -78,3 +149,282 @@ pub async fn run_check(cmd_args: CmdArgs) -> Result<(), Box<dyn Error + Sync + S
     eprintln!("Check finished");
     Ok(())
 }
+
+/// `--config-check`: 只校验配置文件, 每个问题一行, 存在问题时以错误退出
+fn check_config(
+    main_path: &Path,
+    config_paths: Option<Vec<PathBuf>>,
+) -> Result<(), Box<dyn Error + Sync + Send>> {
+    let (mut config_files, config_root) = init::find_config_files(main_path, config_paths);
+    config_files.extend(init::find_directory_config_files(
+        main_path,
+        &config_root,
+        &config_files,
+    ));
+    if config_files.is_empty() {
+        eprintln!("No config file found in {}", main_path.display());
+        return Ok(());
+    }
+
+    let problems = check_config_files(&config_files);
+    for problem in &problems {
+        if problem.path.is_empty() {
+            println!("{}: {}", problem.file.display(), problem.message);
+        } else {
+            println!(
+                "{}: {}: {}",
+                problem.file.display(),
+                problem.path,
+                problem.message
+            );
+        }
+    }
+
+    if !problems.is_empty() {
+        return Err(format!("{} config problems found", problems.len()).into());
+    }
+
+    eprintln!("Config check finished, no problems found");
+    Ok(())
+}
+
+/// 由 `--only-code` 和 `--exclude-code` 指定的诊断过滤
+#[derive(Default)]
+struct CodeFilter {
+    only: Option<Vec<DiagnosticCode>>,
+    exclude: Vec<DiagnosticCode>,
+}
+
+impl CodeFilter {
+    fn filter(&self, diagnostics: Vec<Diagnostic>) -> Vec<Diagnostic> {
+        if self.only.is_none() && self.exclude.is_empty() {
+            return diagnostics;
+        }
+
+        diagnostics
+            .into_iter()
+            .filter(|diagnostic| self.is_reported(diagnostic))
+            .collect()
+    }
+
+    fn stats(&self) -> DiagnosticStats {
+        if self.only.is_none() && self.exclude.is_empty() {
+            return DiagnosticStats::new();
+        }
+
+        DiagnosticStats::with_codes(
+            DiagnosticCode::all()
+                .iter()
+                .copied()
+                .filter(|code| self.is_code_reported(code)),
+        )
+    }
+
+    fn is_code_reported(&self, code: &DiagnosticCode) -> bool {
+        self.only.as_ref().is_none_or(|only| only.contains(code)) && !self.exclude.contains(code)
+    }
+
+    fn is_reported(&self, diagnostic: &Diagnostic) -> bool {
+        let Some(NumberOrString::String(name)) = &diagnostic.code else {
+            return self.only.is_none();
+        };
+        let is_match = |code: &DiagnosticCode| code.get_name() == name;
+        if let Some(only) = &self.only
+            && !only.iter().any(is_match)
+        {
+            return false;
+        }
+
+        !self.exclude.iter().any(is_match)
+    }
+}
+
+fn print_stats(stats: Option<&DiagnosticStats>) {
+    let Some(stats) = stats else {
+        return;
+    };
+
+    let timings = stats.get_sorted_timings();
+    let name_width = timings
+        .iter()
+        .map(|(name, _)| name.len())
+        .max()
+        .unwrap_or_default()
+        .max("Checker".len());
+    eprintln!("{:<name_width$}  {:>12}  {:>8}", "Checker", "Time", "Files");
+    for (name, timing) in timings {
+        eprintln!(
+            "{:<name_width$}  {:>12}  {:>8}",
+            name,
+            format!("{:.3?}", timing.elapsed),
+            timing.file_count
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn make_diagnostic(code: Option<&str>) -> Diagnostic {
+        Diagnostic {
+            code: code.map(|code| NumberOrString::String(code.to_string())),
+            ..Default::default()
+        }
+    }
+
+    fn filter_codes(code_filter: &CodeFilter) -> Vec<Option<String>> {
+        let diagnostics = vec![
+            make_diagnostic(Some("undefined-global")),
+            make_diagnostic(Some("unused")),
+            make_diagnostic(Some("syntax-error")),
+            make_diagnostic(None),
+        ];
+        code_filter
+            .filter(diagnostics)
+            .into_iter()
+            .map(|diagnostic| match diagnostic.code {
+                Some(NumberOrString::String(code)) => Some(code),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_code_filter_only() {
+        let code_filter = CodeFilter {
+            only: Some(vec![
+                DiagnosticCode::UndefinedGlobal,
+                DiagnosticCode::Unused,
+            ]),
+            exclude: Vec::new(),
+        };
+        assert_eq!(
+            filter_codes(&code_filter),
+            vec![
+                Some("undefined-global".to_string()),
+                Some("unused".to_string())
+            ]
+        );
+    }
+
+    #[test]
+    fn test_code_filter_exclude() {
+        let code_filter = CodeFilter {
+            only: None,
+            exclude: vec![DiagnosticCode::Unused],
+        };
+        assert_eq!(
+            filter_codes(&code_filter),
+            vec![
+                Some("undefined-global".to_string()),
+                Some("syntax-error".to_string()),
+                None
+            ]
+        );
+    }
+
+    #[test]
+    fn test_code_filter_empty() {
+        let code_filter = CodeFilter::default();
+        assert_eq!(filter_codes(&code_filter).len(), 4);
+    }
+
+    #[test]
+    fn test_code_filter_stats() {
+        let code_filter = CodeFilter {
+            only: Some(vec![
+                DiagnosticCode::UndefinedGlobal,
+                DiagnosticCode::Unused,
+            ]),
+            exclude: vec![DiagnosticCode::Unused],
+        };
+        let stats = code_filter.stats();
+        assert!(stats.is_tracked(&[DiagnosticCode::UndefinedGlobal]));
+        assert!(!stats.is_tracked(&[DiagnosticCode::Unused]));
+        assert!(!stats.is_tracked(&[DiagnosticCode::SyntaxError]));
+    }
+
+    #[cfg(feature = "cli")]
+    #[test]
+    fn test_parse_diagnostic_code() {
+        use clap::Parser;
+
+        let args = CmdArgs::try_parse_from([
+            "emmylua_check",
+            "--only-code",
+            "undefined-global,unused",
+            ".",
+        ])
+        .unwrap();
+        assert_eq!(
+            args.only_code,
+            Some(vec![
+                DiagnosticCode::UndefinedGlobal,
+                DiagnosticCode::Unused
+            ])
+        );
+
+        assert!(
+            CmdArgs::try_parse_from(["emmylua_check", "--exclude-code", "no-such-code", "."])
+                .is_err()
+        );
+        assert!(
+            CmdArgs::try_parse_from([
+                "emmylua_check",
+                "--only-code",
+                "unused",
+                "--exclude-code",
+                "unused",
+                ".",
+            ])
+            .is_err()
+        );
+    }
+
+    #[cfg(feature = "cli")]
+    #[test]
+    fn test_stats_conflicts_with_fix_dry_run() {
+        use clap::Parser;
+
+        assert!(
+            CmdArgs::try_parse_from(["emmylua_check", "--fix-dry-run", "--stats", "."]).is_err()
+        );
+        assert!(CmdArgs::try_parse_from(["emmylua_check", "--fix", "--stats", "."]).is_ok());
+    }
+
+    #[test]
+    fn test_check_config() {
+        let workspace = test_lib::TestWorkspace::new(
+            "config_check",
+            &[
+                (
+                    ".emmyrc.json",
+                    r#"{ "diagnostics": { "disable": ["unused"] } }"#,
+                ),
+                (
+                    "good/.emmyrc.json",
+                    r#"{ "diagnostics": { "disable": ["unused"] } }"#,
+                ),
+                ("good/main.lua", "print(1)\n"),
+                (
+                    "bad/.emmyrc.json",
+                    r#"{ "diagnostics": { "disable": ["no-such-code"] }, "colour": true }"#,
+                ),
+            ],
+        );
+        // 子目录中的配置文件也会被检查
+        assert!(check_config(&workspace.root, None).is_err());
+        assert!(check_config(&workspace.root.join("good"), None).is_ok());
+        assert!(check_config(&workspace.root.join("bad"), None).is_err());
+        assert!(
+            check_config(
+                &workspace.root,
+                Some(vec![workspace.root.join("bad/.emmyrc.json")])
+            )
+            .is_err()
+        );
+        // 没有配置文件时不是错误
+        assert!(check_config(&workspace.root.join("missing"), None).is_ok());
+    }
+}
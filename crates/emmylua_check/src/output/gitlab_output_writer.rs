@@ -0,0 +1,182 @@
+use std::{
+    collections::HashMap,
+    fs::File,
+    io::Write,
+    path::{Path, PathBuf},
+};
+
+use emmylua_code_analysis::{DbIndex, FileId};
+use lsp_types::{Diagnostic, DiagnosticSeverity};
+use serde_json::{Value, json};
+
+use crate::cmd_args::OutputDestination;
+
+use super::OutputWriter;
+
+/// GitLab Code Quality report, see
+/// https://docs.gitlab.com/ci/testing/code_quality/#code-quality-report-format
+#[derive(Debug)]
+pub struct GitlabOutputWriter {
+    output: Option<File>,
+    workspace: PathBuf,
+    issues: Vec<Value>,
+    fingerprint_counts: HashMap<String, usize>,
+}
+
+impl GitlabOutputWriter {
+    pub fn new(workspace: PathBuf, output: OutputDestination) -> Self {
+        let output = match output {
+            OutputDestination::Stdout => None,
+            OutputDestination::File(path) => {
+                if let Some(parent) = path.parent()
+                    && !parent.exists()
+                {
+                    std::fs::create_dir_all(parent).unwrap();
+                }
+                Some(std::fs::File::create(path).unwrap())
+            }
+        };
+
+        GitlabOutputWriter {
+            output,
+            workspace,
+            issues: Vec::new(),
+            fingerprint_counts: HashMap::new(),
+        }
+    }
+
+    fn get_gitlab_severity(severity: Option<DiagnosticSeverity>) -> &'static str {
+        match severity {
+            Some(DiagnosticSeverity::ERROR) => "critical",
+            Some(DiagnosticSeverity::WARNING) => "major",
+            Some(DiagnosticSeverity::INFORMATION) => "minor",
+            _ => "info",
+        }
+    }
+
+    /// 指纹只由诊断代码, 文件路径和行号决定, 使 GitLab 能够在多次运行之间追踪同一个问题.
+    /// 同一行上相同代码的诊断按出现顺序区分
+    fn make_fingerprint(&mut self, check_name: &str, path: &str, line: u32) -> String {
+        let key = format!("{}:{}:{}", check_name, path, line);
+        let count = self.fingerprint_counts.entry(key.clone()).or_insert(0);
+        let key = if *count == 0 {
+            key
+        } else {
+            format!("{}:{}", key, count)
+        };
+        *count += 1;
+
+        format!("{:016x}", fnv1a_hash(key.as_bytes()))
+    }
+
+    fn get_relative_path(&self, file_path: &Path) -> String {
+        file_path
+            .strip_prefix(&self.workspace)
+            .unwrap_or(file_path)
+            .to_string_lossy()
+            .replace('\\', "/")
+    }
+}
+
+/// 64 位 FNV-1a, 结果不依赖于 Rust 版本和运行平台
+fn fnv1a_hash(bytes: &[u8]) -> u64 {
+    let mut hash: u64 = 0xcbf29ce484222325;
+    for byte in bytes {
+        hash ^= *byte as u64;
+        hash = hash.wrapping_mul(0x100000001b3);
+    }
+    hash
+}
+
+impl OutputWriter for GitlabOutputWriter {
+    fn write(&mut self, db: &DbIndex, file_id: FileId, diagnostics: Vec<Diagnostic>) {
+        if diagnostics.is_empty() {
+            return;
+        }
+
+        let file_path = db.get_vfs().get_file_path(&file_id).unwrap();
+        let path = self.get_relative_path(file_path);
+        for diagnostic in diagnostics {
+            let check_name = diagnostic
+                .code
+                .as_ref()
+                .map(|code| match code {
+                    lsp_types::NumberOrString::Number(n) => n.to_string(),
+                    lsp_types::NumberOrString::String(s) => s.clone(),
+                })
+                .unwrap_or_else(|| "unknown".to_string());
+            // GitLab uses 1-based line numbers
+            let line = diagnostic.range.start.line + 1;
+            let fingerprint = self.make_fingerprint(&check_name, &path, line);
+            self.issues.push(json!({
+                "description": diagnostic.message,
+                "check_name": check_name,
+                "fingerprint": fingerprint,
+                "severity": Self::get_gitlab_severity(diagnostic.severity),
+                "location": {
+                    "path": path,
+                    "lines": {
+                        "begin": line
+                    }
+                }
+            }));
+        }
+    }
+
+    fn finish(&mut self) {
+        let pretty_json = serde_json::to_string_pretty(&self.issues).unwrap();
+
+        if let Some(output) = self.output.as_mut() {
+            output.write_all(pretty_json.as_bytes()).unwrap();
+        } else {
+            println!("{}", pretty_json);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_lib::{TestWorkspace, diagnose_files};
+
+    #[tokio::test]
+    async fn test_gitlab_report() {
+        let workspace = TestWorkspace::new(
+            "gitlab_report",
+            &[("src/main.lua", "\nprint(undefined_a, undefined_a)\n")],
+        );
+        let (analysis, file_ids) = workspace.load().await;
+        let db = analysis.compilation.get_db();
+        let report_path = workspace.root.join("out/report.json");
+        let mut writer = GitlabOutputWriter::new(
+            workspace.root.clone(),
+            OutputDestination::File(report_path.clone()),
+        );
+        let mut receiver = diagnose_files(&analysis, &file_ids);
+        while let Ok((file_id, diagnostics)) = receiver.try_recv() {
+            writer.write(db, file_id, diagnostics.unwrap());
+        }
+        writer.finish();
+        drop(writer);
+
+        let report: Vec<Value> =
+            serde_json::from_str(&std::fs::read_to_string(report_path).unwrap()).unwrap();
+        assert_eq!(report.len(), 2);
+        for issue in &report {
+            assert_eq!(issue["check_name"], "undefined-global");
+            assert_eq!(issue["severity"], "critical");
+            assert_eq!(issue["location"]["path"], "src/main.lua");
+            assert_eq!(issue["location"]["lines"]["begin"], 2);
+            assert_eq!(issue["fingerprint"].as_str().unwrap().len(), 16);
+        }
+        // 同一行上相同代码的诊断也有不同的指纹
+        assert_ne!(report[0]["fingerprint"], report[1]["fingerprint"]);
+
+        // 指纹在多次运行之间保持不变
+        let mut writer = GitlabOutputWriter::new(workspace.root.clone(), OutputDestination::Stdout);
+        assert_eq!(
+            writer.make_fingerprint("undefined-global", "src/main.lua", 2),
+            report[0]["fingerprint"].as_str().unwrap()
+        );
+    }
+}
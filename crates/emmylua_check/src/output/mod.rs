@@ -1,4 +1,6 @@
+mod gitlab_output_writer;
 mod json_output_writer;
+mod ndjson_output_writer;
 mod sarif_output_writer;
 mod text_output_writer;
 
@@ -8,12 +10,13 @@ use emmylua_code_analysis::{DbIndex, FileId};
 use lsp_types::Diagnostic;
 use tokio::sync::mpsc::Receiver;
 
+use crate::baseline::Baseline;
 use crate::cmd_args::{OutputDestination, OutputFormat};
 
 use crate::terminal_display::TerminalDisplay;
 
 /// Type alias for diagnostic result channel
-type DiagnosticReceiver = Receiver<(FileId, Option<Vec<Diagnostic>>)>;
+pub(crate) type DiagnosticReceiver = Receiver<(FileId, Option<Vec<Diagnostic>>)>;
 
 pub async fn output_result(
     total_count: usize,
@@ -23,6 +26,7 @@ pub async fn output_result(
     output_format: OutputFormat,
     output: OutputDestination,
     warnings_as_errors: bool,
+    mut baseline: Option<Baseline>,
 ) -> i32 {
     let mut writer: Box<dyn OutputWriter> = match output_format {
         OutputFormat::Json => Box::new(json_output_writer::JsonOutputWriter::new(output)),
@@ -30,6 +34,11 @@ pub async fn output_result(
             Box::new(text_output_writer::TextOutputWriter::new(workspace.clone()))
         }
         OutputFormat::Sarif => Box::new(sarif_output_writer::SarifOutputWriter::new(output)),
+        OutputFormat::Ndjson => Box::new(ndjson_output_writer::NdjsonOutputWriter::new(output)),
+        OutputFormat::GitlabCodeQuality => Box::new(gitlab_output_writer::GitlabOutputWriter::new(
+            workspace.clone(),
+            output,
+        )),
     };
 
     let terminal_display = TerminalDisplay::new(workspace);
@@ -42,7 +51,10 @@ pub async fn output_result(
 
     while let Some((file_id, diagnostics)) = receiver.recv().await {
         count += 1;
-        if let Some(diagnostics) = diagnostics {
+        if let Some(mut diagnostics) = diagnostics {
+            if let Some(baseline) = baseline.as_mut() {
+                diagnostics = baseline.filter(db, file_id, diagnostics);
+            }
             for diagnostic in &diagnostics {
                 match diagnostic.severity {
                     Some(lsp_types::DiagnosticSeverity::ERROR) => {
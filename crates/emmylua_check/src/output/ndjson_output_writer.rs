@@ -0,0 +1,123 @@
+use std::{
+    fs::File,
+    io::{Write, stdout},
+};
+
+use emmylua_code_analysis::{DbIndex, FileId};
+use lsp_types::{Diagnostic, DiagnosticSeverity, NumberOrString};
+use serde_json::json;
+
+use crate::cmd_args::OutputDestination;
+
+use super::OutputWriter;
+
+/// Newline delimited JSON, one diagnostic per line. Lines are written as soon as
+/// the diagnostics of a file arrive, so consumers can process them incrementally
+#[derive(Debug)]
+pub struct NdjsonOutputWriter {
+    output: Option<File>,
+}
+
+impl NdjsonOutputWriter {
+    pub fn new(output: OutputDestination) -> Self {
+        let output = match output {
+            OutputDestination::Stdout => None,
+            OutputDestination::File(path) => {
+                if let Some(parent) = path.parent()
+                    && !parent.exists()
+                {
+                    std::fs::create_dir_all(parent).unwrap();
+                }
+
+                Some(std::fs::File::create(path).unwrap())
+            }
+        };
+        NdjsonOutputWriter { output }
+    }
+
+    fn get_severity_name(severity: Option<DiagnosticSeverity>) -> &'static str {
+        match severity {
+            Some(DiagnosticSeverity::ERROR) => "error",
+            Some(DiagnosticSeverity::WARNING) => "warning",
+            Some(DiagnosticSeverity::INFORMATION) => "information",
+            Some(DiagnosticSeverity::HINT) => "hint",
+            _ => "unknown",
+        }
+    }
+}
+
+impl OutputWriter for NdjsonOutputWriter {
+    fn write(&mut self, db: &DbIndex, file_id: FileId, diagnostics: Vec<Diagnostic>) {
+        if diagnostics.is_empty() {
+            return;
+        }
+
+        let file_path = db.get_vfs().get_file_path(&file_id).unwrap();
+        let file_path = file_path.to_string_lossy();
+        let mut lines = String::new();
+        for diagnostic in diagnostics {
+            let code = diagnostic.code.as_ref().map(|code| match code {
+                NumberOrString::Number(n) => n.to_string(),
+                NumberOrString::String(s) => s.clone(),
+            });
+            let line = json!({
+                "file": file_path,
+                "code": code,
+                "severity": Self::get_severity_name(diagnostic.severity),
+                "range": diagnostic.range,
+                "message": diagnostic.message,
+            });
+            lines.push_str(&serde_json::to_string(&line).unwrap());
+            lines.push('\n');
+        }
+
+        // 每个文件写完后立即刷新, 保证下游能及时读到结果
+        if let Some(output) = self.output.as_mut() {
+            output.write_all(lines.as_bytes()).unwrap();
+            output.flush().unwrap();
+        } else {
+            let mut stdout = stdout().lock();
+            stdout.write_all(lines.as_bytes()).unwrap();
+            stdout.flush().unwrap();
+        }
+    }
+
+    fn finish(&mut self) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_lib::{TestWorkspace, diagnose_files};
+    use serde_json::Value;
+
+    #[tokio::test]
+    async fn test_ndjson_lines() {
+        let workspace = TestWorkspace::new(
+            "ndjson_lines",
+            &[("main.lua", "print(undefined_a)\nprint(undefined_b)\n")],
+        );
+        let (analysis, file_ids) = workspace.load().await;
+        let db = analysis.compilation.get_db();
+        let output_path = workspace.root.join("out/result.ndjson");
+        let mut writer = NdjsonOutputWriter::new(OutputDestination::File(output_path.clone()));
+        let mut receiver = diagnose_files(&analysis, &file_ids);
+        while let Ok((file_id, diagnostics)) = receiver.try_recv() {
+            writer.write(db, file_id, diagnostics.unwrap());
+        }
+        writer.finish();
+        drop(writer);
+
+        let content = std::fs::read_to_string(output_path).unwrap();
+        let lines = content.lines().collect::<Vec<_>>();
+        assert_eq!(lines.len(), 2);
+        for (i, line) in lines.iter().enumerate() {
+            let value: Value = serde_json::from_str(line).unwrap();
+            assert!(value["file"].as_str().unwrap().ends_with("main.lua"));
+            assert_eq!(value["code"], "undefined-global");
+            assert_eq!(value["severity"], "error");
+            assert_eq!(value["range"]["start"]["line"], i);
+            assert!(value["message"].as_str().unwrap().contains("undefined_"));
+        }
+    }
+}
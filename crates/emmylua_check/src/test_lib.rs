@@ -0,0 +1,74 @@
+use std::path::PathBuf;
+
+use emmylua_code_analysis::{EmmyLuaAnalysis, FileId};
+use lsp_types::Diagnostic;
+use tokio_util::sync::CancellationToken;
+
+use crate::{init, output::DiagnosticReceiver};
+
+/// 测试用的磁盘上的工作区, 离开作用域时删除
+pub struct TestWorkspace {
+    pub root: PathBuf,
+}
+
+impl TestWorkspace {
+    pub fn new(name: &str, files: &[(&str, &str)]) -> Self {
+        let root =
+            std::env::temp_dir().join(format!("emmylua_check_{}_{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&root);
+        for (path, content) in files {
+            let path = root.join(path);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(path, content).unwrap();
+        }
+        Self { root }
+    }
+
+    /// 与命令行相同的方式加载工作区, 返回需要检查的文件
+    pub async fn load(&self) -> (EmmyLuaAnalysis, Vec<FileId>) {
+        let analysis = init::load_workspace(self.root.clone(), vec![self.root.clone()], None, None)
+            .await
+            .unwrap();
+        let db = analysis.compilation.get_db();
+        let mut file_ids = db
+            .get_module_index()
+            .get_main_workspace_file_ids()
+            .into_iter()
+            .filter(|file_id| !db.is_ignored(file_id))
+            .collect::<Vec<_>>();
+        file_ids.sort();
+        (analysis, file_ids)
+    }
+
+    pub fn read(&self, path: &str) -> String {
+        std::fs::read_to_string(self.root.join(path)).unwrap()
+    }
+}
+
+impl Drop for TestWorkspace {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.root);
+    }
+}
+
+/// 诊断所有文件并发送到接收端, 与 `run_check` 中的诊断循环相同
+pub fn diagnose_files(analysis: &EmmyLuaAnalysis, file_ids: &[FileId]) -> DiagnosticReceiver {
+    let (sender, receiver) = tokio::sync::mpsc::channel(file_ids.len().max(1));
+    for file_id in file_ids {
+        let diagnostics = analysis.diagnose_file(*file_id, CancellationToken::new());
+        sender.try_send((*file_id, diagnostics)).unwrap();
+    }
+    receiver
+}
+
+pub fn diagnostic_codes(diagnostics: &[Diagnostic]) -> Vec<String> {
+    let mut codes = diagnostics
+        .iter()
+        .filter_map(|diagnostic| match &diagnostic.code {
+            Some(lsp_types::NumberOrString::String(code)) => Some(code.clone()),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    codes.sort();
+    codes
+}
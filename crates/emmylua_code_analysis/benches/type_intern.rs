@@ -0,0 +1,156 @@
+//! 统计类型驻留前后的内存分配, 运行: `cargo bench -p emmylua_code_analysis --bench type_intern`
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    hint::black_box,
+    sync::{
+        Arc,
+        atomic::{AtomicUsize, Ordering},
+    },
+    time::Instant,
+};
+
+use emmylua_code_analysis::{
+    LuaArrayType, LuaMemberKey, LuaMemberOwner, LuaType, LuaTypeDeclId, VirtualWorkspace,
+};
+use hashbrown::HashSet;
+
+struct CountingAlloc;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static LIVE_BYTES: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        LIVE_BYTES.fetch_add(layout.size(), Ordering::Relaxed);
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        LIVE_BYTES.fetch_sub(layout.size(), Ordering::Relaxed);
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAlloc = CountingAlloc;
+
+const FILE_COUNT: usize = 500;
+const TYPE_COUNT: usize = 100_000;
+
+/// 返回执行 `f` 期间的分配次数和保留下来的字节数
+fn measure<T>(f: impl FnOnce() -> T) -> (T, usize, usize) {
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+    let live_bytes = LIVE_BYTES.load(Ordering::Relaxed);
+    let result = f();
+    (
+        result,
+        ALLOCATIONS.load(Ordering::Relaxed) - allocations,
+        LIVE_BYTES
+            .load(Ordering::Relaxed)
+            .saturating_sub(live_bytes),
+    )
+}
+
+/// 与文档注解相同, 驻留时由内向外逐层进行
+fn make_array_type(intern: bool) -> LuaType {
+    let union = LuaType::from_vec(vec![
+        LuaType::Ref(LuaTypeDeclId::global("Foo")),
+        LuaType::Ref(LuaTypeDeclId::global("Bar")),
+        LuaType::String,
+    ]);
+    if intern {
+        LuaType::Array(Arc::new(LuaArrayType::from_base_type(union.intern()))).intern()
+    } else {
+        LuaType::Array(Arc::new(LuaArrayType::from_base_type(union)))
+    }
+}
+
+fn bench_workspace() {
+    let contents = (0..FILE_COUNT)
+        .map(|i| {
+            format!(
+                "---@class Bench{i}\n\
+                 ---@field items (string|integer)[]\n\
+                 ---@field pair [string, integer]\n\
+                 ---@field map table<string, integer|boolean>\n"
+            )
+        })
+        .collect::<Vec<_>>();
+    let files = contents
+        .iter()
+        .enumerate()
+        .map(|(i, content)| (format!("bench_{i}.lua"), content.as_str()))
+        .collect::<Vec<_>>();
+
+    let mut ws = VirtualWorkspace::new();
+    let start = Instant::now();
+    let (_, allocations, live_bytes) = measure(|| {
+        ws.def_files(
+            files
+                .iter()
+                .map(|(name, content)| (name.as_str(), *content))
+                .collect(),
+        )
+    });
+    let elapsed = start.elapsed();
+
+    let db = ws.analysis.compilation.get_db();
+    let member_index = db.get_member_index();
+    let mut distinct = HashSet::new();
+    for i in 0..FILE_COUNT {
+        let owner = LuaMemberOwner::Type(LuaTypeDeclId::global(&format!("Bench{i}")));
+        let key = LuaMemberKey::Name("items".into());
+        if let Some(LuaType::Array(array)) = member_index
+            .get_member_item(&owner, &key)
+            .and_then(|item| item.resolve_type(db).ok())
+        {
+            distinct.insert(Arc::as_ptr(&array) as usize);
+        }
+    }
+
+    println!(
+        "workspace: {FILE_COUNT} files in {elapsed:?}, {allocations} allocations, {live_bytes} bytes retained, \
+         {} distinct `(string|integer)[]` allocations",
+        distinct.len()
+    );
+}
+
+fn bench_types() {
+    let (plain, plain_allocations, plain_bytes) = measure(|| {
+        (0..TYPE_COUNT)
+            .map(|_| make_array_type(false))
+            .collect::<Vec<_>>()
+    });
+    let (interned, interned_allocations, interned_bytes) = measure(|| {
+        (0..TYPE_COUNT)
+            .map(|_| make_array_type(true))
+            .collect::<Vec<_>>()
+    });
+    println!(
+        "types: {TYPE_COUNT} x `(Foo|Bar|string)[]`, plain {plain_allocations} allocations / {plain_bytes} bytes retained, \
+         interned {interned_allocations} allocations / {interned_bytes} bytes retained"
+    );
+
+    let start = Instant::now();
+    let plain_equal = plain
+        .windows(2)
+        .filter(|w| black_box(&w[0]) == &w[1])
+        .count();
+    let plain_elapsed = start.elapsed();
+    let start = Instant::now();
+    let interned_equal = interned
+        .windows(2)
+        .filter(|w| black_box(&w[0]) == &w[1])
+        .count();
+    let interned_elapsed = start.elapsed();
+    println!(
+        "equality: plain {plain_equal} in {plain_elapsed:?}, interned {interned_equal} in {interned_elapsed:?}"
+    );
+}
+
+fn main() {
+    bench_workspace();
+    bench_types();
+}
@@ -453,11 +453,12 @@ _VERSION = "Lua 5.5"
 ---
 --- This function is similar to `pcall`, except that it sets a new message
 --- handler `msgh`.
----@generic T, R
+---@generic T, R, E
 ---@param f sync fun(...:T...): R...
----@param msgh fun(err:any):any
+---@param msgh fun(err:any): E
 ---@param ... T...
----@return boolean, R...
+---@return_overload true, R...
+---@return_overload false, E
 function xpcall(f, msgh, ...) end
 
 ---@version 5.1, JIT
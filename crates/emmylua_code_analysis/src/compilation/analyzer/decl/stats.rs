@@ -1,7 +1,7 @@
 use emmylua_parser::{
-    LuaAssignStat, LuaAstNode, LuaAstToken, LuaExpr, LuaForRangeStat, LuaForStat, LuaFuncStat,
-    LuaIndexExpr, LuaIndexKey, LuaLocalFuncStat, LuaLocalStat, LuaSyntaxId, LuaSyntaxKind,
-    LuaVarExpr, NumberResult,
+    LuaAssignStat, LuaAstNode, LuaAstToken, LuaCommentOwner, LuaDocTag, LuaExpr, LuaForRangeStat,
+    LuaForStat, LuaFuncStat, LuaIndexExpr, LuaIndexKey, LuaLocalFuncStat, LuaLocalStat,
+    LuaSyntaxId, LuaSyntaxKind, LuaVarExpr, NumberResult,
 };
 
 use crate::{
@@ -57,6 +57,11 @@ pub fn analyze_local_stat(analyzer: &mut DeclAnalyzer, stat: LuaLocalStat) -> Op
 
 pub fn analyze_assign_stat(analyzer: &mut DeclAnalyzer, stat: LuaAssignStat) -> Option<()> {
     let (vars, value_exprs) = stat.get_var_and_expr_list();
+    let has_type_tag = stat.get_comments().iter().any(|comment| {
+        comment
+            .get_doc_tags()
+            .any(|tag| matches!(tag, LuaDocTag::Type(_)))
+    });
     for (idx, var) in vars.iter().enumerate() {
         let value_expr_id = value_exprs.get(idx).map(|expr| expr.get_syntax_id());
 
@@ -130,7 +135,10 @@ pub fn analyze_assign_stat(analyzer: &mut DeclAnalyzer, stat: LuaAssignStat) ->
                 };
 
                 let (owner, global_id) = find_index_owner(analyzer, index_expr.clone());
-                let member = LuaMember::new(member_id, key.clone(), decl_feature, global_id);
+                let mut member = LuaMember::new(member_id, key.clone(), decl_feature, global_id);
+                member.set_union_define(
+                    !has_type_tag && !matches!(value_exprs.get(idx), Some(LuaExpr::ClosureExpr(_))),
+                );
 
                 analyzer.db.get_member_index_mut().add_member(owner, member);
                 if let LuaMemberKey::Name(name) = &key {
@@ -33,8 +33,14 @@ fn analyze_diagnostic_disable(
 ) -> Option<()> {
     let comment = analyzer.comment.clone();
     let owner_block = comment.ancestors::<LuaBlock>().next()?;
-    let owner_block_range = owner_block.get_range();
-    let is_file_disable = owner_block.get_parent::<LuaChunk>().is_some();
+    // 从注释开始到所在块结束, 或者到对应的 `---@diagnostic enable` 为止
+    let valid_range = TextRange::new(comment.get_position(), owner_block.get_range().end());
+    // 只有在第一条语句之前的禁用才作用于整个文件
+    let is_file_disable = owner_block.get_parent::<LuaChunk>().is_some()
+        && owner_block
+            .get_stats()
+            .next()
+            .is_none_or(|stat| stat.get_position() > comment.get_position());
 
     let diagnostic_index = analyzer.db.get_diagnostic_index_mut();
     if let Some(diagnostic_code_list) = diagnostic.get_code_list() {
@@ -46,22 +52,21 @@ fn analyze_diagnostic_disable(
                 continue;
             };
 
-            if is_file_disable {
+            // 整个文件都被禁用时不需要运行检查, 但之前已经被 `enable` 结束过的不能这样处理
+            if is_file_disable
+                && !diagnostic_index.has_diagnostic_action(analyzer.file_id, diagnostic_code)
+            {
                 diagnostic_index.add_file_diagnostic_disabled(analyzer.file_id, diagnostic_code);
-            } else {
-                diagnostic_index.add_diagnostic_action(
-                    analyzer.file_id,
-                    DiagnosticAction::new(
-                        owner_block_range,
-                        DiagnosticActionKind::Disable(diagnostic_code),
-                    ),
-                );
             }
+            diagnostic_index.add_diagnostic_action(
+                analyzer.file_id,
+                DiagnosticAction::new(valid_range, DiagnosticActionKind::Disable(diagnostic_code)),
+            );
         }
     } else {
         diagnostic_index.add_diagnostic_action(
             analyzer.file_id,
-            DiagnosticAction::new(owner_block_range, DiagnosticActionKind::DisableAll),
+            DiagnosticAction::new(valid_range, DiagnosticActionKind::DisableAll),
         );
     }
 
@@ -143,8 +148,15 @@ fn analyze_diagnostic_enable(
     analyzer: &mut DocAnalyzer,
     diagnostic: LuaDocTagDiagnostic,
 ) -> Option<()> {
+    let position = analyzer.comment.get_position();
+    let file_id = analyzer.file_id;
     let diagnostic_index = analyzer.db.get_diagnostic_index_mut();
-    let diagnostic_code_list = diagnostic.get_code_list()?;
+    let Some(diagnostic_code_list) = diagnostic.get_code_list() else {
+        diagnostic_index.clear_file_diagnostic_disabled(file_id);
+        diagnostic_index.close_diagnostic_actions(file_id, position, None);
+        return Some(());
+    };
+
     for code in diagnostic_code_list.get_codes() {
         let name = code.get_name_text();
         let diagnostic_code = if let Ok(code) = DiagnosticCode::from_str(name) {
@@ -153,7 +165,14 @@ fn analyze_diagnostic_enable(
             continue;
         };
 
-        diagnostic_index.add_file_diagnostic_enabled(analyzer.file_id, diagnostic_code);
+        let file_disabled =
+            diagnostic_index.remove_file_diagnostic_disabled(file_id, diagnostic_code);
+        let closed =
+            diagnostic_index.close_diagnostic_actions(file_id, position, Some(diagnostic_code));
+        // 没有对应的 `disable` 时, 表示在这个文件中强制开启该诊断
+        if !file_disabled && !closed {
+            diagnostic_index.add_file_diagnostic_enabled(file_id, diagnostic_code);
+        }
     }
 
     Some(())
@@ -21,11 +21,17 @@ use crate::{
         LuaIndexAccessKey, LuaIntersectionType, LuaMappedType, LuaObjectType, LuaStringTplType,
         LuaTupleType, LuaType,
     },
+    enum_keys_type,
 };
 
 use super::{DocAnalyzer, preprocess_description};
 
+/// 注解中的类型会长期保存在索引里, 驻留后相同的注解共享同一份分配
 pub fn infer_type(analyzer: &mut DocAnalyzer, node: LuaDocType) -> LuaType {
+    infer_doc_type(analyzer, node).intern()
+}
+
+fn infer_doc_type(analyzer: &mut DocAnalyzer, node: LuaDocType) -> LuaType {
     match &node {
         LuaDocType::Name(name_type) => {
             if let Some(name) = name_type.get_name_text() {
@@ -185,6 +191,20 @@ fn infer_buildin_or_ref_type(
                 LuaTypeDeclId::global(name)
             };
 
+            if !founded
+                && let Some(enum_id) = analyzer
+                    .db
+                    .get_type_index()
+                    .find_enum_keys_decl(analyzer.file_id, name)
+            {
+                analyzer.db.get_reference_index_mut().add_type_reference(
+                    analyzer.file_id,
+                    enum_id.clone(),
+                    range,
+                );
+                return enum_keys_type(enum_id);
+            }
+
             if !founded {
                 analyzer.db.get_diagnostic_index_mut().add_diagnostic(
                     analyzer.file_id,
@@ -1,6 +1,8 @@
+use std::path::PathBuf;
+
 use crate::{
     AsyncState, LuaDeclId, LuaExport, LuaExportScope, LuaNoDiscard, LuaSemanticDeclId,
-    LuaSignatureId, PropertyDeclFeature,
+    LuaSignatureId, PropertyDeclFeature, file_path_to_uri,
 };
 
 use super::{
@@ -33,6 +35,7 @@ pub fn analyze_visibility(
 pub fn analyze_source(analyzer: &mut DocAnalyzer, source: LuaDocTagSource) -> Option<()> {
     let path = source.get_path_token()?.get_path().to_string();
     let owner_id = get_owner_id_or_report(analyzer, &source)?;
+    let path = resolve_source_path(analyzer, &path).unwrap_or(path);
 
     analyzer
         .db
@@ -42,6 +45,44 @@ pub fn analyze_source(analyzer: &mut DocAnalyzer, source: LuaDocTagSource) -> Op
     Some(())
 }
 
+/// 将 `path:line` 或 `path:line:col` (从 1 开始) 转换为 `uri#line:col` (从 0 开始),
+/// 相对路径相对于当前文件所在的目录. 已经是 uri 的保持不变
+fn resolve_source_path(analyzer: &DocAnalyzer, source: &str) -> Option<String> {
+    if source.contains("://") || source.contains('#') {
+        return None;
+    }
+
+    let (file_path, line, col) = split_source_position(source.trim());
+    let mut path = PathBuf::from(file_path);
+    if path.is_relative() {
+        let current_path = analyzer.db.get_vfs().get_file_path(&analyzer.file_id)?;
+        path = current_path.parent()?.join(path);
+    }
+
+    let uri = file_path_to_uri(&path)?;
+    Some(format!(
+        "{}#{}:{}",
+        uri.as_str(),
+        line.saturating_sub(1),
+        col.saturating_sub(1)
+    ))
+}
+
+fn split_source_position(source: &str) -> (&str, u32, u32) {
+    fn parse_tail(text: &str) -> Option<(&str, u32)> {
+        let (head, tail) = text.rsplit_once(':')?;
+        Some((head, tail.trim().parse::<u32>().ok()?))
+    }
+
+    match parse_tail(source) {
+        Some((head, last)) => match parse_tail(head) {
+            Some((file_path, line)) => (file_path, line, last),
+            None => (head, last, 1),
+        },
+        None => (source, 1, 1),
+    }
+}
+
 pub fn analyze_nodiscard(analyzer: &mut DocAnalyzer, nodiscard: LuaDocTagNodiscard) -> Option<()> {
     let closure = find_owner_closure_or_report(analyzer, &nodiscard)?;
     let signature_id = LuaSignatureId::from_closure(analyzer.file_id, &closure);
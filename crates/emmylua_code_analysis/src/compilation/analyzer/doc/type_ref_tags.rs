@@ -1,8 +1,8 @@
 use emmylua_parser::{
-    LuaAst, LuaAstNode, LuaAstToken, LuaBlock, LuaDocDescriptionOwner, LuaDocTagAs, LuaDocTagCast,
+    LuaAst, LuaAstNode, LuaAstToken, LuaDocDescriptionOwner, LuaDocTagAs, LuaDocTagCast,
     LuaDocTagModule, LuaDocTagOther, LuaDocTagOverload, LuaDocTagParam, LuaDocTagReturn,
     LuaDocTagReturnCast, LuaDocTagReturnOverload, LuaDocTagSchema, LuaDocTagSee, LuaDocTagType,
-    LuaExpr, LuaLocalName, LuaTokenKind, LuaVarExpr,
+    LuaLocalName, LuaVarExpr,
 };
 
 use super::{
@@ -400,22 +400,7 @@ pub fn analyze_module(analyzer: &mut DocAnalyzer, tag: LuaDocTagModule) -> Optio
 pub fn analyze_as(analyzer: &mut DocAnalyzer, tag: LuaDocTagAs) -> Option<()> {
     let as_type = tag.get_type()?;
     let type_ref = infer_type(analyzer, as_type);
-    let comment = analyzer.comment.clone();
-    let mut left_token = comment.syntax().first_token()?.prev_token()?;
-    if left_token.kind() == LuaTokenKind::TkWhitespace.into() {
-        left_token = left_token.prev_token()?;
-    }
-
-    let mut ast_node = left_token.parent()?;
-    loop {
-        if LuaExpr::can_cast(ast_node.kind().into()) {
-            break;
-        } else if LuaBlock::can_cast(ast_node.kind().into()) {
-            return None;
-        }
-        ast_node = ast_node.parent()?;
-    }
-    let expr = LuaExpr::cast(ast_node)?;
+    let expr = tag.get_target_expr()?;
 
     let file_id = analyzer.file_id;
     let in_filed_syntax_id = InFiled::new(file_id, expr.get_syntax_id());
@@ -1,7 +1,9 @@
-use emmylua_parser::{LuaAstNode, LuaCallExpr, LuaExpr, LuaIndexKey, LuaTableField};
+use emmylua_parser::{LuaAstNode, LuaCallExpr, LuaExpr, LuaIndexKey, LuaLocalStat, LuaTableField};
 
 use crate::{
-    InFiled, LuaOperator, LuaOperatorMetaMethod, LuaOperatorOwner, LuaSignatureId, OperatorFunction,
+    InFiled, LuaDeclId, LuaMemberKey, LuaOperator, LuaOperatorMetaMethod, LuaOperatorOwner,
+    LuaSignatureId, LuaType, LuaTypeDeclId, OperatorFunction, find_members_with_key,
+    semantic::is_sub_type_of,
 };
 
 use super::LuaAnalyzer;
@@ -16,6 +18,8 @@ pub fn analyze_setmetatable(analyzer: &mut LuaAnalyzer, call_expr: LuaCallExpr)
 
     let table = args[0].clone();
     let metatable = args[1].clone();
+    analyze_index_inherit(analyzer, &call_expr, &table, &metatable);
+
     let LuaExpr::TableExpr(metatable) = metatable else {
         return Some(());
     };
@@ -65,3 +69,84 @@ fn analyze_metable_field(
 
     Some(())
 }
+
+/// `setmetatable(Child, { __index = Parent })` 中两者都是类时, `Child` 继承 `Parent` 的成员
+fn analyze_index_inherit(
+    analyzer: &mut LuaAnalyzer,
+    call_expr: &LuaCallExpr,
+    table: &LuaExpr,
+    metatable: &LuaExpr,
+) -> Option<()> {
+    let child_type = get_child_type(analyzer, call_expr, table)?;
+    let child_id = get_class_id(analyzer, &child_type)?;
+    let index_type = get_index_type(analyzer, metatable)?;
+    let parent_id = get_class_id(analyzer, &index_type)?;
+    if child_id == parent_id
+        || is_sub_type_of(analyzer.db, &child_id, &parent_id)
+        || is_sub_type_of(analyzer.db, &parent_id, &child_id)
+    {
+        return None;
+    }
+
+    let file_id = analyzer.file_id;
+    analyzer
+        .db
+        .get_type_index_mut()
+        .add_super_type(child_id, file_id, LuaType::Ref(parent_id));
+
+    Some(())
+}
+
+fn get_child_type(
+    analyzer: &mut LuaAnalyzer,
+    call_expr: &LuaCallExpr,
+    table: &LuaExpr,
+) -> Option<LuaType> {
+    if !matches!(table, LuaExpr::TableExpr(_)) {
+        return analyzer.infer_expr(table).ok();
+    }
+
+    // `local Child = setmetatable({}, mt)` 的类型来自变量上的注解
+    let local_stat = call_expr.get_parent::<LuaLocalStat>()?;
+    let index = local_stat
+        .get_value_exprs()
+        .position(|expr| expr.syntax() == call_expr.syntax())?;
+    let local_name = local_stat.get_local_name_list().nth(index)?;
+    let decl_id = LuaDeclId::new(analyzer.file_id, local_name.get_position());
+    analyzer
+        .db
+        .get_type_index()
+        .get_type_cache(&decl_id.into())
+        .map(|type_cache| type_cache.as_type().clone())
+}
+
+fn get_index_type(analyzer: &mut LuaAnalyzer, metatable: &LuaExpr) -> Option<LuaType> {
+    if let LuaExpr::TableExpr(table_expr) = metatable {
+        let field = table_expr
+            .get_fields()
+            .find(|field| match field.get_field_key() {
+                Some(LuaIndexKey::Name(name)) => name.get_name_text() == "__index",
+                Some(LuaIndexKey::String(string)) => string.get_value() == "__index",
+                _ => false,
+            })?;
+        return analyzer.infer_expr(&field.get_value_expr()?).ok();
+    }
+
+    // `Parent.__index = Parent` 之后直接把 `Parent` 作为元表
+    let meta_type = analyzer.infer_expr(metatable).ok()?;
+    let members = find_members_with_key(
+        analyzer.db,
+        &meta_type,
+        LuaMemberKey::Name("__index".into()),
+        false,
+    )?;
+    Some(members.first()?.typ.clone())
+}
+
+fn get_class_id(analyzer: &LuaAnalyzer, typ: &LuaType) -> Option<LuaTypeDeclId> {
+    let (LuaType::Def(id) | LuaType::Ref(id)) = typ else {
+        return None;
+    };
+    let type_decl = analyzer.db.get_type_index().get_type_decl(id)?;
+    type_decl.is_class().then(|| id.clone())
+}
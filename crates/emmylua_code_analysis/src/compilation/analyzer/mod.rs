@@ -9,31 +9,59 @@ mod unresolve;
 use crate::{
     Emmyrc, FileId, InFiled, InferFailReason, WorkspaceId, db_index::DbIndex, profile::Profile,
 };
+
+use super::{IndexPhase, IndexProgressSink};
 use emmylua_parser::LuaChunk;
 use hashbrown::{HashMap, HashSet};
 use infer_cache_manager::InferCacheManager;
 use std::sync::Arc;
 use unresolve::UnResolve;
 
-pub fn analyze(db: &mut DbIndex, need_analyzed_files: Vec<InFiled<LuaChunk>>, config: Arc<Emmyrc>) {
+pub fn analyze(
+    db: &mut DbIndex,
+    need_analyzed_files: Vec<InFiled<LuaChunk>>,
+    config: Arc<Emmyrc>,
+    progress: Option<&IndexProgressSink>,
+) {
     if need_analyzed_files.is_empty() {
         return;
     }
 
     let contexts = module_analyze(db, need_analyzed_files, config);
 
+    // 每个分析流程都会处理一遍文件, 进度按流程数乘以文件数计算
+    let total = contexts
+        .iter()
+        .map(|(_, context)| context.tree_list.len() * ANALYSIS_PIPELINE_COUNT)
+        .sum();
+    let mut done = 0;
+    let mut report = |file_count: usize| {
+        done += file_count;
+        if let Some(progress) = progress {
+            progress.report(IndexPhase::Analyze, done, total);
+        }
+    };
+
     for (workspace_id, mut context) in contexts {
         context.workspace_id = Some(workspace_id);
         let profile_log = format!("analyze workspace {}", workspace_id);
         let _p = Profile::cond_new(&profile_log, context.tree_list.len() > 1);
+        let file_count = context.tree_list.len();
         run_analysis::<decl::DeclAnalysisPipeline>(db, &mut context);
+        report(file_count);
         run_analysis::<doc::DocAnalysisPipeline>(db, &mut context);
+        report(file_count);
         run_analysis::<flow::FlowAnalysisPipeline>(db, &mut context);
+        report(file_count);
         run_analysis::<lua::LuaAnalysisPipeline>(db, &mut context);
+        report(file_count);
         run_analysis::<unresolve::UnResolveAnalysisPipeline>(db, &mut context);
+        report(file_count);
     }
 }
 
+const ANALYSIS_PIPELINE_COUNT: usize = 5;
+
 trait AnalysisPipeline {
     fn analyze(db: &mut DbIndex, context: &mut AnalyzeContext);
 }
@@ -122,7 +150,7 @@ fn module_analyze(
         }
     }
 
-    contexts.sort_by(|a, b| a.0.cmp(&b.0));
+    contexts.sort_by_key(|(workspace_id, _)| *workspace_id);
 
     contexts.extend(main_vec);
     contexts
@@ -3,9 +3,9 @@ use hashbrown::HashMap;
 use emmylua_parser::LuaAstNode;
 
 use crate::{
-    DbIndex, InFiled, InferFailReason, LuaDocReturnInfo, LuaSemanticDeclId, LuaType, LuaTypeCache,
-    SignatureReturnStatus, compilation::analyzer::infer_cache_manager::InferCacheManager,
-    infer_expr, infer_param,
+    CacheOptions, DbIndex, InFiled, InferFailReason, LuaAnalysisPhase, LuaDocReturnInfo,
+    LuaInferCache, LuaMemberId, LuaSemanticDeclId, LuaType, LuaTypeCache, SignatureReturnStatus,
+    compilation::analyzer::infer_cache_manager::InferCacheManager, infer_expr, infer_param,
 };
 
 use super::UnResolve;
@@ -59,11 +59,55 @@ pub fn resolve_all_reason(
     reason_unresolves: &mut HashMap<InferFailReason, Vec<UnResolve>>,
     loop_count: usize,
 ) {
-    for (reason, _) in reason_unresolves.iter_mut() {
+    for (reason, unresolves) in reason_unresolves.iter_mut() {
+        if loop_count > 0
+            && let InferFailReason::UnResolveMemberType(member_id) = reason
+            && resolve_cyclic_member(db, member_id, unresolves).is_some()
+        {
+            continue;
+        }
         resolve_as_any(db, reason, loop_count);
     }
 }
 
+/// 只由赋值定义的字段, 赋值依赖字段自身(如 `self.n = self.n + 1`)时无法推断.
+/// 先将该赋值绑定为 `never` 从联合中跳过, 再用其余赋值得到的字段类型推断它
+fn resolve_cyclic_member(
+    db: &mut DbIndex,
+    member_id: &LuaMemberId,
+    unresolves: &[UnResolve],
+) -> Option<()> {
+    let member = db.get_member_index().get_member(member_id)?;
+    if !member.is_union_define() {
+        return None;
+    }
+    let unresolve_member = unresolves.iter().find_map(|unresolve| match unresolve {
+        UnResolve::Member(unresolve_member) if unresolve_member.member_id == *member_id => {
+            Some(unresolve_member)
+        }
+        _ => None,
+    })?;
+    let expr = unresolve_member.expr.clone()?;
+
+    db.get_type_index_mut()
+        .bind_type((*member_id).into(), LuaTypeCache::InferType(LuaType::Never));
+    let mut cache = LuaInferCache::new(
+        member_id.file_id,
+        CacheOptions {
+            analysis_phase: LuaAnalysisPhase::Force,
+        },
+    );
+    if let Ok(expr_type) = infer_expr(db, &mut cache, expr) {
+        let expr_type = expr_type
+            .get_result_slot_type(unresolve_member.ret_idx)
+            .unwrap_or(LuaType::Unknown);
+        db.get_type_index_mut()
+            .rebind_type((*member_id).into(), LuaTypeCache::InferType(expr_type));
+    }
+
+    Some(())
+}
+
 pub fn resolve_as_any(db: &mut DbIndex, reason: &InferFailReason, loop_count: usize) -> Option<()> {
     match reason {
         InferFailReason::None
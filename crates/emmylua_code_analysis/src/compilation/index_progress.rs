@@ -0,0 +1,39 @@
+use std::{fmt, sync::Arc};
+
+/// 索引的阶段
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IndexPhase {
+    /// 读取并解析文件
+    Parse,
+    /// 分析已解析的文件
+    Analyze,
+}
+
+/// 索引进度. 解析阶段 `done` 和 `total` 按文件计数; 分析阶段每个文件要经过多个分析流程,
+/// `done` 和 `total` 按文件数乘以流程数(`ANALYSIS_PIPELINE_COUNT`)计数
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IndexProgress {
+    pub phase: IndexPhase,
+    pub done: usize,
+    pub total: usize,
+}
+
+/// 接收索引进度的回调, 在加载工作区等批量更新时调用
+#[derive(Clone)]
+pub struct IndexProgressSink(Arc<dyn Fn(IndexProgress) + Send + Sync>);
+
+impl IndexProgressSink {
+    pub fn new(callback: impl Fn(IndexProgress) + Send + Sync + 'static) -> Self {
+        Self(Arc::new(callback))
+    }
+
+    pub fn report(&self, phase: IndexPhase, done: usize, total: usize) {
+        (self.0)(IndexProgress { phase, done, total });
+    }
+}
+
+impl fmt::Debug for IndexProgressSink {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("IndexProgressSink")
+    }
+}
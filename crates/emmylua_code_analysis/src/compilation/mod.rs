@@ -1,7 +1,13 @@
 mod analyzer;
+mod index_progress;
 mod test;
 
-use std::sync::Arc;
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+};
+
+pub use index_progress::{IndexPhase, IndexProgress, IndexProgressSink};
 
 use crate::{
     Emmyrc, FileId, InFiled, LuaIndex, LuaInferCache, db_index::DbIndex, semantic::SemanticModel,
@@ -11,6 +17,11 @@ use crate::{
 pub struct LuaCompilation {
     db: DbIndex,
     emmyrc: Arc<Emmyrc>,
+    index_progress: Option<IndexProgressSink>,
+    revision: u64,
+    // 每个文件最后一次重新索引或移除时的版本
+    file_revisions: HashMap<FileId, u64>,
+    cleared_revision: u64,
 }
 
 impl LuaCompilation {
@@ -18,6 +29,10 @@ impl LuaCompilation {
         let mut compilation = Self {
             db: DbIndex::new(),
             emmyrc: emmyrc.clone(),
+            index_progress: None,
+            revision: 0,
+            file_revisions: HashMap::new(),
+            cleared_revision: 0,
         };
 
         compilation.db.update_config(emmyrc.clone());
@@ -37,8 +52,10 @@ impl LuaCompilation {
     }
 
     pub fn update_index(&mut self, file_ids: Vec<FileId>) {
+        self.revision += 1;
         let mut need_analyzed_files = vec![];
         for file_id in file_ids {
+            self.file_revisions.insert(file_id, self.revision);
             let tree = match self.db.get_vfs().get_syntax_tree(&file_id) {
                 Some(tree) => tree,
                 None => {
@@ -52,17 +69,49 @@ impl LuaCompilation {
             });
         }
 
-        analyzer::analyze(&mut self.db, need_analyzed_files, self.emmyrc.clone());
+        analyzer::analyze(
+            &mut self.db,
+            need_analyzed_files,
+            self.emmyrc.clone(),
+            self.index_progress.as_ref(),
+        );
     }
 
     pub fn remove_index(&mut self, file_ids: Vec<FileId>) {
+        self.revision += 1;
+        for file_id in &file_ids {
+            self.file_revisions.insert(*file_id, self.revision);
+        }
         self.db.remove_index(file_ids);
     }
 
     pub fn clear_index(&mut self) {
+        self.revision += 1;
+        self.file_revisions.clear();
+        self.cleared_revision = self.revision;
         self.db.clear();
     }
 
+    /// 索引每次变化后递增, 用于判断依赖整个工作区的结果是否需要重新计算
+    pub fn get_revision(&self) -> u64 {
+        self.revision
+    }
+
+    /// `revision` 之后重新索引或移除过的文件, 期间清空过索引时返回 None
+    pub fn get_changed_files(&self, revision: u64) -> Option<HashSet<FileId>> {
+        if self.cleared_revision > revision {
+            return None;
+        }
+
+        Some(
+            self.file_revisions
+                .iter()
+                .filter(|(_, file_revision)| **file_revision > revision)
+                .map(|(file_id, _)| *file_id)
+                .collect(),
+        )
+    }
+
     pub fn get_db(&self) -> &DbIndex {
         &self.db
     }
@@ -75,4 +124,13 @@ impl LuaCompilation {
         self.emmyrc = config.clone();
         self.db.update_config(config);
     }
+
+    /// 设置索引进度的回调, 传入 `None` 停止汇报
+    pub fn set_index_progress(&mut self, sink: Option<IndexProgressSink>) {
+        self.index_progress = sink;
+    }
+
+    pub fn get_index_progress(&self) -> Option<&IndexProgressSink> {
+        self.index_progress.as_ref()
+    }
 }
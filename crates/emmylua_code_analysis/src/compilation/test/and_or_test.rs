@@ -136,4 +136,35 @@ mod test {
             "#,
         ));
     }
+
+    #[test]
+    fn test_short_circuit_narrow() {
+        let mut ws = VirtualWorkspace::new();
+
+        assert!(ws.check_code_for(
+            DiagnosticCode::NeedCheckNil,
+            r#"
+            ---@class AndOrNode
+            ---@field name string
+
+            ---@type AndOrNode?
+            local node
+            local _ = node and node.name
+            "#,
+        ));
+
+        ws.def(
+            r#"
+            ---@type AndOrNode?
+            local node
+            ---@type integer
+            local default
+            A = node and node.name
+            B = node or default
+            "#,
+        );
+
+        assert_eq!(ws.expr_ty("A"), ws.ty("string?"));
+        assert_eq!(ws.expr_ty("B"), ws.ty("AndOrNode|integer"));
+    }
 }
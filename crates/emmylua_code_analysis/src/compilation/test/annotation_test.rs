@@ -288,4 +288,102 @@ mod test {
         "#,
         ));
     }
+
+    #[test]
+    fn test_inline_as_member_access() {
+        let mut ws = VirtualWorkspace::new();
+
+        ws.def(
+            r#"
+        ---@class AsFoo
+        ---@field bar string
+
+        local x = {}
+        d = (x --[[@as AsFoo]]).bar
+        "#,
+        );
+
+        assert_eq!(ws.expr_ty("d"), ws.ty("string"));
+    }
+
+    #[test]
+    fn test_self_field_resolves_to_class() {
+        let mut ws = VirtualWorkspace::new();
+
+        ws.def(
+            r#"
+        ---@class ListNode
+        ---@field next self?
+        ---@field parent self
+        local ListNode = {}
+
+        ---@class SortedNode: ListNode
+
+        function ListNode:walk()
+            a = self.next
+            b = self.parent
+        end
+
+        local node ---@type SortedNode
+        c = node.parent
+        "#,
+        );
+
+        assert_eq!(ws.expr_ty("a"), ws.ty("ListNode?"));
+        assert_eq!(ws.expr_ty("b"), ws.ty("ListNode"));
+        assert_eq!(ws.expr_ty("c"), ws.ty("SortedNode"));
+    }
+
+    #[test]
+    fn test_self_field_through_nullable_prefix() {
+        let mut ws = VirtualWorkspace::new();
+
+        ws.def(
+            r#"
+        ---@class ChainNode
+        ---@field next self?
+        ---@field parent self
+
+        ---@class SortedChainNode: ChainNode
+
+        local node ---@type SortedChainNode
+        a = node.next.parent
+        b = node.next.next
+        "#,
+        );
+
+        assert_eq!(ws.expr_ty("a"), ws.ty("SortedChainNode"));
+        assert_eq!(ws.expr_ty("b"), ws.ty("SortedChainNode?"));
+    }
+
+    #[test]
+    fn test_nested_self_field_resolves_to_class() {
+        let mut ws = VirtualWorkspace::new();
+
+        ws.def(
+            r#"
+        ---@class TreeNode
+        ---@field clone fun(): self
+        ---@field children self[]
+
+        ---@class Box<T>
+        ---@field value T
+        ---@field parent self
+        ---@field copy fun(): self
+
+        local node ---@type TreeNode
+        a = node.clone()
+        b = node.children
+
+        local box ---@type Box<integer>
+        c = box.parent
+        d = box.copy()
+        "#,
+        );
+
+        assert_eq!(ws.expr_ty("a"), ws.ty("TreeNode"));
+        assert_eq!(ws.expr_ty("b"), ws.ty("TreeNode[]"));
+        assert_eq!(ws.expr_ty("c"), ws.ty("Box<integer>"));
+        assert_eq!(ws.expr_ty("d"), ws.ty("Box<integer>"));
+    }
 }
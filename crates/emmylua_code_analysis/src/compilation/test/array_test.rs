@@ -2,7 +2,7 @@
 mod test {
     use std::{ops::Deref, sync::Arc};
 
-    use crate::{DiagnosticCode, VirtualWorkspace};
+    use crate::{DiagnosticCode, LuaType, VirtualWorkspace};
 
     #[test]
     fn test_array_index() {
@@ -68,4 +68,19 @@ mod test {
         "#,
         ));
     }
+
+    #[test]
+    fn test_table_remove_element_type() {
+        let mut ws = VirtualWorkspace::new_with_init_std_lib();
+        ws.def(
+            r#"
+            ---@type string[]
+            local names = {}
+            removed = table.remove(names)
+            "#,
+        );
+
+        let ty = ws.expr_ty("removed");
+        assert_eq!(ty, LuaType::String);
+    }
 }
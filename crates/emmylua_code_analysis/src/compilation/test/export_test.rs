@@ -23,4 +23,47 @@ mod test {
         "#,
         );
     }
+
+    #[test]
+    fn test_require_return_type() {
+        let mut ws = VirtualWorkspace::new();
+        ws.def_files(vec![
+            (
+                "net/init.lua",
+                r#"
+                ---@class Net
+                local Net = {}
+
+                ---@return string
+                function Net.host() end
+
+                return Net
+                "#,
+            ),
+            (
+                "net/http.lua",
+                r#"
+                local http = {}
+
+                ---@return integer
+                function http.port() end
+
+                return http
+                "#,
+            ),
+        ]);
+
+        ws.def(
+            r#"
+            local net = require("net")
+            local http = require("net/http")
+            A = net.host()
+            B = http.port()
+            "#,
+        );
+
+        assert_eq!(ws.expr_ty("require('net')"), ws.ty("Net"));
+        assert_eq!(ws.expr_ty("A"), ws.ty("string"));
+        assert_eq!(ws.expr_ty("B"), ws.ty("integer"));
+    }
 }
@@ -930,6 +930,7 @@ print(a.field)
 
         assert!(ws.check_code_for(DiagnosticCode::CallNonCallable, code));
         assert!(ws.check_code_for(DiagnosticCode::NeedCheckNil, code));
+        assert!(ws.check_code_for(DiagnosticCode::CallPossiblyNil, code));
 
         let a = ws.expr_ty("A");
         let a_desc = ws.humanize_type_detailed(a);
@@ -2722,6 +2723,49 @@ _2 = a[1]
         assert_eq!(ws.expr_ty("after_assign"), ws.ty("Foo"));
     }
 
+    #[test]
+    fn test_discriminant_narrowing_keeps_all_matching_branches() {
+        let mut ws = VirtualWorkspace::new();
+
+        ws.def(
+            r#"
+            ---@class Circle
+            ---@field kind "circle"
+            ---@field radius number
+
+            ---@class Ring
+            ---@field kind "circle"
+            ---@field inner number
+
+            ---@class Square
+            ---@field kind "square"
+            ---@field size number
+
+            ---@class Shape
+            ---@field kind "circle"|"square"
+
+            local a ---@type Circle|Ring|Square
+            if a.kind == "circle" then
+                circles = a
+            else
+                not_circles = a
+            end
+
+            local b ---@type Circle|Square|Shape
+            if b.kind == "square" then
+                squares = b
+            else
+                not_squares = b
+            end
+            "#,
+        );
+
+        assert_eq!(ws.expr_ty("circles"), ws.ty("Circle|Ring"));
+        assert_eq!(ws.expr_ty("not_circles"), ws.ty("Square"));
+        assert_eq!(ws.expr_ty("squares"), ws.ty("Square|Shape"));
+        assert_eq!(ws.expr_ty("not_squares"), ws.ty("Circle|Shape"));
+    }
+
     #[test]
     fn test_exact_string_reassignment_preserves_literal_narrowing() {
         let mut ws = VirtualWorkspace::new();
@@ -2785,4 +2829,38 @@ _2 = a[1]
 
         assert_eq!(ws.expr_ty("after_assign"), ws.ty("Foo|Bar"));
     }
+
+    #[test]
+    fn test_type_call_narrow_union_branches() {
+        let mut ws = VirtualWorkspace::new();
+
+        ws.def(
+            r#"
+            ---@type string|number
+            local x
+
+            if type(x) == "string" then
+                str_branch = x
+            else
+                num_branch = x
+            end
+
+            if "number" == type(x) then
+                reversed_branch = x
+            end
+
+            if type(x) ~= "string" then
+                neq_branch = x
+            else
+                neq_else_branch = x
+            end
+            "#,
+        );
+
+        assert_eq!(ws.expr_ty("str_branch"), ws.ty("string"));
+        assert_eq!(ws.expr_ty("num_branch"), ws.ty("number"));
+        assert_eq!(ws.expr_ty("reversed_branch"), ws.ty("number"));
+        assert_eq!(ws.expr_ty("neq_branch"), ws.ty("number"));
+        assert_eq!(ws.expr_ty("neq_else_branch"), ws.ty("string"));
+    }
 }
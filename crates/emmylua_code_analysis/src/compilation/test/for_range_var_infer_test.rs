@@ -214,4 +214,32 @@ mod test {
             ]))),
         );
     }
+
+    #[test]
+    fn test_nullable_table_iter() {
+        let mut ws = VirtualWorkspace::new_with_init_std_lib();
+
+        ws.def(
+            r#"
+            ---@param arr? string[]
+            ---@param map? table<string, integer>
+            local function f(arr, map)
+                for i, v in ipairs(arr) do
+                    a = i
+                    b = v
+                end
+
+                for k, v in pairs(map) do
+                    c = k
+                    d = v
+                end
+            end
+        "#,
+        );
+
+        assert_eq!(ws.expr_ty("a"), LuaType::Integer);
+        assert_eq!(ws.expr_ty("b"), LuaType::String);
+        assert_eq!(ws.expr_ty("c"), LuaType::String);
+        assert_eq!(ws.expr_ty("d"), LuaType::Integer);
+    }
 }
@@ -0,0 +1,34 @@
+#[cfg(test)]
+mod test {
+    use crate::{HumanizeOptions, RenderLevel, VirtualWorkspace, humanize_type_with};
+
+    #[test]
+    fn test_humanize_type_with_options() {
+        let mut ws = VirtualWorkspace::new();
+        let ty = ws.ty("fun(self: table, a: integer)");
+        let db = ws.analysis.compilation.get_db();
+
+        assert_eq!(
+            humanize_type_with(db, &ty, RenderLevel::Simple, HumanizeOptions::default()),
+            "fun(self: table, a: integer)"
+        );
+
+        let opts = HumanizeOptions {
+            render_self: false,
+            ..Default::default()
+        };
+        assert_eq!(
+            humanize_type_with(db, &ty, RenderLevel::Simple, opts),
+            "fun(a: integer)"
+        );
+
+        let opts = HumanizeOptions {
+            max_depth: 1,
+            ..Default::default()
+        };
+        assert_eq!(
+            humanize_type_with(db, &ty, RenderLevel::Simple, opts),
+            "fun(self: ..., a: ...)"
+        );
+    }
+}
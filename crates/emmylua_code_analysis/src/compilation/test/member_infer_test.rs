@@ -2,7 +2,7 @@
 mod test {
     use smol_str::SmolStr;
 
-    use crate::{LuaType, LuaUnionType, VirtualWorkspace};
+    use crate::{LuaMemberOwner, LuaType, LuaTypeDeclId, LuaUnionType, VirtualWorkspace};
 
     #[test]
     fn test_issue_318() {
@@ -368,4 +368,241 @@ mod test {
 
         assert_eq!(ws.expr_ty("result"), ws.ty("never"));
     }
+
+    #[test]
+    fn test_sorted_members_in_declaration_order() {
+        let mut ws = VirtualWorkspace::new();
+        ws.def_file(
+            "a.lua",
+            r#"
+            ---@class (partial) Foo
+            ---@field b integer
+            ---@field a integer
+            local Foo = {}
+            "#,
+        );
+        ws.def_file(
+            "b.lua",
+            r#"
+            ---@class (partial) Foo
+            ---@field c integer
+            "#,
+        );
+
+        let owner = LuaMemberOwner::Type(LuaTypeDeclId::global("Foo"));
+        let names = ws
+            .analysis
+            .compilation
+            .get_db()
+            .get_member_index()
+            .get_sorted_members(&owner)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|member| member.get_key().to_path())
+            .collect::<Vec<_>>();
+        assert_eq!(names, vec!["b", "a", "c"]);
+    }
+
+    #[test]
+    fn test_resolve_member_path() {
+        let mut ws = VirtualWorkspace::new();
+        ws.def(
+            r#"
+            ---@class PathBase
+            ---@field base_field string
+
+            ---@class PathSub
+            ---@field field integer
+
+            ---@class PathRoot: PathBase
+            ---@field sub? PathSub
+            local PathRoot = {}
+
+            PathRoot.config = {
+                depth = 1,
+            }
+            "#,
+        );
+
+        let db = ws.analysis.compilation.get_db();
+        let path_of = |type_name: &str, path: &[&str]| {
+            db.resolve_member_path(type_name, path)
+                .map(|member| member.get_key().to_path())
+        };
+        assert_eq!(
+            path_of("PathRoot", &["sub", "field"]),
+            Some("field".to_string())
+        );
+        assert_eq!(
+            path_of("PathRoot", &["base_field"]),
+            Some("base_field".to_string())
+        );
+        assert_eq!(
+            path_of("PathRoot", &["config", "depth"]),
+            Some("depth".to_string())
+        );
+        assert_eq!(path_of("PathRoot", &["sub", "missing"]), None);
+        assert_eq!(path_of("Missing", &["sub"]), None);
+    }
+
+    #[test]
+    fn test_resolve_member_path_object_and_union() {
+        let mut ws = VirtualWorkspace::new();
+        ws.def(
+            r#"
+            ---@class PathLeaf
+            ---@field leaf boolean
+
+            ---@class PathOther
+            ---@field other string
+
+            ---@class PathHolder
+            ---@field inline { nested: PathLeaf, value: integer }
+            ---@field either PathOther|PathLeaf|nil
+            local PathHolder = {}
+
+            function PathHolder:init()
+                self.state = nil
+            end
+
+            function PathHolder:load()
+                self.state = { ready = true }
+            end
+            "#,
+        );
+
+        let db = ws.analysis.compilation.get_db();
+        let path_of = |type_name: &str, path: &[&str]| {
+            db.resolve_member_path(type_name, path)
+                .map(|member| member.get_key().to_path())
+        };
+        assert_eq!(
+            path_of("PathHolder", &["inline", "nested", "leaf"]),
+            Some("leaf".to_string())
+        );
+        assert_eq!(path_of("PathHolder", &["inline", "value"]), None);
+        assert_eq!(path_of("PathHolder", &["inline", "missing"]), None);
+        assert_eq!(
+            path_of("PathHolder", &["either", "leaf"]),
+            Some("leaf".to_string())
+        );
+        assert_eq!(
+            path_of("PathHolder", &["either", "other"]),
+            Some("other".to_string())
+        );
+        assert_eq!(
+            path_of("PathHolder", &["state", "ready"]),
+            Some("ready".to_string())
+        );
+    }
+
+    #[test]
+    fn test_field_type_from_all_assignments() {
+        let mut ws = VirtualWorkspace::new();
+
+        ws.def(
+            r#"
+        ---@return number
+        local function compute() end
+        ---@return string
+        local function label() end
+        ---@return boolean
+        local function flag() end
+
+        ---@class Counter
+        ---@field declared string
+        local Counter = {}
+
+        function Counter:reset()
+            self.count = 0
+            self.name = label()
+            self.declared = label()
+        end
+
+        function Counter:update()
+            self.count = compute()
+            self.name = flag()
+            self.declared = flag()
+        end
+
+        local c ---@type Counter
+        a = c.count
+        b = c.name
+        d = c.declared
+        "#,
+        );
+
+        assert_eq!(ws.expr_ty("a"), ws.ty("number"));
+        assert_eq!(ws.expr_ty("b"), ws.ty("string|boolean"));
+        assert_eq!(ws.expr_ty("d"), ws.ty("string"));
+    }
+
+    #[test]
+    fn test_field_type_self_referential_assignment() {
+        let mut ws = VirtualWorkspace::new();
+
+        ws.def(
+            r#"
+        ---@class Counter
+        local Counter = {}
+
+        function Counter:reset()
+            self.count = 0
+        end
+
+        ---@param step integer
+        function Counter:increment(step)
+            self.count = self.count + step
+        end
+
+        local c ---@type Counter
+        a = c.count
+        "#,
+        );
+
+        assert_eq!(ws.expr_ty("a"), ws.ty("integer"));
+        let db = ws.analysis.compilation.get_db();
+        assert_eq!(
+            db.resolve_member_path("Counter", &["count"])
+                .map(|member| member.get_key().to_path()),
+            Some("count".to_string())
+        );
+    }
+
+    #[test]
+    fn test_field_type_keeps_nil_init() {
+        let mut ws = VirtualWorkspace::new();
+
+        ws.def(
+            r#"
+        ---@return string
+        local function label() end
+
+        ---@class Widget
+        local Widget = {}
+
+        function Widget:init()
+            self.name = nil
+            self.empty = nil
+        end
+
+        function Widget:load()
+            self.name = label()
+        end
+
+        local w ---@type Widget
+        a = w.name
+        b = w.empty
+        "#,
+        );
+
+        assert_eq!(ws.expr_ty("a"), ws.ty("string?"));
+        assert_eq!(ws.expr_ty("b"), ws.ty("nil"));
+        let db = ws.analysis.compilation.get_db();
+        assert_eq!(
+            db.resolve_member_path("Widget", &["name"])
+                .map(|member| member.get_key().to_path()),
+            Some("name".to_string())
+        );
+    }
 }
@@ -102,4 +102,74 @@ mod test {
         let ty = ws.expr_ty("A");
         assert_eq!(ws.humanize_type(ty), "Class.Config");
     }
+
+    #[test]
+    fn test_setmetatable_inherit() {
+        let mut ws = VirtualWorkspace::new_with_init_std_lib();
+
+        ws.def(
+            r#"
+            ---@class Animal
+            local Animal = {}
+            Animal.__index = Animal
+
+            ---@return string
+            function Animal:speak()
+            end
+
+            ---@class Dog
+            local Dog = setmetatable({}, { __index = Animal })
+
+            ---@class Cat
+            local Cat = {}
+            setmetatable(Cat, Animal)
+
+            A = Dog:speak()
+            B = Cat:speak()
+            "#,
+        );
+
+        assert_eq!(ws.expr_ty("A"), ws.ty("string"));
+        assert_eq!(ws.expr_ty("B"), ws.ty("string"));
+    }
+
+    #[test]
+    fn test_setmetatable_inherit_transitive() {
+        let mut ws = VirtualWorkspace::new_with_init_std_lib();
+
+        ws.def(
+            r#"
+            ---@class Base
+            local Base = {}
+            Base.__index = Base
+
+            ---@return string
+            function Base:name()
+            end
+
+            ---@class Mid: Base
+            local Mid = {}
+
+            ---@class Leaf: Mid
+            local Leaf = {}
+
+            -- 已经间接继承 Base, 不能反过来让 Base 继承 Leaf
+            setmetatable(Base, { __index = Leaf })
+            setmetatable(Leaf, Base)
+
+            A = Leaf:name()
+            "#,
+        );
+
+        assert_eq!(ws.expr_ty("A"), ws.ty("string"));
+        let base_id = crate::LuaTypeDeclId::global("Base");
+        let supers = ws.get_db_mut().get_type_index().get_super_types(&base_id);
+        assert!(supers.is_none_or(|supers| supers.is_empty()));
+        let leaf_supers = ws
+            .get_db_mut()
+            .get_type_index()
+            .get_super_types(&crate::LuaTypeDeclId::global("Leaf"))
+            .unwrap();
+        assert_eq!(leaf_supers.len(), 1);
+    }
 }
@@ -13,6 +13,7 @@ mod flow;
 mod for_range_var_infer_test;
 mod generic_infer_test;
 mod generic_test;
+mod humanize_type_test;
 mod infer_str_tpl_test;
 mod inherit_type;
 mod mathlib_test;
@@ -28,7 +29,9 @@ mod return_overload_flow_test;
 mod return_overload_generic_test;
 mod return_unwrap_test;
 mod static_cal_cmp;
+mod stdlib_return_test;
 mod syntax_error_test;
 mod tuple_test;
 mod type_check_test;
 mod unpack_test;
+mod variadic_test;
@@ -72,4 +72,39 @@ mod test {
         "#
         ));
     }
+
+    #[test]
+    fn test_local_function_overload() {
+        let mut ws = VirtualWorkspace::new();
+        ws.def(
+            r#"
+            ---@overload fun(a: integer): integer
+            ---@overload fun(a: string): string
+            ---@param a boolean
+            ---@return boolean
+            local function f(a)
+            end
+
+            A = f(1)
+            B = f("x")
+            C = f(true)
+            "#,
+        );
+
+        assert_eq!(ws.expr_ty("A"), ws.ty("integer"));
+        assert_eq!(ws.expr_ty("B"), ws.ty("string"));
+        assert_eq!(ws.expr_ty("C"), ws.ty("boolean"));
+        assert!(ws.check_code_for(
+            DiagnosticCode::ParamTypeMismatch,
+            r#"
+            ---@overload fun(a: integer)
+            ---@param a string
+            local function g(a)
+            end
+
+            g(1)
+            g("x")
+            "#
+        ));
+    }
 }
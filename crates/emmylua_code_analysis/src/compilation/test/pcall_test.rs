@@ -199,4 +199,57 @@ mod test {
         assert_eq!(ws.expr_ty("success"), ws.ty("unknown"));
         assert_eq!(ws.expr_ty("failure"), ws.ty("string"));
     }
+
+    #[test]
+    fn test_pcall_success_branch_narrows_results() {
+        let mut ws = VirtualWorkspace::new_with_init_std_lib();
+
+        ws.def(
+            r#"
+        ---@return integer, string
+        local function foo()
+            return 1, "a"
+        end
+
+        local ok, count, name = pcall(foo)
+        if ok then
+            success_count = count
+            success_name = name
+        end
+        "#,
+        );
+
+        assert_eq!(ws.expr_ty("success_count"), ws.ty("integer"));
+        assert_eq!(ws.expr_ty("success_name"), ws.ty("string"));
+    }
+
+    #[test]
+    fn test_xpcall_splits_success_and_handler_result() {
+        let mut ws = VirtualWorkspace::new_with_init_std_lib();
+
+        ws.def(
+            r#"
+        ---@return integer
+        local function foo()
+            return 1
+        end
+
+        ---@param err any
+        ---@return string
+        local function handler(err)
+            return tostring(err)
+        end
+
+        local ok, result = xpcall(foo, handler)
+        if ok then
+            success = result
+        else
+            failure = result
+        end
+        "#,
+        );
+
+        assert_eq!(ws.expr_ty("success"), ws.ty("integer"));
+        assert_eq!(ws.expr_ty("failure"), ws.ty("string"));
+    }
 }
@@ -0,0 +1,34 @@
+#[cfg(test)]
+mod test {
+    use crate::VirtualWorkspace;
+
+    #[test]
+    fn test_string_builtin_returns() {
+        let mut ws = VirtualWorkspace::new_with_init_std_lib();
+        assert_eq!(ws.expr_ty(r#"string.format("%d", 1)"#), ws.ty("string"));
+        assert_eq!(ws.expr_ty(r#"("%d"):format(1)"#), ws.ty("string"));
+        assert_eq!(
+            ws.expr_ty(r#"table.concat({ "a", "b" }, ",")"#),
+            ws.ty("string")
+        );
+        assert_eq!(ws.expr_ty("tostring(1)"), ws.ty("string"));
+        assert_eq!(ws.expr_ty(r#"tonumber("1")"#), ws.ty("number?"));
+        assert_eq!(ws.expr_ty(r#"tonumber("ff", 16)"#), ws.ty("integer?"));
+    }
+
+    #[test]
+    fn test_builtin_alias_returns() {
+        let mut ws = VirtualWorkspace::new_with_init_std_lib();
+        ws.def(
+            r#"
+            local format = string.format
+            local concat = table.concat
+            A = format("%s", "a")
+            B = concat({})
+            "#,
+        );
+
+        assert_eq!(ws.expr_ty("A"), ws.ty("string"));
+        assert_eq!(ws.expr_ty("B"), ws.ty("string"));
+    }
+}
@@ -0,0 +1,87 @@
+#[cfg(test)]
+mod test {
+    use crate::VirtualWorkspace;
+
+    #[test]
+    fn test_dots_param() {
+        let mut ws = VirtualWorkspace::new_with_init_std_lib();
+        ws.def(
+            r#"
+            ---@param ... string
+            local function f(...)
+                A = { ... }
+                B = select('#', ...)
+                C = { select(2, ...) }
+            end
+            "#,
+        );
+
+        assert_eq!(ws.expr_ty("A"), ws.ty("string[]"));
+        assert_eq!(ws.expr_ty("B"), ws.ty("integer"));
+        assert_eq!(ws.expr_ty("C"), ws.ty("string[]"));
+    }
+
+    #[test]
+    fn test_select_unknown_index() {
+        let mut ws = VirtualWorkspace::new_with_init_std_lib();
+        ws.def(
+            r#"
+            ---@param i integer
+            ---@param ... string
+            local function f(i, ...)
+                A = { select(i, ...) }
+            end
+            "#,
+        );
+
+        assert_eq!(ws.expr_ty("A"), ws.ty("string[]"));
+    }
+
+    #[test]
+    fn test_legacy_vararg_tag() {
+        let mut ws = VirtualWorkspace::new_with_init_std_lib();
+        ws.def(
+            r#"
+            ---@param i integer
+            ---@vararg string
+            function legacy_vararg(i, ...)
+                A = { ... }
+            end
+            "#,
+        );
+
+        assert_eq!(ws.expr_ty("A"), ws.ty("string[]"));
+    }
+
+    #[test]
+    fn test_forward_dots_to_variadic_call() {
+        let mut ws = VirtualWorkspace::new_with_init_std_lib();
+        ws.def(
+            r#"
+            ---@generic T
+            ---@param ... T
+            ---@return T
+            local function first(...) end
+
+            ---@param ... string
+            ---@return string ...
+            local function pass(...)
+                return ...
+            end
+
+            ---@param ... string
+            local function f(...)
+                A = first(...)
+                B = { pass(...) }
+                return pass(...)
+            end
+
+            C = f("a", "b")
+            "#,
+        );
+
+        assert_eq!(ws.expr_ty("A"), ws.ty("string"));
+        assert_eq!(ws.expr_ty("B"), ws.ty("string[]"));
+        assert_eq!(ws.expr_ty("C"), ws.ty("string"));
+    }
+}
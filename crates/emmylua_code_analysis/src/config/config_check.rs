@@ -0,0 +1,277 @@
+use std::{collections::HashMap, path::PathBuf};
+
+use serde_json::Value;
+use serde_path_to_error::Segment;
+use wax::Glob;
+
+use crate::DiagnosticCode;
+
+use super::{
+    Emmyrc,
+    config_loader::{find_unknown_keys, read_config_file},
+    flatten_config::FlattenConfigObject,
+};
+
+/// 配置文件中的一个问题, `path` 是问题在 JSON 中的位置, 如 `diagnostics.disable[1]`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigProblem {
+    pub file: PathBuf,
+    pub path: String,
+    pub message: String,
+}
+
+/// 校验配置文件但不加载工作区, 报告无法解析的文件, 未知的键, 无效的值,
+/// 未知的诊断代码以及无效的 glob 模式
+pub fn check_config_files(config_files: &[PathBuf]) -> Vec<ConfigProblem> {
+    let mut problems = Vec::new();
+    for config_file in config_files {
+        let mut report = |path: String, message: String| {
+            problems.push(ConfigProblem {
+                file: config_file.clone(),
+                path,
+                message,
+            });
+        };
+
+        let config = match read_config_file(config_file) {
+            // 与加载时一样兼容 `.luarc.json` 的写法
+            Ok(value) => FlattenConfigObject::parse(value).to_emmyrc(),
+            Err(e) => {
+                report(String::new(), e);
+                continue;
+            }
+        };
+
+        check_config_value(&config, &mut report);
+    }
+
+    problems
+}
+
+fn check_config_value(config: &Value, report: &mut impl FnMut(String, String)) {
+    for key in find_unknown_keys(config) {
+        report(key, "unknown config key".to_string());
+    }
+
+    for (path, message) in find_invalid_values(config) {
+        report(path, format!("invalid value: {}", message));
+    }
+
+    let diagnostics = &config["diagnostics"];
+    for list in ["disable", "enables"] {
+        for (i, code) in array_items(&diagnostics[list]) {
+            if is_unknown_code(code) {
+                report(
+                    format!("diagnostics.{}[{}]", list, i),
+                    format!("unknown diagnostic code {}", code),
+                );
+            }
+        }
+    }
+    if let Value::Object(severity) = &diagnostics["severity"] {
+        for code in severity.keys() {
+            if is_unknown_code(&Value::String(code.clone())) {
+                report(
+                    format!("diagnostics.severity.{}", code),
+                    format!("unknown diagnostic code \"{}\"", code),
+                );
+            }
+        }
+    }
+
+    let workspace = &config["workspace"];
+    for list in ["ignoreGlobs", "silentGlobs"] {
+        check_globs(
+            &format!("workspace.{}", list),
+            &workspace[list],
+            &mut *report,
+        );
+    }
+    for (i, library) in array_items(&workspace["library"]) {
+        check_globs(
+            &format!("workspace.library[{}].ignoreGlobs", i),
+            &library["ignoreGlobs"],
+            &mut *report,
+        );
+    }
+}
+
+/// 反序列化时无效的值的路径和错误. 每次删除报告的值后重新反序列化, 直到没有错误,
+/// 这样同一部分中的多个无效值都能被报告
+fn find_invalid_values(config: &Value) -> Vec<(String, String)> {
+    let mut config = config.clone();
+    // 已删除的数组元素在原始配置中的下标, 之后的错误位置需要还原为原始的下标
+    let mut removed_items: HashMap<String, Vec<usize>> = HashMap::new();
+    let mut problems = Vec::new();
+    while let Err(e) = serde_path_to_error::deserialize::<_, Emmyrc>(&config) {
+        let message = e.inner().to_string();
+        match remove_invalid_value(&mut config, e.path(), &mut removed_items) {
+            Some(path) => problems.push((path, message)),
+            None => {
+                // 无法定位到具体的值时不再继续, 否则会一直报告同一个错误
+                problems.push((String::new(), message));
+                break;
+            }
+        }
+    }
+
+    problems
+}
+
+/// 删除 `path` 处的值, 返回它在原始配置中的路径
+fn remove_invalid_value(
+    config: &mut Value,
+    path: &serde_path_to_error::Path,
+    removed_items: &mut HashMap<String, Vec<usize>>,
+) -> Option<String> {
+    let segments = path
+        .iter()
+        .take_while(|segment| matches!(segment, Segment::Map { .. } | Segment::Seq { .. }))
+        .collect::<Vec<_>>();
+    let (last, parents) = segments.split_last()?;
+    let mut target = config;
+    let mut original_path = String::new();
+    for segment in parents {
+        target = match segment {
+            Segment::Map { key } => {
+                original_path = join_key(&original_path, key);
+                target.get_mut(key)?
+            }
+            Segment::Seq { index } => {
+                let original_index = to_original_index(removed_items, &original_path, *index);
+                original_path = format!("{}[{}]", original_path, original_index);
+                target.get_mut(*index)?
+            }
+            _ => return None,
+        };
+    }
+
+    match last {
+        Segment::Map { key } => {
+            target.as_object_mut()?.remove(key)?;
+            Some(join_key(&original_path, key))
+        }
+        Segment::Seq { index } => {
+            let array = target.as_array_mut()?;
+            if *index >= array.len() {
+                return None;
+            }
+            array.remove(*index);
+            let original_index = to_original_index(removed_items, &original_path, *index);
+            let removed = removed_items.entry(original_path.clone()).or_default();
+            removed.push(original_index);
+            removed.sort();
+            Some(format!("{}[{}]", original_path, original_index))
+        }
+        _ => None,
+    }
+}
+
+fn join_key(path: &str, key: &str) -> String {
+    if path.is_empty() {
+        key.to_string()
+    } else {
+        format!("{}.{}", path, key)
+    }
+}
+
+/// 将删除元素后的数组下标还原为原始数组中的下标
+fn to_original_index(
+    removed_items: &HashMap<String, Vec<usize>>,
+    array_path: &str,
+    index: usize,
+) -> usize {
+    let mut original_index = index;
+    for removed in removed_items.get(array_path).into_iter().flatten() {
+        if *removed <= original_index {
+            original_index += 1;
+        }
+    }
+    original_index
+}
+
+fn array_items(value: &Value) -> impl Iterator<Item = (usize, &Value)> {
+    value.as_array().into_iter().flatten().enumerate()
+}
+
+/// 未知的诊断代码在反序列化时会变成 `DiagnosticCode::None`
+fn is_unknown_code(code: &Value) -> bool {
+    code.is_string()
+        && serde_json::from_value::<DiagnosticCode>(code.clone())
+            .is_ok_and(|code| code == DiagnosticCode::None)
+}
+
+fn check_globs(path: &str, patterns: &Value, report: &mut impl FnMut(String, String)) {
+    for (i, pattern) in array_items(patterns) {
+        let Some(pattern) = pattern.as_str() else {
+            continue;
+        };
+        if let Err(e) = Glob::new(pattern) {
+            report(
+                format!("{}[{}]", path, i),
+                format!("invalid glob pattern \"{}\": {}", pattern, e),
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_config_files() {
+        let dir =
+            std::env::temp_dir().join(format!("emmylua_config_check_test_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let config_file = dir.join(".emmyrc.json");
+        std::fs::write(
+            &config_file,
+            r#"{
+                "runtime": { "version": 5, "requirePattern": [1, "?.lua", 2] },
+                "workspace": {
+                    "ignoreGlobs": ["**/*.spec.lua", "**/[abc"],
+                    "library": [
+                        { "path": "lib", "ignoreGlobs": ["**/test"] },
+                        { "path": "lib2", "ignoreGlobs": [1] }
+                    ]
+                },
+                "hover": { "enable": true, "colour": true },
+                "diagnostics": {
+                    "disable": ["undefined-global", "no-such-code"],
+                    "severity": { "unused": "hint", "not-a-code": "error" }
+                }
+            }"#,
+        )
+        .unwrap();
+        let missing_file = dir.join("missing.json");
+
+        let problems = check_config_files(&[config_file.clone(), missing_file.clone()]);
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        let mut paths = problems
+            .iter()
+            .filter(|problem| problem.file == config_file)
+            .map(|problem| problem.path.as_str())
+            .collect::<Vec<_>>();
+        paths.sort();
+        assert_eq!(
+            paths,
+            vec![
+                "diagnostics.disable[1]",
+                "diagnostics.severity.not-a-code",
+                "hover.colour",
+                "runtime.requirePattern[0]",
+                "runtime.requirePattern[2]",
+                "runtime.version",
+                "workspace.ignoreGlobs[1]",
+                "workspace.library[1]",
+            ]
+        );
+        assert!(
+            problems
+                .iter()
+                .any(|problem| problem.file == missing_file && problem.path.is_empty())
+        );
+    }
+}
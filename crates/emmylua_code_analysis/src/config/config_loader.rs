@@ -1,54 +1,26 @@
-use std::{collections::HashSet, path::PathBuf};
+use std::{
+    collections::HashSet,
+    path::{Path, PathBuf},
+};
 
 use serde_json::Value;
 
-use crate::{config::lua_loader::load_lua_config, read_file_with_encoding};
+use crate::{DiagnosticCode, config::lua_loader::load_lua_config, read_file_with_encoding};
 
-use super::{Emmyrc, flatten_config::FlattenConfigObject};
+use super::{Emmyrc, EmmyrcDiagnostic, flatten_config::FlattenConfigObject};
+
+/// 子目录中的配置文件, 只覆盖该目录下文件的诊断配置
+pub const DIRECTORY_CONFIG_FILE_NAME: &str = ".emmyrc.json";
 
 pub fn load_configs_raw(config_files: Vec<PathBuf>, partial_emmyrcs: Option<Vec<Value>>) -> Value {
     let mut config_jsons = Vec::new();
 
     for config_file in config_files {
         log::info!("Loading config file: {:?}", config_file);
-        let config_content = match read_file_with_encoding(&config_file, "utf-8") {
-            Some(content) => content,
-            None => {
-                log::error!(
-                    "Failed to read config file: {:?}, error: File not found or unreadable",
-                    config_file
-                );
-                continue;
-            }
-        };
-
-        let config_value = if config_file.extension().and_then(|s| s.to_str()) == Some("lua") {
-            match load_lua_config(&config_content) {
-                Ok(value) => value,
-                Err(e) => {
-                    log::error!(
-                        "Failed to parse lua config file: {:?}, error: {:?}",
-                        &config_file,
-                        e
-                    );
-                    continue;
-                }
-            }
-        } else {
-            match serde_json::from_str(&config_content) {
-                Ok(json) => json,
-                Err(e) => {
-                    log::error!(
-                        "Failed to parse config file: {:?}, error: {:?}",
-                        &config_file,
-                        e
-                    );
-                    continue;
-                }
-            }
-        };
-
-        config_jsons.push(config_value);
+        match read_config_file(&config_file) {
+            Ok(config_value) => config_jsons.push(config_value),
+            Err(e) => log::error!("{}", e),
+        }
     }
 
     if let Some(partial_emmyrcs) = partial_emmyrcs {
@@ -81,14 +53,199 @@ pub fn load_configs_raw(config_files: Vec<PathBuf>, partial_emmyrcs: Option<Vec<
     }
 }
 
+/// 读取并解析单个配置文件, `.lua` 文件按 lua 配置解析, 其余按 json 解析
+pub(super) fn read_config_file(config_file: &Path) -> Result<Value, String> {
+    let Some(config_content) = read_file_with_encoding(config_file, "utf-8") else {
+        return Err(format!(
+            "Failed to read config file: {:?}, error: File not found or unreadable",
+            config_file
+        ));
+    };
+
+    if config_file.extension().and_then(|s| s.to_str()) == Some("lua") {
+        load_lua_config(&config_content).map_err(|e| {
+            format!(
+                "Failed to parse lua config file: {:?}, error: {:?}",
+                config_file, e
+            )
+        })
+    } else {
+        serde_json::from_str(&config_content).map_err(|e| {
+            format!(
+                "Failed to parse config file: {:?}, error: {:?}",
+                config_file, e
+            )
+        })
+    }
+}
+
 pub fn load_configs(config_files: Vec<PathBuf>, partial_emmyrcs: Option<Vec<Value>>) -> Emmyrc {
     let emmyrc_json_value = load_configs_raw(config_files, partial_emmyrcs);
-    serde_json::from_value(emmyrc_json_value).unwrap_or_else(|err| {
+    for key in find_unknown_keys(&emmyrc_json_value) {
+        log::warn!("Unknown config key: {}", key);
+    }
+
+    serde_json::from_value(emmyrc_json_value.clone()).unwrap_or_else(|err| {
         log::error!("Failed to parse config: error: {:?}", err);
-        Emmyrc::default()
+        load_valid_sections(emmyrc_json_value)
     })
 }
 
+/// 工作区子目录中的 `.emmyrc.json`, 沿目录层级依次合并到上层配置的诊断开关上.
+/// 配置文件和源文件一起由 `collect_workspace_files` 收集, 因此遵循相同的忽略规则.
+/// 工作区根目录的配置由 `load_configs` 加载, 不包含在结果中
+pub fn load_directory_configs(
+    workspace_roots: &[PathBuf],
+    config_files: Vec<PathBuf>,
+    emmyrc: &Emmyrc,
+) -> Vec<(PathBuf, Emmyrc)> {
+    let mut config_dirs = config_files
+        .into_iter()
+        .filter(|path| is_directory_config_file(path))
+        .filter_map(|path| Some(path.parent()?.to_path_buf()))
+        .filter(|dir| {
+            workspace_roots
+                .iter()
+                .any(|root| dir.starts_with(root) && dir != root)
+        })
+        .collect::<Vec<_>>();
+    // 父目录排在子目录之前
+    config_dirs.sort();
+    config_dirs.dedup();
+
+    let mut directory_configs: Vec<(PathBuf, Emmyrc)> = Vec::new();
+    for dir in config_dirs {
+        let parent = directory_configs
+            .iter()
+            .rev()
+            .find(|(parent_dir, _)| dir.starts_with(parent_dir))
+            .map(|(_, parent)| parent)
+            .unwrap_or(emmyrc);
+        let config = merge_directory_config(parent, dir.join(DIRECTORY_CONFIG_FILE_NAME));
+        directory_configs.push((dir, config));
+    }
+
+    directory_configs
+}
+
+pub fn is_directory_config_file(path: &Path) -> bool {
+    path.file_name()
+        .is_some_and(|name| name == DIRECTORY_CONFIG_FILE_NAME)
+}
+
+fn merge_directory_config(parent: &Emmyrc, config_file: PathBuf) -> Emmyrc {
+    let mut emmyrc = parent.clone();
+    let config = load_configs_raw(vec![config_file], None);
+    let Some(overlay) = config.get("diagnostics") else {
+        return emmyrc;
+    };
+
+    let mut diagnostics = serde_json::to_value(&parent.diagnostics).unwrap_or_default();
+    merge_values(&mut diagnostics, overlay.clone());
+    let mut diagnostics: EmmyrcDiagnostic = match serde_json::from_value(diagnostics) {
+        Ok(diagnostics) => diagnostics,
+        Err(err) => {
+            log::error!("Failed to parse directory config: error: {:?}", err);
+            return emmyrc;
+        }
+    };
+
+    // 子目录中重新开启的诊断不再被上层关闭, 反之亦然
+    let get_codes = |key: &str| -> Vec<DiagnosticCode> {
+        overlay
+            .get(key)
+            .and_then(|codes| serde_json::from_value(codes.clone()).ok())
+            .unwrap_or_default()
+    };
+    let enables = get_codes("enables");
+    let disable = get_codes("disable");
+    diagnostics.disable.retain(|code| !enables.contains(code));
+    diagnostics.enables.retain(|code| !disable.contains(code));
+
+    emmyrc.diagnostics = diagnostics;
+    emmyrc
+}
+
+/// 收集配置中不被识别的键, 例如从 `.luarc.json` 迁移过来但尚未支持的 LuaLS 配置.
+/// 已知的键取自配置的 JSON schema, 默认值为空而不序列化的字段也能识别
+pub(super) fn find_unknown_keys(config: &Value) -> Vec<String> {
+    let schema = serde_json::to_value(schemars::schema_for!(Emmyrc)).unwrap_or_default();
+    let mut unknown_keys = Vec::new();
+    collect_unknown_keys("", config, &schema, &schema, &mut unknown_keys);
+    unknown_keys
+}
+
+fn collect_unknown_keys(
+    prefix: &str,
+    config: &Value,
+    schema: &Value,
+    root_schema: &Value,
+    unknown_keys: &mut Vec<String>,
+) {
+    // 没有声明属性的对象可能是任意键的映射表, 不再继续检查
+    let (Value::Object(config_map), Some(properties)) =
+        (config, find_schema_properties(root_schema, schema))
+    else {
+        return;
+    };
+
+    for (key, value) in config_map {
+        if key.starts_with('$') {
+            continue;
+        }
+
+        let path = if prefix.is_empty() {
+            key.clone()
+        } else {
+            format!("{}.{}", prefix, key)
+        };
+        match properties.get(key) {
+            Some(child) => collect_unknown_keys(&path, value, child, root_schema, unknown_keys),
+            None => unknown_keys.push(path),
+        }
+    }
+}
+
+/// 沿 `$ref` 和 `anyOf` 等组合找到对象声明的属性, `Option` 字段会生成 `anyOf`
+fn find_schema_properties<'a>(
+    root_schema: &'a Value,
+    schema: &'a Value,
+) -> Option<&'a serde_json::Map<String, Value>> {
+    if let Some(properties) = schema.get("properties").and_then(Value::as_object) {
+        return Some(properties);
+    }
+    if let Some(reference) = schema.get("$ref").and_then(Value::as_str) {
+        let target = root_schema.pointer(reference.strip_prefix('#')?)?;
+        return find_schema_properties(root_schema, target);
+    }
+
+    ["anyOf", "oneOf", "allOf"]
+        .iter()
+        .filter_map(|key| schema.get(*key).and_then(Value::as_array))
+        .flatten()
+        .find_map(|sub_schema| find_schema_properties(root_schema, sub_schema))
+}
+
+/// 整体解析失败时, 逐个保留可以解析的顶层配置, 避免一个错误的值使全部配置失效
+fn load_valid_sections(config: Value) -> Emmyrc {
+    let Value::Object(config_map) = config else {
+        return Emmyrc::default();
+    };
+
+    let mut valid_map = serde_json::Map::new();
+    for (key, section) in config_map {
+        let mut candidate = valid_map.clone();
+        candidate.insert(key.clone(), section);
+        if serde_json::from_value::<Emmyrc>(Value::Object(candidate.clone())).is_ok() {
+            valid_map = candidate;
+        } else {
+            log::warn!("Ignoring invalid config section: {}", key);
+        }
+    }
+
+    serde_json::from_value(Value::Object(valid_map)).unwrap_or_default()
+}
+
 fn merge_values(base: &mut Value, overlay: Value) {
     match (base, overlay) {
         (Value::Object(base_map), Value::Object(overlay_map)) => {
@@ -116,3 +273,125 @@ fn merge_values(base: &mut Value, overlay: Value) {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{WorkspaceFolder, collect_workspace_files};
+
+    #[test]
+    fn test_find_unknown_keys() {
+        let config = serde_json::json!({
+            "$schema": "https://example.com/schema.json",
+            "runtime": {
+                "version": "Lua 5.4",
+                "pathStrict": true
+            },
+            "diagnostics": {
+                "disable": ["undefined-global"],
+                "severity": {
+                    "unused": "hint"
+                }
+            },
+            "doc": {
+                "rstPrimaryDomain": "lua",
+                "rstDefaultRole": "lua:obj"
+            },
+            "telemetry": {
+                "enable": false
+            }
+        });
+
+        let mut unknown_keys = find_unknown_keys(&config);
+        unknown_keys.sort();
+        assert_eq!(unknown_keys, vec!["runtime.pathStrict", "telemetry"]);
+    }
+
+    #[test]
+    fn test_load_directory_configs() {
+        let root = std::env::temp_dir().join(format!(
+            "emmylua_directory_config_test_{}",
+            std::process::id()
+        ));
+        let package_dir = root.join("packages").join("strict");
+        let nested_dir = package_dir.join("legacy");
+        std::fs::create_dir_all(&nested_dir).unwrap();
+        std::fs::write(
+            package_dir.join(DIRECTORY_CONFIG_FILE_NAME),
+            r#"{ "diagnostics": { "enables": ["unused"], "severity": { "unused": "error" } } }"#,
+        )
+        .unwrap();
+        std::fs::write(
+            nested_dir.join(DIRECTORY_CONFIG_FILE_NAME),
+            r#"{ "diagnostics": { "disable": ["unused"] } }"#,
+        )
+        .unwrap();
+        // 被忽略的目录中的配置不生效
+        let vendor_dir = root.join("vendor");
+        std::fs::create_dir_all(&vendor_dir).unwrap();
+        std::fs::write(
+            vendor_dir.join(DIRECTORY_CONFIG_FILE_NAME),
+            r#"{ "diagnostics": { "disable": ["unused"] } }"#,
+        )
+        .unwrap();
+
+        let mut emmyrc = Emmyrc::default();
+        emmyrc.diagnostics.disable.push(DiagnosticCode::Unused);
+        emmyrc.workspace.ignore_globs.push("vendor/**".to_string());
+        let files = collect_workspace_files(
+            &[WorkspaceFolder::new(root.clone(), false)],
+            &emmyrc,
+            Some(vec![format!("**/{}", DIRECTORY_CONFIG_FILE_NAME)]),
+            None,
+        );
+        let configs = load_directory_configs(
+            std::slice::from_ref(&root),
+            files
+                .into_iter()
+                .map(|file| PathBuf::from(file.path))
+                .collect(),
+            &emmyrc,
+        );
+        std::fs::remove_dir_all(&root).unwrap();
+
+        assert_eq!(configs.len(), 2);
+        let (dir, strict) = &configs[0];
+        assert_eq!(dir, &package_dir);
+        assert!(strict.diagnostics.disable.is_empty());
+        assert_eq!(strict.diagnostics.enables, vec![DiagnosticCode::Unused]);
+        assert!(
+            strict
+                .diagnostics
+                .severity
+                .contains_key(&DiagnosticCode::Unused)
+        );
+
+        // 继承上层目录的配置
+        let (dir, legacy) = &configs[1];
+        assert_eq!(dir, &nested_dir);
+        assert!(legacy.diagnostics.enables.is_empty());
+        assert_eq!(legacy.diagnostics.disable, vec![DiagnosticCode::Unused]);
+        assert!(
+            legacy
+                .diagnostics
+                .severity
+                .contains_key(&DiagnosticCode::Unused)
+        );
+    }
+
+    #[test]
+    fn test_load_valid_sections() {
+        let config = serde_json::json!({
+            "runtime": {
+                "version": "Lua 5.1"
+            },
+            "diagnostics": {
+                "enable": "yes"
+            }
+        });
+
+        let emmyrc = load_valid_sections(config);
+        assert_eq!(emmyrc.runtime.version, crate::EmmyrcLuaVersion::Lua51);
+        assert!(emmyrc.diagnostics.enable);
+    }
+}
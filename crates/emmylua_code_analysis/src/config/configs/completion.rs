@@ -29,6 +29,11 @@ pub struct EmmyrcCompletion {
     #[serde(default)]
     #[serde_as(deserialize_as = "DefaultOnError")]
     pub call_snippet: bool,
+    /// Whether to offer a call snippet for every overload of a function, so the
+    /// overload to insert can be picked from the list. Requires `callSnippet`.
+    #[serde(default)]
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    pub overload_snippet: bool,
     /// Symbol that's used to trigger postfix autocompletion.
     #[serde(default = "default_postfix")]
     #[schemars(extend("x-vscode-setting" = {
@@ -53,6 +58,7 @@ impl Default for EmmyrcCompletion {
             auto_require_function: default_require_function(),
             auto_require_naming_convention: Default::default(),
             call_snippet: false,
+            overload_snippet: false,
             auto_require_separator: default_auto_require_separator(),
             postfix: default_postfix(),
             base_function_includes_name: default_true(),
@@ -31,6 +31,15 @@ pub struct EmmyrcDiagnostic {
     /// Delay between opening/changing a file and scanning it for errors, in milliseconds.
     #[schemars(extend("x-vscode-setting" = true))]
     pub diagnostic_interval: Option<u64>,
+    /// Whether `shadowed-local` ignores the `local foo = foo` pattern.
+    #[serde(default = "default_true")]
+    pub shadowed_local_ignore_hoist: bool,
+    /// Whether `shadowed-local` ignores locals whose names start with `_`.
+    #[serde(default = "default_true")]
+    pub shadowed_local_ignore_underscore: bool,
+    /// Regular expressions that names must match for `naming-convention`.
+    #[serde(default)]
+    pub naming_convention: EmmyrcNamingConvention,
 }
 
 impl Default for EmmyrcDiagnostic {
@@ -43,6 +52,9 @@ impl Default for EmmyrcDiagnostic {
             severity: HashMap::new(),
             enables: Vec::new(),
             diagnostic_interval: Some(500),
+            shadowed_local_ignore_hoist: default_true(),
+            shadowed_local_ignore_underscore: default_true(),
+            naming_convention: EmmyrcNamingConvention::default(),
         }
     }
 }
@@ -51,6 +63,24 @@ fn default_true() -> bool {
     true
 }
 
+#[derive(Serialize, Deserialize, Debug, JsonSchema, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+/// Naming patterns per kind of declaration. Kinds without a pattern are not checked.
+pub struct EmmyrcNamingConvention {
+    /// Pattern for local variables and parameters, e.g. `^[a-z_][a-zA-Z0-9_]*$`.
+    #[serde(default)]
+    pub local: Option<String>,
+    /// Pattern for global variables.
+    #[serde(default)]
+    pub global: Option<String>,
+    /// Pattern for class names declared with `---@class`, e.g. `^[A-Z][a-zA-Z0-9]*$`.
+    #[serde(default)]
+    pub class: Option<String>,
+    /// Pattern for `<const>` locals, e.g. `^[A-Z][A-Z0-9_]*$`.
+    #[serde(default)]
+    pub constant: Option<String>,
+}
+
 #[derive(Serialize, Deserialize, Debug, JsonSchema, Clone, Copy)]
 #[serde(rename_all = "camelCase")]
 pub enum DiagnosticSeveritySetting {
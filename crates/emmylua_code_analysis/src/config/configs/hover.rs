@@ -14,6 +14,11 @@ pub struct EmmyrcHover {
     /// You can set it to a number between `1` and `255` to customize
     #[serde(default)]
     pub custom_detail: Option<u8>,
+
+    /// The language of fenced code blocks in hover, such as `lua` or `luau`.
+    /// Editors use it to highlight types and signatures.
+    #[serde(default = "default_code_block_language")]
+    pub code_block_language: String,
 }
 
 impl Default for EmmyrcHover {
@@ -21,6 +26,7 @@ impl Default for EmmyrcHover {
         Self {
             enable: default_true(),
             custom_detail: None,
+            code_block_language: default_code_block_language(),
         }
     }
 }
@@ -28,3 +34,7 @@ impl Default for EmmyrcHover {
 fn default_true() -> bool {
     true
 }
+
+fn default_code_block_language() -> String {
+    "lua".to_string()
+}
@@ -30,6 +30,11 @@ pub struct EmmyrcInlayHint {
     #[serde(default = "default_true")]
     #[schemars(extend("x-vscode-setting" = true))]
     pub local_hint: bool,
+    /// Show types of local variables initialized with a literal, such as
+    /// `local a = 1` or `local s = "str"`.
+    #[serde(default = "default_false")]
+    #[schemars(extend("x-vscode-setting" = true))]
+    pub local_literal_hint: bool,
     /// Show methods that override functions from base class.
     #[serde(default = "default_true")]
     #[schemars(extend("x-vscode-setting" = true))]
@@ -68,6 +73,7 @@ impl Default for EmmyrcInlayHint {
             param_hint: default_true(),
             index_hint: default_true(),
             local_hint: default_true(),
+            local_literal_hint: default_false(),
             override_hint: default_true(),
             meta_call_hint: default_true(),
             enum_param_hint: default_false(),
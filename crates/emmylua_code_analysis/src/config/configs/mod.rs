@@ -14,12 +14,13 @@ mod runtime;
 mod semantictoken;
 mod signature;
 mod strict;
+mod types;
 mod workspace;
 
 pub use code_action::EmmyrcCodeAction;
 pub use codelen::EmmyrcCodeLens;
 pub use completion::{EmmyrcCompletion, EmmyrcFilenameConvention};
-pub use diagnostics::{DiagnosticSeveritySetting, EmmyrcDiagnostic};
+pub use diagnostics::{DiagnosticSeveritySetting, EmmyrcDiagnostic, EmmyrcNamingConvention};
 pub use doc::{DocSyntax, EmmyrcDoc};
 pub use document_color::EmmyrcDocumentColor;
 pub use hover::EmmyrcHover;
@@ -32,6 +33,7 @@ pub use runtime::{EmmyrcLuaVersion, EmmyrcRuntime};
 pub use semantictoken::EmmyrcSemanticToken;
 pub use signature::EmmyrcSignature;
 pub use strict::EmmyrcStrict;
+pub use types::EmmyrcType;
 pub use workspace::{
     EmmyLibraryConfig, EmmyLibraryItem, EmmyrcWorkspace, EmmyrcWorkspaceModuleMap,
 };
@@ -0,0 +1,13 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, JsonSchema, Default, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct EmmyrcType {
+    /// The maximum number of members in an inferred union type.
+    /// When a union grows beyond it, literal members are widened to their base types,
+    /// e.g. many string literals collapse into `string`.
+    /// Default is `None`, which means no limit.
+    #[serde(default)]
+    pub max_union_size: Option<usize>,
+}
@@ -10,6 +10,11 @@ pub struct EmmyrcWorkspace {
     /// Ignore globs. eg: ["**/*.lua"]
     #[serde(default)]
     pub ignore_globs: Vec<String>,
+    /// Silent globs. Matched files are still analyzed for type inference, but produce no
+    /// diagnostics and are excluded from workspace symbols and from global, type, module path
+    /// and auto-require completion. eg: ["vendor/**"]
+    #[serde(default)]
+    pub silent_globs: Vec<String>,
     #[serde(default)]
     /// Library paths. Can be a string path or an object with path and ignore rules.
     /// eg: ["/usr/local/share/lua/5.1"] or [{"path": "/usr/local/share/lua/5.1", "ignoreDir": ["test"], "ignoreGlobs": ["**/*.spec.lua"]}]
@@ -49,6 +54,7 @@ impl Default for EmmyrcWorkspace {
         Self {
             ignore_dir: Vec::new(),
             ignore_globs: Vec::new(),
+            silent_globs: Vec::new(),
             library: Vec::new(),
             package_dirs: Vec::new(),
             workspace_roots: Vec::new(),
@@ -42,6 +42,8 @@ fn flatten_object(prefix: &str, val: &Value, config: &mut HashMap<String, Value>
 fn to_emmyrc_json(config: &FlattenConfigObject) -> Value {
     let mut emmyrc = Value::Object(Default::default());
     for (k, v) in &config.config {
+        // LuaLS 的配置也可以写成 `Lua.runtime.version` 的形式
+        let k = k.strip_prefix("Lua.").unwrap_or(k);
         let keys: Vec<&str> = k.split('.').collect();
         let mut current = &mut emmyrc;
         for i in 0..keys.len() {
@@ -33,4 +33,29 @@ mod test {
 }"#;
         assert_eq!(json_str, expected);
     }
+
+    #[test]
+    fn test_parse_luals_prefix() {
+        let luals_json = serde_json::json!({
+            "Lua.runtime.version": "Lua 5.4",
+            "Lua.diagnostics.disable": ["undefined-global"],
+            "workspace.library": ["lib"]
+        });
+        let config = FlattenConfigObject::parse(luals_json);
+        let emmyrc_json = config.to_emmyrc();
+        assert_eq!(
+            emmyrc_json,
+            serde_json::json!({
+                "runtime": {
+                    "version": "Lua 5.4"
+                },
+                "diagnostics": {
+                    "disable": ["undefined-global"]
+                },
+                "workspace": {
+                    "library": ["lib"]
+                }
+            })
+        );
+    }
 }
@@ -1,3 +1,4 @@
+mod config_check;
 mod config_loader;
 mod configs;
 mod flatten_config;
@@ -6,13 +7,18 @@ mod pre_process;
 
 use std::{collections::HashMap, path::Path};
 
-pub use config_loader::{load_configs, load_configs_raw};
+pub use config_check::{ConfigProblem, check_config_files};
+pub use config_loader::{
+    DIRECTORY_CONFIG_FILE_NAME, is_directory_config_file, load_configs, load_configs_raw,
+    load_directory_configs,
+};
 pub use configs::{
     DiagnosticSeveritySetting, DocSyntax, EmmyLibraryConfig, EmmyLibraryItem, EmmyrcCodeAction,
     EmmyrcCodeLens, EmmyrcCompletion, EmmyrcDiagnostic, EmmyrcDoc, EmmyrcDocumentColor,
     EmmyrcExternalTool, EmmyrcFilenameConvention, EmmyrcHover, EmmyrcInlayHint, EmmyrcInlineValues,
-    EmmyrcLuaVersion, EmmyrcReference, EmmyrcReformat, EmmyrcResource, EmmyrcRuntime,
-    EmmyrcSemanticToken, EmmyrcSignature, EmmyrcStrict, EmmyrcWorkspace, EmmyrcWorkspaceModuleMap,
+    EmmyrcLuaVersion, EmmyrcNamingConvention, EmmyrcReference, EmmyrcReformat, EmmyrcResource,
+    EmmyrcRuntime, EmmyrcSemanticToken, EmmyrcSignature, EmmyrcStrict, EmmyrcType, EmmyrcWorkspace,
+    EmmyrcWorkspaceModuleMap,
 };
 use emmylua_parser::{LuaLanguageLevel, LuaNonStdSymbolSet, ParserConfig, SpecialFunction};
 use rowan::NodeCache;
@@ -61,6 +67,8 @@ pub struct Emmyrc {
     pub doc: EmmyrcDoc,
     #[serde(default)]
     pub format: EmmyrcReformat,
+    #[serde(default, rename = "type")]
+    pub types: EmmyrcType,
 }
 
 impl Emmyrc {
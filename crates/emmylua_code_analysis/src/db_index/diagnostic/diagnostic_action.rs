@@ -1,4 +1,4 @@
-use rowan::TextRange;
+use rowan::{TextRange, TextSize};
 
 use crate::DiagnosticCode;
 
@@ -17,6 +17,13 @@ impl DiagnosticAction {
         self.range
     }
 
+    /// 遇到对应的 `---@diagnostic enable` 时结束禁用范围
+    pub fn close_at(&mut self, offset: TextSize) {
+        if self.range.contains(offset) {
+            self.range = TextRange::new(self.range.start(), offset);
+        }
+    }
+
     pub fn is_enable(&self) -> bool {
         matches!(self.kind, DiagnosticActionKind::Enable(_))
     }
@@ -5,7 +5,7 @@ use hashbrown::{HashMap, HashSet};
 
 pub use analyze_error::AnalyzeError;
 pub use diagnostic_action::{DiagnosticAction, DiagnosticActionKind};
-use rowan::TextRange;
+use rowan::{TextRange, TextSize};
 
 use crate::{DiagnosticCode, FileId};
 
@@ -56,6 +56,50 @@ impl DiagnosticIndex {
             .insert(code);
     }
 
+    pub fn remove_file_diagnostic_disabled(
+        &mut self,
+        file_id: FileId,
+        code: DiagnosticCode,
+    ) -> bool {
+        self.file_diagnostic_disabled
+            .get_mut(&file_id)
+            .is_some_and(|disabled| disabled.remove(&code))
+    }
+
+    pub fn has_diagnostic_action(&self, file_id: FileId, code: DiagnosticCode) -> bool {
+        self.diagnostic_actions
+            .get(&file_id)
+            .is_some_and(|actions| actions.iter().any(|action| action.get_code() == Some(code)))
+    }
+
+    pub fn clear_file_diagnostic_disabled(&mut self, file_id: FileId) {
+        self.file_diagnostic_disabled.remove(&file_id);
+    }
+
+    /// 结束在 `offset` 处仍然生效的禁用, `code` 为 `None` 时结束所有的禁用范围
+    pub fn close_diagnostic_actions(
+        &mut self,
+        file_id: FileId,
+        offset: TextSize,
+        code: Option<DiagnosticCode>,
+    ) -> bool {
+        let Some(actions) = self.diagnostic_actions.get_mut(&file_id) else {
+            return false;
+        };
+
+        let mut closed = false;
+        for action in actions.iter_mut() {
+            if action.is_disable()
+                && (code.is_none() || action.get_code() == code)
+                && action.get_range().contains(offset)
+            {
+                action.close_at(offset);
+                closed = true;
+            }
+        }
+        closed
+    }
+
     pub fn get_diagnostics_actions(&self, file_id: FileId) -> Option<&Vec<DiagnosticAction>> {
         self.diagnostic_actions.get(&file_id)
     }
@@ -1,6 +1,6 @@
 use std::ops::Deref;
 
-use emmylua_parser::{LuaIndexKey, LuaSyntaxId, LuaSyntaxKind, NumberResult};
+use emmylua_parser::{LuaIndexKey, LuaSyntaxId, LuaSyntaxKind, LuaTokenKind, NumberResult};
 use rowan::{TextRange, TextSize};
 use serde::{Deserialize, Serialize};
 use smol_str::SmolStr;
@@ -14,6 +14,7 @@ pub struct LuaMember {
     key: LuaMemberKey,
     feature: LuaMemberFeature,
     global_id: Option<GlobalId>,
+    union_define: bool,
 }
 
 impl LuaMember {
@@ -28,6 +29,7 @@ impl LuaMember {
             key,
             feature: decl_feature,
             global_id: global_path,
+            union_define: false,
         }
     }
 
@@ -68,6 +70,15 @@ impl LuaMember {
     pub fn get_global_id(&self) -> Option<&GlobalId> {
         self.global_id.as_ref()
     }
+
+    /// 没有类型注解且值不是函数的字段赋值, 可以与同名的其他赋值合并为联合类型
+    pub fn set_union_define(&mut self, union_define: bool) {
+        self.union_define = union_define;
+    }
+
+    pub fn is_union_define(&self) -> bool {
+        self.union_define
+    }
 }
 
 #[derive(Debug, Eq, PartialEq, Clone, Copy, Hash, Serialize, Deserialize)]
@@ -144,6 +155,20 @@ impl LuaMemberKey {
         matches!(self, LuaMemberKey::ExprType(_))
     }
 
+    /// 名称是合法的 Lua 标识符, 可以用 `t.name` 访问; 否则只能写成 `t["name"]`
+    pub fn is_identifier(&self) -> bool {
+        let LuaMemberKey::Name(name) = self else {
+            return false;
+        };
+
+        let mut chars = name.chars();
+        chars
+            .next()
+            .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+            && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+            && LuaTokenKind::from_keyword(name).is_none()
+    }
+
     pub fn get_name(&self) -> Option<&str> {
         match self {
             LuaMemberKey::Name(name) => Some(name.as_ref()),
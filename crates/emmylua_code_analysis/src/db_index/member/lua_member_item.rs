@@ -1,6 +1,6 @@
 use crate::{DbIndex, InferFailReason, LuaSemanticDeclId, LuaType, TypeOps};
 
-use super::LuaMemberId;
+use super::{LuaMember, LuaMemberId};
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum LuaMemberIndexItem {
@@ -56,6 +56,9 @@ fn resolve_member_type(
                     return Err(InferFailReason::None);
                 }
             }
+            if is_union_define(&members) {
+                return resolve_union_define_type(db, &members);
+            }
             if db.get_emmyrc().strict.meta_override_file_define {
                 for member in &members {
                     let feature = member.get_feature();
@@ -135,6 +138,9 @@ fn resolve_type_owner_member_id(
                 .iter()
                 .map(|id| member_index.get_member(id))
                 .collect::<Option<Vec<_>>>()?;
+            if is_union_define(&members) {
+                return resolve_union_define_owner(db, &members);
+            }
             for member in &members {
                 let feature = member.get_feature();
                 if feature.is_meta_decl() {
@@ -183,6 +189,49 @@ fn resolve_type_owner_member_id(
     }
 }
 
+fn is_union_define(members: &[&LuaMember]) -> bool {
+    !members.is_empty() && members.iter().all(|member| member.is_union_define())
+}
+
+/// 只由赋值定义的字段, 类型为各次赋值类型的联合, `nil` 初始化也包含在内.
+/// 有赋值未推断时返回 Err 等待重试, 循环依赖的赋值(如 `self.n = self.n + 1`)由 unresolve 阶段单独处理
+fn resolve_union_define_type(
+    db: &DbIndex,
+    members: &[&LuaMember],
+) -> Result<LuaType, InferFailReason> {
+    let mut typ = LuaType::Never;
+    for member in members {
+        let member_type = db
+            .get_type_index()
+            .get_type_cache(&member.get_id().into())
+            .ok_or(InferFailReason::UnResolveMemberType(member.get_id()))?
+            .as_type();
+        typ = TypeOps::Union.apply(db, &typ, member_type);
+    }
+
+    Ok(typ)
+}
+
+/// 优先选择类型可拥有成员的赋值, 其次是第一个推断出非 `nil` 类型的赋值
+fn resolve_union_define_owner(db: &DbIndex, members: &[&LuaMember]) -> Option<LuaMemberId> {
+    let resolved = members
+        .iter()
+        .filter_map(|member| {
+            let cache = db
+                .get_type_index()
+                .get_type_cache(&member.get_id().into())?;
+            Some((member.get_id(), cache.as_type()))
+        })
+        .collect::<Vec<_>>();
+
+    resolved
+        .iter()
+        .find(|(_, typ)| typ.is_member_owner())
+        .or_else(|| resolved.iter().find(|(_, typ)| !typ.is_nil()))
+        .map(|(id, _)| *id)
+        .or_else(|| members.first().map(|member| member.get_id()))
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum MemberTypeResolveState {
     All,
@@ -67,6 +67,7 @@ impl LuaMemberIndex {
         let member = self.get_member(&id)?;
         let key = member.get_key().clone();
         let feature = member.get_feature();
+        let union_define = member.is_union_define();
         let member_map = self
             .owner_members
             .entry(owner.clone())
@@ -96,13 +97,21 @@ impl LuaMemberIndex {
             }
 
             let item = member_map.get_member(&key)?.clone();
-            let new_items = if self.is_item_only_meta(&item) {
+            let is_define_union = matches!(owner, LuaMemberOwner::Type(_))
+                && feature.is_file_define()
+                && union_define
+                && self.is_item_only_union_define(&item);
+            let new_items = if is_define_union || self.is_item_only_meta(&item) {
                 match item {
                     LuaMemberIndexItem::One(old_id) => {
                         if old_id == id {
                             return Some(());
                         }
-                        LuaMemberIndexItem::Many(vec![id, old_id])
+                        if is_define_union {
+                            LuaMemberIndexItem::Many(vec![old_id, id])
+                        } else {
+                            LuaMemberIndexItem::Many(vec![id, old_id])
+                        }
                     }
                     LuaMemberIndexItem::Many(mut ids) => {
                         if ids.contains(&id) {
@@ -126,6 +135,16 @@ impl LuaMemberIndex {
         Some(())
     }
 
+    /// 类的字段没有声明时, 每次赋值都会加入成员项, 字段类型为所有赋值类型的联合.
+    /// 首次赋值带有类型注解或赋值为函数时不合并
+    fn is_item_only_union_define(&self, item: &LuaMemberIndexItem) -> bool {
+        item.get_member_ids().iter().all(|id| {
+            self.get_member(id).is_some_and(|member| {
+                member.get_feature().is_file_define() && member.is_union_define()
+            })
+        })
+    }
+
     fn is_item_only_meta(&self, item: &LuaMemberIndexItem) -> bool {
         match item {
             LuaMemberIndexItem::One(id) => {
@@ -15,9 +15,15 @@ mod signature;
 mod traits;
 mod r#type;
 
-use std::sync::Arc;
+use std::{
+    path::{Path, PathBuf},
+    sync::Arc,
+};
 
-use crate::{Emmyrc, FileId, Vfs};
+use lsp_types::DiagnosticSeverity;
+use wax::{Glob, Pattern};
+
+use crate::{DiagnosticCode, Emmyrc, FileId, Vfs, diagnostic::get_default_severity};
 pub use declaration::*;
 pub use dependency::LuaDependencyIndex;
 pub use diagnostic::{AnalyzeError, DiagnosticAction, DiagnosticActionKind, DiagnosticIndex};
@@ -53,6 +59,8 @@ pub struct DbIndex {
     global_index: LuaGlobalIndex,
     json_schema_index: JsonSchemaIndex,
     emmyrc: Arc<Emmyrc>,
+    directory_configs: Vec<(PathBuf, Arc<Emmyrc>)>,
+    silent_globs: Option<wax::Any<'static>>,
 }
 
 #[allow(unused)]
@@ -81,6 +89,8 @@ impl DbIndex {
             global_index: LuaGlobalIndex::new(),
             json_schema_index: JsonSchemaIndex::new(),
             emmyrc: Arc::new(Emmyrc::default()),
+            directory_configs: Vec::new(),
+            silent_globs: None,
         }
     }
 
@@ -213,12 +223,165 @@ impl DbIndex {
     pub fn update_config(&mut self, config: Arc<Emmyrc>) {
         self.vfs.update_config(config.clone());
         self.modules_index.update_config(config.clone());
+        self.silent_globs = build_silent_globs(&config.workspace.silent_globs);
         self.emmyrc = config;
     }
 
     pub fn get_emmyrc(&self) -> &Emmyrc {
         &self.emmyrc
     }
+
+    /// 子目录中 `.emmyrc.json` 解析后的配置, 目录越深优先级越高
+    pub fn update_directory_configs(&mut self, mut configs: Vec<(PathBuf, Arc<Emmyrc>)>) {
+        configs.sort_by_key(|(dir, _)| std::cmp::Reverse(dir.components().count()));
+        self.directory_configs = configs;
+    }
+
+    /// 文件所在目录最近的子目录配置, 没有时为 `None`
+    pub fn get_file_config_dir(&self, file_id: &FileId) -> Option<&Path> {
+        let path = self.vfs.get_file_path(file_id)?;
+        self.directory_configs
+            .iter()
+            .find(|(dir, _)| path.starts_with(dir))
+            .map(|(dir, _)| dir.as_path())
+    }
+
+    /// 文件实际使用的配置, 子目录中的配置优先于工作区配置
+    pub fn get_file_emmyrc(&self, file_id: &FileId) -> &Emmyrc {
+        let Some(path) = self.vfs.get_file_path(file_id) else {
+            return &self.emmyrc;
+        };
+
+        self.directory_configs
+            .iter()
+            .find(|(dir, _)| path.starts_with(dir))
+            .map(|(_, emmyrc)| emmyrc.as_ref())
+            .unwrap_or(&self.emmyrc)
+    }
+
+    /// 诊断代码实际使用的严重程度, `diagnostics.severity` 中的配置优先于默认值
+    pub fn get_effective_severity(&self, code: DiagnosticCode) -> DiagnosticSeverity {
+        effective_severity(&self.emmyrc, code)
+    }
+
+    /// 同 `get_effective_severity`, 但使用文件所在目录的配置
+    pub fn get_file_effective_severity(
+        &self,
+        file_id: &FileId,
+        code: DiagnosticCode,
+    ) -> DiagnosticSeverity {
+        effective_severity(self.get_file_emmyrc(file_id), code)
+    }
+
+    /// 文件匹配 `workspace.silentGlobs`, 仍然参与类型推断, 但不报告诊断,
+    /// 也不出现在工作区符号以及全局变量、类型、模块路径和自动 require 补全中
+    pub fn is_ignored(&self, file_id: &FileId) -> bool {
+        let Some(silent_globs) = &self.silent_globs else {
+            return false;
+        };
+        let Some(path) = self.vfs.get_file_path(file_id) else {
+            return false;
+        };
+
+        self.modules_index
+            .get_workspace_relative_path(path)
+            .is_some_and(|relative_path| silent_globs.is_match(relative_path))
+    }
+
+    /// 通过完整类型名查找类型声明, 泛型参数可通过 `LuaTypeIndex::get_generic_params` 获取,
+    /// 成员可通过 `LuaMemberIndex::get_members` 获取
+    pub fn find_type_decl_by_name(&self, full_name: &str) -> Option<&LuaTypeDecl> {
+        self.types_index.find_type_decl_by_name(full_name)
+    }
+
+    /// 从类型出发按路径逐级查找成员, 如 `["sub", "field"]` 对应 `Type.sub.field`.
+    /// 每一级都会沿父类查找, 中间成员可以是类、表字面量、`{ field: T }` 形式的表类型或它们的联合.
+    /// 表类型的字段没有独立的成员声明, 路径的最后一级落在这类字段上时返回 None.
+    /// 联合类型中有多个分支包含同名成员时, 只取第一个包含该成员的分支
+    pub fn resolve_member_path(&self, type_name: &str, path: &[&str]) -> Option<&LuaMember> {
+        let type_decl = self.find_type_decl_by_name(type_name)?;
+        let mut typ = LuaType::Ref(type_decl.get_id());
+        let mut member = None;
+        for name in path {
+            let key = LuaMemberKey::Name((*name).into());
+            let (found, field_type) = self.find_member_by_key(&typ, &key)?;
+            member = found;
+            typ = TypeOps::Remove.apply(self, &field_type, &LuaType::Nil);
+        }
+
+        member
+    }
+
+    fn find_member_by_key(
+        &self,
+        typ: &LuaType,
+        key: &LuaMemberKey,
+    ) -> Option<(Option<&LuaMember>, LuaType)> {
+        match typ {
+            LuaType::Ref(type_id) | LuaType::Def(type_id) => type_id
+                .collect_super_types_with_self(self, LuaType::Ref(type_id.clone()))
+                .into_iter()
+                .find_map(|super_type| match super_type {
+                    LuaType::Ref(super_id) => {
+                        self.find_owner_member(&LuaMemberOwner::Type(super_id), key)
+                    }
+                    _ => None,
+                }),
+            LuaType::Generic(generic) => self.find_member_by_key(&generic.get_base_type(), key),
+            LuaType::TableConst(range) => {
+                self.find_owner_member(&LuaMemberOwner::Element(range.clone()), key)
+            }
+            LuaType::Object(object) => object
+                .get_field(key)
+                .map(|field_type| (None, field_type.clone())),
+            LuaType::Union(union) => union
+                .into_vec()
+                .iter()
+                .find_map(|union_type| self.find_member_by_key(union_type, key)),
+            _ => None,
+        }
+    }
+
+    /// 同名成员有多个定义时, 取与跳转定义一致的成员, 类型为成员项解析出的类型
+    fn find_owner_member(
+        &self,
+        owner: &LuaMemberOwner,
+        key: &LuaMemberKey,
+    ) -> Option<(Option<&LuaMember>, LuaType)> {
+        let item = self.members_index.get_member_item(owner, key)?;
+        let member_id = match item.resolve_semantic_decl(self) {
+            Some(LuaSemanticDeclId::Member(member_id)) => member_id,
+            _ => *item.get_member_ids().first()?,
+        };
+        let member = self.members_index.get_member(&member_id)?;
+        let typ = item.resolve_type(self).unwrap_or(LuaType::Unknown);
+        Some((Some(member), typ))
+    }
+}
+
+fn effective_severity(emmyrc: &Emmyrc, code: DiagnosticCode) -> DiagnosticSeverity {
+    match emmyrc.diagnostics.severity.get(&code) {
+        Some(severity) => (*severity).into(),
+        None => get_default_severity(code),
+    }
+}
+
+fn build_silent_globs(patterns: &[String]) -> Option<wax::Any<'static>> {
+    if patterns.is_empty() {
+        return None;
+    }
+
+    let globs = patterns
+        .iter()
+        .filter_map(|pattern| match Glob::new(pattern) {
+            Ok(glob) => Some(glob.into_owned()),
+            Err(e) => {
+                log::error!("Invalid silent glob pattern: {:?}", e);
+                None
+            }
+        })
+        .collect::<Vec<_>>();
+    wax::any(globs).ok()
 }
 
 impl LuaIndex for DbIndex {
@@ -525,6 +525,14 @@ impl LuaModuleIndex {
         false
     }
 
+    /// 文件相对于所在工作区根目录的路径, 嵌套的工作区优先
+    pub fn get_workspace_relative_path<'a>(&self, path: &'a Path) -> Option<&'a Path> {
+        self.workspaces
+            .iter()
+            .filter_map(|workspace| path.strip_prefix(&workspace.root).ok())
+            .min_by_key(|relative_path| relative_path.components().count())
+    }
+
     pub fn get_workspace_id(&self, file_id: FileId) -> Option<WorkspaceId> {
         if let Some(module_info) = self.file_module_map.get(&file_id) {
             return Some(module_info.workspace_id);
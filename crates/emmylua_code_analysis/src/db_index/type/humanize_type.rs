@@ -24,6 +24,8 @@ pub enum RenderLevel {
     Normal,
     Brief,
     Minimal,
+    /// 写回注解的类型, 不截断也不展开, 结果可以被重新解析
+    Annotation,
 }
 
 impl RenderLevel {
@@ -36,6 +38,7 @@ impl RenderLevel {
             RenderLevel::Normal => RenderLevel::Brief,
             RenderLevel::Brief => RenderLevel::Minimal,
             RenderLevel::Minimal => RenderLevel::Minimal,
+            RenderLevel::Annotation => RenderLevel::Annotation,
         }
     }
 
@@ -48,6 +51,7 @@ impl RenderLevel {
             RenderLevel::Normal => 4,
             RenderLevel::Brief => 2,
             RenderLevel::Minimal => 2,
+            RenderLevel::Annotation => usize::MAX,
         }
     }
 
@@ -60,6 +64,7 @@ impl RenderLevel {
             RenderLevel::Normal => 4,
             RenderLevel::Brief => 2,
             RenderLevel::Minimal => 2,
+            RenderLevel::Annotation => usize::MAX,
         }
     }
 
@@ -77,6 +82,27 @@ impl RenderLevel {
 
 const DEFAULT_MAX_DEPTH: u8 = 12;
 
+/// Options that tune how `TypeHumanizer` renders a type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HumanizeOptions {
+    /// Expand generic aliases to their origin type at detailed levels.
+    pub expand_alias: bool,
+    /// Render `self` parameters of function types.
+    pub render_self: bool,
+    /// Maximum recursion depth before writing `...`.
+    pub max_depth: u8,
+}
+
+impl Default for HumanizeOptions {
+    fn default() -> Self {
+        Self {
+            expand_alias: true,
+            render_self: true,
+            max_depth: DEFAULT_MAX_DEPTH,
+        }
+    }
+}
+
 /// Core writer-based type humanizer. Avoids intermediate `String` allocations
 /// and prevents infinite recursion through depth tracking and cycle detection.
 pub struct TypeHumanizer<'a> {
@@ -84,6 +110,8 @@ pub struct TypeHumanizer<'a> {
     level: RenderLevel,
     depth: u8,
     max_depth: u8,
+    expand_alias: bool,
+    render_self: bool,
     /// Tracks visited `LuaTypeDeclId`s to break cycles from recursive aliases / refs.
     visited: HashSet<LuaTypeDeclId>,
 }
@@ -95,6 +123,8 @@ impl<'a> TypeHumanizer<'a> {
             level,
             depth: 0,
             max_depth: DEFAULT_MAX_DEPTH,
+            expand_alias: true,
+            render_self: true,
             visited: HashSet::new(),
         }
     }
@@ -104,6 +134,13 @@ impl<'a> TypeHumanizer<'a> {
         self
     }
 
+    pub fn with_options(mut self, opts: HumanizeOptions) -> Self {
+        self.max_depth = opts.max_depth;
+        self.expand_alias = opts.expand_alias;
+        self.render_self = opts.render_self;
+        self
+    }
+
     // ─── depth guard ────────────────────────────────────────────────
 
     /// Try to enter a deeper recursion level. Returns `None` if depth limit
@@ -559,7 +596,12 @@ impl<'a> TypeHumanizer<'a> {
         w.write_char('(')?;
         let saved = self.level;
         self.level = self.child_level();
-        for (i, param) in lua_func.get_params().iter().enumerate() {
+        let render_self = self.render_self;
+        let params = lua_func
+            .get_params()
+            .iter()
+            .filter(|param| render_self || param.0 != "self");
+        for (i, param) in params.enumerate() {
             if i > 0 {
                 w.write_str(", ")?;
             }
@@ -711,7 +753,8 @@ impl<'a> TypeHumanizer<'a> {
         if matches!(
             self.level,
             RenderLevel::Documentation | RenderLevel::CustomDetailed(_) | RenderLevel::Detailed
-        ) && type_decl.is_alias()
+        ) && self.expand_alias
+            && type_decl.is_alias()
         {
             // cycle detection for alias expansion
             if !self.visited.insert(base_id.clone()) {
@@ -942,10 +985,15 @@ impl<'a> TypeHumanizer<'a> {
         let is_vararg = signature.is_vararg;
         let last_idx = signature.params.len();
         self.level = self.child_level();
+        let mut written = 0;
         for (i, param) in signature.get_type_params().iter().enumerate() {
-            if i > 0 {
+            if !self.render_self && param.0 == "self" {
+                continue;
+            }
+            if written > 0 {
                 w.write_str(", ")?;
             }
+            written += 1;
             if i == last_idx - 1 && is_vararg {
                 w.write_str("...")?;
             }
@@ -1120,7 +1168,18 @@ struct DepthGuardToken;
 /// Humanize a type into a display string. This is the primary backward-compatible
 /// entry point. Internally uses `TypeHumanizer` for efficient, depth-bounded rendering.
 pub fn humanize_type(db: &DbIndex, ty: &LuaType, level: RenderLevel) -> String {
-    let mut humanizer = TypeHumanizer::new(db, level);
+    humanize_type_with(db, ty, level, HumanizeOptions::default())
+}
+
+/// Humanize a type with custom `HumanizeOptions`, e.g. to keep alias names
+/// unexpanded or to hide `self` parameters.
+pub fn humanize_type_with(
+    db: &DbIndex,
+    ty: &LuaType,
+    level: RenderLevel,
+    opts: HumanizeOptions,
+) -> String {
+    let mut humanizer = TypeHumanizer::new(db, level).with_options(opts);
     let mut buf = String::new();
     let _ = humanizer.write_type(ty, &mut buf);
     buf
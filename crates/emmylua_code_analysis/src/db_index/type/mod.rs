@@ -3,6 +3,8 @@ mod generic_param;
 mod humanize_type;
 mod test;
 mod type_decl;
+mod type_descriptor;
+mod type_interner;
 mod type_ops;
 mod type_owner;
 mod type_visit_trait;
@@ -13,8 +15,12 @@ use crate::{DbIndex, FileId, InFiled, db_index::r#type::type_decl::LuaTypeIdenti
 pub use basic_union::{BasicTypeKind, BasicTypeUnion};
 pub use generic_param::GenericParam;
 use hashbrown::{HashMap, HashSet};
-pub use humanize_type::{RenderLevel, TypeHumanizer, format_union_type, humanize_type};
+pub use humanize_type::{
+    HumanizeOptions, RenderLevel, TypeHumanizer, format_union_type, humanize_type,
+    humanize_type_with,
+};
 pub use type_decl::{LuaDeclLocation, LuaDeclTypeKind, LuaTypeDecl, LuaTypeDeclId, LuaTypeFlag};
+pub use type_descriptor::{FieldDescriptor, ParamDescriptor, TypeDescriptor};
 pub use type_ops::TypeOps;
 pub(crate) use type_ops::union_type_shallow;
 pub use type_owner::{LuaTypeCache, LuaTypeOwner};
@@ -109,6 +115,19 @@ impl LuaTypeIndex {
         self.full_name_type_map.get(&global_id)
     }
 
+    /// `MyEnum.keys` 表示枚举 `MyEnum` 的所有字段名, 只在没有同名类型时生效
+    pub fn find_enum_keys_decl(&self, file_id: FileId, name: &str) -> Option<LuaTypeDeclId> {
+        let enum_name = name.strip_suffix(".keys")?;
+        let type_decl = self.find_type_decl(file_id, enum_name)?;
+        type_decl.is_enum().then(|| type_decl.get_id())
+    }
+
+    /// 通过完整类型名查找全局类型声明, 不依赖文件的命名空间上下文
+    pub fn find_type_decl_by_name(&self, full_name: &str) -> Option<&LuaTypeDecl> {
+        self.full_name_type_map
+            .get(&LuaTypeDeclId::global(full_name))
+    }
+
     pub fn find_type_decls(
         &self,
         file_id: FileId,
@@ -287,6 +306,15 @@ impl LuaTypeIndex {
             .insert(owner);
     }
 
+    /// 替换已绑定的类型, 只用于 unresolve 阶段的占位类型
+    pub fn rebind_type(&mut self, owner: LuaTypeOwner, cache: LuaTypeCache) {
+        if let Some(type_cache) = self.types.get_mut(&owner) {
+            *type_cache = cache;
+        } else {
+            self.bind_type(owner, cache);
+        }
+    }
+
     pub fn get_type_cache(&self, owner: &LuaTypeOwner) -> Option<&LuaTypeCache> {
         self.types.get(owner)
     }
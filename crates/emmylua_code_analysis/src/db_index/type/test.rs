@@ -161,4 +161,165 @@ mod test {
         assert_eq!(decl.get_namespace(), "test".into());
         assert_eq!(decl.get_full_name(), "test.new_type");
     }
+
+    #[test]
+    fn test_find_type_decl_by_name() {
+        let mut index = create_type_index();
+        let file_id = FileId { id: 1 };
+        index.add_file_namespace(file_id, "ns".to_string());
+        index.add_type_decl(
+            file_id,
+            LuaTypeDecl::new(
+                file_id,
+                TextRange::new(0.into(), 4.into()),
+                "Foo".to_string(),
+                LuaDeclTypeKind::Class,
+                LuaTypeFlag::None.into(),
+                LuaTypeDeclId::global("ns.Foo"),
+            ),
+        );
+
+        let decl = index.find_type_decl_by_name("ns.Foo");
+        assert!(decl.is_some());
+        assert_eq!(decl.unwrap().get_name(), "Foo");
+        assert!(index.find_type_decl_by_name("Foo").is_none());
+    }
+
+    #[test]
+    fn test_intern_union() {
+        use std::sync::Arc;
+
+        use crate::{LuaType, LuaUnionType};
+
+        let a = LuaType::Ref(LuaTypeDeclId::global("A"));
+        let b = LuaType::Ref(LuaTypeDeclId::global("B"));
+        let c = LuaType::Ref(LuaTypeDeclId::global("C"));
+        let new_union = |types: Vec<LuaType>| {
+            let LuaType::Union(union) =
+                LuaType::Union(LuaUnionType::from_vec(types).into()).intern()
+            else {
+                panic!("expected union type");
+            };
+            union
+        };
+        let first = new_union(vec![a.clone(), b.clone(), c.clone()]);
+        let second = new_union(vec![a.clone(), b.clone(), c.clone()]);
+        assert!(Arc::ptr_eq(&first, &second));
+
+        // 成员顺序不同的联合类型相等, 但不能共享, 否则显示顺序会被改变
+        let reordered = new_union(vec![c, a, b]);
+        assert!(first == reordered);
+        assert!(!Arc::ptr_eq(&first, &reordered));
+    }
+
+    #[test]
+    fn test_intern_union_keeps_written_order() {
+        use crate::VirtualWorkspace;
+
+        let mut ws = VirtualWorkspace::new();
+        ws.def(
+            r#"
+            ---@class InternFoo
+            ---@class InternBar
+            "#,
+        );
+        let first = ws.ty("InternFoo|InternBar");
+        let second = ws.ty("InternBar|InternFoo");
+        assert_eq!(ws.humanize_type(first), "(InternFoo|InternBar)");
+        assert_eq!(ws.humanize_type(second), "(InternBar|InternFoo)");
+    }
+
+    #[test]
+    fn test_intern_doc_type() {
+        use std::sync::Arc;
+
+        use crate::{LuaType, VirtualWorkspace};
+
+        let mut ws = VirtualWorkspace::new();
+        ws.def(
+            r#"
+            ---@class Foo
+            "#,
+        );
+
+        let types = (0..100)
+            .map(|_| ws.ty("(string|Foo)[]"))
+            .collect::<Vec<_>>();
+        let LuaType::Array(first) = &types[0] else {
+            panic!("expected array type");
+        };
+        for ty in &types[1..] {
+            let LuaType::Array(array) = ty else {
+                panic!("expected array type");
+            };
+            assert!(Arc::ptr_eq(first, array));
+        }
+    }
+
+    #[test]
+    fn test_type_descriptor() {
+        use crate::{TypeDescriptor, VirtualWorkspace};
+
+        let mut ws = VirtualWorkspace::new();
+        ws.def(
+            r#"
+            ---@class Foo
+            "#,
+        );
+
+        let types = [
+            "Foo",
+            "(string|Foo)[]",
+            "[integer, \"a\", true]",
+            "fun(a: string, b?: Foo): integer, string",
+            "table<string, Foo>",
+            "{ x: number, y: 1.5 }",
+        ];
+        for type_str in types {
+            let descriptor = ws
+                .ty(type_str)
+                .to_descriptor(ws.analysis.compilation.get_db());
+            let json = serde_json::to_string(&descriptor).unwrap();
+            let round_trip = serde_json::from_str::<TypeDescriptor>(&json).unwrap();
+            assert_eq!(descriptor, round_trip, "{}", json);
+        }
+
+        let descriptor = ws
+            .ty("Foo[]")
+            .to_descriptor(ws.analysis.compilation.get_db());
+        assert_eq!(
+            serde_json::to_value(&descriptor).unwrap(),
+            serde_json::json!({ "kind": "array", "base": { "kind": "ref", "name": "Foo" } })
+        );
+
+        let TypeDescriptor::Signature {
+            params, returns, ..
+        } = ws
+            .ty("fun(a: string): integer")
+            .to_descriptor(ws.analysis.compilation.get_db())
+        else {
+            panic!("expected function descriptor");
+        };
+        assert_eq!(params[0].name, "a");
+        assert_eq!(params[0].typ, Some(TypeDescriptor::String));
+        assert_eq!(returns, vec![TypeDescriptor::Integer]);
+
+        let db = ws.analysis.compilation.get_db();
+        for (value, json) in [
+            (f64::INFINITY, serde_json::json!("inf")),
+            (f64::NEG_INFINITY, serde_json::json!("-inf")),
+            (f64::NAN, serde_json::json!("nan")),
+            (1.5, serde_json::json!(1.5)),
+        ] {
+            let descriptor = crate::LuaType::FloatConst(value).to_descriptor(db);
+            let expected = serde_json::json!({ "kind": "floatConst", "value": json });
+            assert_eq!(serde_json::to_value(&descriptor).unwrap(), expected);
+            let TypeDescriptor::FloatConst { value: round_trip } =
+                serde_json::from_value(expected).unwrap()
+            else {
+                panic!("expected float descriptor");
+            };
+            assert!(round_trip == value || (round_trip.is_nan() && value.is_nan()));
+        }
+    }
 }
@@ -0,0 +1,250 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{DbIndex, LuaMemberKey, LuaSignature, RenderLevel, humanize_type};
+
+use super::{LuaFunctionType, LuaType, VariadicType};
+
+/// 可序列化的类型结构, 供外部工具使用.
+/// 与 `humanize_type` 不同, 这里保留类型的结构而不是渲染成字符串
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum TypeDescriptor {
+    Unknown,
+    Any,
+    Nil,
+    Table,
+    Userdata,
+    Function,
+    Thread,
+    Boolean,
+    String,
+    Integer,
+    Number,
+    Never,
+    BooleanConst {
+        value: bool,
+    },
+    StringConst {
+        value: String,
+    },
+    IntegerConst {
+        value: i64,
+    },
+    FloatConst {
+        /// JSON 中没有 NaN 和无穷大, 这些值写成字符串 `"nan"`, `"inf"` 和 `"-inf"`
+        #[serde(with = "float_value")]
+        value: f64,
+    },
+    Ref {
+        name: String,
+    },
+    Array {
+        base: Box<TypeDescriptor>,
+    },
+    Tuple {
+        types: Vec<TypeDescriptor>,
+    },
+    Union {
+        types: Vec<TypeDescriptor>,
+    },
+    Intersection {
+        types: Vec<TypeDescriptor>,
+    },
+    Generic {
+        name: String,
+        params: Vec<TypeDescriptor>,
+    },
+    Variadic {
+        base: Box<TypeDescriptor>,
+    },
+    #[serde(rename_all = "camelCase")]
+    Signature {
+        params: Vec<ParamDescriptor>,
+        returns: Vec<TypeDescriptor>,
+        is_colon_define: bool,
+    },
+    Object {
+        fields: Vec<FieldDescriptor>,
+    },
+    /// 没有结构化表示的类型, 使用 `humanize_type` 的结果
+    Other {
+        text: String,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ParamDescriptor {
+    pub name: String,
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none", default)]
+    pub typ: Option<TypeDescriptor>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FieldDescriptor {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub typ: TypeDescriptor,
+}
+
+impl LuaType {
+    pub fn to_descriptor(&self, db: &DbIndex) -> TypeDescriptor {
+        match self {
+            LuaType::Unknown => TypeDescriptor::Unknown,
+            LuaType::Any => TypeDescriptor::Any,
+            LuaType::Nil => TypeDescriptor::Nil,
+            LuaType::Table => TypeDescriptor::Table,
+            LuaType::Userdata => TypeDescriptor::Userdata,
+            LuaType::Function => TypeDescriptor::Function,
+            LuaType::Thread => TypeDescriptor::Thread,
+            LuaType::Boolean => TypeDescriptor::Boolean,
+            LuaType::String => TypeDescriptor::String,
+            LuaType::Integer => TypeDescriptor::Integer,
+            LuaType::Number => TypeDescriptor::Number,
+            LuaType::Never => TypeDescriptor::Never,
+            LuaType::BooleanConst(value) | LuaType::DocBooleanConst(value) => {
+                TypeDescriptor::BooleanConst { value: *value }
+            }
+            LuaType::StringConst(value) | LuaType::DocStringConst(value) => {
+                TypeDescriptor::StringConst {
+                    value: value.to_string(),
+                }
+            }
+            LuaType::IntegerConst(value) | LuaType::DocIntegerConst(value) => {
+                TypeDescriptor::IntegerConst { value: *value }
+            }
+            LuaType::FloatConst(value) => TypeDescriptor::FloatConst { value: *value },
+            LuaType::Ref(id) | LuaType::Def(id) => TypeDescriptor::Ref {
+                name: id.get_name().to_string(),
+            },
+            LuaType::Array(array) => TypeDescriptor::Array {
+                base: Box::new(array.get_base().to_descriptor(db)),
+            },
+            LuaType::Tuple(tuple) => TypeDescriptor::Tuple {
+                types: to_descriptors(db, tuple.get_types()),
+            },
+            LuaType::Union(union) => TypeDescriptor::Union {
+                types: to_descriptors(db, &union.into_vec()),
+            },
+            LuaType::MultiLineUnion(union) => TypeDescriptor::Union {
+                types: union
+                    .get_unions()
+                    .iter()
+                    .map(|(ty, _)| ty.to_descriptor(db))
+                    .collect(),
+            },
+            LuaType::Intersection(intersection) => TypeDescriptor::Intersection {
+                types: to_descriptors(db, intersection.get_types()),
+            },
+            LuaType::Generic(generic) => TypeDescriptor::Generic {
+                name: generic.get_base_type_id_ref().get_name().to_string(),
+                params: to_descriptors(db, generic.get_params()),
+            },
+            LuaType::Variadic(variadic) => match variadic.as_ref() {
+                VariadicType::Base(base) => TypeDescriptor::Variadic {
+                    base: Box::new(base.to_descriptor(db)),
+                },
+                VariadicType::Multi(types) => TypeDescriptor::Tuple {
+                    types: to_descriptors(db, types),
+                },
+            },
+            LuaType::DocFunction(func) => function_to_descriptor(db, func),
+            LuaType::Signature(signature_id) => match db.get_signature_index().get(signature_id) {
+                Some(signature) => TypeDescriptor::Signature {
+                    params: to_param_descriptors(db, &signature.get_type_params()),
+                    returns: to_return_descriptors(db, signature.get_return_type()),
+                    is_colon_define: signature.is_colon_define,
+                },
+                None => TypeDescriptor::Function,
+            },
+            LuaType::Object(object) => {
+                let mut fields = object
+                    .get_fields()
+                    .iter()
+                    .filter_map(|(key, ty)| {
+                        let name = match key {
+                            LuaMemberKey::Name(name) => name.to_string(),
+                            LuaMemberKey::Integer(i) => format!("[{}]", i),
+                            _ => return None,
+                        };
+                        Some(FieldDescriptor {
+                            name,
+                            typ: ty.to_descriptor(db),
+                        })
+                    })
+                    .collect::<Vec<_>>();
+                fields.sort_by(|a, b| a.name.cmp(&b.name));
+                TypeDescriptor::Object { fields }
+            }
+            _ => TypeDescriptor::Other {
+                text: humanize_type(db, self, RenderLevel::Simple),
+            },
+        }
+    }
+}
+
+fn to_descriptors(db: &DbIndex, types: &[LuaType]) -> Vec<TypeDescriptor> {
+    types.iter().map(|ty| ty.to_descriptor(db)).collect()
+}
+
+fn to_param_descriptors(
+    db: &DbIndex,
+    params: &[(String, Option<LuaType>)],
+) -> Vec<ParamDescriptor> {
+    params
+        .iter()
+        .map(|(name, ty)| ParamDescriptor {
+            name: name.clone(),
+            typ: ty.as_ref().map(|ty| ty.to_descriptor(db)),
+        })
+        .collect()
+}
+
+/// 没有返回值时为空
+fn to_return_descriptors(db: &DbIndex, return_type: LuaType) -> Vec<TypeDescriptor> {
+    if return_type.is_nil() {
+        return Vec::new();
+    }
+
+    to_descriptors(db, &LuaSignature::return_type_to_row(return_type))
+}
+
+fn function_to_descriptor(db: &DbIndex, func: &LuaFunctionType) -> TypeDescriptor {
+    TypeDescriptor::Signature {
+        params: to_param_descriptors(db, func.get_params()),
+        returns: to_return_descriptors(db, func.get_ret().clone()),
+        is_colon_define: func.is_colon_define(),
+    }
+}
+
+mod float_value {
+    use serde::{Deserialize, Deserializer, Serializer, de::Error};
+
+    pub fn serialize<S: Serializer>(value: &f64, serializer: S) -> Result<S::Ok, S::Error> {
+        if value.is_nan() {
+            serializer.serialize_str("nan")
+        } else if value.is_infinite() {
+            serializer.serialize_str(if *value > 0.0 { "inf" } else { "-inf" })
+        } else {
+            serializer.serialize_f64(*value)
+        }
+    }
+
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum FloatValue {
+        Number(f64),
+        Text(String),
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<f64, D::Error> {
+        match FloatValue::deserialize(deserializer)? {
+            FloatValue::Number(value) => Ok(value),
+            FloatValue::Text(text) => match text.as_str() {
+                "nan" => Ok(f64::NAN),
+                "inf" => Ok(f64::INFINITY),
+                "-inf" => Ok(f64::NEG_INFINITY),
+                _ => Err(D::Error::custom(format!("invalid float value: {}", text))),
+            },
+        }
+    }
+}
@@ -0,0 +1,136 @@
+use std::{
+    hash::{DefaultHasher, Hash, Hasher},
+    sync::{Arc, LazyLock, Mutex, MutexGuard, Weak},
+};
+
+use hashbrown::HashMap;
+
+use super::{LuaArrayType, LuaGenericType, LuaTupleType, LuaType, LuaUnionType, TypeVisitTrait};
+
+/// 按哈希分片加锁, 并行分析时不同类型的驻留互不阻塞
+const SHARD_COUNT: usize = 16;
+
+/// 每个分片超过这个数量时先清理已经释放的类型, 仍然超过则清空
+const MAX_INTERNED: usize = (1 << 16) / SHARD_COUNT;
+
+static INTERNER: LazyLock<[Mutex<TypeInterner>; SHARD_COUNT]> =
+    LazyLock::new(|| std::array::from_fn(|_| Mutex::new(TypeInterner::default())));
+
+/// 驻留表只保存弱引用, 持锁线程 panic 后表中的数据仍然可用
+fn lock_shard(hash: u64) -> MutexGuard<'static, TypeInterner> {
+    INTERNER[(hash % SHARD_COUNT as u64) as usize]
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+}
+
+/// 让结构相同的复合类型共享同一个 `Arc`.
+/// 只保存弱引用, 不会延长类型的生命周期
+#[derive(Debug, Default)]
+struct TypeInterner {
+    unions: InternTable<LuaUnionType>,
+    arrays: InternTable<LuaArrayType>,
+    tuples: InternTable<LuaTupleType>,
+    generics: InternTable<LuaGenericType>,
+}
+
+#[derive(Debug)]
+struct InternTable<T> {
+    buckets: HashMap<u64, Vec<Weak<T>>>,
+    len: usize,
+}
+
+impl<T> Default for InternTable<T> {
+    fn default() -> Self {
+        Self {
+            buckets: HashMap::new(),
+            len: 0,
+        }
+    }
+}
+
+impl<T: Eq + TypeVisitTrait> InternTable<T> {
+    fn intern(&mut self, hash: u64, value: &Arc<T>) -> Arc<T> {
+        if let Some(bucket) = self.buckets.get(&hash) {
+            for weak in bucket {
+                if let Some(interned) = weak.upgrade()
+                    && *interned == **value
+                    && is_same_order(&*interned, value)
+                {
+                    return interned;
+                }
+            }
+        }
+
+        if self.len >= MAX_INTERNED {
+            self.prune();
+        }
+        self.buckets
+            .entry(hash)
+            .or_default()
+            .push(Arc::downgrade(value));
+        self.len += 1;
+        value.clone()
+    }
+
+    fn prune(&mut self) {
+        self.buckets.retain(|_, bucket| {
+            bucket.retain(|weak| weak.strong_count() > 0);
+            !bucket.is_empty()
+        });
+        self.len = self.buckets.values().map(|bucket| bucket.len()).sum();
+        if self.len >= MAX_INTERNED {
+            self.buckets.clear();
+            self.len = 0;
+        }
+    }
+}
+
+impl LuaType {
+    /// 返回与 `self` 结构相同的已驻留类型.
+    /// `LuaType` 的哈希对复合类型按指针计算, 子类型先驻留才能让外层类型命中
+    pub fn intern(self) -> LuaType {
+        match self {
+            LuaType::Union(union) => {
+                let hash = hash_union(&union);
+                LuaType::Union(lock_shard(hash).unions.intern(hash, &union))
+            }
+            LuaType::Array(array) => {
+                let hash = hash_value(&array);
+                LuaType::Array(lock_shard(hash).arrays.intern(hash, &array))
+            }
+            LuaType::Tuple(tuple) => {
+                let hash = hash_value(&tuple);
+                LuaType::Tuple(lock_shard(hash).tuples.intern(hash, &tuple))
+            }
+            LuaType::Generic(generic) => {
+                let hash = hash_value(&generic);
+                LuaType::Generic(lock_shard(hash).generics.intern(hash, &generic))
+            }
+            _ => self,
+        }
+    }
+}
+
+fn hash_value<T: Hash>(value: &T) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// 联合类型的相等与成员顺序无关, 但显示时保留书写顺序, 驻留时按顺序区分
+fn hash_union(union: &LuaUnionType) -> u64 {
+    match union {
+        LuaUnionType::Basic(basic) => hash_value(&(0, basic)),
+        LuaUnionType::Nullable(ty) => hash_value(&(1, ty)),
+        LuaUnionType::Multi(types) => hash_value(&(2, types)),
+    }
+}
+
+/// 逐个比较遍历到的子类型, 嵌套在内的联合类型成员顺序不同也视为不同
+fn is_same_order<T: TypeVisitTrait>(a: &T, b: &T) -> bool {
+    let mut a_types = Vec::new();
+    a.visit_type(&mut |ty| a_types.push(ty.clone()));
+    let mut b_types = Vec::new();
+    b.visit_type(&mut |ty| b_types.push(ty.clone()));
+    a_types == b_types
+}
@@ -198,4 +198,33 @@ mod tests {
         "#
         ));
     }
+
+    #[test]
+    fn test_max_union_size() {
+        let mut ws = VirtualWorkspace::new();
+        let mut emmyrc = ws.get_emmyrc();
+        emmyrc.types.max_union_size = Some(3);
+        ws.update_emmyrc(emmyrc);
+
+        let type_abc = ws.ty("'a' | 'b' | 'c'");
+        let type_d = ws.ty("'d'");
+        assert_eq!(
+            TypeOps::Union.apply(ws.get_db_mut(), &type_abc, &type_d),
+            ws.ty("string")
+        );
+
+        let type_mixed = ws.ty("'a' | 'b' | 1 | 2");
+        assert_eq!(
+            TypeOps::Union.apply(ws.get_db_mut(), &type_mixed, &type_d),
+            ws.ty("string | integer")
+        );
+        // 只在推断时放宽, 声明的联合类型按原样显示
+        assert_eq!(ws.humanize_type_detailed(type_mixed), "(\"a\"|\"b\"|1|2)");
+
+        let type_small = ws.ty("'a' | 'b'");
+        assert_eq!(
+            TypeOps::Union.apply(ws.get_db_mut(), &type_small, &type_d),
+            ws.ty("'a' | 'b' | 'd'")
+        );
+    }
 }
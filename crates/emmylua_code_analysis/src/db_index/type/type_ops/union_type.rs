@@ -6,7 +6,7 @@ pub fn union_type(db: &DbIndex, source: LuaType, target: LuaType) -> LuaType {
     let match_source = get_real_type(db, &source)
         .cloned()
         .unwrap_or_else(|| source.clone());
-    union_type_impl(&match_source, source, target)
+    limit_union_size(db, union_type_impl(&match_source, source, target))
 }
 
 pub(crate) fn union_type_shallow(source: LuaType, target: LuaType) -> LuaType {
@@ -118,3 +118,41 @@ fn union_type_impl(match_source: &LuaType, source: LuaType, target: LuaType) ->
         _ => LuaType::from_vec(vec![source, target]),
     }
 }
+
+fn limit_union_size(db: &DbIndex, typ: LuaType) -> LuaType {
+    match &typ {
+        LuaType::Union(union) => widen_oversized_union(db, union).unwrap_or(typ),
+        _ => typ,
+    }
+}
+
+/// 联合类型的成员数超过 `type.maxUnionSize` 时, 将其中的字面量放宽为对应的基础类型.
+/// 没有可以放宽的成员时返回 `None`
+fn widen_oversized_union(db: &DbIndex, union: &LuaUnionType) -> Option<LuaType> {
+    let max_union_size = db.get_emmyrc().types.max_union_size?;
+    let types = union.into_vec();
+    if types.len() <= max_union_size {
+        return None;
+    }
+
+    let mut widened = false;
+    let types = types
+        .into_iter()
+        .map(|typ| {
+            let base = match typ {
+                LuaType::StringConst(_) | LuaType::DocStringConst(_) => LuaType::String,
+                LuaType::IntegerConst(_) | LuaType::DocIntegerConst(_) => LuaType::Integer,
+                LuaType::FloatConst(_) => LuaType::Number,
+                LuaType::BooleanConst(_) | LuaType::DocBooleanConst(_) => LuaType::Boolean,
+                _ => return typ,
+            };
+            widened = true;
+            base
+        })
+        .collect::<Vec<_>>();
+    if !widened {
+        return None;
+    }
+
+    Some(LuaType::from_vec(types))
+}
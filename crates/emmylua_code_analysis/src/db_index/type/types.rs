@@ -5,7 +5,7 @@ use smol_str::SmolStr;
 use std::{hash::Hash, ops::Deref, sync::Arc};
 
 use crate::{
-    AsyncState, DbIndex, FileId, InFiled, SemanticModel,
+    AsyncState, DbIndex, FileId, InFiled, SemanticModel, check_type_compact,
     db_index::{
         LuaMemberKey, LuaSignatureId,
         r#type::{
@@ -516,6 +516,16 @@ impl LuaType {
         matches!(self, LuaType::MultiLineUnion(_))
     }
 
+    /// 该类型的值是否可以赋给 `expected` 类型, 不需要语法节点的上下文.
+    ///
+    /// # 注意
+    ///
+    /// 规则与 `type_check` 相同, 是宽松的兼容性检查: 父类的值赋给子类也视为可以,
+    /// 见 `SemanticModel::is_assignable`
+    pub fn is_assignable_to(&self, db: &DbIndex, expected: &LuaType) -> bool {
+        check_type_compact(db, expected, self).is_ok()
+    }
+
     pub fn from_vec(types: Vec<LuaType>) -> Self {
         match types.len() {
             0 => LuaType::Nil,
@@ -938,12 +948,11 @@ impl LuaObjectType {
         }
 
         let mut ty = None;
-        let mut count = 1;
         let mut fields = self.fields.iter().collect::<Vec<_>>();
 
-        fields.sort_by(|(a, _), (b, _)| a.cmp(b));
+        fields.sort_by_key(|(key, _)| *key);
 
-        for (key, value_type) in fields {
+        for (count, (key, value_type)) in (1..).zip(fields) {
             let idx = match key {
                 LuaMemberKey::Integer(i) => i,
                 _ => {
@@ -955,8 +964,6 @@ impl LuaObjectType {
                 return None;
             }
 
-            count += 1;
-
             ty = Some(match ty {
                 Some(t) => TypeOps::Union.apply(db, &t, value_type),
                 None => value_type.clone(),
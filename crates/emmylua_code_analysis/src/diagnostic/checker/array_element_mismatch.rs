@@ -0,0 +1,58 @@
+use emmylua_parser::{LuaAstNode, LuaCallExpr};
+
+use crate::{DiagnosticCode, LuaType, SemanticModel};
+
+use super::{Checker, DiagnosticContext, humanize_lint_type, is_global_member_call};
+
+pub struct ArrayElementMismatchChecker;
+
+impl Checker for ArrayElementMismatchChecker {
+    const CODES: &[DiagnosticCode] = &[DiagnosticCode::ArrayElementMismatch];
+
+    fn check(context: &mut DiagnosticContext, semantic_model: &SemanticModel) {
+        let root = semantic_model.get_root().clone();
+        for call_expr in root.descendants::<LuaCallExpr>() {
+            check_table_insert(context, semantic_model, call_expr);
+        }
+    }
+}
+
+/// `table.insert(list, value)` 或 `table.insert(list, pos, value)`, `list` 为 `T[]` 时检查 `value` 的类型
+fn check_table_insert(
+    context: &mut DiagnosticContext,
+    semantic_model: &SemanticModel,
+    call_expr: LuaCallExpr,
+) -> Option<()> {
+    if !is_global_member_call(semantic_model, &call_expr, "table", "insert") {
+        return Some(());
+    }
+
+    let args = call_expr.get_args_list()?.get_args().collect::<Vec<_>>();
+    let value_expr = match args.len() {
+        2 => args[1].clone(),
+        3 => args[2].clone(),
+        _ => return Some(()),
+    };
+    let LuaType::Array(array_type) = semantic_model.infer_expr(args[0].clone()).ok()? else {
+        return Some(());
+    };
+    let element_type = array_type.get_base();
+    let value_type = semantic_model.infer_expr(value_expr.clone()).ok()?;
+    if semantic_model.is_assignable(&value_type, element_type) {
+        return Some(());
+    }
+
+    let db = semantic_model.get_db();
+    context.add_diagnostic(
+        DiagnosticCode::ArrayElementMismatch,
+        value_expr.get_range(),
+        t!(
+            "Cannot insert a value of type `%{value}` into an array of `%{element}`",
+            value = humanize_lint_type(db, &value_type),
+            element = humanize_lint_type(db, element_type)
+        )
+        .to_string(),
+        None,
+    );
+    Some(())
+}
@@ -0,0 +1,88 @@
+use emmylua_parser::{LuaAssignStat, LuaAstNode, LuaIndexExpr, LuaVarExpr};
+
+use crate::{
+    DiagnosticCode, LuaMemberOwner, LuaSemanticDeclId, LuaType, SemanticDeclLevel, SemanticModel,
+};
+
+use super::{Checker, DiagnosticContext, humanize_lint_type};
+
+pub struct AssignNilToNonNilChecker;
+
+impl Checker for AssignNilToNonNilChecker {
+    const CODES: &[DiagnosticCode] = &[DiagnosticCode::AssignNilToNonNil];
+
+    fn check(context: &mut DiagnosticContext, semantic_model: &SemanticModel) {
+        for assign in semantic_model.get_root().descendants::<LuaAssignStat>() {
+            check_assign_stat(context, semantic_model, &assign);
+        }
+    }
+}
+
+fn check_assign_stat(
+    context: &mut DiagnosticContext,
+    semantic_model: &SemanticModel,
+    assign: &LuaAssignStat,
+) -> Option<()> {
+    let (vars, exprs) = assign.get_var_and_expr_list();
+    let value_types = semantic_model.infer_expr_list_types(&exprs, Some(vars.len()));
+    for (idx, var) in vars.iter().enumerate() {
+        let LuaVarExpr::IndexExpr(index_expr) = var else {
+            continue;
+        };
+        if !value_types.get(idx).is_some_and(|(typ, _)| typ.is_nil()) {
+            continue;
+        }
+
+        check_index_expr(context, semantic_model, index_expr);
+    }
+
+    Some(())
+}
+
+fn check_index_expr(
+    context: &mut DiagnosticContext,
+    semantic_model: &SemanticModel,
+    index_expr: &LuaIndexExpr,
+) -> Option<()> {
+    let LuaSemanticDeclId::Member(member_id) = semantic_model.find_decl(
+        index_expr.syntax().clone().into(),
+        SemanticDeclLevel::default(),
+    )?
+    else {
+        return None;
+    };
+
+    let db = semantic_model.get_db();
+    // 只检查类中显式声明了类型的字段
+    let owner = db.get_member_index().get_current_owner(&member_id)?;
+    if !matches!(owner, LuaMemberOwner::Type(_)) {
+        return None;
+    }
+    let type_cache = db.get_type_index().get_type_cache(&member_id.into())?;
+    if !type_cache.is_doc() {
+        return None;
+    }
+
+    let field_type = type_cache.as_type();
+    if field_type.is_optional() || field_type.contain_tpl() {
+        return None;
+    }
+    if semantic_model.is_assignable(&LuaType::Nil, field_type) {
+        return None;
+    }
+
+    let name = index_expr.get_index_key()?.get_path_part();
+    context.add_diagnostic(
+        DiagnosticCode::AssignNilToNonNil,
+        index_expr.get_range(),
+        t!(
+            "Cannot assign `nil` to field `%{name}` of non-nil type `%{typ}`",
+            name = name,
+            typ = humanize_lint_type(db, field_type)
+        )
+        .to_string(),
+        None,
+    );
+
+    Some(())
+}
@@ -8,11 +8,10 @@ use rowan::{NodeOrToken, TextRange};
 
 use crate::{
     DiagnosticCode, LuaDeclExtra, LuaDeclId, LuaMemberKey, LuaSemanticDeclId, LuaType,
-    SemanticDeclLevel, SemanticModel, TypeCheckFailReason, TypeCheckResult, VariadicType,
-    infer_index_expr,
+    SemanticDeclLevel, SemanticModel, TypeMismatchReport, VariadicType, infer_index_expr,
 };
 
-use super::{Checker, DiagnosticContext, humanize_lint_type};
+use super::{Checker, DiagnosticContext, humanize_lint_type, type_mismatch_reason};
 
 pub struct AssignTypeMismatchChecker;
 
@@ -398,7 +397,7 @@ fn check_assign_type_mismatch(
         _ => {}
     }
 
-    let result = semantic_model.type_check_detail(source_type, value_type);
+    let result = semantic_model.type_check_detailed(source_type, value_type);
     if result.is_err() {
         add_type_check_diagnostic(
             context,
@@ -419,17 +418,13 @@ fn add_type_check_diagnostic(
     range: TextRange,
     source_type: &LuaType,
     value_type: &LuaType,
-    result: TypeCheckResult,
+    result: Result<(), TypeMismatchReport>,
 ) {
     let db = semantic_model.get_db();
     match result {
         Ok(_) => (),
-        Err(reason) => {
-            let reason_message = match reason {
-                TypeCheckFailReason::TypeNotMatchWithReason(reason) => reason,
-                TypeCheckFailReason::TypeRecursion => t!("type recursion").to_string(),
-                _ => "".to_string(),
-            };
+        Err(report) => {
+            let reason_message = type_mismatch_reason(report);
 
             context.add_diagnostic(
                 DiagnosticCode::AssignTypeMismatch,
@@ -2,12 +2,15 @@ use emmylua_parser::{LuaAstNode, LuaCallExpr, LuaClosureExpr};
 
 use crate::{AsyncState, DiagnosticCode, LuaSignatureId, LuaType, SemanticModel};
 
-use super::{Checker, DiagnosticContext};
+use super::{Checker, DiagnosticContext, is_global_member_call};
 
 pub struct AwaitInSyncChecker;
 
 impl Checker for AwaitInSyncChecker {
-    const CODES: &[DiagnosticCode] = &[DiagnosticCode::AwaitInSync];
+    const CODES: &[DiagnosticCode] = &[
+        DiagnosticCode::AwaitInSync,
+        DiagnosticCode::YieldOutsideCoroutine,
+    ];
 
     fn check(context: &mut DiagnosticContext, semantic_model: &SemanticModel) {
         let root = semantic_model.get_root().clone();
@@ -28,14 +31,29 @@ fn check_call_in_async(
 
     if async_state == AsyncState::Async
         && let Some(prefix_expr) = call_expr.get_prefix_expr()
-        && check_async_func_in_sync_call(semantic_model, call_expr).is_err()
+        && check_async_func_in_sync_call(semantic_model, call_expr.clone()).is_err()
     {
-        context.add_diagnostic(
-            DiagnosticCode::AwaitInSync,
-            prefix_expr.get_range(),
-            t!("Async function can only be called in async function.").to_string(),
-            None,
-        );
+        // `coroutine.yield` 有单独的诊断, 关闭时仍按普通异步函数报告
+        if context.is_checker_enable_by_code(&DiagnosticCode::YieldOutsideCoroutine)
+            && is_global_member_call(semantic_model, &call_expr, "coroutine", "yield")
+        {
+            context.add_diagnostic(
+                DiagnosticCode::YieldOutsideCoroutine,
+                prefix_expr.get_range(),
+                t!(
+                    "`coroutine.yield` can only be called in an async function or a coroutine body."
+                )
+                .to_string(),
+                None,
+            );
+        } else {
+            context.add_diagnostic(
+                DiagnosticCode::AwaitInSync,
+                prefix_expr.get_range(),
+                t!("Async function can only be called in async function.").to_string(),
+                None,
+            );
+        }
     }
 
     Some(())
@@ -1,4 +1,4 @@
-use emmylua_parser::{LuaAst, LuaAstNode, LuaDocTagCast};
+use emmylua_parser::{LuaAst, LuaAstNode, LuaDocTagAs, LuaDocTagCast};
 use hashbrown::HashSet;
 use rowan::TextRange;
 
@@ -12,12 +12,21 @@ use super::{Checker, DiagnosticContext, humanize_lint_type};
 pub struct CastTypeMismatchChecker;
 
 impl Checker for CastTypeMismatchChecker {
-    const CODES: &[DiagnosticCode] = &[DiagnosticCode::CastTypeMismatch];
+    const CODES: &[DiagnosticCode] = &[
+        DiagnosticCode::CastTypeMismatch,
+        DiagnosticCode::AsTypeMismatch,
+    ];
 
     fn check(context: &mut DiagnosticContext, semantic_model: &SemanticModel) {
         for node in semantic_model.get_root().descendants::<LuaAst>() {
-            if let LuaAst::LuaDocTagCast(cast_tag) = node {
-                check_cast_tag(context, semantic_model, &cast_tag);
+            match node {
+                LuaAst::LuaDocTagCast(cast_tag) => {
+                    check_cast_tag(context, semantic_model, &cast_tag);
+                }
+                LuaAst::LuaDocTagAs(as_tag) => {
+                    check_as_tag(context, semantic_model, &as_tag);
+                }
+                _ => {}
             }
         }
     }
@@ -50,6 +59,7 @@ fn check_cast_tag(
             check_cast_compatibility(
                 context,
                 semantic_model,
+                DiagnosticCode::CastTypeMismatch,
                 op_type.get_range(),
                 &origin_type,
                 &target_type,
@@ -60,9 +70,42 @@ fn check_cast_tag(
     Some(())
 }
 
+/// `--[[@as T]]` 与 `@cast` 使用相同的规则, 源类型为表达式本身的类型, 使用单独的诊断代码
+fn check_as_tag(
+    context: &mut DiagnosticContext,
+    semantic_model: &SemanticModel,
+    as_tag: &LuaDocTagAs,
+) -> Option<()> {
+    let target_doc_type = as_tag.get_type()?;
+    let expr = as_tag.get_target_expr()?;
+    let origin_type = {
+        let typ = semantic_model.infer_expr_without_cast(expr).ok()?;
+        expand_type(semantic_model.get_db(), &typ).unwrap_or(typ)
+    };
+    // 无法推断的表达式通常正是需要 `@as` 的地方
+    if origin_type.is_unknown() {
+        return Some(());
+    }
+
+    let doc_ctx = DocTypeInferContext::new(semantic_model.get_db(), semantic_model.get_file_id());
+    let target_type = {
+        let typ = infer_doc_type(doc_ctx, &target_doc_type);
+        expand_type(semantic_model.get_db(), &typ).unwrap_or(typ)
+    };
+    check_cast_compatibility(
+        context,
+        semantic_model,
+        DiagnosticCode::AsTypeMismatch,
+        target_doc_type.get_range(),
+        &origin_type,
+        &target_type,
+    )
+}
+
 fn check_cast_compatibility(
     context: &mut DiagnosticContext,
     semantic_model: &SemanticModel,
+    code: DiagnosticCode,
     range: TextRange,
     origin_type: &LuaType,
     target_type: &LuaType,
@@ -92,6 +135,7 @@ fn check_cast_compatibility(
         add_cast_type_mismatch_diagnostic(
             context,
             semantic_model,
+            code,
             range,
             origin_type,
             target_type,
@@ -105,6 +149,7 @@ fn check_cast_compatibility(
 fn add_cast_type_mismatch_diagnostic(
     context: &mut DiagnosticContext,
     semantic_model: &SemanticModel,
+    code: DiagnosticCode,
     range: TextRange,
     origin_type: &LuaType,
     target_type: &LuaType,
@@ -122,18 +167,22 @@ fn add_cast_type_mismatch_diagnostic(
                 TypeCheckFailReason::TypeRecursion => t!("type recursion").to_string(),
             };
 
-            context.add_diagnostic(
-                DiagnosticCode::CastTypeMismatch,
-                range,
+            let message = if code == DiagnosticCode::AsTypeMismatch {
+                t!(
+                    "Cannot cast `%{original}` to `%{target}` with `@as`. %{reason}",
+                    original = humanize_lint_type(db, origin_type),
+                    target = humanize_lint_type(db, target_type),
+                    reason = reason_message
+                )
+            } else {
                 t!(
                     "Cannot cast `%{original}` to `%{target}`. %{reason}",
                     original = humanize_lint_type(db, origin_type),
                     target = humanize_lint_type(db, target_type),
                     reason = reason_message
                 )
-                .to_string(),
-                None,
-            );
+            };
+            context.add_diagnostic(code, range, message.to_string(), None);
         }
     }
 }
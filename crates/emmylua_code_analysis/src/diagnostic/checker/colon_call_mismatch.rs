@@ -0,0 +1,100 @@
+use emmylua_parser::{LuaAst, LuaAstNode, LuaAstToken, LuaCallExpr, LuaExpr};
+use rowan::TextRange;
+
+use crate::{DiagnosticCode, LuaType, SemanticModel};
+
+use super::{Checker, DiagnosticContext};
+
+pub struct ColonCallMismatchChecker;
+
+impl Checker for ColonCallMismatchChecker {
+    const CODES: &[DiagnosticCode] = &[DiagnosticCode::ColonCallMismatch];
+
+    fn check(context: &mut DiagnosticContext, semantic_model: &SemanticModel) {
+        for node in semantic_model.get_root().descendants::<LuaAst>() {
+            if let LuaAst::LuaCallExpr(call_expr) = node {
+                check_call_expr(context, semantic_model, call_expr);
+            }
+        }
+    }
+}
+
+fn check_call_expr(
+    context: &mut DiagnosticContext,
+    semantic_model: &SemanticModel,
+    call_expr: LuaCallExpr,
+) -> Option<()> {
+    let LuaExpr::IndexExpr(index_expr) = call_expr.get_prefix_expr()? else {
+        return None;
+    };
+    let index_token = index_expr.get_index_token()?;
+    if !index_token.is_dot() && !index_token.is_colon() {
+        return None;
+    }
+    let name = index_expr.get_name_token()?;
+    let receiver = index_expr.get_prefix_expr()?;
+
+    let LuaType::Signature(signature_id) = semantic_model
+        .infer_expr(LuaExpr::IndexExpr(index_expr.clone()))
+        .ok()?
+    else {
+        return None;
+    };
+    let signature = semantic_model
+        .get_db()
+        .get_signature_index()
+        .get(&signature_id)?;
+
+    let range = TextRange::new(index_token.get_position(), index_expr.get_range().end());
+    let receiver_text = receiver.get_text();
+    let name_text = name.get_name_text();
+    if index_token.is_dot() && signature.is_colon_define {
+        if !should_use_colon_call(&call_expr, &receiver_text) {
+            return Some(());
+        }
+
+        context.add_diagnostic(
+            DiagnosticCode::ColonCallMismatch,
+            range,
+            t!(
+                "Method `%{name}` is defined with `:`, call it as `%{call}`",
+                name = name_text,
+                call = format!("{}:{}(...)", receiver_text, name_text)
+            )
+            .to_string(),
+            None,
+        );
+    } else if index_token.is_colon() && !signature.is_colon_define {
+        let receiver_type = semantic_model.infer_expr(receiver).ok();
+        if signature.is_method(semantic_model, receiver_type.as_ref()) {
+            return Some(());
+        }
+
+        context.add_diagnostic(
+            DiagnosticCode::ColonCallMismatch,
+            range,
+            t!(
+                "Function `%{name}` is not defined with `:`, call it as `%{call}`",
+                name = name_text,
+                call = format!("{}.{}(...)", receiver_text, name_text)
+            )
+            .to_string(),
+            None,
+        );
+    }
+
+    Some(())
+}
+
+/// `obj.method()` 没有传入接收者, 或者 `obj.method(obj)` 手动传入了接收者.
+/// 其他情况如 `Base.method(self)` 通常是有意为之, 不报告
+fn should_use_colon_call(call_expr: &LuaCallExpr, receiver_text: &str) -> bool {
+    let Some(first_arg) = call_expr
+        .get_args_list()
+        .and_then(|args| args.get_args().next())
+    else {
+        return true;
+    };
+
+    first_arg.get_text() == receiver_text
+}
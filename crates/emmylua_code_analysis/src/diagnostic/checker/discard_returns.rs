@@ -1,8 +1,9 @@
-use emmylua_parser::{LuaAstNode, LuaCallExprStat};
+use emmylua_parser::{LuaAstNode, LuaCallExprStat, LuaClosureExpr};
 use rowan::NodeOrToken;
 
 use crate::{
-    DiagnosticCode, LuaNoDiscard, LuaSemanticDeclId, LuaType, SemanticDeclLevel, SemanticModel,
+    AsyncState, DiagnosticCode, LuaNoDiscard, LuaSemanticDeclId, LuaSignature, LuaSignatureId,
+    LuaType, SemanticDeclLevel, SemanticModel,
 };
 
 use super::{Checker, DiagnosticContext};
@@ -10,7 +11,8 @@ use super::{Checker, DiagnosticContext};
 pub struct DiscardReturnsChecker;
 
 impl Checker for DiscardReturnsChecker {
-    const CODES: &[DiagnosticCode] = &[DiagnosticCode::DiscardReturns];
+    const CODES: &[DiagnosticCode] =
+        &[DiagnosticCode::DiscardReturns, DiagnosticCode::MissingAwait];
 
     fn check(context: &mut DiagnosticContext, semantic_model: &SemanticModel) {
         let root = semantic_model.get_root().clone();
@@ -83,7 +85,49 @@ fn check_call_expr(
             nodiscard_message,
             None,
         );
+    } else if is_async_with_result(signature)
+        && is_in_async_context(semantic_model, &call_expr_stat)
+    {
+        context.add_diagnostic(
+            DiagnosticCode::MissingAwait,
+            prefix_node.text_range(),
+            t!("The result of async function is discarded, it is never yielded or used")
+                .to_string(),
+            None,
+        );
     }
 
     Some(())
 }
+
+fn is_async_with_result(signature: &LuaSignature) -> bool {
+    if signature.async_state != AsyncState::Async {
+        return false;
+    }
+
+    let return_type = signature.get_return_type();
+    !(return_type.is_nil() || return_type.is_unknown() || return_type.is_any())
+}
+
+/// 向上查找第一个非 `@sync` 的闭包, 判断其是否为 `@async`
+fn is_in_async_context(semantic_model: &SemanticModel, call_expr_stat: &LuaCallExprStat) -> bool {
+    let file_id = semantic_model.get_file_id();
+    for closure in call_expr_stat.ancestors::<LuaClosureExpr>() {
+        let signature_id = LuaSignatureId::from_closure(file_id, &closure);
+        let Some(signature) = semantic_model
+            .get_db()
+            .get_signature_index()
+            .get(&signature_id)
+        else {
+            return false;
+        };
+
+        match signature.async_state {
+            AsyncState::Sync => continue,
+            AsyncState::Async => return true,
+            AsyncState::None => return false,
+        }
+    }
+
+    false
+}
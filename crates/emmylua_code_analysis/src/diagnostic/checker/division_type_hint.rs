@@ -0,0 +1,126 @@
+use emmylua_parser::{
+    BinaryOperator, LuaAssignStat, LuaAstNode, LuaBinaryExpr, LuaCallArgList, LuaCallExpr,
+    LuaClosureExpr, LuaExpr, LuaLanguageLevel, LuaLocalStat, LuaParenExpr, LuaReturnStat,
+    LuaSyntaxNode,
+};
+
+use crate::{
+    DiagnosticCode, LuaDeclId, LuaSemanticDeclId, LuaSignatureId, LuaType, LuaTypeOwner,
+    SemanticDeclLevel, SemanticModel,
+};
+
+use super::{Checker, DiagnosticContext};
+
+pub struct DivisionTypeHintChecker;
+
+impl Checker for DivisionTypeHintChecker {
+    const CODES: &[DiagnosticCode] = &[DiagnosticCode::DivisionTypeHint];
+
+    fn check(context: &mut DiagnosticContext, semantic_model: &SemanticModel) {
+        // `//` 从 Lua 5.3 开始才有
+        if semantic_model.get_emmyrc().get_language_level() < LuaLanguageLevel::Lua53 {
+            return;
+        }
+
+        let root = semantic_model.get_root().clone();
+        for binary_expr in root.descendants::<LuaBinaryExpr>() {
+            let is_div = binary_expr
+                .get_op_token()
+                .is_some_and(|op| op.get_op() == BinaryOperator::OpDiv);
+            if is_div && is_integer_expected(semantic_model, &binary_expr).unwrap_or(false) {
+                context.add_diagnostic(
+                    DiagnosticCode::DivisionTypeHint,
+                    binary_expr.get_range(),
+                    t!("`/` always produces a float, use `//` for integer division").to_string(),
+                    None,
+                );
+            }
+        }
+    }
+}
+
+/// 表达式的值被赋给 `integer` 类型的变量, 参数或返回值
+fn is_integer_expected(
+    semantic_model: &SemanticModel,
+    binary_expr: &LuaBinaryExpr,
+) -> Option<bool> {
+    let mut node = binary_expr.syntax().clone();
+    let mut parent = node.parent()?;
+    while LuaParenExpr::can_cast(parent.kind().into()) {
+        node = parent;
+        parent = node.parent()?;
+    }
+
+    let expected_type = if let Some(local_stat) = LuaLocalStat::cast(parent.clone()) {
+        let index = position_of(local_stat.get_value_exprs(), &node)?;
+        let local_name = local_stat.get_local_name_list().nth(index)?;
+        let decl_id = LuaDeclId::new(semantic_model.get_file_id(), local_name.get_position());
+        get_doc_type(semantic_model, decl_id.into())?
+    } else if let Some(assign_stat) = LuaAssignStat::cast(parent.clone()) {
+        let (vars, exprs) = assign_stat.get_var_and_expr_list();
+        let index = position_of(exprs.into_iter(), &node)?;
+        let var = vars.get(index)?;
+        let owner: LuaTypeOwner = match semantic_model
+            .find_decl(var.syntax().clone().into(), SemanticDeclLevel::default())?
+        {
+            LuaSemanticDeclId::LuaDecl(decl_id) => decl_id.into(),
+            LuaSemanticDeclId::Member(member_id) => member_id.into(),
+            _ => return None,
+        };
+        get_doc_type(semantic_model, owner)?
+    } else if let Some(arg_list) = LuaCallArgList::cast(parent.clone()) {
+        let call_expr = arg_list.get_parent::<LuaCallExpr>()?;
+        let mut index = position_of(arg_list.get_args(), &node)?;
+        let func = semantic_model.infer_call_expr_func(call_expr.clone(), None)?;
+        match (call_expr.is_colon_call(), func.is_colon_define()) {
+            (true, false) => index += 1,
+            (false, true) => index = index.checked_sub(1)?,
+            _ => {}
+        }
+        func.get_params().get(index)?.1.clone()?
+    } else if let Some(return_stat) = LuaReturnStat::cast(parent) {
+        let index = position_of(return_stat.get_expr_list(), &node)?;
+        let closure = return_stat.ancestors::<LuaClosureExpr>().next()?;
+        let signature_id = LuaSignatureId::from_closure(semantic_model.get_file_id(), &closure);
+        let signature = semantic_model
+            .get_db()
+            .get_signature_index()
+            .get(&signature_id)?;
+        signature.return_docs.get(index)?.type_ref.clone()
+    } else {
+        return None;
+    };
+
+    Some(is_integer_only(&expected_type))
+}
+
+/// `integer`, 整数常量, 以及只由它们组成的联合类型(可以包含 `nil`)
+fn is_integer_only(typ: &LuaType) -> bool {
+    match typ {
+        LuaType::Integer | LuaType::IntegerConst(_) | LuaType::DocIntegerConst(_) => true,
+        LuaType::Union(union) => {
+            let types = union.into_vec();
+            types.iter().any(|typ| !typ.is_nil())
+                && types.iter().all(|typ| typ.is_nil() || is_integer_only(typ))
+        }
+        LuaType::MultiLineUnion(union) => union
+            .get_unions()
+            .iter()
+            .all(|(typ, _)| is_integer_only(typ)),
+        _ => false,
+    }
+}
+
+fn position_of(mut exprs: impl Iterator<Item = LuaExpr>, node: &LuaSyntaxNode) -> Option<usize> {
+    exprs.position(|expr| expr.syntax() == node)
+}
+
+/// 只考虑注解中写明的类型, 推断出的类型本身就可能来自这个除法
+fn get_doc_type(semantic_model: &SemanticModel, owner: LuaTypeOwner) -> Option<LuaType> {
+    semantic_model
+        .get_db()
+        .get_type_index()
+        .get_type_cache(&owner)
+        .filter(|type_cache| type_cache.is_doc())
+        .map(|type_cache| type_cache.as_type().clone())
+}
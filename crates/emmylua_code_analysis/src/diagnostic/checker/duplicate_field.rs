@@ -1,15 +1,17 @@
 use hashbrown::{HashMap, HashSet};
 
 use emmylua_parser::{
-    LuaAstNode, LuaDocTagClass, LuaDocTagField, LuaIndexExpr, LuaStat, LuaSyntaxKind, LuaSyntaxNode,
+    LuaAstNode, LuaAstToken, LuaClosureExpr, LuaDocTagClass, LuaDocTagField, LuaDocTagParam,
+    LuaIndexExpr, LuaStat, LuaSyntaxKind, LuaSyntaxNode,
 };
+use rowan::TextRange;
 
 use crate::{
-    DiagnosticCode, LuaDecl, LuaDeclExtra, LuaMember, LuaMemberFeature, LuaMemberKey,
-    LuaSemanticDeclId, LuaType, LuaTypeDeclId, SemanticDeclLevel, SemanticModel,
+    DiagnosticCode, FileId, InFiled, LuaDecl, LuaDeclExtra, LuaMember, LuaMemberFeature,
+    LuaMemberKey, LuaSemanticDeclId, LuaType, LuaTypeDeclId, SemanticDeclLevel, SemanticModel,
 };
 
-use super::{Checker, DiagnosticContext};
+use super::{Checker, DiagnosticContext, get_closure_expr_comment};
 
 pub struct DuplicateFieldChecker;
 
@@ -26,6 +28,11 @@ impl Checker for DuplicateFieldChecker {
                 check_decl_duplicate_field(context, semantic_model, &decl_info);
             }
         }
+
+        let root = semantic_model.get_root().clone();
+        for closure_expr in root.descendants::<LuaClosureExpr>() {
+            check_duplicate_doc_param(context, &closure_expr);
+        }
     }
 }
 
@@ -99,7 +106,7 @@ fn check_decl_duplicate_field(
     semantic_model: &SemanticModel,
     decl_info: &DeclInfo,
 ) -> Option<()> {
-    let type_decl = context
+    let type_decl = semantic_model
         .get_db()
         .get_type_index()
         .get_type_decl(&decl_info.id)?;
@@ -163,9 +170,11 @@ fn check_decl_duplicate_field(
         }
 
         // 2. 检查 ---@field 成员
+        // `(partial)` 类跨文件的同名字段会被合并, 类型冲突由 `partial-member-conflict` 处理
         let field_decls = member_infos
             .iter()
             .filter(|info| info.feature.is_field_decl())
+            .filter(|info| !type_decl.is_partial() || info.member.get_file_id() == file_id)
             .collect::<Vec<_>>();
         // 如果 field_decls 数量大于1，则进一步检查
         if field_decls.len() > 1 {
@@ -176,16 +185,20 @@ fn check_decl_duplicate_field(
 
             // 如果不全是 DocFunction，则报错
             if !all_doc_functions {
-                for field_decl in &field_decls {
-                    if field_decl.member.get_file_id() == file_id {
-                        context.add_diagnostic(
-                            DiagnosticCode::DuplicateDocField,
-                            // TODO: 范围缩小到名称而不是整个 ---@field
-                            field_decl.member.get_range(),
-                            t!("Duplicate field `%{name}`.", name = key.to_path()).to_string(),
-                            None,
-                        );
-                    }
+                let ranges = field_decls
+                    .iter()
+                    .filter(|info| info.member.get_file_id() == file_id)
+                    .map(|info| info.member.get_range())
+                    .collect::<Vec<_>>();
+                for range in &ranges {
+                    context.add_diagnostic_with_related(
+                        DiagnosticCode::DuplicateDocField,
+                        // TODO: 范围缩小到名称而不是整个 ---@field
+                        *range,
+                        t!("Duplicate field `%{name}`.", name = key.to_path()).to_string(),
+                        None,
+                        other_declarations(context.get_file_id(), &ranges, range, &key.to_path()),
+                    );
                 }
             }
         }
@@ -194,6 +207,63 @@ fn check_decl_duplicate_field(
     Some(())
 }
 
+/// 同一函数上重复的 `---@param`, 只有最后一个生效
+fn check_duplicate_doc_param(
+    context: &mut DiagnosticContext,
+    closure_expr: &LuaClosureExpr,
+) -> Option<()> {
+    let mut param_map: HashMap<String, Vec<TextRange>> = HashMap::new();
+    for tag in get_closure_expr_comment(closure_expr)?.children::<LuaDocTagParam>() {
+        let Some(name_token) = tag.get_name_token() else {
+            continue;
+        };
+        param_map
+            .entry(name_token.get_name_text().to_string())
+            .or_default()
+            .push(name_token.get_range());
+    }
+
+    for (name, ranges) in param_map {
+        if ranges.len() < 2 {
+            continue;
+        }
+
+        for range in &ranges {
+            context.add_diagnostic_with_related(
+                DiagnosticCode::DuplicateDocField,
+                *range,
+                t!(
+                    "Duplicate param `%{name}`, the last definition is used.",
+                    name = name
+                )
+                .to_string(),
+                None,
+                other_declarations(context.get_file_id(), &ranges, range, &name),
+            );
+        }
+    }
+
+    Some(())
+}
+
+fn other_declarations(
+    file_id: FileId,
+    ranges: &[TextRange],
+    current: &TextRange,
+    name: &str,
+) -> Vec<(InFiled<TextRange>, String)> {
+    ranges
+        .iter()
+        .filter(|range| *range != current)
+        .map(|range| {
+            (
+                InFiled::new(file_id, *range),
+                t!("`%{name}` is declared here", name = name).to_string(),
+            )
+        })
+        .collect()
+}
+
 /// 特殊处理: require("a").fun = function() end
 fn check_one_member(
     context: &mut DiagnosticContext,
@@ -0,0 +1,220 @@
+use emmylua_parser::{LuaAstNode, LuaCallExpr, LuaExpr, LuaLiteralToken};
+use rowan::TextRange;
+
+use crate::{DiagnosticCode, LuaType, SemanticModel};
+
+use super::{Checker, DiagnosticContext, humanize_lint_type, is_global_member_call};
+
+pub struct FormatArgMismatchChecker;
+
+impl Checker for FormatArgMismatchChecker {
+    const CODES: &[DiagnosticCode] = &[DiagnosticCode::FormatArgMismatch];
+
+    fn check(context: &mut DiagnosticContext, semantic_model: &SemanticModel) {
+        let root = semantic_model.get_root().clone();
+        for call_expr in root.descendants::<LuaCallExpr>() {
+            check_format_call(context, semantic_model, call_expr);
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FormatArgKind {
+    /// `%d`, `%u`, `%x`, `%c` 等, 参数必须能转换为整数
+    Integer,
+    /// `%f`, `%g`, `%e` 等
+    Number,
+    /// `%s`, `%q`, `%p`, 任意值都可以接受
+    Any,
+}
+
+#[derive(Debug)]
+struct FormatSpec {
+    text: String,
+    kind: FormatArgKind,
+}
+
+fn check_format_call(
+    context: &mut DiagnosticContext,
+    semantic_model: &SemanticModel,
+    call_expr: LuaCallExpr,
+) -> Option<()> {
+    let LuaExpr::IndexExpr(prefix_expr) = call_expr.get_prefix_expr()? else {
+        return None;
+    };
+    if prefix_expr.get_name_token()?.get_name_text() != "format" {
+        return None;
+    }
+
+    let mut args = call_expr.get_args_list()?.get_args().collect::<Vec<_>>();
+    // `string.format(fmt, ...)` 或 `("fmt"):format(...)`
+    let format_expr = if call_expr.is_colon_call() {
+        prefix_expr.get_prefix_expr()?
+    } else {
+        // 被局部变量遮蔽的 `string` 不是标准库
+        if !is_global_member_call(semantic_model, &call_expr, "string", "format") || args.is_empty()
+        {
+            return None;
+        }
+        args.remove(0)
+    };
+
+    let format = get_string_literal(&format_expr)?;
+    let specs = parse_format_specs(&format)?;
+
+    // 最后一个参数是调用或 `...` 时, 实际参数个数无法确定, 跳过个数检查
+    let has_multi_value = args.last().is_some_and(is_multi_value_expr);
+    let fixed_count = if has_multi_value {
+        args.len() - 1
+    } else {
+        args.len()
+    };
+
+    if !has_multi_value && args.len() < specs.len() {
+        context.add_diagnostic(
+            DiagnosticCode::FormatArgMismatch,
+            call_expr.get_args_list()?.get_range(),
+            t!(
+                "Format string expects %{expected} arguments, but got %{count}",
+                expected = specs.len(),
+                count = args.len()
+            )
+            .to_string(),
+            None,
+        );
+    } else if !has_multi_value && args.len() > specs.len() {
+        let first = args.get(specs.len())?;
+        let last = args.last()?;
+        context.add_diagnostic(
+            DiagnosticCode::FormatArgMismatch,
+            TextRange::new(first.get_range().start(), last.get_range().end()),
+            t!(
+                "Format string expects %{expected} arguments, but got %{count}",
+                expected = specs.len(),
+                count = args.len()
+            )
+            .to_string(),
+            None,
+        );
+    }
+
+    for (spec, arg) in specs.iter().zip(args.iter().take(fixed_count)) {
+        if spec.kind == FormatArgKind::Any {
+            continue;
+        }
+        let Ok(arg_type) = semantic_model.infer_expr(arg.clone()) else {
+            continue;
+        };
+        if !is_number_like(semantic_model, &arg_type) {
+            context.add_diagnostic(
+                DiagnosticCode::FormatArgMismatch,
+                arg.get_range(),
+                t!(
+                    "Format specifier `%{spec}` expects a number, but got `%{typ}`",
+                    spec = spec.text,
+                    typ = humanize_lint_type(semantic_model.get_db(), &arg_type)
+                )
+                .to_string(),
+                None,
+            );
+            continue;
+        }
+        if spec.kind == FormatArgKind::Number || !has_fraction(&arg_type) {
+            continue;
+        }
+
+        context.add_diagnostic(
+            DiagnosticCode::FormatArgMismatch,
+            arg.get_range(),
+            t!(
+                "Format specifier `%{spec}` expects an integer, but got `%{typ}`",
+                spec = spec.text,
+                typ = humanize_lint_type(semantic_model.get_db(), &arg_type)
+            )
+            .to_string(),
+            None,
+        );
+    }
+
+    Some(())
+}
+
+fn get_string_literal(expr: &LuaExpr) -> Option<String> {
+    match expr {
+        LuaExpr::LiteralExpr(literal) => match literal.get_literal()? {
+            LuaLiteralToken::String(string) => Some(string.get_value()),
+            _ => None,
+        },
+        LuaExpr::ParenExpr(paren) => get_string_literal(&paren.get_expr()?),
+        _ => None,
+    }
+}
+
+fn is_multi_value_expr(expr: &LuaExpr) -> bool {
+    match expr {
+        LuaExpr::CallExpr(_) => true,
+        LuaExpr::LiteralExpr(literal) => {
+            matches!(literal.get_literal(), Some(LuaLiteralToken::Dots(_)))
+        }
+        _ => false,
+    }
+}
+
+/// 解析格式字符串, 格式无效时返回 None, 由运行时报错
+fn parse_format_specs(format: &str) -> Option<Vec<FormatSpec>> {
+    let mut specs = Vec::new();
+    let mut chars = format.char_indices().peekable();
+    while let Some((start, c)) = chars.next() {
+        if c != '%' {
+            continue;
+        }
+        if chars.next_if(|(_, c)| *c == '%').is_some() {
+            continue;
+        }
+
+        while chars
+            .next_if(|(_, c)| matches!(c, '-' | '+' | ' ' | '#' | '0'))
+            .is_some()
+        {}
+        while chars.next_if(|(_, c)| c.is_ascii_digit()).is_some() {}
+        if chars.next_if(|(_, c)| *c == '.').is_some() {
+            while chars.next_if(|(_, c)| c.is_ascii_digit()).is_some() {}
+        }
+
+        let (end, conversion) = chars.next()?;
+        let kind = match conversion {
+            'd' | 'i' | 'u' | 'c' | 'o' | 'x' | 'X' => FormatArgKind::Integer,
+            'a' | 'A' | 'e' | 'E' | 'f' | 'F' | 'g' | 'G' => FormatArgKind::Number,
+            's' | 'q' | 'p' => FormatArgKind::Any,
+            _ => return None,
+        };
+        specs.push(FormatSpec {
+            text: format[start..end + conversion.len_utf8()].to_string(),
+            kind,
+        });
+    }
+
+    Some(specs)
+}
+
+/// 字符串参数在运行时会尝试转换为数字, 只有确定无法转换的字符串常量才报告
+fn is_number_like(semantic_model: &SemanticModel, typ: &LuaType) -> bool {
+    match typ {
+        LuaType::Any | LuaType::Unknown | LuaType::Variadic(_) => true,
+        LuaType::StringConst(s) | LuaType::DocStringConst(s) => s.trim().parse::<f64>().is_ok(),
+        _ if typ.is_string() || typ.contain_tpl() => true,
+        _ => semantic_model.type_check(&LuaType::Number, typ).is_ok(),
+    }
+}
+
+/// 确定带有小数部分的数字在运行时报错 "number has no integer representation"
+fn has_fraction(typ: &LuaType) -> bool {
+    match typ {
+        LuaType::FloatConst(f) => f.fract() != 0.0,
+        LuaType::StringConst(s) | LuaType::DocStringConst(s) => {
+            s.trim().parse::<f64>().is_ok_and(|f| f.fract() != 0.0)
+        }
+        LuaType::Union(union) => union.into_vec().iter().all(has_fraction),
+        _ => false,
+    }
+}
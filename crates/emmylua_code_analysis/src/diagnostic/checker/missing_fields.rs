@@ -96,14 +96,14 @@ fn check_table_expr(
             })
         }
         LuaType::Object(_) => type_cache.entry(table_type.clone()).or_insert_with(|| {
-            get_required_fields(context, &vec![table_type.clone()]).unwrap_or_default()
+            get_required_fields(context, std::slice::from_ref(&table_type)).unwrap_or_default()
         }),
         LuaType::Intersection(intersections) => {
             type_cache.entry(table_type.clone()).or_insert_with(|| {
                 let mut computed_fields = HashSet::new();
                 for intersection_component in intersections.get_types() {
                     computed_fields.extend(
-                        get_required_fields(context, &vec![intersection_component.clone()])
+                        get_required_fields(context, std::slice::from_ref(intersection_component))
                             .unwrap_or_default(),
                     );
                 }
@@ -139,13 +139,15 @@ fn check_table_expr(
 fn get_required_fields(
     context: &mut DiagnosticContext,
     // types 应为广度优先, 子类型会先于父类型被遍历, 而子类型的优先级高于父类型
-    types: &Vec<LuaType>,
+    types: &[LuaType],
 ) -> Option<HashSet<String>> {
     let member_index = context.db.get_member_index();
     let mut required_fields: HashSet<String> = HashSet::new();
 
     let mut optional_type = HashSet::new();
-    for super_type in types {
+    for (i, super_type) in types.iter().enumerate() {
+        // 第一个是类型自身, 其余都是父类型
+        let is_super = i > 0;
         match super_type {
             LuaType::Ref(type_decl_id) => process_type_decl_id(
                 context,
@@ -153,6 +155,7 @@ fn get_required_fields(
                 &mut required_fields,
                 &mut optional_type,
                 type_decl_id.clone(),
+                is_super,
             ),
             LuaType::Generic(generic_type) => process_type_decl_id(
                 context,
@@ -160,6 +163,7 @@ fn get_required_fields(
                 &mut required_fields,
                 &mut optional_type,
                 generic_type.get_base_type_id().clone(),
+                is_super,
             ),
             // 处理 ---@class test: { a: number }
             LuaType::Object(object_type) => {
@@ -185,10 +189,18 @@ fn get_required_fields(
         required_fields: &mut HashSet<String>,
         optional_type: &mut HashSet<String>,
         type_decl_id: LuaTypeDeclId,
+        is_super: bool,
     ) -> Option<()> {
         let members = member_index.get_members(&LuaMemberOwner::Type(type_decl_id))?;
         for member in members {
             let name = member.get_key().to_path();
+            // 父类表上直接赋值的成员带有默认值, 子类的构造表可以省略
+            if is_super && !member.is_field() {
+                if !name.is_empty() {
+                    optional_type.insert(name);
+                }
+                continue;
+            }
             let decl_type = context
                 .db
                 .get_type_index()
@@ -1,5 +1,7 @@
 mod access_invisible;
 mod analyze_error;
+mod array_element_mismatch;
+mod assign_nil_to_non_nil;
 mod assign_type_mismatch;
 mod attribute_check;
 mod await_in_sync;
@@ -12,24 +14,33 @@ mod check_return_count;
 mod circle_doc_class;
 mod code_style;
 mod code_style_check;
+mod colon_call_mismatch;
 mod deprecated;
 mod discard_returns;
+mod division_type_hint;
 mod duplicate_field;
 mod duplicate_index;
 mod duplicate_require;
 mod duplicate_type;
 mod enum_value_mismatch;
+mod format_arg_mismatch;
 mod generic;
 mod global_non_module;
 mod incomplete_signature_doc;
 mod local_const_reassign;
 mod missing_fields;
+mod naming_convention;
 mod need_check_nil;
 mod param_type_check;
+mod partial_member_conflict;
 mod readonly_check;
+mod recursive_alias;
 mod redefined_local;
+mod redundant_annotation;
 mod require_module_visibility;
 mod return_type_mismatch;
+mod shadowed_local;
+mod suspicious_comparison;
 mod syntax_error;
 mod unbalanced_assignments;
 mod undefined_doc_param;
@@ -37,22 +48,31 @@ mod undefined_global;
 mod unknown_doc_tag;
 mod unnecessary_assert;
 mod unnecessary_if;
+mod unreachable_code;
 mod unused;
+mod unused_export;
+mod unused_require;
 
 use emmylua_parser::{
-    LuaAstNode, LuaClosureExpr, LuaComment, LuaReturnStat, LuaStat, LuaSyntaxKind,
+    LuaAstNode, LuaCallExpr, LuaClosureExpr, LuaComment, LuaExpr, LuaReturnStat, LuaStat,
+    LuaSyntaxKind,
+};
+use lsp_types::{
+    Diagnostic, DiagnosticRelatedInformation, DiagnosticSeverity, DiagnosticTag, Location,
+    NumberOrString,
 };
-use lsp_types::{Diagnostic, DiagnosticSeverity, DiagnosticTag, NumberOrString};
 use rowan::TextRange;
-use std::sync::Arc;
+use std::{collections::HashSet, sync::Arc, time::Instant};
+use tokio_util::sync::CancellationToken;
+pub(crate) use unused_export::UsedExports;
 
 use crate::{
-    FileId, LuaType, RenderLevel, db_index::DbIndex, humanize_type, semantic::SemanticModel,
+    FileId, InFiled, LuaMemberId, LuaType, RenderLevel, TypeCheckFailReason, TypeMismatchReport,
+    db_index::DbIndex, humanize_type, semantic::SemanticModel,
 };
 
 use super::{
-    DiagnosticCode,
-    lua_diagnostic_code::{get_default_severity, is_code_default_enable},
+    DiagnosticCode, DiagnosticStats, lua_diagnostic_code::is_code_default_enable,
     lua_diagnostic_config::LuaDiagnosticConfig,
 };
 
@@ -63,17 +83,35 @@ pub trait Checker {
 }
 
 fn run_check<T: Checker>(context: &mut DiagnosticContext, semantic_model: &SemanticModel) {
+    // 文件在诊断过程中被修改时, 剩下的检查器不再执行
+    if context.is_cancelled() {
+        return;
+    }
+
     if T::CODES
         .iter()
         .any(|code| context.is_checker_enable_by_code(code))
     {
-        // let name = T::CODES.iter().map(|c| c.get_name()).collect::<Vec<_>>().join(",");
-        // let show_name = format!("{}({})", std::any::type_name::<T>(), name);
-        // let _p = Profile::new(&show_name);
+        let Some(stats) = context
+            .stats
+            .clone()
+            .filter(|stats| stats.is_tracked(T::CODES))
+        else {
+            T::check(context, semantic_model);
+            return;
+        };
+
+        let start = Instant::now();
         T::check(context, semantic_model);
+        stats.record(get_checker_name::<T>(), start.elapsed());
     }
 }
 
+fn get_checker_name<T: Checker>() -> &'static str {
+    let type_name = std::any::type_name::<T>();
+    type_name.rsplit("::").next().unwrap_or(type_name)
+}
+
 pub fn check_file(context: &mut DiagnosticContext, semantic_model: &SemanticModel) -> Option<()> {
     run_check::<syntax_error::SyntaxErrorChecker>(context, semantic_model);
     run_check::<analyze_error::AnalyzeErrorChecker>(context, semantic_model);
@@ -127,6 +165,20 @@ pub fn check_file(context: &mut DiagnosticContext, semantic_model: &SemanticMode
     run_check::<code_style::invert_if::InvertIfChecker>(context, semantic_model);
     run_check::<readonly_check::ReadOnlyChecker>(context, semantic_model);
     run_check::<global_non_module::GlobalInNonModuleChecker>(context, semantic_model);
+    run_check::<redundant_annotation::RedundantAnnotationChecker>(context, semantic_model);
+    run_check::<colon_call_mismatch::ColonCallMismatchChecker>(context, semantic_model);
+    run_check::<unreachable_code::UnreachableCodeChecker>(context, semantic_model);
+    run_check::<shadowed_local::ShadowedLocalChecker>(context, semantic_model);
+    run_check::<partial_member_conflict::PartialMemberConflictChecker>(context, semantic_model);
+    run_check::<recursive_alias::RecursiveAliasChecker>(context, semantic_model);
+    run_check::<assign_nil_to_non_nil::AssignNilToNonNilChecker>(context, semantic_model);
+    run_check::<format_arg_mismatch::FormatArgMismatchChecker>(context, semantic_model);
+    run_check::<division_type_hint::DivisionTypeHintChecker>(context, semantic_model);
+    run_check::<unused_require::UnusedRequireChecker>(context, semantic_model);
+    run_check::<suspicious_comparison::SuspiciousComparisonChecker>(context, semantic_model);
+    run_check::<array_element_mismatch::ArrayElementMismatchChecker>(context, semantic_model);
+    run_check::<unused_export::UnusedExportChecker>(context, semantic_model);
+    run_check::<naming_convention::NamingConventionChecker>(context, semantic_model);
     Some(())
 }
 
@@ -135,6 +187,9 @@ pub struct DiagnosticContext<'a> {
     db: &'a DbIndex,
     diagnostics: Vec<Diagnostic>,
     pub config: Arc<LuaDiagnosticConfig>,
+    stats: Option<Arc<DiagnosticStats>>,
+    cancel_token: Option<CancellationToken>,
+    used_exports: Option<Arc<HashSet<LuaMemberId>>>,
 }
 
 impl<'a> DiagnosticContext<'a> {
@@ -144,9 +199,36 @@ impl<'a> DiagnosticContext<'a> {
             db,
             diagnostics: Vec::new(),
             config,
+            stats: None,
+            cancel_token: None,
+            used_exports: None,
         }
     }
 
+    pub fn set_cancel_token(&mut self, cancel_token: CancellationToken) {
+        self.cancel_token = Some(cancel_token);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancel_token
+            .as_ref()
+            .is_some_and(|token| token.is_cancelled())
+    }
+
+    /// 记录每个检查器的耗时
+    pub fn set_stats(&mut self, stats: Arc<DiagnosticStats>) {
+        self.stats = Some(stats);
+    }
+
+    /// 整个工作区中被其他文件引用过的模块导出成员, 见 `unused_export::UsedExports`
+    pub fn set_used_exports(&mut self, used_exports: Arc<HashSet<LuaMemberId>>) {
+        self.used_exports = Some(used_exports);
+    }
+
+    pub fn get_used_exports(&self) -> Option<Arc<HashSet<LuaMemberId>>> {
+        self.used_exports.clone()
+    }
+
     pub fn get_db(&self) -> &DbIndex {
         self.db
     }
@@ -161,6 +243,18 @@ impl<'a> DiagnosticContext<'a> {
         range: TextRange,
         message: String,
         data: Option<serde_json::Value>,
+    ) {
+        self.add_diagnostic_with_related(code, range, message, data, Vec::new());
+    }
+
+    /// 添加诊断, 并附带指向其他位置的相关信息, 相关位置可以在其他文件中
+    pub fn add_diagnostic_with_related(
+        &mut self,
+        code: DiagnosticCode,
+        range: TextRange,
+        message: String,
+        data: Option<serde_json::Value>,
+        related: Vec<(InFiled<TextRange>, String)>,
     ) {
         if !self.is_checker_enable_by_code(&code) {
             return;
@@ -170,6 +264,7 @@ impl<'a> DiagnosticContext<'a> {
             return;
         }
 
+        let related_information = self.translate_related_information(related);
         let diagnostic = Diagnostic {
             message,
             range: self.translate_range(range).unwrap_or(lsp_types::Range {
@@ -186,6 +281,7 @@ impl<'a> DiagnosticContext<'a> {
             code: Some(NumberOrString::String(code.get_name().to_string())),
             source: Some("EmmyLua".into()),
             tags: self.get_tags(code),
+            related_information,
             data,
             ..Default::default()
         };
@@ -193,30 +289,75 @@ impl<'a> DiagnosticContext<'a> {
         self.diagnostics.push(diagnostic);
     }
 
-    fn should_report_diagnostic(&self, code: &DiagnosticCode, range: &TextRange) -> bool {
+    pub fn should_report_diagnostic(&self, code: &DiagnosticCode, range: &TextRange) -> bool {
         let diagnostic_index = self.get_db().get_diagnostic_index();
 
         !diagnostic_index.is_file_diagnostic_code_disabled(&self.get_file_id(), code, range)
     }
 
-    fn get_severity(&self, code: DiagnosticCode) -> Option<DiagnosticSeverity> {
-        if let Some(severity) = self.config.severity.get(&code) {
-            return Some(*severity);
+    /// `code` 从 `predecessor` 中拆分出来时使用: 代码注释中对 `predecessor` 的禁用总是生效;
+    /// `predecessor` 在配置(包括目录配置)中被关闭时, `code` 也不再报告, 除非 `code` 本身被明确开启
+    pub fn is_suppressed_by_predecessor(
+        &self,
+        code: &DiagnosticCode,
+        predecessor: &DiagnosticCode,
+        range: &TextRange,
+    ) -> bool {
+        if !self.should_report_diagnostic(predecessor, range) {
+            return true;
         }
 
-        Some(get_default_severity(code))
+        let explicitly_enabled = self.config.workspace_enabled.contains(code)
+            || self
+                .get_db()
+                .get_diagnostic_index()
+                .is_file_enabled(&self.get_file_id(), code);
+        !explicitly_enabled && !self.is_checker_enable_by_code(predecessor)
+    }
+
+    fn get_severity(&self, code: DiagnosticCode) -> Option<DiagnosticSeverity> {
+        Some(
+            self.get_db()
+                .get_file_effective_severity(&self.file_id, code),
+        )
     }
 
     fn get_tags(&self, code: DiagnosticCode) -> Option<Vec<DiagnosticTag>> {
         match code {
-            DiagnosticCode::Unused | DiagnosticCode::UnreachableCode => {
-                Some(vec![DiagnosticTag::UNNECESSARY])
-            }
+            DiagnosticCode::Unused
+            | DiagnosticCode::UnusedRequire
+            | DiagnosticCode::UnusedExport
+            | DiagnosticCode::UnreachableCode => Some(vec![DiagnosticTag::UNNECESSARY]),
             DiagnosticCode::Deprecated => Some(vec![DiagnosticTag::DEPRECATED]),
             _ => None,
         }
     }
 
+    fn translate_related_information(
+        &self,
+        related: Vec<(InFiled<TextRange>, String)>,
+    ) -> Option<Vec<DiagnosticRelatedInformation>> {
+        if related.is_empty() {
+            return None;
+        }
+
+        let vfs = self.db.get_vfs();
+        let related_information = related
+            .into_iter()
+            .filter_map(|(range, message)| {
+                let document = vfs.get_document(&range.file_id)?;
+                Some(DiagnosticRelatedInformation {
+                    location: Location {
+                        uri: document.get_uri(),
+                        range: document.to_lsp_range(range.value)?,
+                    },
+                    message,
+                })
+            })
+            .collect();
+        Some(related_information)
+    }
+
     fn translate_range(&self, range: TextRange) -> Option<lsp_types::Range> {
         let document = self.db.get_vfs().get_document(&self.file_id)?;
         let (start_line, start_character) = document.get_line_col(range.start())?;
@@ -299,6 +440,57 @@ pub fn get_return_stats(closure_expr: &LuaClosureExpr) -> impl Iterator<Item = L
         })
 }
 
+/// 调用形如 `table.insert(...)`, 且 `table` 是全局变量而不是同名的局部变量
+pub fn is_global_member_call(
+    semantic_model: &SemanticModel,
+    call_expr: &LuaCallExpr,
+    global_name: &str,
+    member_name: &str,
+) -> bool {
+    let Some(LuaExpr::IndexExpr(index_expr)) = call_expr.get_prefix_expr() else {
+        return false;
+    };
+    if index_expr
+        .get_name_token()
+        .is_none_or(|name_token| name_token.get_name_text() != member_name)
+    {
+        return false;
+    }
+    let Some(LuaExpr::NameExpr(name_expr)) = index_expr.get_prefix_expr() else {
+        return false;
+    };
+    if name_expr.get_name_text().as_deref() != Some(global_name) {
+        return false;
+    }
+
+    semantic_model
+        .get_db()
+        .get_decl_index()
+        .get_decl_tree(&semantic_model.get_file_id())
+        .and_then(|decl_tree| decl_tree.find_local_decl(global_name, name_expr.get_position()))
+        .is_none_or(|decl| !decl.is_local())
+}
+
+/// 类型不匹配的原因, 嵌套的表/记录成员不匹配时指出具体的成员路径
+pub fn type_mismatch_reason(report: TypeMismatchReport) -> String {
+    let path = report.path_string();
+    let reason = match report.reason {
+        TypeCheckFailReason::TypeNotMatchWithReason(reason) => reason,
+        TypeCheckFailReason::TypeRecursion => t!("type recursion").to_string(),
+        TypeCheckFailReason::TypeNotMatch | TypeCheckFailReason::DonotCheck => "".to_string(),
+    };
+    if path.is_empty() {
+        return reason;
+    }
+
+    let path_message = t!("Member `%{path}` does not match.", path = path).to_string();
+    if reason.is_empty() {
+        path_message
+    } else {
+        format!("{} {}", path_message, reason)
+    }
+}
+
 pub fn humanize_lint_type(db: &DbIndex, typ: &LuaType) -> String {
     match typ {
         // TODO: 应该仅去掉命名空间
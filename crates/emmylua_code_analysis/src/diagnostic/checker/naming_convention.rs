@@ -0,0 +1,132 @@
+use std::collections::HashSet;
+
+use emmylua_parser::{LuaAstNode, LuaAstToken, LuaDocTagClass};
+use regex::Regex;
+use rowan::TextRange;
+
+use crate::{DiagnosticCode, LocalAttribute, LuaDecl, LuaDeclExtra, LuaType, SemanticModel};
+
+use super::{Checker, DiagnosticContext};
+
+pub struct NamingConventionChecker;
+
+impl Checker for NamingConventionChecker {
+    const CODES: &[DiagnosticCode] = &[DiagnosticCode::NamingConvention];
+
+    /// 按 `diagnostics.namingConvention` 中配置的正则检查声明的名字, 未配置的类别不检查
+    fn check(context: &mut DiagnosticContext, semantic_model: &SemanticModel) {
+        let config = context.config.clone();
+        let rules = &config.naming_convention;
+        if let Some(pattern) = &rules.class {
+            for class_tag in semantic_model.get_root().descendants::<LuaDocTagClass>() {
+                if let Some(name_token) = class_tag.get_name_token() {
+                    check_name(
+                        context,
+                        NameKind::Class,
+                        pattern,
+                        name_token.get_name_text(),
+                        name_token.get_range(),
+                    );
+                }
+            }
+        }
+
+        let file_id = semantic_model.get_file_id();
+        let Some(decl_tree) = semantic_model
+            .get_db()
+            .get_decl_index()
+            .get_decl_tree(&file_id)
+        else {
+            return;
+        };
+
+        let mut decls = decl_tree.get_decls().values().collect::<Vec<_>>();
+        decls.sort_by_key(|decl| decl.get_position());
+        let mut checked_globals = HashSet::new();
+        for decl in decls {
+            let name = decl.get_name();
+            if name == "..." || name == "_" {
+                continue;
+            }
+
+            let Some(kind) = get_name_kind(semantic_model, decl) else {
+                continue;
+            };
+            // 全局变量每个文件只在第一次赋值处检查
+            if kind == NameKind::Global && !checked_globals.insert(name) {
+                continue;
+            }
+            let pattern = match kind {
+                NameKind::Local => &rules.local,
+                NameKind::Global => &rules.global,
+                NameKind::Constant => &rules.constant,
+                NameKind::Class => &rules.class,
+            };
+            if let Some(pattern) = pattern {
+                check_name(context, kind, pattern, name, decl.get_range());
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum NameKind {
+    Local,
+    Global,
+    Constant,
+    Class,
+}
+
+impl NameKind {
+    fn description(self) -> String {
+        match self {
+            NameKind::Local => t!("Local variable").to_string(),
+            NameKind::Global => t!("Global variable").to_string(),
+            NameKind::Constant => t!("Constant").to_string(),
+            NameKind::Class => t!("Class").to_string(),
+        }
+    }
+}
+
+fn get_name_kind(semantic_model: &SemanticModel, decl: &LuaDecl) -> Option<NameKind> {
+    match &decl.extra {
+        LuaDeclExtra::Local {
+            attrib: Some(LocalAttribute::Const),
+            ..
+        } => Some(NameKind::Constant),
+        LuaDeclExtra::Local { .. } | LuaDeclExtra::Param { .. } => {
+            // 定义了类的局部变量按 `---@class` 的名字检查
+            match semantic_model.get_type(decl.get_id().into()) {
+                LuaType::Def(_) => None,
+                _ => Some(NameKind::Local),
+            }
+        }
+        LuaDeclExtra::Global { .. } => Some(NameKind::Global),
+        LuaDeclExtra::ImplicitSelf { .. } => None,
+    }
+}
+
+fn check_name(
+    context: &mut DiagnosticContext,
+    kind: NameKind,
+    pattern: &Regex,
+    name: &str,
+    range: TextRange,
+) {
+    if pattern.is_match(name) {
+        return;
+    }
+
+    context.add_diagnostic(
+        DiagnosticCode::NamingConvention,
+        range,
+        t!(
+            "%{kind} `%{name}` does not match the naming pattern `%{pattern}`",
+            kind = kind.description(),
+            name = name,
+            pattern = pattern.as_str()
+        )
+        .to_string(),
+        None,
+    );
+}
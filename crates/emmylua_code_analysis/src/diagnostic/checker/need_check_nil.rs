@@ -9,7 +9,10 @@ use super::{Checker, DiagnosticContext};
 pub struct NeedCheckNilChecker;
 
 impl Checker for NeedCheckNilChecker {
-    const CODES: &[DiagnosticCode] = &[DiagnosticCode::NeedCheckNil];
+    const CODES: &[DiagnosticCode] = &[
+        DiagnosticCode::NeedCheckNil,
+        DiagnosticCode::CallPossiblyNil,
+    ];
 
     fn check(context: &mut DiagnosticContext, semantic_model: &SemanticModel) {
         let root = semantic_model.get_root().clone();
@@ -38,9 +41,25 @@ fn check_call_expr(
     let prefix = call_expr.get_prefix_expr()?;
     let func = semantic_model.infer_expr(prefix.clone()).ok()?;
     if func.is_nullable() {
+        // 这类调用以前按 need-check-nil 报告, 已有的 need-check-nil 禁用同样生效
+        let range = prefix.get_range();
+        if context.is_suppressed_by_predecessor(
+            &DiagnosticCode::CallPossiblyNil,
+            &DiagnosticCode::NeedCheckNil,
+            &range,
+        ) {
+            return Some(());
+        }
+
+        // 调用可能为 nil 的值有单独的诊断, 关闭时仍按 need-check-nil 报告
+        let code = if context.is_checker_enable_by_code(&DiagnosticCode::CallPossiblyNil) {
+            DiagnosticCode::CallPossiblyNil
+        } else {
+            DiagnosticCode::NeedCheckNil
+        };
         context.add_diagnostic(
-            DiagnosticCode::NeedCheckNil,
-            prefix.get_range(),
+            code,
+            range,
             t!("function %{name} may be nil", name = prefix.syntax().text()).to_string(),
             None,
         );
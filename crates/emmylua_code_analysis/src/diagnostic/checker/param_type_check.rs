@@ -3,8 +3,9 @@ use rowan::TextRange;
 
 use crate::{
     DiagnosticCode, LuaSemanticDeclId, LuaType, RenderLevel, SemanticDeclLevel, SemanticModel,
-    TypeCheckFailReason, TypeCheckResult,
-    diagnostic::checker::assign_type_mismatch::check_table_expr, humanize_type,
+    TypeMismatchReport, TypeOps,
+    diagnostic::checker::{assign_type_mismatch::check_table_expr, type_mismatch_reason},
+    humanize_type,
 };
 
 use super::{Checker, DiagnosticContext};
@@ -50,8 +51,12 @@ fn check_call_expr(
             params.insert(0, ("self".into(), Some(LuaType::SelfInfer)));
         }
         (true, false) => {
-            // 往调用参数插入插入调用者类型
-            arg_types.insert(0, get_call_source_type(semantic_model, &call_expr)?);
+            // 往调用参数插入插入调用者类型, 调用者可能为 `nil` 由 need-check-nil 报告
+            let source_type = get_call_source_type(semantic_model, &call_expr)?;
+            arg_types.insert(
+                0,
+                TypeOps::Remove.apply(semantic_model.get_db(), &source_type, &LuaType::Nil),
+            );
             arg_ranges.insert(0, call_expr.get_colon_token()?.get_range());
         }
     }
@@ -85,7 +90,7 @@ fn check_call_expr(
             {
                 check_type = result;
             }
-            let result = semantic_model.type_check_detail(&check_type, arg_type);
+            let result = semantic_model.type_check_detailed(&check_type, arg_type);
             if result.is_err() {
                 // 这里执行了`AssignTypeMismatch`的检查
                 if arg_type.is_table() {
@@ -138,7 +143,7 @@ fn check_variadic_param_match_args(
     arg_ranges: &[TextRange],
 ) {
     for (arg_type, arg_range) in arg_types.iter().zip(arg_ranges.iter()) {
-        let result = semantic_model.type_check_detail(variadic_type, arg_type);
+        let result = semantic_model.type_check_detailed(variadic_type, arg_type);
         if result.is_err() {
             try_add_diagnostic(
                 context,
@@ -158,7 +163,7 @@ fn try_add_diagnostic(
     range: TextRange,
     param_type: &LuaType,
     expr_type: &LuaType,
-    result: TypeCheckResult,
+    result: Result<(), TypeMismatchReport>,
 ) {
     if let (LuaType::Integer, LuaType::FloatConst(f)) = (param_type, expr_type)
         && f.fract() == 0.0
@@ -182,19 +187,13 @@ fn add_type_check_diagnostic(
     range: TextRange,
     param_type: &LuaType,
     expr_type: &LuaType,
-    result: TypeCheckResult,
+    result: Result<(), TypeMismatchReport>,
 ) {
     let db = semantic_model.get_db();
     match result {
         Ok(_) => (),
-        Err(reason) => {
-            let reason_message = match reason {
-                TypeCheckFailReason::TypeNotMatchWithReason(reason) => reason,
-                TypeCheckFailReason::TypeNotMatch | TypeCheckFailReason::DonotCheck => {
-                    "".to_string()
-                }
-                TypeCheckFailReason::TypeRecursion => "type recursion".to_string(),
-            };
+        Err(report) => {
+            let reason_message = type_mismatch_reason(report);
             context.add_diagnostic(
                 DiagnosticCode::ParamTypeMismatch,
                 range,
@@ -0,0 +1,111 @@
+use emmylua_parser::{LuaAstNode, LuaDocTagClass};
+use hashbrown::HashSet;
+
+use crate::{
+    DiagnosticCode, InFiled, LuaMember, LuaMemberOwner, LuaType, LuaTypeDeclId, SemanticModel,
+};
+
+use super::{Checker, DiagnosticContext, humanize_lint_type};
+
+pub struct PartialMemberConflictChecker;
+
+impl Checker for PartialMemberConflictChecker {
+    const CODES: &[DiagnosticCode] = &[DiagnosticCode::PartialMemberConflict];
+
+    fn check(context: &mut DiagnosticContext, semantic_model: &SemanticModel) {
+        let file_id = semantic_model.get_file_id();
+        let mut checked = HashSet::new();
+        for class_tag in semantic_model.get_root().descendants::<LuaDocTagClass>() {
+            let Some(name_token) = class_tag.get_name_token() else {
+                continue;
+            };
+            let Some(type_decl) = semantic_model
+                .get_db()
+                .get_type_index()
+                .find_type_decl(file_id, name_token.get_name_text())
+            else {
+                continue;
+            };
+            if !type_decl.is_partial() || !checked.insert(type_decl.get_id()) {
+                continue;
+            }
+
+            check_partial_class(context, semantic_model, type_decl.get_id());
+        }
+    }
+}
+
+/// 同一个 `(partial)` 类在不同文件中声明的字段会合并到同一个成员所有者,
+/// 此时同名字段的类型必须一致
+fn check_partial_class(
+    context: &mut DiagnosticContext,
+    semantic_model: &SemanticModel,
+    type_id: LuaTypeDeclId,
+) -> Option<()> {
+    let file_id = semantic_model.get_file_id();
+    let members = semantic_model
+        .get_db()
+        .get_member_index()
+        .get_members(&LuaMemberOwner::Type(type_id))?;
+    let field_decls = members
+        .into_iter()
+        .filter(|member| member.get_feature().is_field_decl())
+        .collect::<Vec<_>>();
+
+    for member in field_decls.iter().filter(|it| it.get_file_id() == file_id) {
+        let member_type = semantic_model.get_type(member.get_id().into());
+        let Some((other, other_type)) =
+            find_conflict_member(semantic_model, member, &member_type, &field_decls)
+        else {
+            continue;
+        };
+
+        let key = member.get_key().to_path();
+        context.add_diagnostic_with_related(
+            DiagnosticCode::PartialMemberConflict,
+            member.get_range(),
+            t!(
+                "Field `%{name}` is declared as `%{typ}` here but as `%{other}` in another partial definition",
+                name = key,
+                typ = humanize_lint_type(semantic_model.get_db(), &member_type),
+                other = humanize_lint_type(semantic_model.get_db(), &other_type)
+            )
+            .to_string(),
+            None,
+            vec![(
+                InFiled::new(other.get_file_id(), other.get_range()),
+                t!("`%{name}` is declared here", name = key).to_string(),
+            )],
+        );
+    }
+
+    Some(())
+}
+
+fn find_conflict_member<'a>(
+    semantic_model: &SemanticModel,
+    member: &LuaMember,
+    member_type: &LuaType,
+    field_decls: &[&'a LuaMember],
+) -> Option<(&'a LuaMember, LuaType)> {
+    for other in field_decls {
+        if other.get_file_id() == member.get_file_id() || other.get_key() != member.get_key() {
+            continue;
+        }
+
+        let other_type = semantic_model.get_type(other.get_id().into());
+        if !is_same_type(semantic_model, member_type, &other_type) {
+            return Some((*other, other_type));
+        }
+    }
+
+    None
+}
+
+fn is_same_type(semantic_model: &SemanticModel, left: &LuaType, right: &LuaType) -> bool {
+    if left == right {
+        return true;
+    }
+
+    semantic_model.is_assignable(right, left) && semantic_model.is_assignable(left, right)
+}
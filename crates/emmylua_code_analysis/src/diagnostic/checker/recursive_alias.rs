@@ -0,0 +1,69 @@
+use std::collections::HashSet;
+
+use emmylua_parser::{LuaAstNode, LuaAstToken, LuaDocTagAlias};
+
+use crate::{DiagnosticCode, LuaType, SemanticModel};
+
+use super::{Checker, DiagnosticContext};
+
+pub struct RecursiveAliasChecker;
+
+impl Checker for RecursiveAliasChecker {
+    const CODES: &[DiagnosticCode] = &[DiagnosticCode::RecursiveAlias];
+
+    /// 检查直接互相引用而无法展开的别名, 如 `---@alias A B` 与 `---@alias B A`
+    fn check(context: &mut DiagnosticContext, semantic_model: &SemanticModel) {
+        let root = semantic_model.get_root().clone();
+
+        for tag in root.descendants::<LuaDocTagAlias>() {
+            check_doc_tag_alias(context, &tag);
+        }
+    }
+}
+
+fn check_doc_tag_alias(context: &mut DiagnosticContext, tag: &LuaDocTagAlias) -> Option<()> {
+    let type_index = context.db.get_type_index();
+    let name_token = tag.get_name_token()?;
+    let alias_decl = type_index.find_type_decl(context.file_id, name_token.get_name_text())?;
+    if !alias_decl.is_alias() {
+        return Some(());
+    }
+
+    let alias_id = alias_decl.get_id();
+    let mut path = vec![alias_decl.get_full_name().to_string()];
+    let mut visited = HashSet::new();
+    visited.insert(alias_id.clone());
+
+    let mut current = alias_decl;
+    // 只有别名直接展开为另一个别名时才会无限展开, `---@alias Json string|Json[]` 这类递归是合法的
+    while let Some(LuaType::Ref(next_id)) = current.get_alias_ref() {
+        let next_decl = type_index.get_type_decl(next_id)?;
+        if !next_decl.is_alias() {
+            return Some(());
+        }
+
+        path.push(next_decl.get_full_name().to_string());
+        if *next_id == alias_id {
+            context.add_diagnostic(
+                DiagnosticCode::RecursiveAlias,
+                name_token.get_range(),
+                t!(
+                    "Alias `%{name}` is recursive: %{path}",
+                    name = alias_decl.get_full_name(),
+                    path = path.join(" -> ")
+                )
+                .to_string(),
+                None,
+            );
+            return Some(());
+        }
+
+        // 环不经过当前别名, 由环上的别名自身报告
+        if !visited.insert(next_id.clone()) {
+            return Some(());
+        }
+        current = next_decl;
+    }
+
+    Some(())
+}
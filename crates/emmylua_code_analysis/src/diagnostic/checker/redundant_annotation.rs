@@ -0,0 +1,82 @@
+use emmylua_parser::{LuaAst, LuaAstNode, LuaComment, LuaDocTagType, LuaExpr};
+
+use crate::{DiagnosticCode, DocTypeInferContext, LuaType, SemanticModel, infer_doc_type};
+
+use super::{Checker, DiagnosticContext, humanize_lint_type};
+
+pub struct RedundantAnnotationChecker;
+
+impl Checker for RedundantAnnotationChecker {
+    const CODES: &[DiagnosticCode] = &[DiagnosticCode::RedundantAnnotation];
+
+    fn check(context: &mut DiagnosticContext, semantic_model: &SemanticModel) {
+        for node in semantic_model.get_root().descendants::<LuaAst>() {
+            if let LuaAst::LuaDocTagType(type_tag) = node {
+                check_type_tag(context, semantic_model, type_tag);
+            }
+        }
+    }
+}
+
+fn check_type_tag(
+    context: &mut DiagnosticContext,
+    semantic_model: &SemanticModel,
+    type_tag: LuaDocTagType,
+) -> Option<()> {
+    let comment = type_tag.get_parent::<LuaComment>()?;
+    let LuaAst::LuaLocalStat(local_stat) = comment.get_owner()? else {
+        return None;
+    };
+
+    let local_count = local_stat.get_local_name_list().count();
+    let value_exprs = local_stat.get_value_exprs().collect::<Vec<_>>();
+    let doc_ctx = DocTypeInferContext::new(semantic_model.get_db(), semantic_model.get_file_id());
+    for (idx, doc_type) in type_tag.get_type_list().enumerate() {
+        let Some(value_expr) = value_exprs.get(idx) else {
+            break;
+        };
+        // 只有最后一个表达式可能是多返回值, 此时类型并不一一对应
+        if idx + 1 == value_exprs.len() && local_count > value_exprs.len() {
+            break;
+        }
+        // 表与闭包的类型会受到注解影响, 注解并不冗余
+        if matches!(value_expr, LuaExpr::TableExpr(_) | LuaExpr::ClosureExpr(_)) {
+            continue;
+        }
+
+        let annotation_type = infer_doc_type(doc_ctx, &doc_type);
+        let Ok(value_type) = semantic_model.infer_expr(value_expr.clone()) else {
+            continue;
+        };
+        if !is_same_type(semantic_model, &annotation_type, &value_type) {
+            continue;
+        }
+
+        context.add_diagnostic(
+            DiagnosticCode::RedundantAnnotation,
+            doc_type.get_range(),
+            t!(
+                "Type annotation `%{typ}` is redundant, it matches the inferred type",
+                typ = humanize_lint_type(semantic_model.get_db(), &annotation_type)
+            )
+            .to_string(),
+            None,
+        );
+    }
+
+    Some(())
+}
+
+/// 注解类型与推断类型互相兼容时视为相同, 拓宽或收窄类型的注解不在此列
+fn is_same_type(semantic_model: &SemanticModel, annotation: &LuaType, value: &LuaType) -> bool {
+    if annotation.is_unknown() || annotation.is_any() || value.is_unknown() || value.is_any() {
+        return false;
+    }
+
+    if annotation == value {
+        return true;
+    }
+
+    semantic_model.is_assignable(value, annotation)
+        && semantic_model.is_assignable(annotation, value)
+}
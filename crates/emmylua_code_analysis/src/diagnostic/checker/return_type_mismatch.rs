@@ -5,8 +5,10 @@ use rowan::{NodeOrToken, TextRange};
 
 use crate::{
     DiagnosticCode, LuaSemanticDeclId, LuaSignatureId, LuaType, SemanticDeclLevel, SemanticModel,
-    SignatureReturnStatus, TypeCheckFailReason, TypeCheckResult,
-    diagnostic::checker::{assign_type_mismatch::check_table_expr, humanize_lint_type},
+    SignatureReturnStatus, TypeMismatchReport,
+    diagnostic::checker::{
+        assign_type_mismatch::check_table_expr, humanize_lint_type, type_mismatch_reason,
+    },
 };
 
 use super::{Checker, DiagnosticContext, get_return_stats};
@@ -86,7 +88,7 @@ fn check_return_stat(
                     check_type = self_type;
                 }
 
-                let result = semantic_model.type_check_detail(check_type, return_expr_type);
+                let result = semantic_model.type_check_detailed(check_type, return_expr_type);
                 if result.is_err() {
                     if return_expr_type.is_table()
                         && let Some(return_expr) = return_exprs.get(index)
@@ -123,7 +125,7 @@ fn check_return_stat(
             }
             let return_expr_type = &return_expr_types[0];
             let return_expr_range = return_expr_ranges[0];
-            let result = semantic_model.type_check_detail(check_type, return_expr_type);
+            let result = semantic_model.type_check_detailed(check_type, return_expr_type);
             if result.is_err() {
                 if return_expr_type.is_table()
                     && let Some(return_expr) = return_exprs.first()
@@ -163,19 +165,13 @@ fn add_type_check_diagnostic(
     range: TextRange,
     param_type: &LuaType,
     expr_type: &LuaType,
-    result: TypeCheckResult,
+    result: Result<(), TypeMismatchReport>,
 ) {
     let db = semantic_model.get_db();
     match result {
         Ok(_) => (),
-        Err(reason) => {
-            let reason_message = match reason {
-                TypeCheckFailReason::TypeNotMatchWithReason(reason) => reason,
-                TypeCheckFailReason::TypeNotMatch | TypeCheckFailReason::DonotCheck => {
-                    "".to_string()
-                }
-                TypeCheckFailReason::TypeRecursion => "type recursion".to_string(),
-            };
+        Err(report) => {
+            let reason_message = type_mismatch_reason(report);
             context.add_diagnostic(
                 DiagnosticCode::ReturnTypeMismatch,
                 range,
@@ -0,0 +1,93 @@
+use emmylua_parser::{LuaAstNode, LuaBlock, LuaNameExpr};
+
+use crate::{DiagnosticCode, InFiled, LuaDecl, SemanticModel};
+
+use super::{Checker, DiagnosticContext};
+
+pub struct ShadowedLocalChecker;
+
+impl Checker for ShadowedLocalChecker {
+    const CODES: &[DiagnosticCode] = &[DiagnosticCode::ShadowedLocal];
+
+    fn check(context: &mut DiagnosticContext, semantic_model: &SemanticModel) {
+        let file_id = semantic_model.get_file_id();
+        let Some(decl_tree) = semantic_model
+            .get_db()
+            .get_decl_index()
+            .get_decl_tree(&file_id)
+        else {
+            return;
+        };
+
+        let diagnostics_config = &semantic_model.get_emmyrc().diagnostics;
+        let ignore_hoist = diagnostics_config.shadowed_local_ignore_hoist;
+        // `_` 开头的名字通常表示有意不使用的变量
+        let ignore_underscore = diagnostics_config.shadowed_local_ignore_underscore;
+        let mut decls = decl_tree
+            .get_decls()
+            .values()
+            .filter(|decl| decl.is_local() && !decl.is_param())
+            .collect::<Vec<_>>();
+        decls.sort_by_key(|decl| decl.get_position());
+
+        for decl in decls {
+            let name = decl.get_name();
+            if name == "..." || (ignore_underscore && name.starts_with('_')) {
+                continue;
+            }
+
+            let Some(shadowed_decl) = decl_tree.find_local_decl(name, decl.get_position()) else {
+                continue;
+            };
+            if !shadowed_decl.is_local() {
+                continue;
+            }
+            // 同一作用域内的重复声明由 `redefined-local` 处理
+            if is_same_block(semantic_model, decl, shadowed_decl) {
+                continue;
+            }
+            if ignore_hoist && is_hoist_decl(semantic_model, decl) {
+                continue;
+            }
+
+            context.add_diagnostic_with_related(
+                DiagnosticCode::ShadowedLocal,
+                decl.get_range(),
+                t!(
+                    "Local variable `%{name}` shadows a variable in an enclosing scope",
+                    name = name
+                )
+                .to_string(),
+                None,
+                vec![(
+                    InFiled::new(context.get_file_id(), shadowed_decl.get_range()),
+                    t!("`%{name}` is declared here", name = name).to_string(),
+                )],
+            );
+        }
+    }
+}
+
+fn get_decl_block(semantic_model: &SemanticModel, decl: &LuaDecl) -> Option<LuaBlock> {
+    let root = semantic_model.get_root().syntax();
+    let node = decl.get_syntax_id().to_node_from_root(root)?;
+    node.ancestors().find_map(LuaBlock::cast)
+}
+
+fn is_same_block(semantic_model: &SemanticModel, decl: &LuaDecl, shadowed_decl: &LuaDecl) -> bool {
+    let block = get_decl_block(semantic_model, decl);
+    block.is_some() && block == get_decl_block(semantic_model, shadowed_decl)
+}
+
+/// `local foo = foo`
+fn is_hoist_decl(semantic_model: &SemanticModel, decl: &LuaDecl) -> bool {
+    let Some(value_syntax_id) = decl.get_value_syntax_id() else {
+        return false;
+    };
+    let root = semantic_model.get_root().syntax();
+    value_syntax_id
+        .to_node_from_root(root)
+        .and_then(LuaNameExpr::cast)
+        .and_then(|name_expr| name_expr.get_name_text())
+        .is_some_and(|value_name| value_name == decl.get_name())
+}
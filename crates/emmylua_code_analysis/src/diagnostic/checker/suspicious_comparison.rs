@@ -0,0 +1,110 @@
+use emmylua_parser::{BinaryOperator, LuaAstNode, LuaBinaryExpr, LuaExpr};
+
+use crate::{
+    DbIndex, DiagnosticCode, LuaType, SemanticModel, diagnostic::checker::humanize_lint_type,
+};
+
+use super::{Checker, DiagnosticContext};
+
+pub struct SuspiciousComparisonChecker;
+
+impl Checker for SuspiciousComparisonChecker {
+    const CODES: &[DiagnosticCode] = &[DiagnosticCode::SuspiciousComparison];
+
+    fn check(context: &mut DiagnosticContext, semantic_model: &SemanticModel) {
+        let root = semantic_model.get_root().clone();
+        for binary_expr in root.descendants::<LuaBinaryExpr>() {
+            check_binary_expr(context, semantic_model, binary_expr);
+        }
+    }
+}
+
+fn check_binary_expr(
+    context: &mut DiagnosticContext,
+    semantic_model: &SemanticModel,
+    binary_expr: LuaBinaryExpr,
+) -> Option<()> {
+    let op = binary_expr.get_op_token()?.get_op();
+    if !matches!(op, BinaryOperator::OpEq | BinaryOperator::OpNe) {
+        return Some(());
+    }
+
+    let (left_expr, right_expr) = binary_expr.get_exprs()?;
+    let left_type = get_comparable_type(semantic_model, left_expr)?;
+    let right_type = get_comparable_type(semantic_model, right_expr)?;
+    if semantic_model.is_assignable(&right_type, &left_type)
+        || semantic_model.is_assignable(&left_type, &right_type)
+    {
+        return Some(());
+    }
+
+    let db = semantic_model.get_db();
+    context.add_diagnostic(
+        DiagnosticCode::SuspiciousComparison,
+        binary_expr.get_range(),
+        t!(
+            "Values of type `%{left}` and `%{right}` can never be equal",
+            left = humanize_lint_type(db, &left_type),
+            right = humanize_lint_type(db, &right_type)
+        )
+        .to_string(),
+        None,
+    );
+    Some(())
+}
+
+fn get_comparable_type(semantic_model: &SemanticModel, expr: LuaExpr) -> Option<LuaType> {
+    let is_literal = matches!(expr, LuaExpr::LiteralExpr(_));
+    let typ = semantic_model.infer_expr(expr).ok()?;
+    let db = semantic_model.get_db();
+    if is_literal {
+        // 字面量的值是确定的, 按注解中的字面量比较
+        widen_type(db, &typ)?;
+        return Some(match typ {
+            LuaType::StringConst(s) => LuaType::DocStringConst(s),
+            LuaType::IntegerConst(i) => LuaType::DocIntegerConst(i),
+            LuaType::BooleanConst(b) => LuaType::DocBooleanConst(b),
+            _ => typ,
+        });
+    }
+
+    widen_type(db, &typ)
+}
+
+/// 只比较由基础类型组成的值, 其他类型 (`any`, 类等) 返回 `None`, 枚举展开为各个成员的字面量类型.
+/// 从变量推断出的字面量会被放宽为对应的基础类型, 变量的值可能在之后被修改; 注解中写明的字面量保留
+fn widen_type(db: &DbIndex, typ: &LuaType) -> Option<LuaType> {
+    match typ {
+        LuaType::String
+        | LuaType::Integer
+        | LuaType::Number
+        | LuaType::Boolean
+        | LuaType::DocStringConst(_)
+        | LuaType::DocIntegerConst(_)
+        | LuaType::DocBooleanConst(_) => Some(typ.clone()),
+        LuaType::StringConst(_) => Some(LuaType::String),
+        LuaType::IntegerConst(_) => Some(LuaType::Integer),
+        LuaType::FloatConst(_) => Some(LuaType::Number),
+        LuaType::BooleanConst(_) => Some(LuaType::Boolean),
+        LuaType::Ref(type_decl_id) => {
+            let enum_field_type = db
+                .get_type_index()
+                .get_type_decl(type_decl_id)?
+                .get_enum_field_type(db)?;
+            widen_type(db, &enum_field_type)
+        }
+        LuaType::Union(union) => {
+            let types = union
+                .into_vec()
+                .iter()
+                .filter(|typ| !typ.is_nil())
+                .map(|typ| widen_type(db, typ))
+                .collect::<Option<Vec<_>>>()?;
+            if types.is_empty() {
+                return None;
+            }
+            Some(LuaType::from_vec(types))
+        }
+        _ => None,
+    }
+}
@@ -34,7 +34,9 @@ fn check_tag(
     tag_other: &LuaDocTagOther,
     known_tags: &HashSet<&str>,
 ) -> Option<()> {
+    // `---@region` 和 `---@endregion` 用于标记折叠区域
     if let Some(token) = tag_other.token_by_kind(LuaTokenKind::TkTagOther)
+        && !matches!(token.get_text(), "region" | "endregion")
         && !known_tags.contains(token.get_text())
     {
         context.add_diagnostic(
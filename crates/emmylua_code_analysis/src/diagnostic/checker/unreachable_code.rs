@@ -0,0 +1,229 @@
+use emmylua_parser::{
+    LuaAstNode, LuaBlock, LuaCallExprStat, LuaExpr, LuaGotoStat, LuaIfStat, LuaStat, LuaSyntaxKind,
+    LuaSyntaxNode,
+};
+use rowan::TextRange;
+
+use crate::{DiagnosticCode, LuaSemanticDeclId, LuaType, SemanticDeclLevel, SemanticModel};
+
+use super::{Checker, DiagnosticContext};
+
+pub struct UnreachableCodeChecker;
+
+impl Checker for UnreachableCodeChecker {
+    const CODES: &[DiagnosticCode] = &[DiagnosticCode::UnreachableCode];
+
+    fn check(context: &mut DiagnosticContext, semantic_model: &SemanticModel) {
+        for block in semantic_model.get_root().descendants::<LuaBlock>() {
+            check_block(context, semantic_model, &block);
+        }
+    }
+}
+
+fn check_block(
+    context: &mut DiagnosticContext,
+    semantic_model: &SemanticModel,
+    block: &LuaBlock,
+) -> Option<()> {
+    let stats = block.get_stats().collect::<Vec<_>>();
+    let mut start = 0;
+    while let Some(terminator_idx) = stats[start..]
+        .iter()
+        .position(|stat| is_terminator(semantic_model, stat))
+    {
+        let first_dead_idx = start + terminator_idx + 1;
+        // 标签可以通过 `goto` 到达, 从标签开始的语句重新变为可达
+        let label_idx = stats[first_dead_idx..]
+            .iter()
+            .position(|stat| matches!(stat, LuaStat::LabelStat(_)))
+            .map(|idx| first_dead_idx + idx);
+        let dead_stats = &stats[first_dead_idx..label_idx.unwrap_or(stats.len())];
+        if let (Some(first_dead), Some(last_dead)) = (dead_stats.first(), dead_stats.last()) {
+            context.add_diagnostic(
+                DiagnosticCode::UnreachableCode,
+                TextRange::new(first_dead.get_position(), last_dead.get_range().end()),
+                t!("Unreachable code").to_string(),
+                None,
+            );
+        }
+
+        start = label_idx?;
+    }
+
+    Some(())
+}
+
+/// 语句执行后控制流不会继续到后续语句
+fn is_terminator(semantic_model: &SemanticModel, stat: &LuaStat) -> bool {
+    match stat {
+        LuaStat::ReturnStat(_) | LuaStat::BreakStat(_) => true,
+        LuaStat::CallExprStat(call_stat) => is_no_return_call(semantic_model, call_stat),
+        LuaStat::DoStat(do_stat) => do_stat
+            .get_block()
+            .is_some_and(|it| is_terminated_block(semantic_model, &it)),
+        LuaStat::IfStat(if_stat) => is_terminated_if(semantic_model, if_stat),
+        // 死循环只能通过 `break` 或 `goto` 离开
+        LuaStat::WhileStat(while_stat) => {
+            is_const_condition(semantic_model, while_stat.get_condition_expr(), true)
+                && !can_exit_loop(while_stat.syntax())
+        }
+        LuaStat::RepeatStat(repeat_stat) => {
+            (is_const_condition(semantic_model, repeat_stat.get_condition_expr(), false)
+                || repeat_stat
+                    .get_block()
+                    .is_some_and(|it| is_terminated_block(semantic_model, &it)))
+                && !can_exit_loop(repeat_stat.syntax())
+        }
+        _ => false,
+    }
+}
+
+fn is_no_return_call(semantic_model: &SemanticModel, call_stat: &LuaCallExprStat) -> bool {
+    let Some(call_expr) = call_stat.get_call_expr() else {
+        return false;
+    };
+    let Some(prefix_expr) = call_expr.get_prefix_expr() else {
+        return false;
+    };
+
+    if call_expr.is_error() {
+        return is_builtin_function(semantic_model, &prefix_expr, "error");
+    }
+
+    prefix_expr.syntax().text() == "os.exit"
+        && is_builtin_function(semantic_model, &prefix_expr, "exit")
+}
+
+/// 调用的是标准库中的函数, 而不是同名的局部变量或成员.
+/// 无法解析时 (例如未加载标准库) 按全局的标准库函数处理
+fn is_builtin_function(semantic_model: &SemanticModel, prefix_expr: &LuaExpr, name: &str) -> bool {
+    let db = semantic_model.get_db();
+    let module_index = db.get_module_index();
+    match semantic_model.find_decl(
+        prefix_expr.syntax().clone().into(),
+        SemanticDeclLevel::default(),
+    ) {
+        Some(LuaSemanticDeclId::LuaDecl(decl_id)) => {
+            db.get_decl_index().get_decl(&decl_id).is_some_and(|decl| {
+                decl.is_global()
+                    && module_index.is_std(&decl.get_file_id())
+                    && decl.get_name() == name
+            })
+        }
+        Some(LuaSemanticDeclId::Member(member_id)) => db
+            .get_member_index()
+            .get_member(&member_id)
+            .is_some_and(|member| {
+                module_index.is_std(&member_id.file_id) && member.get_key().get_name() == Some(name)
+            }),
+        None => true,
+        _ => false,
+    }
+}
+
+/// 块中存在终止语句, 并且其后没有可以通过 `goto` 到达的标签
+pub(super) fn is_terminated_block(semantic_model: &SemanticModel, block: &LuaBlock) -> bool {
+    let mut terminated = false;
+    for stat in block.get_stats() {
+        if matches!(stat, LuaStat::LabelStat(_)) {
+            terminated = false;
+        } else if !terminated && is_terminator(semantic_model, &stat) {
+            terminated = true;
+        }
+    }
+    terminated
+}
+
+/// 只有存在 `else` 分支且所有分支都终止时, `if` 语句才是无条件终止的
+fn is_terminated_if(semantic_model: &SemanticModel, if_stat: &LuaIfStat) -> bool {
+    if if_stat.get_else_clause().is_none() {
+        return false;
+    }
+
+    if !if_stat
+        .get_block()
+        .is_some_and(|it| is_terminated_block(semantic_model, &it))
+    {
+        return false;
+    }
+
+    if_stat.get_all_clause().all(|clause| {
+        clause
+            .get_block()
+            .is_some_and(|it| is_terminated_block(semantic_model, &it))
+    })
+}
+
+/// 条件总是 `value`, 包括 `(true)` 等可以推断为布尔常量的表达式
+fn is_const_condition(semantic_model: &SemanticModel, expr: Option<LuaExpr>, value: bool) -> bool {
+    let Some(expr) = expr else {
+        return false;
+    };
+
+    matches!(
+        semantic_model.infer_expr(expr),
+        Ok(LuaType::BooleanConst(b)) if b == value
+    )
+}
+
+/// 循环体中存在跳出这个循环的 `break`, 或者目标标签在循环体外的 `goto`
+fn can_exit_loop(loop_node: &LuaSyntaxNode) -> bool {
+    loop_node
+        .descendants()
+        .any(|node| match node.kind().into() {
+            LuaSyntaxKind::BreakStat => node
+                .ancestors()
+                .skip(1)
+                .find(|ancestor| is_loop_or_closure(ancestor.kind().into()))
+                .is_some_and(|ancestor| &ancestor == loop_node),
+            LuaSyntaxKind::GotoStat => {
+                LuaGotoStat::cast(node).is_some_and(|it| is_goto_exit_loop(&it, loop_node))
+            }
+            _ => false,
+        })
+}
+
+/// `goto` 只能跳转到所在块或外层块中的标签, 且不能跳出闭包.
+/// 在循环体内找到可见的同名标签时, 跳转不会离开循环
+fn is_goto_exit_loop(goto_stat: &LuaGotoStat, loop_node: &LuaSyntaxNode) -> bool {
+    let Some(label_name) = goto_stat.get_label_name_token() else {
+        return false;
+    };
+    let label_name = label_name.get_name_text();
+    for ancestor in goto_stat
+        .syntax()
+        .ancestors()
+        .take_while(|ancestor| ancestor != loop_node)
+    {
+        if ancestor.kind() == LuaSyntaxKind::ClosureExpr.into() {
+            return false;
+        }
+        let Some(block) = LuaBlock::cast(ancestor) else {
+            continue;
+        };
+        let has_label = block.get_stats().any(|stat| {
+            let LuaStat::LabelStat(label_stat) = stat else {
+                return false;
+            };
+            label_stat
+                .get_label_name_token()
+                .is_some_and(|name| name.get_name_text() == label_name)
+        });
+        if has_label {
+            return false;
+        }
+    }
+
+    true
+}
+
+fn is_loop_or_closure(kind: LuaSyntaxKind) -> bool {
+    matches!(
+        kind,
+        LuaSyntaxKind::WhileStat
+            | LuaSyntaxKind::RepeatStat
+            | LuaSyntaxKind::ForStat
+            | LuaSyntaxKind::ForRangeStat
+            | LuaSyntaxKind::ClosureExpr
+    )
+}
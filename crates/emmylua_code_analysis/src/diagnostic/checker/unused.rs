@@ -3,7 +3,7 @@ use rowan::TextRange;
 
 use crate::{DiagnosticCode, LuaDecl, LuaReferenceIndex, SemanticModel};
 
-use super::{Checker, DiagnosticContext};
+use super::{Checker, DiagnosticContext, unused_require::is_require_decl};
 
 pub struct UnusedChecker;
 
@@ -22,11 +22,17 @@ impl Checker for UnusedChecker {
 
         let root = semantic_model.get_root();
         let ref_index = semantic_model.get_db().get_reference_index();
+        // 未使用的 require 由 `unused-require` 报告
+        let check_require = !context.is_checker_enable_by_code(&DiagnosticCode::UnusedRequire);
         for (_, decl) in decl_tree.get_decls().iter() {
             if decl.is_global() || decl.is_param() && decl.get_name() == "..." {
                 continue;
             }
 
+            if !check_require && is_require_decl(decl) {
+                continue;
+            }
+
             if let Err(result) = get_unused_check_result(ref_index, decl, root) {
                 let name = decl.get_name();
                 if name.starts_with('_') {
@@ -0,0 +1,242 @@
+use emmylua_parser::{
+    LuaAstNode, LuaComment, LuaCommentOwner, LuaDocTag, LuaStat, LuaSyntaxNode, LuaTableField,
+    VisibilityKind,
+};
+
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+};
+use tokio_util::sync::CancellationToken;
+
+use crate::{
+    DbIndex, DiagnosticCode, FileId, LuaInferCache, LuaMember, LuaMemberId, LuaMemberKey,
+    LuaMemberOwner, LuaSemanticDeclId, LuaType, ModuleInfo, SemanticDeclLevel, SemanticModel,
+    semantic::is_reference_to,
+};
+
+use super::{Checker, DiagnosticContext};
+
+pub struct UnusedExportChecker;
+
+impl Checker for UnusedExportChecker {
+    const CODES: &[DiagnosticCode] = &[DiagnosticCode::UnusedExport];
+
+    /// 检查模块导出表中定义的成员是否被其他文件引用, 带有 `---@public` 的成员视为有意公开的接口
+    fn check(context: &mut DiagnosticContext, semantic_model: &SemanticModel) {
+        let Some(used_exports) = context.get_used_exports() else {
+            return;
+        };
+        let db = semantic_model.get_db();
+        let Some(module_info) = db
+            .get_module_index()
+            .get_module(semantic_model.get_file_id())
+        else {
+            return;
+        };
+
+        let root = semantic_model.get_root().syntax().clone();
+        for member in get_export_members(db, module_info) {
+            if used_exports.contains(&member.get_id())
+                || !is_public_member(db, member)
+                || is_marked_public(&root, member).unwrap_or(false)
+            {
+                continue;
+            }
+            let Some(name) = member.get_key().get_name() else {
+                continue;
+            };
+
+            context.add_diagnostic(
+                DiagnosticCode::UnusedExport,
+                member.get_range(),
+                t!(
+                    "Exported member `%{name}` is not used by any other file",
+                    name = name
+                )
+                .to_string(),
+                None,
+            );
+        }
+    }
+}
+
+/// 被其他文件引用过的模块导出成员, 所有文件索引完成后汇总.
+///
+/// 只统计引用索引中的 `M.name` 形式的访问, `M[key]` 这类动态访问以及把模块表整体传给其他函数的用法
+/// 不会被计入, 因此这些成员可能被误报为未使用.
+#[derive(Debug)]
+pub(crate) struct UsedExports {
+    revision: u64,
+    // 被引用的成员及一个引用它的文件
+    witnesses: HashMap<LuaMemberId, FileId>,
+    used: Arc<HashSet<LuaMemberId>>,
+}
+
+impl UsedExports {
+    pub fn get_revision(&self) -> u64 {
+        self.revision
+    }
+
+    pub fn get_used(&self) -> Arc<HashSet<LuaMemberId>> {
+        self.used.clone()
+    }
+
+    /// 在上一次的结果上增量更新: 只重新解析变化文件中的引用, 以及指向变化文件中成员或失去引用来源的成员的引用.
+    /// `changed_files` 为 None 时完整计算, 被取消时返回 None
+    pub fn update(
+        previous: Option<&UsedExports>,
+        db: &DbIndex,
+        revision: u64,
+        changed_files: Option<&HashSet<FileId>>,
+        cancel_token: &CancellationToken,
+    ) -> Option<UsedExports> {
+        let empty = HashSet::new();
+        let (mut witnesses, changed_files, full) = match (previous, changed_files) {
+            (Some(previous), Some(changed_files)) => {
+                (previous.witnesses.clone(), changed_files, false)
+            }
+            _ => (HashMap::new(), &empty, true),
+        };
+
+        // 引用来源或成员所在文件变化后, 之前的结果不再可靠
+        let mut pending = HashSet::new();
+        witnesses.retain(|member_id, file_id| {
+            let keep =
+                !changed_files.contains(file_id) && !changed_files.contains(&member_id.file_id);
+            if !keep {
+                pending.insert(*member_id);
+            }
+            keep
+        });
+
+        let mut exports: HashMap<&LuaMemberKey, Vec<LuaMemberId>> = HashMap::new();
+        for module_info in db.get_module_index().get_module_infos() {
+            if !db.get_module_index().is_main(&module_info.file_id) {
+                continue;
+            }
+            for member in get_export_members(db, module_info) {
+                exports
+                    .entry(member.get_key())
+                    .or_default()
+                    .push(member.get_id());
+            }
+        }
+
+        let mut infer_caches: HashMap<FileId, LuaInferCache> = HashMap::new();
+        for (key, member_ids) in exports {
+            let Some(index_references) = db.get_reference_index().get_index_references(key) else {
+                continue;
+            };
+            for reference in index_references {
+                if cancel_token.is_cancelled() {
+                    return None;
+                }
+
+                let file_id = reference.file_id;
+                let mut candidates = member_ids
+                    .iter()
+                    .filter(|member_id| {
+                        member_id.file_id != file_id
+                            && !witnesses.contains_key(*member_id)
+                            && (full
+                                || changed_files.contains(&file_id)
+                                || changed_files.contains(&member_id.file_id)
+                                || pending.contains(*member_id))
+                    })
+                    .peekable();
+                if candidates.peek().is_none() {
+                    continue;
+                }
+                let Some(node) = db
+                    .get_vfs()
+                    .get_syntax_tree(&file_id)
+                    .and_then(|tree| reference.value.to_node_from_root(&tree.get_red_root()))
+                else {
+                    continue;
+                };
+
+                let infer_cache = infer_caches
+                    .entry(file_id)
+                    .or_insert_with(|| LuaInferCache::new(file_id, Default::default()));
+                let referenced = candidates
+                    .filter(|member_id| {
+                        is_reference_to(
+                            db,
+                            infer_cache,
+                            node.clone(),
+                            LuaSemanticDeclId::Member(**member_id),
+                            SemanticDeclLevel::default(),
+                        )
+                        .unwrap_or(false)
+                    })
+                    .copied()
+                    .collect::<Vec<_>>();
+                for member_id in referenced {
+                    witnesses.insert(member_id, file_id);
+                }
+            }
+        }
+
+        let used = Arc::new(witnesses.keys().copied().collect());
+        Some(UsedExports {
+            revision,
+            witnesses,
+            used,
+        })
+    }
+}
+
+/// 模块导出表中在模块文件内定义的函数和值
+fn get_export_members<'a>(db: &'a DbIndex, module_info: &ModuleInfo) -> Vec<&'a LuaMember> {
+    if module_info.is_meta {
+        return Vec::new();
+    }
+
+    let owner = match &module_info.export_type {
+        Some(LuaType::TableConst(inst)) => LuaMemberOwner::Element(inst.clone()),
+        Some(LuaType::Ref(type_id)) | Some(LuaType::Def(type_id)) => {
+            LuaMemberOwner::Type(type_id.clone())
+        }
+        _ => return Vec::new(),
+    };
+
+    db.get_member_index()
+        .get_members(&owner)
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|member| member.get_file_id() == module_info.file_id && !member.is_field())
+        .collect()
+}
+
+/// 带有 `---@private`, `---@package` 等可见性的成员本就不是公开接口
+fn is_public_member(db: &DbIndex, member: &LuaMember) -> bool {
+    db.get_property_index()
+        .get_property(&LuaSemanticDeclId::Member(member.get_id()))
+        .is_none_or(|property| property.visibility == VisibilityKind::Public)
+}
+
+/// 成员的注释中是否显式写了 `---@public`
+fn is_marked_public(root: &LuaSyntaxNode, member: &LuaMember) -> Option<bool> {
+    let node = member.get_syntax_id().to_node_from_root(root)?;
+    let comments: Vec<LuaComment> = node.ancestors().find_map(|node| {
+        if let Some(field) = LuaTableField::cast(node.clone()) {
+            Some(field.get_comments())
+        } else {
+            LuaStat::cast(node).map(|stat| stat.get_comments())
+        }
+    })?;
+
+    Some(comments.iter().any(|comment| {
+        comment.get_doc_tags().any(|tag| {
+            matches!(
+                tag,
+                LuaDocTag::Visibility(visibility)
+                    if visibility
+                        .get_visibility_token()
+                        .and_then(|token| token.get_visibility())
+                        == Some(VisibilityKind::Public)
+            )
+        })
+    }))
+}
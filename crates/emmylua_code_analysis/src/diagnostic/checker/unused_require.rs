@@ -0,0 +1,54 @@
+use emmylua_parser::LuaSyntaxKind;
+
+use crate::{DiagnosticCode, LuaDecl, LuaReferenceIndex, SemanticModel};
+
+use super::{Checker, DiagnosticContext};
+
+pub struct UnusedRequireChecker;
+
+impl Checker for UnusedRequireChecker {
+    const CODES: &[DiagnosticCode] = &[DiagnosticCode::UnusedRequire];
+
+    /// 只检查 `local M = require("x")`, 单独的 `require("x")` 通常是为了副作用
+    fn check(context: &mut DiagnosticContext, semantic_model: &SemanticModel) {
+        let file_id = semantic_model.get_file_id();
+        let Some(decl_tree) = semantic_model
+            .get_db()
+            .get_decl_index()
+            .get_decl_tree(&file_id)
+        else {
+            return;
+        };
+
+        let ref_index = semantic_model.get_db().get_reference_index();
+        for (_, decl) in decl_tree.get_decls().iter() {
+            if !decl.is_local() || !is_require_decl(decl) || decl.get_name().starts_with('_') {
+                continue;
+            }
+
+            if !is_decl_used(ref_index, decl) {
+                context.add_diagnostic(
+                    DiagnosticCode::UnusedRequire,
+                    decl.get_range(),
+                    t!(
+                        "Required module `%{name}` is never used",
+                        name = decl.get_name()
+                    )
+                    .to_string(),
+                    None,
+                );
+            }
+        }
+    }
+}
+
+pub fn is_require_decl(decl: &LuaDecl) -> bool {
+    decl.get_value_syntax_id()
+        .is_some_and(|expr_id| expr_id.get_kind() == LuaSyntaxKind::RequireCallExpr)
+}
+
+fn is_decl_used(ref_index: &LuaReferenceIndex, decl: &LuaDecl) -> bool {
+    ref_index
+        .get_decl_references(&decl.get_file_id(), &decl.get_id())
+        .is_some_and(|decl_ref| !decl_ref.cells.is_empty())
+}
@@ -0,0 +1,214 @@
+use emmylua_parser::{
+    LuaAstNode, LuaAstToken, LuaCallExpr, LuaExpr, LuaIndexExpr, LuaIndexKey, LuaLocalName, LuaStat,
+};
+use lsp_types::{Range, TextEdit};
+use rowan::{NodeOrToken, TextRange, TextSize, TokenAtOffset};
+
+use crate::{DiagnosticCode, SemanticModel};
+
+/// 诊断的自动修复, 返回当前文件中的编辑. 没有可用的修复时返回 `None`.
+/// 语言服务器的快速修复和命令行工具的 `--fix-dry-run` 共用这些修复
+pub fn build_diagnostic_fix(
+    semantic_model: &SemanticModel,
+    code: DiagnosticCode,
+    range: Range,
+    data: &Option<serde_json::Value>,
+) -> Option<Vec<TextEdit>> {
+    match code {
+        DiagnosticCode::NeedCheckNil | DiagnosticCode::CallPossiblyNil => {
+            build_need_check_nil_fix(semantic_model, range)
+        }
+        DiagnosticCode::PreferredLocalAlias => build_preferred_local_alias_fix(range, data),
+        DiagnosticCode::ColonCallMismatch => build_colon_call_fix(semantic_model, range),
+        DiagnosticCode::UnusedRequire => build_remove_unused_require_fix(semantic_model, range),
+        DiagnosticCode::Unused => build_remove_unused_local_fix(semantic_model, range),
+        _ => None,
+    }
+}
+
+/// 修复不会改变代码的运行结果, 可以在没有人工确认的情况下批量应用
+pub fn is_safe_diagnostic_fix(code: DiagnosticCode) -> bool {
+    matches!(
+        code,
+        DiagnosticCode::UnusedRequire
+            | DiagnosticCode::Unused
+            | DiagnosticCode::PreferredLocalAlias
+    )
+}
+
+/// 在表达式末尾插入 `--[[@cast -?]]`
+fn build_need_check_nil_fix(semantic_model: &SemanticModel, range: Range) -> Option<Vec<TextEdit>> {
+    let document = semantic_model.get_document();
+    let offset = document.get_offset(range.end.line as usize, range.end.character as usize)?;
+    let root = semantic_model.get_root();
+    let token = match root.syntax().token_at_offset(offset) {
+        TokenAtOffset::Single(token) => token,
+        TokenAtOffset::Between(_, token) => token,
+        _ => return None,
+    };
+    // 取上一个token的父节点
+    let NodeOrToken::Node(expr_node) = token.prev_sibling_or_token()? else {
+        return None;
+    };
+    let expr = LuaExpr::cast(expr_node)?;
+    let mut lsp_range = document.to_lsp_range(expr.syntax().text_range())?;
+    // 将范围缩小到最尾部的字符
+    lsp_range.start = lsp_range.end;
+
+    Some(vec![TextEdit {
+        range: lsp_range,
+        new_text: "--[[@cast -?]]".to_string(),
+    }])
+}
+
+/// 替换为诊断数据中的局部别名
+fn build_preferred_local_alias_fix(
+    range: Range,
+    data: &Option<serde_json::Value>,
+) -> Option<Vec<TextEdit>> {
+    let alias_name = data.as_ref()?.get("preferredAlias")?.as_str()?;
+    Some(vec![TextEdit {
+        range,
+        new_text: alias_name.to_string(),
+    }])
+}
+
+/// 将 `obj.method(obj, ...)` 改为 `obj:method(...)`
+fn build_colon_call_fix(semantic_model: &SemanticModel, range: Range) -> Option<Vec<TextEdit>> {
+    let document = semantic_model.get_document();
+    let offset = document.get_offset(range.start.line as usize, range.start.character as usize)?;
+    let root = semantic_model.get_root();
+    let token = root.syntax().token_at_offset(offset).right_biased()?;
+    let index_expr = LuaIndexExpr::cast(token.parent()?)?;
+    let call_expr = LuaCallExpr::cast(index_expr.syntax().parent()?)?;
+    let index_token = index_expr.get_index_token()?;
+    // 只处理 `obj.method(...)` 改为 `obj:method(...)` 的情况
+    if !index_token.is_dot() {
+        return None;
+    }
+
+    let mut text_edits = vec![TextEdit {
+        range: document.to_lsp_range(index_token.get_range())?,
+        new_text: ":".to_string(),
+    }];
+
+    let receiver = index_expr.get_prefix_expr()?;
+    let args = call_expr.get_args_list()?.get_args().collect::<Vec<_>>();
+    if let Some(first_arg) = args.first() {
+        if first_arg.get_text() != receiver.get_text() {
+            return None;
+        }
+        // 删除第一个参数及其后的逗号
+        let end = match args.get(1) {
+            Some(next_arg) => next_arg.get_position(),
+            None => first_arg.get_range().end(),
+        };
+        text_edits.push(TextEdit {
+            range: document.to_lsp_range(TextRange::new(first_arg.get_position(), end))?,
+            new_text: String::new(),
+        });
+    }
+
+    Some(text_edits)
+}
+
+/// 删除未使用的 `local x = require(...)`
+fn build_remove_unused_require_fix(
+    semantic_model: &SemanticModel,
+    range: Range,
+) -> Option<Vec<TextEdit>> {
+    let LuaStat::LocalStat(local_stat) = find_local_stat(semantic_model, range)? else {
+        return None;
+    };
+    // 同一语句中还声明了其他变量时不能删除
+    if local_stat.get_local_name_list().count() != 1 {
+        return None;
+    }
+
+    Some(vec![build_remove_stat_edit(
+        semantic_model,
+        LuaStat::LocalStat(local_stat),
+    )?])
+}
+
+/// 删除未使用的局部变量或局部函数, 只处理初始化没有副作用的情况.
+/// `<close>` 变量在离开作用域时会调用 `__close`, 不能删除
+fn build_remove_unused_local_fix(
+    semantic_model: &SemanticModel,
+    range: Range,
+) -> Option<Vec<TextEdit>> {
+    let stat = find_local_stat(semantic_model, range)?;
+    match &stat {
+        LuaStat::LocalStat(local_stat) => {
+            if local_stat.get_local_name_list().count() != 1
+                || local_stat.get_local_name_list().any(|local_name| {
+                    local_name
+                        .get_attrib()
+                        .is_some_and(|attrib| attrib.is_close())
+                })
+                || !local_stat
+                    .get_value_exprs()
+                    .all(|expr| is_side_effect_free(&expr))
+            {
+                return None;
+            }
+        }
+        LuaStat::LocalFuncStat(_) => {}
+        _ => return None,
+    }
+
+    Some(vec![build_remove_stat_edit(semantic_model, stat)?])
+}
+
+/// 诊断范围的起始位置是局部变量名时, 返回声明它的语句
+fn find_local_stat(semantic_model: &SemanticModel, range: Range) -> Option<LuaStat> {
+    let document = semantic_model.get_document();
+    let offset = document.get_offset(range.start.line as usize, range.start.character as usize)?;
+    let root = semantic_model.get_root();
+    let token = root.syntax().token_at_offset(offset).right_biased()?;
+    let local_name = LuaLocalName::cast(token.parent()?)?;
+    LuaStat::cast(local_name.syntax().parent()?)
+}
+
+fn is_side_effect_free(expr: &LuaExpr) -> bool {
+    match expr {
+        LuaExpr::LiteralExpr(_) | LuaExpr::NameExpr(_) | LuaExpr::ClosureExpr(_) => true,
+        LuaExpr::TableExpr(table_expr) => table_expr.get_fields().all(|field| {
+            let key_is_free = match field.get_field_key() {
+                Some(LuaIndexKey::Expr(key_expr)) => is_side_effect_free(&key_expr),
+                _ => true,
+            };
+            key_is_free
+                && field
+                    .get_value_expr()
+                    .is_none_or(|value_expr| is_side_effect_free(&value_expr))
+        }),
+        _ => false,
+    }
+}
+
+/// 删除语句. 语句独占若干行时删除整行, 否则只删除语句本身.
+/// 附着在语句上的注释可能是文件头或类型定义, 保留不动
+fn build_remove_stat_edit(semantic_model: &SemanticModel, stat: LuaStat) -> Option<TextEdit> {
+    let document = semantic_model.get_document();
+    let text = document.get_text();
+    let range = stat.get_range();
+    let start = usize::from(range.start());
+    let end = usize::from(range.end());
+    let line_start = text[..start].rfind('\n').map_or(0, |i| i + 1);
+    let line_end = text[end..].find('\n').map_or(text.len(), |i| end + i + 1);
+    let remove_range =
+        if text[line_start..start].trim().is_empty() && text[end..line_end].trim().is_empty() {
+            TextRange::new(
+                TextSize::from(line_start as u32),
+                TextSize::from(line_end as u32),
+            )
+        } else {
+            range
+        };
+
+    Some(TextEdit {
+        range: document.to_lsp_range(remove_range)?,
+        new_text: String::new(),
+    })
+}
@@ -0,0 +1,62 @@
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Mutex,
+    time::Duration,
+};
+
+use crate::DiagnosticCode;
+
+/// 每个检查器在所有文件上累计的耗时
+#[derive(Debug, Default)]
+pub struct DiagnosticStats {
+    timings: Mutex<HashMap<&'static str, CheckerTiming>>,
+    // 为空时统计所有检查器
+    codes: Option<HashSet<DiagnosticCode>>,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct CheckerTiming {
+    pub elapsed: Duration,
+    pub file_count: usize,
+}
+
+impl DiagnosticStats {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 只统计至少负责其中一个诊断代码的检查器
+    pub fn with_codes(codes: impl IntoIterator<Item = DiagnosticCode>) -> Self {
+        Self {
+            timings: Mutex::default(),
+            codes: Some(codes.into_iter().collect()),
+        }
+    }
+
+    pub fn is_tracked(&self, checker_codes: &[DiagnosticCode]) -> bool {
+        match &self.codes {
+            Some(codes) => checker_codes.iter().any(|code| codes.contains(code)),
+            None => true,
+        }
+    }
+
+    pub fn record(&self, checker_name: &'static str, elapsed: Duration) {
+        let mut timings = self.timings.lock().unwrap_or_else(|e| e.into_inner());
+        let timing = timings.entry(checker_name).or_default();
+        timing.elapsed += elapsed;
+        timing.file_count += 1;
+    }
+
+    /// 按耗时从高到低排序
+    pub fn get_sorted_timings(&self) -> Vec<(&'static str, CheckerTiming)> {
+        let mut timings = self
+            .timings
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .iter()
+            .map(|(name, timing)| (*name, *timing))
+            .collect::<Vec<_>>();
+        timings.sort_by(|a, b| b.1.elapsed.cmp(&a.1.elapsed).then(a.0.cmp(b.0)));
+        timings
+    }
+}
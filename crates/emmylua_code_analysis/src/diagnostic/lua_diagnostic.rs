@@ -1,8 +1,17 @@
-use std::sync::Arc;
+use std::{
+    collections::HashSet,
+    path::PathBuf,
+    sync::{Arc, Mutex},
+};
+
+use hashbrown::HashMap;
 
 pub use super::checker::DiagnosticContext;
-use super::{checker::check_file, lua_diagnostic_config::LuaDiagnosticConfig};
-use crate::{DiagnosticCode, Emmyrc, FileId, LuaCompilation};
+use super::{
+    checker::{UsedExports, check_file},
+    lua_diagnostic_config::LuaDiagnosticConfig,
+};
+use crate::{DiagnosticCode, DiagnosticStats, Emmyrc, FileId, LuaCompilation, LuaMemberId};
 use lsp_types::Diagnostic;
 use tokio_util::sync::CancellationToken;
 
@@ -10,6 +19,10 @@ use tokio_util::sync::CancellationToken;
 pub struct LuaDiagnostic {
     enable: bool,
     config: Arc<LuaDiagnosticConfig>,
+    directory_configs: HashMap<PathBuf, Arc<LuaDiagnosticConfig>>,
+    stats: Option<Arc<DiagnosticStats>>,
+    // 按索引版本缓存, 所有文件共用一次汇总结果, 索引变化后增量更新
+    used_exports: Mutex<Option<UsedExports>>,
 }
 
 impl Default for LuaDiagnostic {
@@ -23,6 +36,9 @@ impl LuaDiagnostic {
         Self {
             enable: true,
             config: Arc::new(LuaDiagnosticConfig::default()),
+            directory_configs: HashMap::new(),
+            stats: None,
+            used_exports: Mutex::new(None),
         }
     }
 
@@ -31,6 +47,14 @@ impl LuaDiagnostic {
         self.config = LuaDiagnosticConfig::new(&emmyrc).into();
     }
 
+    /// 子目录中的配置, 对应 `DbIndex::update_directory_configs`
+    pub fn update_directory_configs(&mut self, configs: &[(PathBuf, Arc<Emmyrc>)]) {
+        self.directory_configs = configs
+            .iter()
+            .map(|(dir, emmyrc)| (dir.clone(), LuaDiagnosticConfig::new(emmyrc).into()))
+            .collect();
+    }
+
     // 只开启指定的诊断
     pub fn enable_only(&mut self, code: DiagnosticCode) {
         let mut emmyrc = Emmyrc::default();
@@ -43,6 +67,13 @@ impl LuaDiagnostic {
         self.config = LuaDiagnosticConfig::new(&emmyrc).into();
     }
 
+    /// 开始统计每个检查器在所有文件上的耗时
+    pub fn enable_stats(&mut self, stats: DiagnosticStats) -> Arc<DiagnosticStats> {
+        let stats = Arc::new(stats);
+        self.stats = Some(stats.clone());
+        stats
+    }
+
     pub fn diagnose_file(
         &self,
         compilation: &LuaCompilation,
@@ -64,11 +95,68 @@ impl LuaDiagnostic {
             return None;
         }
 
+        if db.is_ignored(&file_id) {
+            return None;
+        }
+
+        if !db.get_file_emmyrc(&file_id).diagnostics.enable {
+            return None;
+        }
+
+        let config = db
+            .get_file_config_dir(&file_id)
+            .and_then(|dir| self.directory_configs.get(dir))
+            .unwrap_or(&self.config)
+            .clone();
         let semantic_model = compilation.get_semantic_model(file_id)?;
-        let mut context = DiagnosticContext::new(file_id, db, self.config.clone());
+        let mut context = DiagnosticContext::new(file_id, db, config);
+        if let Some(stats) = &self.stats {
+            context.set_stats(stats.clone());
+        }
+        if context.is_checker_enable_by_code(&DiagnosticCode::UnusedExport)
+            && let Some(used_exports) = self.get_used_exports(compilation, &cancel_token)
+        {
+            context.set_used_exports(used_exports);
+        }
+        context.set_cancel_token(cancel_token);
 
         check_file(&mut context, &semantic_model);
+        // 被取消的诊断结果不完整, 不应发布
+        if context.is_cancelled() {
+            return None;
+        }
 
         Some(context.get_diagnostics())
     }
+
+    fn get_used_exports(
+        &self,
+        compilation: &LuaCompilation,
+        cancel_token: &CancellationToken,
+    ) -> Option<Arc<HashSet<LuaMemberId>>> {
+        let revision = compilation.get_revision();
+        let mut used_exports = self
+            .used_exports
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        if let Some(cached) = used_exports.as_ref()
+            && cached.get_revision() == revision
+        {
+            return Some(cached.get_used());
+        }
+
+        let changed_files = used_exports
+            .as_ref()
+            .and_then(|cached| compilation.get_changed_files(cached.get_revision()));
+        let result = UsedExports::update(
+            used_exports.as_ref(),
+            compilation.get_db(),
+            revision,
+            changed_files.as_ref(),
+            cancel_token,
+        )?;
+        let used = result.get_used();
+        *used_exports = Some(result);
+        Some(used)
+    }
 }
@@ -63,7 +63,7 @@ pub enum DiagnosticCode {
     RedundantReturnValue,
     /// Undefined Doc Param
     UndefinedDocParam,
-    /// Duplicate doc field
+    /// Duplicate doc field or doc param
     DuplicateDocField,
     /// Unknown doc annotation
     UnknownDocTag,
@@ -119,10 +119,64 @@ pub enum DiagnosticCode {
     InvertIf,
     /// Call to a non-callable value
     CallNonCallable,
+    /// Redundant type annotation
+    RedundantAnnotation,
+    /// Method called with `.` instead of `:` or the other way round
+    ColonCallMismatch,
+    /// Local variable shadows another local with the same name
+    ShadowedLocal,
+    /// Result of an async function is discarded inside an async function
+    MissingAwait,
+    /// Partial class member conflict
+    PartialMemberConflict,
+    /// Recursive alias
+    RecursiveAlias,
+    /// Assign nil to non-nil field
+    AssignNilToNonNil,
+    /// string.format arguments do not match the format string
+    FormatArgMismatch,
+    /// `/` is used where an integer is expected
+    DivisionTypeHint,
+    /// Unused require
+    UnusedRequire,
+    /// Suspicious comparison
+    SuspiciousComparison,
+    /// Yield outside coroutine
+    YieldOutsideCoroutine,
+    /// Array element mismatch
+    ArrayElementMismatch,
+    /// Unused export
+    UnusedExport,
+    /// Naming convention
+    NamingConvention,
+    /// Call possibly nil
+    CallPossiblyNil,
+    /// Inline `@as` cast to an unrelated type
+    AsTypeMismatch,
     #[serde(other)]
     None,
 }
 
+/// Metadata of a diagnostic code, used by tooling and the config schema
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DiagnosticCodeMetadata {
+    pub name: &'static str,
+    pub description: &'static str,
+    pub default_severity: DiagnosticSeverity,
+    pub default_enabled: bool,
+}
+
+impl DiagnosticCode {
+    pub fn metadata(&self, level: LuaLanguageLevel) -> DiagnosticCodeMetadata {
+        DiagnosticCodeMetadata {
+            name: self.get_name(),
+            description: self.get_description(),
+            default_severity: get_default_severity(*self),
+            default_enabled: is_code_default_enable(self, level),
+        }
+    }
+}
+
 // Update functions to match enum variants
 pub fn get_default_severity(code: DiagnosticCode) -> DiagnosticSeverity {
     match code {
@@ -148,6 +202,12 @@ pub fn get_default_severity(code: DiagnosticCode) -> DiagnosticSeverity {
         DiagnosticCode::IterVariableReassign => DiagnosticSeverity::ERROR,
         DiagnosticCode::PreferredLocalAlias => DiagnosticSeverity::HINT,
         DiagnosticCode::CallNonCallable => DiagnosticSeverity::WARNING,
+        DiagnosticCode::RedundantAnnotation => DiagnosticSeverity::HINT,
+        DiagnosticCode::ColonCallMismatch => DiagnosticSeverity::WARNING,
+        DiagnosticCode::ShadowedLocal => DiagnosticSeverity::HINT,
+        DiagnosticCode::DivisionTypeHint => DiagnosticSeverity::HINT,
+        DiagnosticCode::UnusedRequire => DiagnosticSeverity::HINT,
+        DiagnosticCode::UnusedExport => DiagnosticSeverity::HINT,
         _ => DiagnosticSeverity::WARNING,
     }
 }
@@ -155,10 +215,17 @@ pub fn get_default_severity(code: DiagnosticCode) -> DiagnosticSeverity {
 pub fn is_code_default_enable(code: &DiagnosticCode, level: LuaLanguageLevel) -> bool {
     match code {
         DiagnosticCode::IterVariableReassign => level >= LuaLanguageLevel::Lua55,
+        DiagnosticCode::DivisionTypeHint => level >= LuaLanguageLevel::Lua53,
         DiagnosticCode::CodeStyleCheck => false,
         DiagnosticCode::IncompleteSignatureDoc => false,
         DiagnosticCode::MissingGlobalDoc => false,
         DiagnosticCode::UnknownDocTag => false,
+        DiagnosticCode::RedundantAnnotation => false,
+        DiagnosticCode::ShadowedLocal => false,
+        DiagnosticCode::MissingAwait => false,
+        DiagnosticCode::UnusedExport => false,
+        DiagnosticCode::AssignNilToNonNil => false,
+        DiagnosticCode::AsTypeMismatch => false,
         // ... handle other variants
 
         // neovim-code-style
@@ -1,7 +1,6 @@
-use hashbrown::{HashMap, HashSet};
+use hashbrown::HashSet;
 
 use emmylua_parser::LuaLanguageLevel;
-use lsp_types::DiagnosticSeverity;
 use regex::Regex;
 use smol_str::SmolStr;
 
@@ -15,10 +14,19 @@ pub struct LuaDiagnosticConfig {
     pub workspace_disabled: HashSet<DiagnosticCode>,
     pub global_disable_set: HashSet<SmolStr>,
     pub global_disable_glob: Vec<Regex>,
-    pub severity: HashMap<DiagnosticCode, DiagnosticSeverity>,
+    pub naming_convention: NamingConventionRules,
     pub level: LuaLanguageLevel,
 }
 
+/// `naming-convention` 使用的已编译的正则, 未配置或无效的规则为 `None`
+#[derive(Debug, Clone, Default)]
+pub struct NamingConventionRules {
+    pub local: Option<Regex>,
+    pub global: Option<Regex>,
+    pub class: Option<Regex>,
+    pub constant: Option<Regex>,
+}
+
 impl LuaDiagnosticConfig {
     pub fn new(emmyrc: &Emmyrc) -> Self {
         let workspace_disabled = emmyrc.diagnostics.disable.iter().cloned().collect();
@@ -34,26 +42,37 @@ impl LuaDiagnosticConfig {
             .diagnostics
             .globals_regex
             .iter()
-            .filter_map(|s| match Regex::new(s) {
-                Ok(r) => Some(r),
-                Err(e) => {
-                    log::error!("Invalid regex: {}, error: {}", s, e);
-                    None
-                }
-            })
+            .filter_map(|s| compile_regex(s))
             .collect();
 
-        let mut severity = HashMap::new();
-        for (code, sev) in &emmyrc.diagnostics.severity {
-            severity.insert(*code, (*sev).into());
-        }
+        let naming_convention = &emmyrc.diagnostics.naming_convention;
+        let naming_convention = NamingConventionRules {
+            local: naming_convention.local.as_deref().and_then(compile_regex),
+            global: naming_convention.global.as_deref().and_then(compile_regex),
+            class: naming_convention.class.as_deref().and_then(compile_regex),
+            constant: naming_convention
+                .constant
+                .as_deref()
+                .and_then(compile_regex),
+        };
+
         Self {
             workspace_disabled,
             workspace_enabled,
             global_disable_set,
             global_disable_glob,
-            severity,
+            naming_convention,
             level: emmyrc.get_language_level(),
         }
     }
 }
+
+fn compile_regex(s: &str) -> Option<Regex> {
+    match Regex::new(s) {
+        Ok(r) => Some(r),
+        Err(e) => {
+            log::error!("Invalid regex: {}, error: {}", s, e);
+            None
+        }
+    }
+}
@@ -1,8 +1,13 @@
 mod checker;
+mod diagnostic_fix;
+mod diagnostic_stats;
 mod lua_diagnostic;
 mod lua_diagnostic_code;
 mod lua_diagnostic_config;
 mod test;
 
+pub use diagnostic_fix::{build_diagnostic_fix, is_safe_diagnostic_fix};
+pub use diagnostic_stats::{CheckerTiming, DiagnosticStats};
 pub use lua_diagnostic::LuaDiagnostic;
-pub use lua_diagnostic_code::DiagnosticCode;
+pub(crate) use lua_diagnostic_code::get_default_severity;
+pub use lua_diagnostic_code::{DiagnosticCode, DiagnosticCodeMetadata};
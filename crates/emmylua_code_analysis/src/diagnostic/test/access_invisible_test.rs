@@ -85,4 +85,91 @@ mod tests {
             "#
         ));
     }
+
+    #[test]
+    fn test_protected_member() {
+        let mut ws = VirtualWorkspace::new();
+        ws.def(
+            r#"
+            ---@class ProtectedBase
+            ---@field protected secret integer
+            local ProtectedBase = {}
+            "#,
+        );
+
+        assert!(ws.check_code_for(
+            DiagnosticCode::AccessInvisible,
+            r#"
+                ---@class ProtectedDerived: ProtectedBase
+                local ProtectedDerived = {}
+
+                function ProtectedDerived:read()
+                    return self.secret
+                end
+            "#
+        ));
+
+        assert!(!ws.check_code_for(
+            DiagnosticCode::AccessInvisible,
+            r#"
+                ---@type ProtectedBase
+                local base
+                print(base.secret)
+            "#
+        ));
+    }
+
+    #[test]
+    fn test_private_member() {
+        let mut ws = VirtualWorkspace::new();
+        assert!(ws.check_code_for(
+            DiagnosticCode::AccessInvisible,
+            r#"
+                ---@class PrivateOwner
+                ---@field private count integer
+                local PrivateOwner = {}
+
+                function PrivateOwner:inc()
+                    self.count = self.count + 1
+                end
+            "#
+        ));
+
+        assert!(!ws.check_code_for(
+            DiagnosticCode::AccessInvisible,
+            r#"
+                ---@type PrivateOwner
+                local owner
+                print(owner.count)
+            "#
+        ));
+    }
+
+    #[test]
+    fn test_package_member() {
+        let mut ws = VirtualWorkspace::new();
+        ws.def_file(
+            "package_owner.lua",
+            r#"
+            ---@class PackageOwner
+            ---@field package token string
+            local PackageOwner = {}
+
+            function PackageOwner:get()
+                return self.token
+            end
+
+            return PackageOwner
+            "#,
+        );
+
+        assert!(!ws.check_code_for(
+            DiagnosticCode::AccessInvisible,
+            r#"
+                ---@type PackageOwner
+                local owner
+                print(owner.token)
+            "#
+        ));
+    }
 }
@@ -0,0 +1,45 @@
+#[cfg(test)]
+mod test {
+    use crate::{DiagnosticCode, VirtualWorkspace};
+
+    #[test]
+    fn test_table_insert() {
+        let mut ws = VirtualWorkspace::new_with_init_std_lib();
+
+        assert!(!ws.check_code_for(
+            DiagnosticCode::ArrayElementMismatch,
+            r#"
+            ---@type string[]
+            local names = {}
+            table.insert(names, 1)
+            "#
+        ));
+
+        assert!(!ws.check_code_for(
+            DiagnosticCode::ArrayElementMismatch,
+            r#"
+            ---@type string[]
+            local names = {}
+            table.insert(names, 1, true)
+            "#
+        ));
+
+        assert!(ws.check_code_for(
+            DiagnosticCode::ArrayElementMismatch,
+            r#"
+            ---@type string[]
+            local names = {}
+            table.insert(names, "a")
+            table.insert(names, 1, "b")
+            "#
+        ));
+
+        assert!(ws.check_code_for(
+            DiagnosticCode::ArrayElementMismatch,
+            r#"
+            local t = {}
+            table.insert(t, 1)
+            "#
+        ));
+    }
+}
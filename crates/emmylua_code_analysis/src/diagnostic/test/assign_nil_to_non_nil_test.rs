@@ -0,0 +1,76 @@
+#[cfg(test)]
+mod tests {
+    use lsp_types::NumberOrString;
+    use tokio_util::sync::CancellationToken;
+
+    use crate::{DiagnosticCode, VirtualWorkspace};
+
+    #[test]
+    fn test_assign_nil_to_non_nil_field() {
+        let mut ws = VirtualWorkspace::new();
+        ws.def(
+            r#"
+            ---@class NilFieldFoo
+            ---@field a integer
+            ---@field b? integer
+            ---@field c integer|nil
+            ---@field d any
+            "#,
+        );
+        assert!(!ws.check_code_for(
+            DiagnosticCode::AssignNilToNonNil,
+            r#"
+            ---@type NilFieldFoo
+            local f
+            f.a = nil
+            "#
+        ));
+        assert!(ws.check_code_for(
+            DiagnosticCode::AssignNilToNonNil,
+            r#"
+            ---@type NilFieldFoo
+            local f
+            f.b = nil
+            f.c = nil
+            f.d = nil
+            f.a = 1
+            "#
+        ));
+    }
+
+    #[test]
+    fn test_assign_nil_to_undeclared_field() {
+        let mut ws = VirtualWorkspace::new();
+        assert!(ws.check_code_for(
+            DiagnosticCode::AssignNilToNonNil,
+            r#"
+            local t = { a = 1 }
+            t.a = nil
+            "#
+        ));
+    }
+
+    #[test]
+    fn test_assign_nil_to_non_nil_default_off() {
+        let mut ws = VirtualWorkspace::new();
+        let file_id = ws.def(
+            r#"
+            ---@class NilFieldBar
+            ---@field a integer
+            local Bar = {}
+
+            function Bar:close()
+                self.a = nil
+            end
+            "#,
+        );
+        let code = Some(NumberOrString::String(
+            DiagnosticCode::AssignNilToNonNil.get_name().to_string(),
+        ));
+        let diagnostics = ws
+            .analysis
+            .diagnose_file(file_id, CancellationToken::new())
+            .unwrap_or_default();
+        assert!(diagnostics.iter().all(|diagnostic| diagnostic.code != code));
+    }
+}
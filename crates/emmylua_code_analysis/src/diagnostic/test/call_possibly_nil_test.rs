@@ -0,0 +1,187 @@
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use lsp_types::NumberOrString;
+    use tokio_util::sync::CancellationToken;
+
+    use crate::{DiagnosticCode, VirtualWorkspace};
+
+    #[test]
+    fn test_call_possibly_nil() {
+        let mut ws = VirtualWorkspace::new();
+
+        assert!(!ws.check_code_for(
+            DiagnosticCode::CallPossiblyNil,
+            r#"
+            local f ---@type fun()?
+            f()
+            "#
+        ));
+
+        assert!(!ws.check_code_for(
+            DiagnosticCode::CallPossiblyNil,
+            r#"
+            ---@class Options
+            ---@field on_done? fun(ok: boolean)
+
+            ---@param opts Options
+            local function run(opts)
+                opts.on_done(true)
+            end
+            "#
+        ));
+
+        assert!(ws.check_code_for(
+            DiagnosticCode::CallPossiblyNil,
+            r#"
+            local f ---@type fun()?
+            if f then
+                f()
+            end
+
+            local g ---@type (fun(): integer)|nil
+            local _ = g and g()
+            "#
+        ));
+    }
+
+    #[test]
+    fn test_call_possibly_nil_replaces_need_check_nil() {
+        let mut ws = VirtualWorkspace::new();
+        let code = r#"
+            local f ---@type fun()?
+            f()
+        "#;
+
+        // 两个诊断都开启时只按 call-possibly-nil 报告
+        let file_id = ws.def(code);
+        let codes = ws
+            .analysis
+            .diagnose_file(file_id, CancellationToken::new())
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|diagnostic| diagnostic.code)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            codes,
+            vec![NumberOrString::String(
+                DiagnosticCode::CallPossiblyNil.get_name().to_string()
+            )]
+        );
+
+        // 关闭 call-possibly-nil 后仍按 need-check-nil 报告
+        assert!(!ws.check_code_for(DiagnosticCode::NeedCheckNil, code));
+    }
+
+    #[test]
+    fn test_call_possibly_nil_honours_need_check_nil_suppression() {
+        let mut ws = VirtualWorkspace::new();
+
+        assert!(ws.check_code_for(
+            DiagnosticCode::CallPossiblyNil,
+            r#"
+            local f ---@type fun()?
+            ---@diagnostic disable-next-line: need-check-nil
+            f()
+            "#
+        ));
+
+        assert!(ws.check_code_for(
+            DiagnosticCode::CallPossiblyNil,
+            r#"
+            ---@diagnostic disable: need-check-nil
+            local f ---@type fun()?
+            f()
+            "#
+        ));
+
+        assert!(ws.check_code_for(
+            DiagnosticCode::CallPossiblyNil,
+            r#"
+            local f ---@type fun()?
+            ---@diagnostic disable-next-line: call-possibly-nil
+            f()
+            "#
+        ));
+    }
+
+    #[test]
+    fn test_call_possibly_nil_honours_need_check_nil_config() {
+        let mut ws = VirtualWorkspace::new();
+        let mut emmyrc = ws.get_emmyrc();
+        emmyrc
+            .diagnostics
+            .disable
+            .push(DiagnosticCode::NeedCheckNil);
+        ws.update_emmyrc(emmyrc);
+
+        let file_id = ws.def(
+            r#"
+            local f ---@type fun()?
+            f()
+            "#,
+        );
+        let diagnostics = ws
+            .analysis
+            .diagnose_file(file_id, CancellationToken::new())
+            .unwrap_or_default();
+        assert!(diagnostics.is_empty(), "{:?}", diagnostics);
+
+        // 明确开启 call-possibly-nil 时仍然报告
+        let mut emmyrc = ws.get_emmyrc();
+        emmyrc
+            .diagnostics
+            .enables
+            .push(DiagnosticCode::CallPossiblyNil);
+        ws.update_emmyrc(emmyrc);
+        let codes = ws
+            .analysis
+            .diagnose_file(file_id, CancellationToken::new())
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|diagnostic| diagnostic.code)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            codes,
+            vec![NumberOrString::String(
+                DiagnosticCode::CallPossiblyNil.get_name().to_string()
+            )]
+        );
+    }
+
+    #[test]
+    fn test_call_possibly_nil_honours_need_check_nil_directory_config() {
+        let mut ws = VirtualWorkspace::new();
+        let package_dir = ws.virtual_url_generator.base.join("package");
+        let mut emmyrc = ws.get_emmyrc();
+        emmyrc
+            .diagnostics
+            .disable
+            .push(DiagnosticCode::NeedCheckNil);
+        ws.analysis
+            .update_directory_configs(vec![(package_dir, Arc::new(emmyrc))]);
+
+        let code = r#"
+            local f ---@type fun()?
+            f()
+        "#;
+        let root_file = ws.def_file("root.lua", code);
+        let package_file = ws.def_file("package/init.lua", code);
+        let diagnose = |file_id| {
+            ws.analysis
+                .diagnose_file(file_id, CancellationToken::new())
+                .unwrap_or_default()
+                .into_iter()
+                .filter_map(|diagnostic| diagnostic.code)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            diagnose(root_file),
+            vec![NumberOrString::String(
+                DiagnosticCode::CallPossiblyNil.get_name().to_string()
+            )]
+        );
+        assert!(diagnose(package_file).is_empty());
+    }
+}
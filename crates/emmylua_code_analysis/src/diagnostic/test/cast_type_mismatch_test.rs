@@ -1,5 +1,8 @@
 #[cfg(test)]
 mod tests {
+    use lsp_types::NumberOrString;
+    use tokio_util::sync::CancellationToken;
+
     use crate::DiagnosticCode;
     use crate::VirtualWorkspace;
 
@@ -245,4 +248,57 @@ mod tests {
             "#
         ));
     }
+
+    #[test]
+    fn test_inline_as_cast() {
+        let mut ws = VirtualWorkspace::new();
+        assert!(!ws.check_code_for(
+            DiagnosticCode::AsTypeMismatch,
+            r#"
+                local a = 1
+                local b = a --[[@as string]]
+            "#
+        ));
+        assert!(ws.check_code_for(
+            DiagnosticCode::CastTypeMismatch,
+            r#"
+                local a = 1
+                local b = a --[[@as string]]
+            "#
+        ));
+
+        assert!(ws.check_code_for(
+            DiagnosticCode::AsTypeMismatch,
+            r#"
+                ---@class CastFoo
+                ---@field bar string
+
+                local t = {} --[[@as CastFoo]]
+                local u = unknown_value --[[@as CastFoo]]
+            "#
+        ));
+    }
+
+    #[test]
+    fn test_inline_as_cast_disabled_by_default() {
+        let mut ws = VirtualWorkspace::new();
+        let file_id = ws.def(
+            r#"
+                local a = 1
+                local b = a --[[@as string]]
+            "#,
+        );
+        let code = Some(NumberOrString::String(
+            DiagnosticCode::AsTypeMismatch.get_name().to_string(),
+        ));
+        let diagnostics = ws
+            .analysis
+            .diagnose_file(file_id, CancellationToken::new())
+            .unwrap_or_default();
+        assert!(
+            diagnostics.iter().all(|d| d.code != code),
+            "{:?}",
+            diagnostics
+        );
+    }
 }
@@ -221,7 +221,7 @@ mod tests {
             "#
         ));
 
-        assert!(!ws.check_code_for(
+        assert!(ws.check_code_for(
             DiagnosticCode::MissingReturn,
             r#"
             local A
@@ -399,6 +399,155 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_missing_return_fall_through() {
+        let mut ws = VirtualWorkspace::new();
+
+        assert!(!ws.check_code_for(
+            DiagnosticCode::MissingReturn,
+            r#"
+            local A
+            ---@return number
+            function F()
+                if A then
+                    return 1
+                else
+                    A = 2
+                end
+            end
+            "#
+        ));
+
+        assert!(!ws.check_code_for(
+            DiagnosticCode::MissingReturn,
+            r#"
+            local A
+            ---@return number
+            function F()
+                while true do
+                    if A then
+                        break
+                    end
+                end
+            end
+            "#
+        ));
+
+        assert!(ws.check_code_for(
+            DiagnosticCode::MissingReturn,
+            r#"
+            local A
+            ---@return number
+            function F()
+                while true do
+                    for _ = 1, 10 do
+                        break
+                    end
+                end
+            end
+            "#
+        ));
+
+        assert!(ws.check_code_for(
+            DiagnosticCode::MissingReturn,
+            r#"
+            local A
+            ---@return number
+            function F()
+                repeat
+                    if A then
+                        return 1
+                    end
+                until false
+            end
+            "#
+        ));
+
+        assert!(ws.check_code_for(
+            DiagnosticCode::MissingReturn,
+            r#"
+            local A
+            ---@return number
+            function F()
+                if A then
+                    return 1
+                end
+                error("unreachable")
+            end
+            "#
+        ));
+    }
+
+    #[test]
+    fn test_missing_return_loop_goto() {
+        let mut ws = VirtualWorkspace::new();
+
+        // 跳转到循环体内的标签不会离开循环
+        assert!(ws.check_code_for(
+            DiagnosticCode::MissingReturn,
+            r#"
+            local A
+            ---@return number
+            function F()
+                while true do
+                    if A then
+                        goto continue
+                    end
+                    if A == 1 then
+                        return 1
+                    end
+                    ::continue::
+                end
+            end
+            "#
+        ));
+
+        assert!(!ws.check_code_for(
+            DiagnosticCode::MissingReturn,
+            r#"
+            local A
+            ---@return number
+            function F()
+                while true do
+                    if A then
+                        goto done
+                    end
+                end
+                ::done::
+            end
+            "#
+        ));
+
+        assert!(ws.check_code_for(
+            DiagnosticCode::MissingReturn,
+            r#"
+            local A
+            ---@return number
+            function F()
+                while (true) do
+                    if A then
+                        return 1
+                    end
+                end
+            end
+            "#
+        ));
+
+        assert!(!ws.check_code_for(
+            DiagnosticCode::MissingReturn,
+            r#"
+            local A
+            ---@return number
+            function F()
+                local running = true
+                while running do
+                    running = A
+                end
+            end
+            "#
+        ));
+    }
+
     #[test]
     fn test_issue_236() {
         let mut ws = VirtualWorkspace::new_with_init_std_lib();
@@ -0,0 +1,69 @@
+#[cfg(test)]
+mod tests {
+    use crate::{DiagnosticCode, VirtualWorkspace};
+
+    #[test]
+    fn test_dot_call_colon_method() {
+        let mut ws = VirtualWorkspace::new();
+        ws.def(
+            r#"
+            ---@class ColonCallA
+            local A = {}
+
+            function A:method() end
+
+            function A.func() end
+
+            ColonCallA = A
+            "#,
+        );
+        assert!(!ws.check_code_for(
+            DiagnosticCode::ColonCallMismatch,
+            r#"
+            ColonCallA.method()
+            "#
+        ));
+        assert!(!ws.check_code_for(
+            DiagnosticCode::ColonCallMismatch,
+            r#"
+            ColonCallA.method(ColonCallA)
+            "#
+        ));
+        assert!(!ws.check_code_for(
+            DiagnosticCode::ColonCallMismatch,
+            r#"
+            ColonCallA:func()
+            "#
+        ));
+    }
+
+    #[test]
+    fn test_valid_call() {
+        let mut ws = VirtualWorkspace::new();
+        assert!(ws.check_code_for(
+            DiagnosticCode::ColonCallMismatch,
+            r#"
+            ---@class ColonCallB
+            local B = {}
+
+            function B:method() end
+
+            function B.func() end
+
+            ---@param self ColonCallB
+            function B.explicit_self(self) end
+
+            B:method()
+            B.func()
+            B:explicit_self()
+
+            ---@class ColonCallC: ColonCallB
+            local C = {}
+
+            function C:method()
+                B.method(self)
+            end
+            "#
+        ));
+    }
+}
@@ -0,0 +1,129 @@
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashSet, str::FromStr};
+
+    use emmylua_parser::LuaLanguageLevel;
+    use lsp_types::{DiagnosticSeverity, NumberOrString};
+    use tokio_util::sync::CancellationToken;
+
+    use crate::{DiagnosticCode, DiagnosticSeveritySetting, DiagnosticStats, VirtualWorkspace};
+
+    #[test]
+    fn test_all_codes() {
+        let codes = DiagnosticCode::all();
+        assert!(!codes.contains(&DiagnosticCode::None));
+
+        let mut names = HashSet::new();
+        for code in codes {
+            assert!(names.insert(code.get_name()));
+            assert_eq!(DiagnosticCode::from_str(code.get_name()), Ok(*code));
+            assert!(!code.get_description().is_empty());
+        }
+    }
+
+    #[test]
+    fn test_metadata() {
+        let metadata = DiagnosticCode::SyntaxError.metadata(LuaLanguageLevel::Lua54);
+        assert_eq!(metadata.name, "syntax-error");
+        assert_eq!(metadata.description, "Syntax error");
+        assert_eq!(metadata.default_severity, DiagnosticSeverity::ERROR);
+        assert!(metadata.default_enabled);
+
+        let metadata = DiagnosticCode::ShadowedLocal.metadata(LuaLanguageLevel::Lua54);
+        assert!(!metadata.default_enabled);
+    }
+
+    #[test]
+    fn test_severity_override() {
+        let mut ws = VirtualWorkspace::new();
+        let mut emmyrc = ws.get_emmyrc();
+        emmyrc.diagnostics.severity.insert(
+            DiagnosticCode::ParamTypeMismatch,
+            DiagnosticSeveritySetting::Error,
+        );
+        ws.update_emmyrc(emmyrc);
+
+        let db = ws.analysis.compilation.get_db();
+        assert_eq!(
+            db.get_effective_severity(DiagnosticCode::ParamTypeMismatch),
+            DiagnosticSeverity::ERROR
+        );
+        assert_eq!(
+            db.get_effective_severity(DiagnosticCode::RedundantParameter),
+            DiagnosticSeverity::WARNING
+        );
+
+        let file_id = ws.def(
+            r#"
+            ---@param a string
+            local function f(a) end
+            f(1)
+            f("a", 2)
+            "#,
+        );
+        let diagnostics = ws
+            .analysis
+            .diagnose_file(file_id, CancellationToken::new())
+            .unwrap();
+        let get_severity = |code: DiagnosticCode| {
+            diagnostics
+                .iter()
+                .find(|diagnostic| {
+                    diagnostic.code == Some(NumberOrString::String(code.get_name().to_string()))
+                })
+                .and_then(|diagnostic| diagnostic.severity)
+        };
+        assert_eq!(
+            get_severity(DiagnosticCode::ParamTypeMismatch),
+            Some(DiagnosticSeverity::ERROR)
+        );
+        assert_eq!(
+            get_severity(DiagnosticCode::RedundantParameter),
+            Some(DiagnosticSeverity::WARNING)
+        );
+    }
+
+    #[test]
+    fn test_checker_stats() {
+        let mut ws = VirtualWorkspace::new();
+        let stats = ws.analysis.diagnostic.enable_stats(DiagnosticStats::new());
+        let file_id = ws.def(
+            r#"
+            local a = 1
+            "#,
+        );
+        ws.analysis.diagnose_file(file_id, CancellationToken::new());
+        ws.analysis.diagnose_file(file_id, CancellationToken::new());
+
+        let timings = stats.get_sorted_timings();
+        let unused = timings
+            .iter()
+            .find(|(name, _)| *name == "UnusedChecker")
+            .map(|(_, timing)| timing.file_count);
+        assert_eq!(unused, Some(2));
+    }
+
+    #[test]
+    fn test_checker_stats_with_codes() {
+        let mut ws = VirtualWorkspace::new();
+        let stats = ws
+            .analysis
+            .diagnostic
+            .enable_stats(DiagnosticStats::with_codes([
+                DiagnosticCode::UndefinedGlobal,
+            ]));
+        let file_id = ws.def(
+            r#"
+            print(a)
+            "#,
+        );
+        ws.analysis.diagnose_file(file_id, CancellationToken::new());
+
+        let names = stats
+            .get_sorted_timings()
+            .into_iter()
+            .map(|(name, _)| name)
+            .collect::<Vec<_>>();
+        assert_eq!(names, vec!["UndefinedGlobalChecker"]);
+    }
+}
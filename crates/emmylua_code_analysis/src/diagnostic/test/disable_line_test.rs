@@ -34,4 +34,82 @@ mod test {
             "#
         ));
     }
+
+    #[test]
+    fn test_disable_enable_region() {
+        let mut ws = crate::VirtualWorkspace::new();
+
+        assert!(ws.check_code_for(
+            DiagnosticCode::UndefinedGlobal,
+            r#"
+            ---@diagnostic disable: undefined-global
+            local _a = undefined_a
+            "#
+        ));
+
+        assert!(!ws.check_code_for(
+            DiagnosticCode::UndefinedGlobal,
+            r#"
+            ---@diagnostic disable: undefined-global
+            local _a = undefined_a
+            ---@diagnostic enable: undefined-global
+            local _b = undefined_b
+            "#
+        ));
+
+        assert!(!ws.check_code_for(
+            DiagnosticCode::UndefinedGlobal,
+            r#"
+            ---@diagnostic disable
+            local _a = undefined_a
+            ---@diagnostic enable
+            local _b = undefined_b
+            "#
+        ));
+
+        // 不带诊断代码的 enable 也会结束指定代码的禁用
+        assert!(!ws.check_code_for(
+            DiagnosticCode::UndefinedGlobal,
+            r#"
+            ---@diagnostic disable: undefined-global
+            local _a = undefined_a
+            ---@diagnostic enable
+            local _b = undefined_b
+            "#
+        ));
+
+        assert!(!ws.check_code_for(
+            DiagnosticCode::UndefinedGlobal,
+            r#"
+            local function f()
+                ---@diagnostic disable: undefined-global
+                local _a = undefined_a
+                ---@diagnostic enable
+                local _b = undefined_b
+            end
+            "#
+        ));
+
+        // 禁用从注释开始, 不影响同一个块中前面的代码
+        assert!(!ws.check_code_for(
+            DiagnosticCode::UndefinedGlobal,
+            r#"
+            local function f()
+                local _a = undefined_a
+                ---@diagnostic disable: undefined-global
+                local _b = undefined_b
+            end
+            "#
+        ));
+
+        // 文件顶层中间的禁用同样只从注释开始
+        assert!(!ws.check_code_for(
+            DiagnosticCode::UndefinedGlobal,
+            r#"
+            local _a = undefined_a
+            ---@diagnostic disable: undefined-global
+            local _b = undefined_b
+            "#
+        ));
+    }
 }
@@ -0,0 +1,63 @@
+#[cfg(test)]
+mod test {
+    use crate::{DiagnosticCode, VirtualWorkspace};
+
+    #[test]
+    fn test_nodiscard() {
+        let mut ws = VirtualWorkspace::new();
+        ws.def(
+            r#"
+            ---@nodiscard
+            ---@return integer
+            function make_id() end
+            "#,
+        );
+        assert!(!ws.check_code_for(
+            DiagnosticCode::DiscardReturns,
+            r#"
+            make_id()
+            "#
+        ));
+        assert!(ws.check_code_for(
+            DiagnosticCode::DiscardReturns,
+            r#"
+            local id = make_id()
+            "#
+        ));
+    }
+
+    #[test]
+    fn test_missing_await() {
+        let mut ws = VirtualWorkspace::new();
+        ws.def(
+            r#"
+            ---@async
+            ---@return string
+            function fetch() end
+
+            ---@async
+            function sleep() end
+            "#,
+        );
+        assert!(!ws.check_code_for(
+            DiagnosticCode::MissingAwait,
+            r#"
+            ---@async
+            local function run()
+                fetch()
+            end
+            "#
+        ));
+        assert!(ws.check_code_for(
+            DiagnosticCode::MissingAwait,
+            r#"
+            ---@async
+            local function run()
+                local content = fetch()
+                sleep()
+                return content
+            end
+            "#
+        ));
+    }
+}
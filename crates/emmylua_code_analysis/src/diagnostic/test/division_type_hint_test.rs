@@ -0,0 +1,100 @@
+#[cfg(test)]
+mod tests {
+    use crate::{DiagnosticCode, EmmyrcLuaVersion, VirtualWorkspace};
+
+    #[test]
+    fn test_integer_expected() {
+        let mut ws = VirtualWorkspace::new();
+        assert!(!ws.check_code_for(
+            DiagnosticCode::DivisionTypeHint,
+            r#"
+            local total, count = 10, 3
+            ---@type integer
+            local avg = total / count
+            "#
+        ));
+        assert!(!ws.check_code_for(
+            DiagnosticCode::DivisionTypeHint,
+            r#"
+            ---@param n integer
+            local function take(n) end
+            take((10 / 3))
+            "#
+        ));
+        assert!(!ws.check_code_for(
+            DiagnosticCode::DivisionTypeHint,
+            r#"
+            ---@return integer
+            local function half(n)
+                return n / 2
+            end
+            "#
+        ));
+        assert!(!ws.check_code_for(
+            DiagnosticCode::DivisionTypeHint,
+            r#"
+            ---@class DivisionPoint
+            ---@field x integer
+            local p = {}
+            p.x = 10 / 3
+            "#
+        ));
+    }
+
+    #[test]
+    fn test_integer_literal_and_union() {
+        let mut ws = VirtualWorkspace::new();
+        assert!(!ws.check_code_for(
+            DiagnosticCode::DivisionTypeHint,
+            r#"
+            ---@type 1|2|3
+            local level = 10 / 3
+            "#
+        ));
+        assert!(!ws.check_code_for(
+            DiagnosticCode::DivisionTypeHint,
+            r#"
+            ---@param count integer?
+            local function f(count) end
+            f(10 / 3)
+            "#
+        ));
+        assert!(ws.check_code_for(
+            DiagnosticCode::DivisionTypeHint,
+            r#"
+            ---@type integer|string
+            local value = 10 / 3
+            "#
+        ));
+    }
+
+    #[test]
+    fn test_float_allowed() {
+        let mut ws = VirtualWorkspace::new();
+        assert!(ws.check_code_for(
+            DiagnosticCode::DivisionTypeHint,
+            r#"
+            ---@type number
+            local ratio = 10 / 3
+            ---@type integer
+            local floor = 10 // 3
+            local unknown = 10 / 3
+            "#
+        ));
+    }
+
+    #[test]
+    fn test_lua51() {
+        let mut ws = VirtualWorkspace::new();
+        let mut emmyrc = ws.get_emmyrc();
+        emmyrc.runtime.version = EmmyrcLuaVersion::Lua51;
+        ws.update_emmyrc(emmyrc);
+        assert!(ws.check_code_for(
+            DiagnosticCode::DivisionTypeHint,
+            r#"
+            ---@type integer
+            local avg = 10 / 3
+            "#
+        ));
+    }
+}
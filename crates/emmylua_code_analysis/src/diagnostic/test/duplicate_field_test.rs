@@ -50,6 +50,46 @@ mod test {
         ));
     }
 
+    #[test]
+    fn test_duplicate_doc_param() {
+        let mut ws = VirtualWorkspace::new();
+
+        assert!(!ws.check_code_for(
+            DiagnosticCode::DuplicateDocField,
+            r#"
+            ---@param a string
+            ---@param b number
+            ---@param a integer
+            local function f(a, b)
+            end
+            "#
+        ));
+
+        assert!(ws.check_code_for(
+            DiagnosticCode::DuplicateDocField,
+            r#"
+            ---@param a string
+            local function f(a)
+                ---@param a number
+                return function(a) end
+            end
+            "#
+        ));
+
+        // 最后一个定义生效
+        assert!(!ws.check_code_for(
+            DiagnosticCode::ParamTypeMismatch,
+            r#"
+            ---@param a string
+            ---@param a integer
+            local function f(a)
+            end
+
+            f("1")
+            "#
+        ));
+    }
+
     #[test]
     fn test_duplicate_function_1() {
         let mut ws = VirtualWorkspace::new();
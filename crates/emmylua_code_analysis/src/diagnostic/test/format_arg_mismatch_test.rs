@@ -0,0 +1,146 @@
+#[cfg(test)]
+mod tests {
+    use crate::{DiagnosticCode, VirtualWorkspace};
+
+    #[test]
+    fn test_format_arg_count() {
+        let mut ws = VirtualWorkspace::new_with_init_std_lib();
+        assert!(ws.check_code_for(
+            DiagnosticCode::FormatArgMismatch,
+            r#"
+            local s = string.format("%s: %d%%", "count", 1)
+            "#
+        ));
+        assert!(!ws.check_code_for(
+            DiagnosticCode::FormatArgMismatch,
+            r#"
+            local s = string.format("%s: %d", "count")
+            "#
+        ));
+        assert!(!ws.check_code_for(
+            DiagnosticCode::FormatArgMismatch,
+            r#"
+            local s = string.format("%s", "a", "b")
+            "#
+        ));
+    }
+
+    #[test]
+    fn test_format_arg_type() {
+        let mut ws = VirtualWorkspace::new_with_init_std_lib();
+        assert!(!ws.check_code_for(
+            DiagnosticCode::FormatArgMismatch,
+            r#"
+            local s = string.format("%d", true)
+            "#
+        ));
+        assert!(!ws.check_code_for(
+            DiagnosticCode::FormatArgMismatch,
+            r#"
+            local s = string.format("%5.2f", "abc")
+            "#
+        ));
+        assert!(ws.check_code_for(
+            DiagnosticCode::FormatArgMismatch,
+            r#"
+            ---@param name string
+            local function f(name)
+                return string.format("%s %x %g", {}, "10", name)
+            end
+            "#
+        ));
+    }
+
+    #[test]
+    fn test_format_integer_arg() {
+        let mut ws = VirtualWorkspace::new_with_init_std_lib();
+        assert!(!ws.check_code_for(
+            DiagnosticCode::FormatArgMismatch,
+            r#"
+            local s = string.format("%d", 1.5)
+            "#
+        ));
+        assert!(!ws.check_code_for(
+            DiagnosticCode::FormatArgMismatch,
+            r#"
+            local s = string.format("%x", "2.5")
+            "#
+        ));
+        assert!(ws.check_code_for(
+            DiagnosticCode::FormatArgMismatch,
+            r#"
+            ---@param n number
+            local function f(n)
+                return string.format("%d %c %f", 2.0, n, 1.5)
+            end
+            "#
+        ));
+        assert!(!ws.check_code_for(
+            DiagnosticCode::FormatArgMismatch,
+            r#"
+            local s = string.format("%u %d", true, 1)
+            "#
+        ));
+    }
+
+    #[test]
+    fn test_format_method_call() {
+        let mut ws = VirtualWorkspace::new_with_init_std_lib();
+        assert!(!ws.check_code_for(
+            DiagnosticCode::FormatArgMismatch,
+            r#"
+            local s = ("%d items"):format()
+            "#
+        ));
+        assert!(ws.check_code_for(
+            DiagnosticCode::FormatArgMismatch,
+            r#"
+            local s = ("%d items"):format(3)
+            "#
+        ));
+    }
+
+    #[test]
+    fn test_format_multi_value_arg() {
+        let mut ws = VirtualWorkspace::new_with_init_std_lib();
+        assert!(ws.check_code_for(
+            DiagnosticCode::FormatArgMismatch,
+            r#"
+            local function f(...)
+                return string.format("%s %s %s", ...)
+            end
+            "#
+        ));
+        assert!(ws.check_code_for(
+            DiagnosticCode::FormatArgMismatch,
+            r#"
+            local fmt = "%d"
+            local s = string.format(fmt)
+            "#
+        ));
+        assert!(ws.check_code_for(
+            DiagnosticCode::FormatArgMismatch,
+            r#"
+            local s = string.format("%s", 1, ...)
+            "#
+        ));
+    }
+
+    #[test]
+    fn test_format_shadowed_string() {
+        let mut ws = VirtualWorkspace::new_with_init_std_lib();
+        assert!(ws.check_code_for(
+            DiagnosticCode::FormatArgMismatch,
+            r#"
+            local string = { format = function(fmt) return fmt end }
+            local s = string.format("%d %d")
+            "#
+        ));
+        assert!(!ws.check_code_for(
+            DiagnosticCode::FormatArgMismatch,
+            r#"
+            local s = string.format("%d %d")
+            "#
+        ));
+    }
+}
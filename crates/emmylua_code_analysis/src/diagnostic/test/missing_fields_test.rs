@@ -259,4 +259,44 @@ foo({})
         "#
         ));
     }
+
+    #[test]
+    fn test_inherited_default_field() {
+        let mut ws = VirtualWorkspace::new();
+        assert!(ws.check_code_for(
+            DiagnosticCode::MissingFields,
+            r#"
+            ---@class DefaultBase
+            ---@field name string
+            local DefaultBase = {}
+            DefaultBase.count = 0
+
+            ---@class DefaultChild: DefaultBase
+            ---@field id integer
+
+            ---@type DefaultChild
+            local child = {
+                name = "child",
+                id = 1,
+            }
+        "#
+        ));
+
+        assert!(!ws.check_code_for(
+            DiagnosticCode::MissingFields,
+            r#"
+            ---@class DefaultBase2
+            ---@field name string
+            local DefaultBase2 = {}
+            DefaultBase2.count = 0
+
+            ---@class DefaultChild2: DefaultBase2
+
+            ---@type DefaultChild2
+            local child = {
+                count = 1,
+            }
+        "#
+        ));
+    }
 }
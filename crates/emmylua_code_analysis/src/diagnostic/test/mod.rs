@@ -1,28 +1,43 @@
 mod access_invisible_test;
+mod array_element_mismatch_test;
+mod assign_nil_to_non_nil_test;
 mod assign_type_mismatch_test;
 mod await_in_sync_test;
 mod call_non_callable_test;
+mod call_possibly_nil_test;
 mod cast_type_mismatch_test;
 mod check_return_count_test;
 mod code_style;
+mod colon_call_mismatch_test;
+mod diagnostic_code_test;
 mod disable_line_test;
+mod discard_returns_test;
+mod division_type_hint_test;
 mod duplicate_field_test;
 mod duplicate_index_test;
 mod duplicate_require_test;
 mod enum_value_mismatch_test;
+mod format_arg_mismatch_test;
 mod generic_constraint_mismatch_test;
 mod global_in_non_module_test;
 mod incomplete_signature_doc_test;
 mod inject_field_test;
 mod missing_fields_test;
 mod missing_parameter_test;
+mod naming_convention_test;
 mod need_check_nil_test;
 mod param_type_check_test;
+mod partial_member_conflict_test;
 mod readonly_check;
+mod recursive_alias_test;
 mod redefined_local_test;
+mod redundant_annotation_test;
 mod redundant_parameter_test;
 mod require_module_visibility_test;
 mod return_type_mismatch_test;
+mod shadowed_local_test;
+mod silent_globs_test;
+mod suspicious_comparison_test;
 mod syntax_error_test;
 mod unbalanced_assignments_test;
 mod undefined_doc_param_test;
@@ -31,5 +46,9 @@ mod undefined_global_test;
 mod unknown_doc_tag;
 mod unnecessary_assert_test;
 mod unnecessary_if_test;
+mod unreachable_code_test;
 mod unresolved_require_test;
+mod unused_export_test;
+mod unused_require_test;
 mod unused_test;
+mod yield_outside_coroutine_test;
@@ -0,0 +1,70 @@
+#[cfg(test)]
+mod tests {
+    use lsp_types::NumberOrString;
+    use tokio_util::sync::CancellationToken;
+
+    use crate::{DiagnosticCode, VirtualWorkspace};
+
+    fn naming_violations(ws: &mut VirtualWorkspace, block_str: &str) -> Vec<String> {
+        let file_id = ws.def(block_str);
+        let code = Some(NumberOrString::String(
+            DiagnosticCode::NamingConvention.get_name().to_string(),
+        ));
+        ws.analysis
+            .diagnose_file(file_id, CancellationToken::new())
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|diagnostic| diagnostic.code == code)
+            .map(|diagnostic| diagnostic.message)
+            .collect()
+    }
+
+    #[test]
+    fn test_naming_convention() {
+        let mut ws = VirtualWorkspace::new();
+        let mut emmyrc = ws.get_emmyrc();
+        emmyrc.diagnostics.naming_convention.local = Some("^[a-z_][a-zA-Z0-9_]*$".to_string());
+        emmyrc.diagnostics.naming_convention.class = Some("^[A-Z][a-zA-Z0-9]*$".to_string());
+        emmyrc.diagnostics.naming_convention.constant = Some("^[A-Z][A-Z0-9_]*$".to_string());
+        ws.update_emmyrc(emmyrc);
+
+        let messages = naming_violations(
+            &mut ws,
+            r#"
+            ---@class my_class
+            local MyClass = {}
+
+            ---@class Player
+            local Player = {}
+
+            local BadName = 1
+            local goodName = 2
+            local MAX_SIZE <const> = 10
+            local max_size <const> = 10
+            "#,
+        );
+
+        assert_eq!(
+            messages,
+            vec![
+                "Class `my_class` does not match the naming pattern `^[A-Z][a-zA-Z0-9]*$`",
+                "Local variable `BadName` does not match the naming pattern `^[a-z_][a-zA-Z0-9_]*$`",
+                "Constant `max_size` does not match the naming pattern `^[A-Z][A-Z0-9_]*$`",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_naming_convention_without_patterns() {
+        let mut ws = VirtualWorkspace::new();
+        let messages = naming_violations(
+            &mut ws,
+            r#"
+            ---@class my_class
+            local Bad_Name = 1
+            "#,
+        );
+
+        assert!(messages.is_empty());
+    }
+}
@@ -1555,4 +1555,101 @@ mod test {
         "#,
         ));
     }
+
+    #[test]
+    fn test_enum_keys_type() {
+        let mut ws = VirtualWorkspace::new();
+        ws.def(
+            r#"
+            ---@enum Color
+            Color = {
+                Red = 1,
+                Green = 2,
+            }
+
+            ---@param name Color.keys
+            function get_color(name)
+            end
+            "#,
+        );
+
+        assert!(ws.check_code_for(
+            DiagnosticCode::ParamTypeMismatch,
+            r#"
+            get_color("Red")
+            "#,
+        ));
+        assert!(!ws.check_code_for(
+            DiagnosticCode::ParamTypeMismatch,
+            r#"
+            get_color("Blue")
+            "#,
+        ));
+        assert!(ws.check_code_for(
+            DiagnosticCode::TypeNotFound,
+            r#"
+            ---@type Color.keys
+            local name
+            "#,
+        ));
+    }
+
+    #[test]
+    fn test_param_type_mismatch_member_path() {
+        let mut ws = VirtualWorkspace::new();
+        ws.enable_check(DiagnosticCode::ParamTypeMismatch);
+        let file_id = ws.def(
+            r#"
+            ---@class Inner
+            ---@field value string
+
+            ---@class Outer
+            ---@field inner Inner
+
+            ---@param o Outer
+            local function f(o) end
+
+            ---@type { inner: { value: integer } }
+            local arg
+
+            f(arg)
+            "#,
+        );
+
+        let diagnostics = ws
+            .analysis
+            .diagnose_file(file_id, tokio_util::sync::CancellationToken::new())
+            .unwrap();
+        let code = Some(lsp_types::NumberOrString::String(
+            DiagnosticCode::ParamTypeMismatch.get_name().to_string(),
+        ));
+        let diagnostic = diagnostics
+            .iter()
+            .find(|diag| diag.code == code)
+            .expect("param type mismatch should be reported");
+        assert!(
+            diagnostic
+                .message
+                .contains("Member `inner.value` does not match."),
+            "{}",
+            diagnostic.message
+        );
+    }
+
+    #[test]
+    fn test_colon_call_on_nullable_self_field() {
+        let mut ws = VirtualWorkspace::new();
+        assert!(ws.check_code_for(
+            DiagnosticCode::ParamTypeMismatch,
+            r#"
+            ---@class ChainCall
+            ---@field get fun(self: self, a: number): ChainCall?
+
+            ---@type ChainCall
+            local A
+
+            local _a = A:get(1):get(2):get(3)
+            "#
+        ));
+    }
 }
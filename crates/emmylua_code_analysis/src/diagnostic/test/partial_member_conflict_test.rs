@@ -0,0 +1,104 @@
+#[cfg(test)]
+mod tests {
+    use crate::{DiagnosticCode, VirtualWorkspace};
+    use tokio_util::sync::CancellationToken;
+
+    #[test]
+    fn test_partial_member_conflict() {
+        let mut ws = VirtualWorkspace::new();
+        ws.def_file(
+            "a.lua",
+            r#"
+            ---@class (partial) Foo
+            ---@field name string
+            local Foo = {}
+            "#,
+        );
+        assert!(!ws.check_code_for(
+            DiagnosticCode::PartialMemberConflict,
+            r#"
+            ---@class (partial) Foo
+            ---@field name integer
+            local Foo = {}
+            "#
+        ));
+    }
+
+    #[test]
+    fn test_partial_member_same_type() {
+        let mut ws = VirtualWorkspace::new();
+        ws.def_file(
+            "a.lua",
+            r#"
+            ---@class (partial) Foo
+            ---@field name string
+            local Foo = {}
+            "#,
+        );
+        let code = r#"
+            ---@class (partial) Foo
+            ---@field name string
+            local Foo = {}
+            "#;
+        assert!(ws.check_code_for(DiagnosticCode::PartialMemberConflict, code));
+        assert!(ws.check_code_for(DiagnosticCode::DuplicateDocField, code));
+    }
+
+    #[test]
+    fn test_partial_members_merged() {
+        let mut ws = VirtualWorkspace::new();
+        ws.def_file(
+            "a.lua",
+            r#"
+            ---@class (partial) Foo
+            ---@field name string
+            "#,
+        );
+        ws.def_file(
+            "b.lua",
+            r#"
+            ---@class (partial) Foo
+            ---@field age integer
+            "#,
+        );
+        assert!(ws.check_code_for(
+            DiagnosticCode::UndefinedField,
+            r#"
+            ---@type Foo
+            local foo
+
+            local name = foo.name
+            local age = foo.age
+            "#
+        ));
+    }
+
+    #[test]
+    fn test_partial_member_conflict_related_other_file() {
+        let mut ws = VirtualWorkspace::new();
+        let a_file = ws.def_file(
+            "a.lua",
+            "---@class (partial) Foo\n---@field name string\nlocal Foo = {}\n",
+        );
+        let b_file = ws.def_file(
+            "b.lua",
+            "local x = 1\n\n---@class (partial) Foo\n---@field name integer\nlocal Foo = {}\n",
+        );
+        ws.analysis
+            .diagnostic
+            .enable_only(DiagnosticCode::PartialMemberConflict);
+        let diagnostics = ws
+            .analysis
+            .diagnose_file(b_file, CancellationToken::new())
+            .unwrap();
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].range.start.line, 3);
+
+        // 相关信息指向另一个文件中的声明
+        let related = diagnostics[0].related_information.as_ref().unwrap();
+        assert_eq!(related.len(), 1);
+        let a_uri = ws.analysis.compilation.get_db().get_vfs().get_uri(&a_file);
+        assert_eq!(Some(related[0].location.uri.clone()), a_uri);
+        assert_eq!(related[0].location.range.start.line, 1);
+    }
+}
@@ -0,0 +1,34 @@
+#[cfg(test)]
+mod tests {
+    use crate::{DiagnosticCode, VirtualWorkspace};
+
+    #[test]
+    fn test_recursive_alias() {
+        let mut ws = VirtualWorkspace::new();
+        assert!(!ws.check_code_for(
+            DiagnosticCode::RecursiveAlias,
+            r#"
+            ---@alias RecA RecB
+            ---@alias RecB RecA
+            "#
+        ));
+        assert!(!ws.check_code_for(
+            DiagnosticCode::RecursiveAlias,
+            r#"
+            ---@alias RecSelf RecSelf
+            "#
+        ));
+    }
+
+    #[test]
+    fn test_valid_alias() {
+        let mut ws = VirtualWorkspace::new();
+        assert!(ws.check_code_for(
+            DiagnosticCode::RecursiveAlias,
+            r#"
+            ---@alias Json string|number|boolean|Json[]|table<string, Json>
+            ---@alias JsonValue Json
+            "#
+        ));
+    }
+}
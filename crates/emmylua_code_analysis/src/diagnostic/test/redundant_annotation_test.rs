@@ -0,0 +1,70 @@
+#[cfg(test)]
+mod tests {
+    use crate::{DiagnosticCode, VirtualWorkspace};
+
+    #[test]
+    fn test_redundant_annotation() {
+        let mut ws = VirtualWorkspace::new();
+        ws.def(
+            r#"
+            ---@return string
+            function get_name() end
+            "#,
+        );
+        assert!(!ws.check_code_for(
+            DiagnosticCode::RedundantAnnotation,
+            r#"
+            ---@type string
+            local name = get_name()
+            "#
+        ));
+    }
+
+    #[test]
+    fn test_widen_annotation() {
+        let mut ws = VirtualWorkspace::new();
+        assert!(ws.check_code_for(
+            DiagnosticCode::RedundantAnnotation,
+            r#"
+            ---@type number
+            local a = 1
+
+            ---@type string?
+            local b = "hello"
+            "#
+        ));
+    }
+
+    #[test]
+    fn test_narrow_annotation() {
+        let mut ws = VirtualWorkspace::new();
+        ws.def(
+            r#"
+            ---@return string|number
+            function get_value() end
+            "#,
+        );
+        assert!(ws.check_code_for(
+            DiagnosticCode::RedundantAnnotation,
+            r#"
+            ---@type string
+            local value = get_value()
+            "#
+        ));
+    }
+
+    #[test]
+    fn test_table_annotation() {
+        let mut ws = VirtualWorkspace::new();
+        assert!(ws.check_code_for(
+            DiagnosticCode::RedundantAnnotation,
+            r#"
+            ---@class RedundantA
+            ---@field a integer
+
+            ---@type RedundantA
+            local a = { a = 1 }
+            "#
+        ));
+    }
+}
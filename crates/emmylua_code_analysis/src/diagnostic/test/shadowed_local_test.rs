@@ -0,0 +1,115 @@
+#[cfg(test)]
+mod tests {
+    use crate::{DiagnosticCode, VirtualWorkspace};
+
+    #[test]
+    fn test_shadowed_local() {
+        let mut ws = VirtualWorkspace::new();
+        assert!(!ws.check_code_for(
+            DiagnosticCode::ShadowedLocal,
+            r#"
+            local x = 1
+            local function f()
+                local x = 2
+                return x
+            end
+            "#
+        ));
+    }
+
+    #[test]
+    fn test_shadowed_param() {
+        let mut ws = VirtualWorkspace::new();
+        assert!(!ws.check_code_for(
+            DiagnosticCode::ShadowedLocal,
+            r#"
+            local function f(a)
+                if a then
+                    local a = 1
+                    return a
+                end
+            end
+            "#
+        ));
+    }
+
+    #[test]
+    fn test_not_shadowed() {
+        let mut ws = VirtualWorkspace::new();
+        assert!(ws.check_code_for(
+            DiagnosticCode::ShadowedLocal,
+            r#"
+            local function f()
+                local y = 1
+                return y
+            end
+
+            local function g()
+                local y = 2
+                return y
+            end
+
+            local z = 1
+            local z = 2
+            "#
+        ));
+    }
+
+    #[test]
+    fn test_hoist_pattern() {
+        let mut ws = VirtualWorkspace::new();
+        assert!(ws.check_code_for(
+            DiagnosticCode::ShadowedLocal,
+            r#"
+            local print = print
+            local function f()
+                local print = print
+                print(1)
+            end
+            "#
+        ));
+
+        let mut emmyrc = ws.get_emmyrc();
+        emmyrc.diagnostics.shadowed_local_ignore_hoist = false;
+        ws.update_emmyrc(emmyrc);
+        assert!(!ws.check_code_for(
+            DiagnosticCode::ShadowedLocal,
+            r#"
+            local value = 1
+            local function f()
+                local value = value
+                return value
+            end
+            "#
+        ));
+    }
+
+    #[test]
+    fn test_underscore_prefix() {
+        let mut ws = VirtualWorkspace::new();
+        assert!(ws.check_code_for(
+            DiagnosticCode::ShadowedLocal,
+            r#"
+            local _ = 1
+            local function f()
+                local _ = 2
+                return _
+            end
+            "#
+        ));
+
+        let mut emmyrc = ws.get_emmyrc();
+        emmyrc.diagnostics.shadowed_local_ignore_underscore = false;
+        ws.update_emmyrc(emmyrc);
+        assert!(!ws.check_code_for(
+            DiagnosticCode::ShadowedLocal,
+            r#"
+            local _tmp = 1
+            local function f()
+                local _tmp = 2
+                return _tmp
+            end
+            "#
+        ));
+    }
+}
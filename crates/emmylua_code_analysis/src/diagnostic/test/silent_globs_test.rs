@@ -0,0 +1,47 @@
+#[cfg(test)]
+mod tests {
+    use tokio_util::sync::CancellationToken;
+
+    use crate::{DiagnosticCode, VirtualWorkspace};
+
+    #[test]
+    fn test_silent_globs() {
+        let mut ws = VirtualWorkspace::new();
+        let mut emmyrc = ws.get_emmyrc();
+        emmyrc.workspace.silent_globs = vec!["vendor/**".to_string()];
+        ws.update_emmyrc(emmyrc);
+        ws.analysis
+            .diagnostic
+            .enable_only(DiagnosticCode::UndefinedGlobal);
+
+        let vendor_file = ws.def_file(
+            "vendor/lib.lua",
+            r#"
+            ---@class VendorLib
+            ---@field name string
+            VendorLib = {}
+            print(undefined_in_vendor)
+            "#,
+        );
+        assert!(ws.analysis.compilation.get_db().is_ignored(&vendor_file));
+        assert!(
+            ws.analysis
+                .diagnose_file(vendor_file, CancellationToken::new())
+                .is_none()
+        );
+
+        // 被忽略的文件仍然参与类型推断
+        assert!(ws.check_code_for(
+            DiagnosticCode::UndefinedGlobal,
+            r#"
+            local name = VendorLib.name
+            "#
+        ));
+        assert!(!ws.check_code_for(
+            DiagnosticCode::UndefinedGlobal,
+            r#"
+            print(undefined_in_main)
+            "#
+        ));
+    }
+}
@@ -0,0 +1,104 @@
+#[cfg(test)]
+mod tests {
+    use crate::{DiagnosticCode, VirtualWorkspace};
+
+    #[test]
+    fn test_incompatible_types() {
+        let mut ws = VirtualWorkspace::new();
+        assert!(!ws.check_code_for(
+            DiagnosticCode::SuspiciousComparison,
+            r#"
+            ---@type number
+            local status
+            if status == "1" then
+            end
+            "#
+        ));
+        assert!(!ws.check_code_for(
+            DiagnosticCode::SuspiciousComparison,
+            r#"
+            ---@type "open"|"closed"
+            local state
+            local _ = state ~= "opened" and state ~= true
+            "#
+        ));
+    }
+
+    #[test]
+    fn test_compatible_types() {
+        let mut ws = VirtualWorkspace::new();
+        assert!(ws.check_code_for(
+            DiagnosticCode::SuspiciousComparison,
+            r#"
+            ---@type number
+            local count
+            ---@type integer?
+            local limit
+            ---@type "open"|"closed"
+            local state
+            local name = "a"
+            local _ = count == 1
+                or count == limit
+                or state == "open"
+                or name == "b"
+                or limit == nil
+            "#
+        ));
+    }
+
+    #[test]
+    fn test_unknown_types() {
+        let mut ws = VirtualWorkspace::new();
+        assert!(ws.check_code_for(
+            DiagnosticCode::SuspiciousComparison,
+            r#"
+            ---@type any
+            local value
+            ---@class SuspiciousObject
+            ---@type SuspiciousObject
+            local object
+            local _ = value == "1" or unknown_value == 1 or object == "a"
+            "#
+        ));
+    }
+
+    #[test]
+    fn test_enum_types() {
+        let mut ws = VirtualWorkspace::new();
+        ws.def(
+            r#"
+            ---@enum SuspiciousColor
+            SuspiciousColor = {
+                Red = 1,
+                Green = 2,
+            }
+            "#,
+        );
+        assert!(!ws.check_code_for(
+            DiagnosticCode::SuspiciousComparison,
+            r#"
+            ---@type SuspiciousColor
+            local color
+            if color == "red" then
+            end
+            "#
+        ));
+        assert!(!ws.check_code_for(
+            DiagnosticCode::SuspiciousComparison,
+            r#"
+            ---@type SuspiciousColor
+            local color
+            if color == 3 then
+            end
+            "#
+        ));
+        assert!(ws.check_code_for(
+            DiagnosticCode::SuspiciousComparison,
+            r#"
+            ---@type SuspiciousColor
+            local color
+            local _ = color == 1 or color == SuspiciousColor.Green
+            "#
+        ));
+    }
+}
@@ -0,0 +1,167 @@
+#[cfg(test)]
+mod tests {
+    use crate::{DiagnosticCode, VirtualWorkspace};
+
+    #[test]
+    fn test_after_error() {
+        let mut ws = VirtualWorkspace::new();
+        assert!(!ws.check_code_for(
+            DiagnosticCode::UnreachableCode,
+            r#"
+            local function f()
+                error("failed")
+                print(1)
+            end
+            "#
+        ));
+        assert!(!ws.check_code_for(
+            DiagnosticCode::UnreachableCode,
+            r#"
+            os.exit(1)
+            print(1)
+            "#
+        ));
+    }
+
+    #[test]
+    fn test_after_break() {
+        let mut ws = VirtualWorkspace::new();
+        assert!(!ws.check_code_for(
+            DiagnosticCode::UnreachableCode,
+            r#"
+            for i = 1, 10 do
+                break
+                print(i)
+            end
+            "#
+        ));
+    }
+
+    #[test]
+    fn test_if_all_branches_terminate() {
+        let mut ws = VirtualWorkspace::new();
+        assert!(!ws.check_code_for(
+            DiagnosticCode::UnreachableCode,
+            r#"
+            local function f(a)
+                if a then
+                    return 1
+                elseif a == nil then
+                    error("nil")
+                else
+                    return 2
+                end
+                print(a)
+            end
+            "#
+        ));
+    }
+
+    #[test]
+    fn test_conditional_terminator() {
+        let mut ws = VirtualWorkspace::new();
+        assert!(ws.check_code_for(
+            DiagnosticCode::UnreachableCode,
+            r#"
+            local function f(a)
+                if a then
+                    return 1
+                end
+                while a do
+                    error("loop")
+                end
+                print(a)
+                return 2
+            end
+            "#
+        ));
+    }
+
+    #[test]
+    fn test_label_after_terminator() {
+        let mut ws = VirtualWorkspace::new();
+        assert!(ws.check_code_for(
+            DiagnosticCode::UnreachableCode,
+            r#"
+            local function f(a)
+                for i = 1, a do
+                    if i == 1 then
+                        goto continue
+                    end
+                    do break end
+                    ::continue::
+                    print(i)
+                end
+            end
+            "#
+        ));
+        assert!(!ws.check_code_for(
+            DiagnosticCode::UnreachableCode,
+            r#"
+            local function f(a)
+                do return end
+                print(a)
+                ::done::
+                return a
+            end
+            "#
+        ));
+    }
+
+    #[test]
+    fn test_shadowed_exit_functions() {
+        let mut ws = VirtualWorkspace::new_with_init_std_lib();
+        assert!(!ws.check_code_for(
+            DiagnosticCode::UnreachableCode,
+            r#"
+            error("failed")
+            print(1)
+            "#
+        ));
+        assert!(ws.check_code_for(
+            DiagnosticCode::UnreachableCode,
+            r#"
+            local function error(message)
+                print(message)
+            end
+            error("logged")
+            print(1)
+            "#
+        ));
+        assert!(ws.check_code_for(
+            DiagnosticCode::UnreachableCode,
+            r#"
+            local os = { exit = function() end }
+            os.exit(1)
+            print(1)
+            "#
+        ));
+    }
+
+    #[test]
+    fn test_infinite_loop_with_inner_goto() {
+        let mut ws = VirtualWorkspace::new();
+        assert!(!ws.check_code_for(
+            DiagnosticCode::UnreachableCode,
+            r#"
+            local A
+            while true do
+                if A then
+                    goto continue
+                end
+                print(A)
+                ::continue::
+            end
+            print(1)
+            "#
+        ));
+        assert!(!ws.check_code_for(
+            DiagnosticCode::UnreachableCode,
+            r#"
+            repeat
+            until (false)
+            print(1)
+            "#
+        ));
+    }
+}
@@ -0,0 +1,149 @@
+#[cfg(test)]
+mod tests {
+    use lsp_types::NumberOrString;
+    use tokio_util::sync::CancellationToken;
+
+    use crate::{DiagnosticCode, FileId, VirtualWorkspace};
+
+    fn unused_export_count(ws: &mut VirtualWorkspace, module: &str, user: &str) -> usize {
+        ws.analysis
+            .diagnostic
+            .enable_only(DiagnosticCode::UnusedExport);
+        let file_ids = ws.def_files(vec![("mod_a.lua", module), ("user.lua", user)]);
+        count_unused_exports(ws, file_ids[0])
+    }
+
+    fn count_unused_exports(ws: &VirtualWorkspace, file_id: FileId) -> usize {
+        let code = Some(NumberOrString::String(
+            DiagnosticCode::UnusedExport.get_name().to_string(),
+        ));
+        ws.analysis
+            .diagnose_file(file_id, CancellationToken::new())
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|diagnostic| diagnostic.code == code)
+            .count()
+    }
+
+    #[test]
+    fn test_unused_export() {
+        let mut ws = VirtualWorkspace::new();
+        let count = unused_export_count(
+            &mut ws,
+            r#"
+            local M = {}
+
+            function M.used()
+            end
+
+            function M.unused()
+            end
+
+            M.value = 1
+
+            return M
+            "#,
+            r#"
+            local mod_a = require("mod_a")
+            mod_a.used()
+            "#,
+        );
+
+        assert_eq!(count, 2);
+    }
+
+    #[test]
+    fn test_unused_export_marked_public() {
+        let mut ws = VirtualWorkspace::new();
+        let count = unused_export_count(
+            &mut ws,
+            r#"
+            local M = {}
+
+            ---@public
+            function M.api()
+            end
+
+            ---@private
+            function M.helper()
+            end
+
+            return M
+            "#,
+            r#"
+            local mod_a = require("mod_a")
+            "#,
+        );
+
+        assert_eq!(count, 0);
+    }
+
+    #[test]
+    fn test_unused_export_after_update() {
+        let mut ws = VirtualWorkspace::new();
+        let module = r#"
+            local M = {}
+
+            function M.run()
+            end
+
+            return M
+            "#;
+        assert_eq!(
+            unused_export_count(&mut ws, module, "local mod_a = require(\"mod_a\")"),
+            1
+        );
+        assert_eq!(
+            unused_export_count(&mut ws, module, "require(\"mod_a\").run()"),
+            0
+        );
+        assert_eq!(
+            unused_export_count(&mut ws, module, "local mod_a = require(\"mod_a\")"),
+            1
+        );
+
+        // 模块文件变化后成员位置改变, 未变化的引用文件需要重新解析
+        let user = "require(\"mod_a\").run()";
+        assert_eq!(unused_export_count(&mut ws, module, user), 0);
+        let moved_module = r#"
+            local M = {}
+
+            -- moved
+            function M.run()
+            end
+
+            return M
+            "#;
+        let module_id = ws.def_file("mod_a.lua", moved_module);
+        assert_eq!(count_unused_exports(&ws, module_id), 0);
+
+        // 无关文件变化不影响结果
+        ws.def_file("other.lua", "local x = 1");
+        assert_eq!(count_unused_exports(&ws, module_id), 0);
+    }
+
+    #[test]
+    fn test_unused_export_ignores_module_table_passed_around() {
+        let mut ws = VirtualWorkspace::new();
+        // 只统计 `M.name` 形式的索引引用, 通过未标注类型的参数访问时仍然报告
+        let count = unused_export_count(
+            &mut ws,
+            r#"
+            local M = {}
+
+            function M.run()
+            end
+
+            return M
+            "#,
+            r#"
+            local function call(m)
+                m.run()
+            end
+            call(require("mod_a"))
+            "#,
+        );
+
+        assert_eq!(count, 1);
+    }
+}
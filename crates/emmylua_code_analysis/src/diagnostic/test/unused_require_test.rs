@@ -0,0 +1,64 @@
+#[cfg(test)]
+mod tests {
+    use lsp_types::NumberOrString;
+    use tokio_util::sync::CancellationToken;
+
+    use crate::{DiagnosticCode, VirtualWorkspace};
+
+    #[test]
+    fn test_unused_require() {
+        let mut ws = VirtualWorkspace::new();
+        ws.def_file("mod_a.lua", "return {}");
+
+        assert!(!ws.check_code_for(
+            DiagnosticCode::UnusedRequire,
+            r#"
+            local mod_a = require("mod_a")
+            "#
+        ));
+
+        assert!(ws.check_code_for(
+            DiagnosticCode::UnusedRequire,
+            r#"
+            local mod_a = require("mod_a")
+            mod_a.run()
+            "#
+        ));
+
+        assert!(ws.check_code_for(
+            DiagnosticCode::UnusedRequire,
+            r#"
+            require("mod_a")
+            local _mod_a = require("mod_a")
+            "#
+        ));
+    }
+
+    #[test]
+    fn test_unused_not_reported_twice() {
+        let mut ws = VirtualWorkspace::new();
+        ws.def_file("mod_a.lua", "return {}");
+        let file_id = ws.def(
+            r#"
+            local mod_a = require("mod_a")
+            "#,
+        );
+        let codes = ws
+            .analysis
+            .diagnose_file(file_id, CancellationToken::new())
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|diagnostic| diagnostic.code)
+            .collect::<Vec<_>>();
+        assert!(codes.contains(&NumberOrString::String("unused-require".to_string())));
+        assert!(!codes.contains(&NumberOrString::String("unused".to_string())));
+
+        // 关闭 `unused-require` 后仍然由 `unused` 报告
+        assert!(!ws.check_code_for(
+            DiagnosticCode::Unused,
+            r#"
+            local mod_a = require("mod_a")
+            "#
+        ));
+    }
+}
@@ -0,0 +1,48 @@
+#[cfg(test)]
+mod test {
+    use crate::DiagnosticCode;
+
+    #[test]
+    fn test_yield_outside_coroutine() {
+        let mut ws = crate::VirtualWorkspace::new_with_init_std_lib();
+
+        assert!(!ws.check_code_for(
+            DiagnosticCode::YieldOutsideCoroutine,
+            r#"
+            local function f()
+                coroutine.yield(1)
+            end
+            "#
+        ));
+
+        assert!(!ws.check_code_for(
+            DiagnosticCode::YieldOutsideCoroutine,
+            r#"
+            coroutine.yield()
+            "#
+        ));
+
+        assert!(ws.check_code_for(
+            DiagnosticCode::YieldOutsideCoroutine,
+            r#"
+            ---@async
+            local function f()
+                coroutine.yield(1)
+            end
+            "#
+        ));
+
+        assert!(ws.check_code_for(
+            DiagnosticCode::YieldOutsideCoroutine,
+            r#"
+            local co = coroutine.create(function()
+                coroutine.yield(1)
+            end)
+
+            local gen = coroutine.wrap(function()
+                coroutine.yield(2)
+            end)
+            "#
+        ));
+    }
+}
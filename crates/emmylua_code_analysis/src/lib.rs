@@ -161,7 +161,8 @@ impl EmmyLuaAnalysis {
         let mut updated_files = HashSet::new();
         {
             let _p = Profile::new("update files");
-            for (uri, text) in files {
+            let total = files.len();
+            for (i, (uri, text)) in files.into_iter().enumerate() {
                 let is_new_text = text.is_some();
                 let file_id = self
                     .compilation
@@ -172,6 +173,9 @@ impl EmmyLuaAnalysis {
                 if is_new_text {
                     updated_files.insert(file_id);
                 }
+                if let Some(progress) = self.compilation.get_index_progress() {
+                    progress.report(IndexPhase::Parse, i + 1, total);
+                }
             }
         }
         self.compilation
@@ -290,6 +294,14 @@ impl EmmyLuaAnalysis {
         self.diagnostic.update_config(config);
     }
 
+    /// 子目录中 `.emmyrc.json` 的配置, 见 `load_directory_configs`
+    pub fn update_directory_configs(&mut self, configs: Vec<(PathBuf, Arc<Emmyrc>)>) {
+        self.diagnostic.update_directory_configs(&configs);
+        self.compilation
+            .get_db_mut()
+            .update_directory_configs(configs);
+    }
+
     pub fn get_emmyrc(&self) -> Arc<Emmyrc> {
         self.emmyrc.clone()
     }
@@ -474,4 +486,112 @@ mod tests {
 
         assert_eq!(analysis.reindex_count, 1);
     }
+
+    #[test]
+    fn update_files_reports_index_progress() {
+        let mut analysis = EmmyLuaAnalysis::new();
+        let workspace_root = std::env::current_dir().unwrap();
+        analysis.add_main_workspace(workspace_root.clone());
+
+        let reports = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let sink_reports = reports.clone();
+        analysis
+            .compilation
+            .set_index_progress(Some(IndexProgressSink::new(move |progress| {
+                sink_reports.lock().unwrap().push(progress);
+            })));
+        analysis.update_files_by_path(vec![
+            (
+                workspace_root.join("__index_progress_a.lua"),
+                Some("return 1\n".to_string()),
+            ),
+            (
+                workspace_root.join("__index_progress_b.lua"),
+                Some("return 2\n".to_string()),
+            ),
+        ]);
+
+        let reports = reports.lock().unwrap();
+        let parse_reports: Vec<_> = reports
+            .iter()
+            .filter(|progress| progress.phase == IndexPhase::Parse)
+            .map(|progress| (progress.done, progress.total))
+            .collect();
+        assert_eq!(parse_reports, vec![(1, 2), (2, 2)]);
+        let last = reports.last().unwrap();
+        assert_eq!(last.phase, IndexPhase::Analyze);
+        assert_eq!(last.done, last.total);
+    }
+
+    #[test]
+    fn directory_config_overrides_diagnostics() {
+        let mut analysis = EmmyLuaAnalysis::new();
+        let workspace_root = std::env::current_dir().unwrap();
+        let package_dir = workspace_root.join("__directory_config_test");
+        analysis.add_main_workspace(workspace_root.clone());
+
+        let mut emmyrc = Emmyrc::default();
+        emmyrc
+            .diagnostics
+            .disable
+            .push(DiagnosticCode::UndefinedGlobal);
+        analysis.update_config(Arc::new(emmyrc.clone()));
+        emmyrc.diagnostics.disable.clear();
+        analysis.update_directory_configs(vec![(package_dir.clone(), Arc::new(emmyrc))]);
+
+        let root_file = workspace_root.join("__directory_config_root.lua");
+        let package_file = package_dir.join("init.lua");
+        analysis.update_files_by_path(vec![
+            (
+                root_file.clone(),
+                Some("print(undefined_name)\n".to_string()),
+            ),
+            (
+                package_file.clone(),
+                Some("print(undefined_name)\n".to_string()),
+            ),
+        ]);
+
+        let has_undefined_global = |path: &PathBuf| {
+            let file_id = analysis
+                .get_file_id(&file_path_to_uri(path).unwrap())
+                .unwrap();
+            analysis
+                .diagnose_file(file_id, CancellationToken::new())
+                .unwrap_or_default()
+                .iter()
+                .any(|diagnostic| {
+                    diagnostic.code
+                        == Some(lsp_types::NumberOrString::String(
+                            DiagnosticCode::UndefinedGlobal.get_name().to_string(),
+                        ))
+                })
+        };
+        assert!(!has_undefined_global(&root_file));
+        assert!(has_undefined_global(&package_file));
+    }
+
+    #[test]
+    fn diagnose_file_skips_cancelled_run() {
+        let mut analysis = EmmyLuaAnalysis::new();
+        let workspace_root = std::env::current_dir().unwrap();
+        let file_path = workspace_root.join("__cancelled_diagnostic_test.lua");
+        analysis.add_main_workspace(workspace_root);
+        analysis.update_files_by_path(vec![(
+            file_path.clone(),
+            Some("print(undefined_name)\n".to_string()),
+        )]);
+        let file_id = analysis
+            .get_file_id(&file_path_to_uri(&file_path).unwrap())
+            .unwrap();
+
+        assert!(
+            analysis
+                .diagnose_file(file_id, CancellationToken::new())
+                .is_some()
+        );
+        let cancel_token = CancellationToken::new();
+        cancel_token.cancel();
+        assert!(analysis.diagnose_file(file_id, cancel_token).is_none());
+    }
 }
@@ -145,6 +145,7 @@ fn resolve_literal_operand(
 #[derive(Debug)]
 enum NumOrLen {
     Num(i64),
+    NumUnknown,
     Len,
     LenUnknown,
 }
@@ -177,6 +178,7 @@ fn instantiate_select_call(source: &LuaType, index: &LuaType) -> LuaType {
                 NumOrLen::LenUnknown
             }
         }
+        LuaType::Integer => NumOrLen::NumUnknown,
         _ => return LuaType::Unknown,
     };
 
@@ -203,6 +205,11 @@ fn instantiate_select_call(source: &LuaType, index: &LuaType) -> LuaType {
                 LuaType::Variadic(multi.into())
             }
         },
+        // 起始位置未知时, 同类型的可变参数仍然可以保留元素类型
+        NumOrLen::NumUnknown => match multi_return {
+            VariadicType::Base(_) => LuaType::Variadic(multi_return.clone().into()),
+            VariadicType::Multi(_) => source.clone(),
+        },
         NumOrLen::Len => {
             let len = multi_return.get_min_len();
             if let Some(len) = len {
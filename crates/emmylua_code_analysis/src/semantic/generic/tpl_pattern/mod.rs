@@ -10,8 +10,8 @@ use smol_str::SmolStr;
 
 use crate::{
     InferFailReason, LuaFunctionType, LuaMemberInfo, LuaMemberKey, LuaMemberOwner, LuaObjectType,
-    LuaSemanticDeclId, LuaTupleType, LuaTypeDeclId, LuaUnionType, SemanticDeclLevel, VariadicType,
-    check_type_compact,
+    LuaSemanticDeclId, LuaTupleType, LuaTypeDeclId, LuaUnionType, SemanticDeclLevel, TypeOps,
+    VariadicType, check_type_compact,
     db_index::{DbIndex, LuaGenericType, LuaType},
     infer_node_semantic_decl,
     semantic::{
@@ -306,6 +306,11 @@ fn array_tpl_pattern_match(
                 .ok_or(InferFailReason::None)?;
             tpl_pattern_match(context, base, &target_base)?;
         }
+        // 可空的数组, 例如 `string[]?`
+        LuaType::Union(_) if target.is_nullable() => {
+            let target = TypeOps::Remove.apply(context.db, target, &LuaType::Nil);
+            array_tpl_pattern_match(context, base, &target)?;
+        }
         _ => {}
     }
 
@@ -322,6 +327,11 @@ fn table_generic_tpl_pattern_match(
     }
 
     match target {
+        // 可空的表, 例如 `table<string, number>?`
+        LuaType::Union(_) if target.is_nullable() => {
+            let target = TypeOps::Remove.apply(context.db, target, &LuaType::Nil);
+            table_generic_tpl_pattern_match(context, table_generic_params, &target)?;
+        }
         LuaType::TableGeneric(target_table_generic_params) => {
             let min_len = table_generic_params
                 .len()
@@ -152,10 +152,11 @@ fn infer_buildin_or_ref_type(
         }
         _ => {
             let file_id = ctx.file_id;
-            let type_id = if let Some(name_type_decl) =
-                ctx.db.get_type_index().find_type_decl(file_id, name)
-            {
+            let type_index = ctx.db.get_type_index();
+            let type_id = if let Some(name_type_decl) = type_index.find_type_decl(file_id, name) {
                 name_type_decl.get_id()
+            } else if let Some(enum_id) = type_index.find_enum_keys_decl(file_id, name) {
+                return enum_keys_type(enum_id);
             } else {
                 LuaTypeDeclId::global(name)
             };
@@ -165,6 +166,13 @@ fn infer_buildin_or_ref_type(
     }
 }
 
+/// `MyEnum.keys` 对应的类型, 即 `keyof MyEnum`
+pub(crate) fn enum_keys_type(enum_id: LuaTypeDeclId) -> LuaType {
+    LuaType::Call(
+        LuaAliasCallType::new(LuaAliasCallKind::KeyOf, vec![LuaType::Ref(enum_id)]).into(),
+    )
+}
+
 fn infer_special_table_type(
     ctx: DocTypeInferContext<'_>,
     table_type: &LuaDocType,
@@ -11,7 +11,8 @@ use smol_str::SmolStr;
 
 use crate::{
     CacheEntry, GenericTpl, InFiled, InferGuardRef, LuaAliasCallKind, LuaDeclOrMemberId,
-    LuaInferCache, LuaInstanceType, LuaMemberOwner, LuaOperatorOwner, TypeOps,
+    LuaInferCache, LuaInstanceType, LuaMemberOwner, LuaOperatorOwner, TypeOps, TypeVisitTrait,
+    build_self_type,
     db_index::{
         DbIndex, LuaGenericType, LuaIntersectionType, LuaMemberKey, LuaObjectType,
         LuaOperatorMetaMethod, LuaTupleType, LuaType, LuaTypeDeclId, LuaUnionType,
@@ -52,6 +53,7 @@ pub fn infer_index_expr(
         &InferGuard::new(),
     ) {
         Ok(member_type) => {
+            let member_type = instantiate_self_member_type(db, &prefix_type, member_type);
             if pass_flow {
                 return infer_member_type_pass_flow(
                     db,
@@ -93,6 +95,45 @@ pub fn infer_index_expr(
     Err(reason)
 }
 
+/// 类型为 `self` 的字段在通过具体的类访问时解析为该类, 如 `---@field next self`
+fn instantiate_self_member_type(
+    db: &DbIndex,
+    prefix_type: &LuaType,
+    member_type: LuaType,
+) -> LuaType {
+    // `self` 也可能出现在函数、数组等类型内部, 例如 `fun(): self`
+    let mut contain_self = false;
+    member_type.visit_type(&mut |typ| contain_self |= typ.is_self_infer());
+    if !contain_self {
+        return member_type;
+    }
+
+    let Some(self_type) = get_prefix_self_type(db, prefix_type) else {
+        return member_type;
+    };
+    let mut substitutor = TypeSubstitutor::new();
+    substitutor.add_self_type(self_type);
+    instantiate_type_generic(db, &member_type, &substitutor)
+}
+
+/// 联合类型忽略 `nil` 后逐个解析, 例如 `node.next.parent` 中 `node.next` 的类型为 `ListNode?`
+fn get_prefix_self_type(db: &DbIndex, prefix_type: &LuaType) -> Option<LuaType> {
+    match prefix_type {
+        LuaType::Ref(id) | LuaType::Def(id) => Some(build_self_type(db, &LuaType::Ref(id.clone()))),
+        LuaType::Generic(_) => Some(prefix_type.clone()),
+        LuaType::Union(union) => {
+            let self_types = union
+                .into_vec()
+                .iter()
+                .filter(|typ| !typ.is_nil())
+                .map(|typ| get_prefix_self_type(db, typ))
+                .collect::<Option<Vec<_>>>()?;
+            Some(LuaType::from_vec(self_types))
+        }
+        _ => None,
+    }
+}
+
 fn infer_member_type_pass_flow(
     db: &DbIndex,
     cache: &mut LuaInferCache,
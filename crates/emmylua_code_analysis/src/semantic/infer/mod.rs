@@ -18,6 +18,7 @@ use emmylua_parser::{
 use infer_binary::infer_binary_expr;
 use infer_call::infer_call_expr;
 pub use infer_call::infer_call_expr_func;
+pub(crate) use infer_doc_type::enum_keys_type;
 pub use infer_doc_type::{DocTypeInferContext, infer_doc_type};
 pub use infer_fail_reason::InferFailReason;
 pub use infer_index::infer_index_expr;
@@ -66,21 +67,7 @@ pub fn infer_expr(db: &DbIndex, cache: &mut LuaInferCache, expr: LuaExpr) -> Inf
     }
 
     cache.expr_cache.insert(key, CacheEntry::Ready);
-    let result_type = match expr {
-        LuaExpr::CallExpr(call_expr) => infer_call_expr(db, cache, call_expr),
-        LuaExpr::TableExpr(table_expr) => infer_table_expr(db, cache, table_expr),
-        LuaExpr::LiteralExpr(literal_expr) => infer_literal_expr(db, cache, literal_expr),
-        LuaExpr::BinaryExpr(binary_expr) => infer_binary_expr(db, cache, binary_expr),
-        LuaExpr::UnaryExpr(unary_expr) => infer_unary_expr(db, cache, unary_expr),
-        LuaExpr::ClosureExpr(closure_expr) => infer_closure_expr(db, cache, closure_expr),
-        LuaExpr::ParenExpr(paren_expr) => infer_expr(
-            db,
-            cache,
-            paren_expr.get_expr().ok_or(InferFailReason::None)?,
-        ),
-        LuaExpr::NameExpr(name_expr) => infer_name_expr(db, cache, name_expr),
-        LuaExpr::IndexExpr(index_expr) => infer_index_expr(db, cache, index_expr, true),
-    };
+    let result_type = infer_expr_by_kind(db, cache, expr);
 
     match &result_type {
         Ok(result_type) => {
@@ -112,6 +99,36 @@ pub fn infer_expr(db: &DbIndex, cache: &mut LuaInferCache, expr: LuaExpr) -> Inf
     result_type
 }
 
+/// 推断表达式本身的类型, 忽略绑定在表达式上的 `@as`. 结果不写入缓存
+pub fn infer_expr_without_cast(
+    db: &DbIndex,
+    cache: &mut LuaInferCache,
+    expr: LuaExpr,
+) -> InferResult {
+    match infer_expr_by_kind(db, cache, expr) {
+        Err(InferFailReason::None) | Err(InferFailReason::RecursiveInfer) => Ok(LuaType::Unknown),
+        result => result,
+    }
+}
+
+fn infer_expr_by_kind(db: &DbIndex, cache: &mut LuaInferCache, expr: LuaExpr) -> InferResult {
+    match expr {
+        LuaExpr::CallExpr(call_expr) => infer_call_expr(db, cache, call_expr),
+        LuaExpr::TableExpr(table_expr) => infer_table_expr(db, cache, table_expr),
+        LuaExpr::LiteralExpr(literal_expr) => infer_literal_expr(db, cache, literal_expr),
+        LuaExpr::BinaryExpr(binary_expr) => infer_binary_expr(db, cache, binary_expr),
+        LuaExpr::UnaryExpr(unary_expr) => infer_unary_expr(db, cache, unary_expr),
+        LuaExpr::ClosureExpr(closure_expr) => infer_closure_expr(db, cache, closure_expr),
+        LuaExpr::ParenExpr(paren_expr) => infer_expr(
+            db,
+            cache,
+            paren_expr.get_expr().ok_or(InferFailReason::None)?,
+        ),
+        LuaExpr::NameExpr(name_expr) => infer_name_expr(db, cache, name_expr),
+        LuaExpr::IndexExpr(index_expr) => infer_index_expr(db, cache, index_expr, true),
+    }
+}
+
 fn infer_literal_expr(db: &DbIndex, config: &LuaInferCache, expr: LuaLiteralExpr) -> InferResult {
     match expr.get_literal().ok_or(InferFailReason::None)? {
         LuaLiteralToken::Nil(_) => Ok(LuaType::Nil),
@@ -618,8 +618,10 @@ fn maybe_field_literal_eq_action(
 
     let right_type = infer_expr(db, cache, LuaExpr::LiteralExpr(literal_expr))?;
     let index = LuaIndexMemberExpr::IndexExpr(index_expr);
-    let mut opt_result = None;
-    let mut union_types = union_type.into_vec();
+    let union_types = union_type.into_vec();
+    // 判别字段一定等于字面量的分支, 以及判别字段可能等于字面量的分支
+    let mut always_equal = vec![false; union_types.len()];
+    let mut may_equal = vec![false; union_types.len()];
     for (i, sub_type) in union_types.iter().enumerate() {
         let member_type = match infer_member_by_member_key(
             db,
@@ -631,27 +633,46 @@ fn maybe_field_literal_eq_action(
             Ok(member_type) => member_type,
             Err(_) => continue, // If we cannot infer the member type, skip this type
         };
-        if always_literal_equal(&member_type, &right_type) {
-            // If the right type matches the member type, we can narrow it
-            opt_result = Some(i);
-        }
+        always_equal[i] = always_literal_equal(&member_type, &right_type);
+        may_equal[i] = maybe_literal_equal(&member_type, &right_type);
     }
 
-    match condition_flow {
-        InferConditionFlow::TrueCondition => {
-            if let Some(i) = opt_result {
-                return Ok(Some(ConditionFlowAction::Result(union_types[i].clone())));
-            }
-        }
-        InferConditionFlow::FalseCondition => {
-            if let Some(i) = opt_result {
-                union_types.remove(i);
-                return Ok(Some(ConditionFlowAction::Result(LuaType::from_vec(
-                    union_types,
-                ))));
-            }
-        }
+    if !always_equal.contains(&true) {
+        return Ok(None);
     }
 
-    Ok(None)
+    let narrowed_types = match condition_flow {
+        InferConditionFlow::TrueCondition => union_types
+            .into_iter()
+            .zip(may_equal)
+            .filter_map(|(sub_type, may_equal)| may_equal.then_some(sub_type))
+            .collect(),
+        InferConditionFlow::FalseCondition => union_types
+            .into_iter()
+            .zip(always_equal)
+            .filter_map(|(sub_type, always_equal)| (!always_equal).then_some(sub_type))
+            .collect(),
+    };
+
+    Ok(Some(ConditionFlowAction::Result(LuaType::from_vec(
+        narrowed_types,
+    ))))
+}
+
+/// 判别字段的类型是否可能等于字面量, 非字面量类型 (如 `string`) 视为可能相等
+fn maybe_literal_equal(member_type: &LuaType, literal_type: &LuaType) -> bool {
+    match member_type {
+        LuaType::Union(union) => union
+            .into_vec()
+            .iter()
+            .any(|candidate| maybe_literal_equal(candidate, literal_type)),
+        LuaType::StringConst(_)
+        | LuaType::DocStringConst(_)
+        | LuaType::BooleanConst(_)
+        | LuaType::DocBooleanConst(_)
+        | LuaType::IntegerConst(_)
+        | LuaType::DocIntegerConst(_) => always_literal_equal(member_type, literal_type),
+        LuaType::Nil => false,
+        _ => true,
+    }
 }
@@ -50,6 +50,48 @@ mod test {
         assert_eq!(ty, expected);
     }
 
+    #[test]
+    fn test_operator_overloads() {
+        let mut ws = VirtualWorkspace::new();
+
+        ws.def(
+            r#"
+            ---@class Vec2
+            ---@operator add(Vec2): Vec2
+            ---@operator sub(Vec2): Vec2
+            ---@operator mul(number): Vec2
+            ---@operator div(number): Vec2
+            ---@operator concat(string): string
+            ---@operator unm: Vec2
+            ---@operator lt(Vec2): boolean
+
+            ---@type Vec2
+            local a
+            ---@type Vec2
+            local b
+
+            A = a + b
+            B = a - b
+            C = a * 2
+            D = 2 * a
+            E = a / 2
+            F = a .. "!"
+            G = -a
+            H = a < b
+        "#,
+        );
+
+        let vec2 = ws.ty("Vec2");
+        assert_eq!(ws.expr_ty("A"), vec2);
+        assert_eq!(ws.expr_ty("B"), vec2);
+        assert_eq!(ws.expr_ty("C"), vec2);
+        assert_eq!(ws.expr_ty("D"), vec2);
+        assert_eq!(ws.expr_ty("E"), vec2);
+        assert_eq!(ws.expr_ty("F"), ws.ty("string"));
+        assert_eq!(ws.expr_ty("G"), vec2);
+        assert_eq!(ws.expr_ty("H"), ws.ty("boolean"));
+    }
+
     #[test]
     fn test_issue_867() {
         let mut ws = VirtualWorkspace::new();
@@ -23,12 +23,13 @@ use emmylua_parser::{
 pub use infer::infer_index_expr;
 use infer::{infer_bind_value_type, infer_expr_list_types};
 pub use infer::{infer_table_field_value_should_be, infer_table_should_be};
-use lsp_types::Uri;
+use lsp_types::{Location, Uri};
 pub use member::LuaMemberInfo;
 pub use member::find_index_operations;
 pub use member::get_member_map;
 use member::{find_member_origin_owner, find_members};
-use reference::is_reference_to;
+use reference::find_references;
+pub(crate) use reference::is_reference_to;
 use rowan::{NodeOrToken, TextRange};
 pub use semantic_info::SemanticInfo;
 pub(crate) use semantic_info::{infer_node_semantic_decl, resolve_global_decl_id};
@@ -36,12 +37,12 @@ use semantic_info::{
     infer_node_semantic_info, infer_token_semantic_decl, infer_token_semantic_info,
 };
 pub(crate) use type_check::check_type_compact;
-use type_check::is_sub_type_of;
+pub(crate) use type_check::is_sub_type_of;
 pub use visibility::check_export_visibility;
 use visibility::check_visibility;
 
 pub use crate::semantic::member::find_members_with_key;
-use crate::semantic::type_check::check_type_compact_detail;
+use crate::semantic::type_check::{check_type_compact_detail, check_type_compact_report};
 use crate::{Emmyrc, LuaDocument, LuaSemanticDeclId, ModuleInfo, db_index::LuaTypeDeclId};
 use crate::{
     FileId,
@@ -53,12 +54,14 @@ pub use guard::{InferGuard, InferGuardRef};
 pub use infer::InferFailReason;
 pub use infer::infer_call_expr_func;
 pub(crate) use infer::infer_expr;
+use infer::infer_expr_without_cast;
 pub use infer::infer_param;
 use overload_resolve::resolve_signature;
 pub use semantic_info::SemanticDeclLevel;
-pub use type_check::{TypeCheckFailReason, TypeCheckResult};
+pub use type_check::{TypeCheckFailReason, TypeCheckResult, TypeMismatchReport};
 
 pub use generic::get_keyof_members;
+pub(crate) use infer::enum_keys_type;
 pub use infer::{DocTypeInferContext, infer_doc_type};
 
 #[derive(Debug)]
@@ -127,6 +130,11 @@ impl<'a> SemanticModel<'a> {
         infer_expr(self.db, &mut self.infer_cache.borrow_mut(), expr)
     }
 
+    /// 推断表达式在 `--[[@as T]]` 之前的类型
+    pub fn infer_expr_without_cast(&self, expr: LuaExpr) -> Result<LuaType, InferFailReason> {
+        infer_expr_without_cast(self.db, &mut self.infer_cache.borrow_mut(), expr)
+    }
+
     pub fn infer_table_should_be(&self, table: LuaTableExpr) -> Option<LuaType> {
         infer_table_should_be(self.db, &mut self.infer_cache.borrow_mut(), table).ok()
     }
@@ -155,10 +163,30 @@ impl<'a> SemanticModel<'a> {
         check_type_compact(self.db, source, compact_type)
     }
 
+    /// `actual` 类型的值是否可以赋给 `expected` 类型, 与 `type_check(expected, actual).is_ok()` 相同.
+    /// 需要失败原因时使用 `type_check`
+    ///
+    /// # 注意
+    ///
+    /// 这是宽松的兼容性检查而不是严格的子类型关系: 与 `type_check` 一样, 父类的值也可以赋给子类,
+    /// 例如 `is_assignable(Animal, Dog)` 为 true. 因此双向都可赋值并不表示两个类型相同
+    pub fn is_assignable(&self, actual: &LuaType, expected: &LuaType) -> bool {
+        self.type_check(expected, actual).is_ok()
+    }
+
     pub fn type_check_detail(&self, source: &LuaType, compact_type: &LuaType) -> TypeCheckResult {
         check_type_compact_detail(self.db, source, compact_type)
     }
 
+    /// 与 `type_check_detail` 相同, 但额外返回不匹配成员的路径, 便于诊断定位到嵌套的表字段
+    pub fn type_check_detailed(
+        &self,
+        source: &LuaType,
+        compact_type: &LuaType,
+    ) -> Result<(), TypeMismatchReport> {
+        check_type_compact_report(self.db, source, compact_type)
+    }
+
     pub fn infer_call_expr_func(
         &self,
         call_expr: LuaCallExpr,
@@ -241,6 +269,15 @@ impl<'a> SemanticModel<'a> {
         .unwrap_or(false)
     }
 
+    /// 在整个工作区中查找引用, 包括读取, 写入以及注释中对类型的引用
+    pub fn find_references(
+        &self,
+        semantic_decl_id: LuaSemanticDeclId,
+        include_decl: bool,
+    ) -> Vec<Location> {
+        find_references(self.db, &semantic_decl_id, include_decl)
+    }
+
     pub fn is_semantic_visible(
         &self,
         token: LuaSyntaxToken,
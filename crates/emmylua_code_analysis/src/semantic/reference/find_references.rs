@@ -0,0 +1,420 @@
+use std::collections::{HashMap, HashSet};
+
+use emmylua_parser::{
+    LuaAssignStat, LuaAst, LuaAstNode, LuaAstToken, LuaCallExpr, LuaClosureExpr, LuaCommentOwner,
+    LuaDocTagParam, LuaLocalStat, LuaStat, LuaSyntaxNode, LuaTableField,
+};
+use lsp_types::Location;
+use rowan::TextRange;
+
+use crate::{
+    DbIndex, FileId, LuaDeclId, LuaInferCache, LuaMemberId, LuaSemanticDeclId, LuaType,
+    LuaTypeDeclId, SemanticDeclLevel,
+    semantic::{infer_expr, infer_node_semantic_decl},
+};
+
+use super::is_reference_to;
+
+/// 在整个工作区中查找引用, 包括读取、写入和注释中的引用.
+/// 局部变量和成员会继续查找通过赋值得到的别名以及 `require` 导入的模块导出.
+/// `include_decl` 为 `false` 时不包含被查找的声明本身
+pub fn find_references(
+    db: &DbIndex,
+    semantic_decl: &LuaSemanticDeclId,
+    include_decl: bool,
+) -> Vec<Location> {
+    let mut collector = ReferenceCollector::new(db);
+    match semantic_decl {
+        LuaSemanticDeclId::LuaDecl(_) | LuaSemanticDeclId::Member(_) => {
+            collector.enqueue(semantic_decl.clone());
+            let mut first = true;
+            while let Some(semantic_id) = collector.worklist.pop() {
+                // 别名的声明也是引用, 只有被查找的声明本身受 `include_decl` 控制
+                let include_decl = include_decl || !first;
+                first = false;
+                match semantic_id {
+                    LuaSemanticDeclId::LuaDecl(decl_id) => {
+                        collect_decl_references(&mut collector, decl_id, include_decl);
+                    }
+                    LuaSemanticDeclId::Member(member_id) => {
+                        collect_member_references(&mut collector, member_id, include_decl);
+                    }
+                    _ => {}
+                }
+            }
+        }
+        LuaSemanticDeclId::TypeDecl(type_decl_id) => {
+            collect_type_decl_references(&mut collector, type_decl_id, include_decl);
+        }
+        _ => {}
+    }
+
+    collector.locations
+}
+
+struct ReferenceCollector<'a> {
+    db: &'a DbIndex,
+    locations: Vec<Location>,
+    visited: HashSet<(FileId, TextRange)>,
+    excluded: Option<(FileId, TextRange)>,
+    worklist: Vec<LuaSemanticDeclId>,
+    visited_semantic_ids: HashSet<LuaSemanticDeclId>,
+    visited_module_exports: HashSet<FileId>,
+    infer_caches: HashMap<FileId, LuaInferCache>,
+}
+
+impl<'a> ReferenceCollector<'a> {
+    fn new(db: &'a DbIndex) -> Self {
+        Self {
+            db,
+            locations: Vec::new(),
+            visited: HashSet::new(),
+            excluded: None,
+            worklist: Vec::new(),
+            visited_semantic_ids: HashSet::new(),
+            visited_module_exports: HashSet::new(),
+            infer_caches: HashMap::new(),
+        }
+    }
+
+    fn add(&mut self, file_id: FileId, range: TextRange) -> Option<()> {
+        if self.excluded == Some((file_id, range)) || !self.visited.insert((file_id, range)) {
+            return None;
+        }
+
+        let document = self.db.get_vfs().get_document(&file_id)?;
+        self.locations.push(document.to_lsp_location(range)?);
+        Some(())
+    }
+
+    /// 声明本身: 需要时加入结果, 否则在之后的引用中跳过
+    fn add_decl(&mut self, file_id: FileId, range: TextRange, include_decl: bool) {
+        if include_decl {
+            self.add(file_id, range);
+        } else {
+            self.excluded = Some((file_id, range));
+        }
+    }
+
+    fn enqueue(&mut self, semantic_id: LuaSemanticDeclId) {
+        if self.visited_semantic_ids.insert(semantic_id.clone()) {
+            self.worklist.push(semantic_id);
+        }
+    }
+
+    fn get_root(&self, file_id: FileId) -> Option<LuaSyntaxNode> {
+        Some(self.db.get_vfs().get_syntax_tree(&file_id)?.get_red_root())
+    }
+
+    fn get_infer_cache(&mut self, file_id: FileId) -> &mut LuaInferCache {
+        self.infer_caches
+            .entry(file_id)
+            .or_insert_with(|| LuaInferCache::new(file_id, Default::default()))
+    }
+
+    fn find_decl(&mut self, file_id: FileId, node: LuaSyntaxNode) -> Option<LuaSemanticDeclId> {
+        let db = self.db;
+        infer_node_semantic_decl(
+            db,
+            self.get_infer_cache(file_id),
+            node,
+            SemanticDeclLevel::default(),
+        )
+    }
+}
+
+fn collect_decl_references(
+    collector: &mut ReferenceCollector,
+    decl_id: LuaDeclId,
+    include_decl: bool,
+) -> Option<()> {
+    let db = collector.db;
+    let decl = db.get_decl_index().get_decl(&decl_id)?;
+    collector.add_decl(decl_id.file_id, decl.get_range(), include_decl);
+    if !decl.is_local() {
+        let global_references = db
+            .get_reference_index()
+            .get_global_references(decl.get_name())?;
+        for reference in global_references {
+            collector.add(reference.file_id, reference.value.get_range());
+        }
+
+        return Some(());
+    }
+
+    if decl.is_param() {
+        collect_doc_param_references(collector, decl_id);
+    }
+
+    // 函数和表的值可能被赋给其他变量或成员
+    let should_follow_value_alias = matches!(
+        db.get_type_index()
+            .get_type_cache(&decl_id.into())
+            .map(|type_cache| type_cache.as_type()),
+        Some(
+            LuaType::Signature(_)
+                | LuaType::Table
+                | LuaType::TableConst(_)
+                | LuaType::Ref(_)
+                | LuaType::Def(_)
+        )
+    );
+    if let Some(decl_refs) = db
+        .get_reference_index()
+        .get_decl_references(&decl_id.file_id, &decl_id)
+    {
+        for cell in &decl_refs.cells {
+            collector.add(decl_id.file_id, cell.range);
+            if should_follow_value_alias {
+                enqueue_value_alias(collector, decl_id.file_id, cell.range);
+            }
+        }
+    }
+
+    collect_module_export_references(collector, decl_id);
+    Some(())
+}
+
+/// 参数在 `---@param` 中的名字
+fn collect_doc_param_references(
+    collector: &mut ReferenceCollector,
+    decl_id: LuaDeclId,
+) -> Option<()> {
+    let db = collector.db;
+    let decl = db.get_decl_index().get_decl(&decl_id)?;
+    let root = collector.get_root(decl_id.file_id)?;
+    let param_node = LuaAst::cast(decl.get_syntax_id().to_node_from_root(&root)?)?;
+    let closure_expr = param_node.ancestors::<LuaClosureExpr>().next()?;
+    let comments = if let Some(table_field) = closure_expr.get_parent::<LuaTableField>() {
+        table_field.get_comments()
+    } else {
+        closure_expr.ancestors::<LuaStat>().next()?.get_comments()
+    };
+
+    for comment in comments {
+        for doc_param in comment
+            .get_doc_tags()
+            .filter_map(|tag| LuaDocTagParam::cast(tag.syntax().clone()))
+        {
+            if let Some(name_token) = doc_param.get_name_token()
+                && name_token.get_name_text() == decl.get_name()
+            {
+                collector.add(decl_id.file_id, name_token.get_range());
+            }
+        }
+    }
+
+    Some(())
+}
+
+/// `x = value` 或 `{ x = value }` 中的成员是 `value` 的别名
+fn enqueue_value_alias(
+    collector: &mut ReferenceCollector,
+    file_id: FileId,
+    range: TextRange,
+) -> Option<()> {
+    let root = collector.get_root(file_id)?;
+    let position = range.start();
+    let token = root.token_at_offset(position).right_biased()?;
+    let value_node = token.parent()?.parent()?;
+    let var_node = if let Some(assign_stat) = LuaAssignStat::cast(value_node.clone()) {
+        let (vars, values) = assign_stat.get_var_and_expr_list();
+        let idx = values
+            .iter()
+            .position(|value| value.get_position() == position)?;
+        vars.get(idx)?.syntax().clone()
+    } else {
+        LuaTableField::cast(value_node)?.syntax().clone()
+    };
+
+    if let Some(LuaSemanticDeclId::Member(member_id)) = collector.find_decl(file_id, var_node) {
+        collector.enqueue(LuaSemanticDeclId::Member(member_id));
+    }
+
+    Some(())
+}
+
+fn collect_member_references(
+    collector: &mut ReferenceCollector,
+    member_id: LuaMemberId,
+    include_decl: bool,
+) -> Option<()> {
+    let db = collector.db;
+    let member = db.get_member_index().get_member(&member_id)?;
+    collector.add_decl(member_id.file_id, member.get_range(), include_decl);
+
+    // `---@field` 定义的成员也记录在索引引用中
+    let index_references = db
+        .get_reference_index()
+        .get_index_references(member.get_key())?;
+    let semantic_decl = LuaSemanticDeclId::Member(member_id);
+    for reference in index_references {
+        let file_id = reference.file_id;
+        let Some(root) = collector.get_root(file_id) else {
+            continue;
+        };
+        let Some(node) = reference.value.to_node_from_root(&root) else {
+            continue;
+        };
+        if is_reference_to(
+            db,
+            collector.get_infer_cache(file_id),
+            node.clone(),
+            semantic_decl.clone(),
+            SemanticDeclLevel::default(),
+        )
+        .unwrap_or(false)
+        {
+            collector.add(file_id, reference.value.get_range());
+            collect_member_value_alias(collector, file_id, node);
+        }
+    }
+
+    Some(())
+}
+
+/// `local x = t.m` 或 `x = t.m` 中的 `x` 是成员的别名
+fn collect_member_value_alias(
+    collector: &mut ReferenceCollector,
+    file_id: FileId,
+    node: LuaSyntaxNode,
+) -> Option<()> {
+    let position = node.text_range().start();
+    let parent = node.parent()?;
+    let (name_range, decl_position) = if let Some(assign_stat) = LuaAssignStat::cast(parent.clone())
+    {
+        let (vars, values) = assign_stat.get_var_and_expr_list();
+        let idx = values
+            .iter()
+            .position(|value| value.get_position() == position)?;
+        let var = vars.get(idx)?;
+        (var.get_range(), var.get_position())
+    } else {
+        let local_stat = LuaLocalStat::cast(parent)?;
+        let idx = local_stat
+            .get_value_exprs()
+            .position(|value| value.get_position() == position)?;
+        let name = local_stat.get_local_name_list().nth(idx)?;
+        (name.get_range(), name.get_position())
+    };
+
+    collector.add(file_id, name_range);
+    collector.enqueue(LuaSemanticDeclId::LuaDecl(LuaDeclId::new(
+        file_id,
+        decl_position,
+    )));
+    Some(())
+}
+
+/// 模块导出的变量: 继续查找 `require` 该模块后绑定的变量
+fn collect_module_export_references(
+    collector: &mut ReferenceCollector,
+    decl_id: LuaDeclId,
+) -> Option<()> {
+    let db = collector.db;
+    let module_file_id = decl_id.file_id;
+    let module_info = db.get_module_index().get_module(module_file_id)?;
+    if module_info.semantic_id.as_ref() != Some(&LuaSemanticDeclId::LuaDecl(decl_id))
+        || !collector.visited_module_exports.insert(module_file_id)
+    {
+        return Some(());
+    }
+
+    let mut dependents = db
+        .get_file_dependencies_index()
+        .get_file_dependencies()
+        .collect_file_dependents(vec![module_file_id]);
+    dependents.sort();
+    for file_id in dependents {
+        let Some(root) = collector.get_root(file_id) else {
+            continue;
+        };
+        for call_expr in root.descendants().filter_map(LuaCallExpr::cast) {
+            if !call_expr.is_require()
+                || get_require_file_id(collector, file_id, &call_expr) != Some(module_file_id)
+            {
+                continue;
+            }
+
+            match find_require_binding(collector, file_id, &call_expr) {
+                Some(binding @ (LuaSemanticDeclId::LuaDecl(_) | LuaSemanticDeclId::Member(_))) => {
+                    collector.enqueue(binding)
+                }
+                Some(_) => {}
+                None => {
+                    collector.add(file_id, call_expr.get_range());
+                }
+            }
+        }
+    }
+
+    Some(())
+}
+
+fn get_require_file_id(
+    collector: &mut ReferenceCollector,
+    file_id: FileId,
+    call_expr: &LuaCallExpr,
+) -> Option<FileId> {
+    let db = collector.db;
+    let first_arg = call_expr.get_args_list()?.get_args().next()?;
+    let LuaType::StringConst(module_path) =
+        infer_expr(db, collector.get_infer_cache(file_id), first_arg).ok()?
+    else {
+        return None;
+    };
+
+    Some(db.get_module_index().find_module(&module_path)?.file_id)
+}
+
+/// `require` 的结果赋给的变量或成员
+fn find_require_binding(
+    collector: &mut ReferenceCollector,
+    file_id: FileId,
+    call_expr: &LuaCallExpr,
+) -> Option<LuaSemanticDeclId> {
+    let position = call_expr.get_position();
+    for node in call_expr.syntax().ancestors().skip(1) {
+        if let Some(local_stat) = LuaLocalStat::cast(node.clone()) {
+            let idx = local_stat
+                .get_value_exprs()
+                .position(|value| value.get_position() == position)?;
+            let name = local_stat.get_local_name_list().nth(idx)?;
+            return Some(LuaSemanticDeclId::LuaDecl(LuaDeclId::new(
+                file_id,
+                name.get_position(),
+            )));
+        }
+        if let Some(assign_stat) = LuaAssignStat::cast(node) {
+            let (vars, values) = assign_stat.get_var_and_expr_list();
+            let idx = values
+                .iter()
+                .position(|value| value.get_position() == position)?;
+            let var = vars.get(idx)?.syntax().clone();
+            return collector.find_decl(file_id, var);
+        }
+    }
+
+    None
+}
+
+fn collect_type_decl_references(
+    collector: &mut ReferenceCollector,
+    type_decl_id: &LuaTypeDeclId,
+    include_decl: bool,
+) -> Option<()> {
+    let db = collector.db;
+    if include_decl && let Some(type_decl) = db.get_type_index().get_type_decl(type_decl_id) {
+        for location in type_decl.get_locations() {
+            collector.add(location.file_id, location.range);
+        }
+    }
+
+    // 类型只会在注释中被引用
+    let type_references = db.get_reference_index().get_type_references(type_decl_id)?;
+    for reference in type_references {
+        collector.add(reference.file_id, reference.value);
+    }
+
+    Some(())
+}
@@ -1,7 +1,12 @@
+mod find_references;
+mod test;
+
 use emmylua_parser::LuaSyntaxNode;
 
 use crate::{DbIndex, LuaMemberId, LuaSemanticDeclId};
 
+pub use find_references::find_references;
+
 use super::{
     LuaInferCache, SemanticDeclLevel, member::find_member_origin_owner,
     semantic_info::infer_node_semantic_decl,
@@ -0,0 +1,147 @@
+#[cfg(test)]
+mod test {
+    use emmylua_parser::{LuaAstNode, LuaIndexExpr, LuaNameExpr};
+
+    use crate::{LuaSemanticDeclId, LuaTypeDeclId, SemanticDeclLevel, VirtualWorkspace};
+
+    #[test]
+    fn test_find_global_references() {
+        let mut ws = VirtualWorkspace::new();
+        ws.def_file("a.lua", "GlobalValue = 1\n");
+        let file_id = ws.def_file("b.lua", "local x = GlobalValue\nGlobalValue = 2\n");
+
+        let semantic_model = ws.analysis.compilation.get_semantic_model(file_id).unwrap();
+        let name_expr = semantic_model
+            .get_root()
+            .descendants::<LuaNameExpr>()
+            .next()
+            .unwrap();
+        let decl_id = semantic_model
+            .find_decl(
+                name_expr.syntax().clone().into(),
+                SemanticDeclLevel::default(),
+            )
+            .unwrap();
+
+        assert_eq!(
+            semantic_model.find_references(decl_id.clone(), true).len(),
+            3
+        );
+        assert_eq!(semantic_model.find_references(decl_id, false).len(), 2);
+    }
+
+    #[test]
+    fn test_find_member_references() {
+        let mut ws = VirtualWorkspace::new();
+        ws.def_file(
+            "a.lua",
+            r#"
+            ---@class Point
+            ---@field x number
+            Point = {}
+            "#,
+        );
+        let file_id = ws.def_file("b.lua", "Point.x = 1\nlocal y = Point.x\n");
+
+        let semantic_model = ws.analysis.compilation.get_semantic_model(file_id).unwrap();
+        let uri = semantic_model.get_document().get_uri();
+        let index_expr = semantic_model
+            .get_root()
+            .descendants::<LuaIndexExpr>()
+            .last()
+            .unwrap();
+        let decl_id = semantic_model
+            .find_decl(
+                index_expr.syntax().clone().into(),
+                SemanticDeclLevel::default(),
+            )
+            .unwrap();
+        assert!(matches!(decl_id, LuaSemanticDeclId::Member(_)));
+
+        // 读取和写入都包含在结果中, `local y` 是成员的别名
+        let references = semantic_model.find_references(decl_id, true);
+        let mut lines = references
+            .iter()
+            .filter(|location| location.uri == uri)
+            .map(|location| location.range.start.line)
+            .collect::<Vec<_>>();
+        lines.sort();
+        assert_eq!(lines, vec![0, 1, 1]);
+    }
+
+    #[test]
+    fn test_find_type_references() {
+        let mut ws = VirtualWorkspace::new();
+        ws.def_file("a.lua", "---@class Point\n");
+        let file_id = ws.def_file("b.lua", "---@type Point\nlocal p\n");
+
+        let semantic_model = ws.analysis.compilation.get_semantic_model(file_id).unwrap();
+        let decl_id = LuaSemanticDeclId::TypeDecl(LuaTypeDeclId::global("Point"));
+
+        let references = semantic_model.find_references(decl_id.clone(), false);
+        assert_eq!(references.len(), 1);
+        assert_eq!(references[0].range.start.line, 0);
+        assert_eq!(semantic_model.find_references(decl_id, true).len(), 2);
+    }
+
+    #[test]
+    fn test_find_doc_comment_references() {
+        let mut ws = VirtualWorkspace::new();
+        let file_id = ws.def_file(
+            "a.lua",
+            r#"---@class Doc
+---@field value integer
+local Doc = {}
+
+---@param count integer
+function Doc.set(count)
+    ---@cast count integer
+    Doc.value = count
+end
+"#,
+        );
+
+        let semantic_model = ws.analysis.compilation.get_semantic_model(file_id).unwrap();
+        let get_lines = |decl_id: LuaSemanticDeclId| {
+            let mut lines = semantic_model
+                .find_references(decl_id, true)
+                .iter()
+                .map(|location| location.range.start.line)
+                .collect::<Vec<_>>();
+            lines.sort();
+            lines
+        };
+
+        // `---@param` 中的参数名
+        let param_expr = semantic_model
+            .get_root()
+            .descendants::<LuaNameExpr>()
+            .find(|name_expr| name_expr.get_name_text().as_deref() == Some("count"))
+            .unwrap();
+        let param_decl = semantic_model
+            .find_decl(
+                param_expr.syntax().clone().into(),
+                SemanticDeclLevel::default(),
+            )
+            .unwrap();
+        assert_eq!(get_lines(param_decl), vec![4, 5, 6, 7]);
+
+        // `---@field` 定义的成员
+        let index_expr = semantic_model
+            .get_root()
+            .descendants::<LuaIndexExpr>()
+            .find(|index_expr| {
+                index_expr
+                    .get_name_token()
+                    .is_some_and(|name| name.get_name_text() == "value")
+            })
+            .unwrap();
+        let member_decl = semantic_model
+            .find_decl(
+                index_expr.syntax().clone().into(),
+                SemanticDeclLevel::default(),
+            )
+            .unwrap();
+        assert_eq!(get_lines(member_decl), vec![1, 7]);
+    }
+}
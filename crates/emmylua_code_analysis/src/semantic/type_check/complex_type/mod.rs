@@ -106,6 +106,7 @@ pub fn check_complex_type_compact(
                     check_guard.next_level()?,
                 );
             }
+            let path_len = context.mismatch_path.len();
             for sub_type in union_type.into_vec() {
                 match check_general_type_compact(
                     context,
@@ -114,7 +115,9 @@ pub fn check_complex_type_compact(
                     check_guard.next_level()?,
                 ) {
                     Ok(_) => return Ok(()),
-                    Err(e) if e.is_type_not_match() => {}
+                    Err(e) if e.is_type_not_match() => {
+                        context.truncate_mismatch_path(path_len);
+                    }
                     Err(e) => return Err(e),
                 }
             }
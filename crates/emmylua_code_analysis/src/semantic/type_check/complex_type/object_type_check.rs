@@ -82,16 +82,22 @@ fn check_object_type_compact_object_type(
                 if source_type.is_nullable() || source_type.is_any() {
                     continue;
                 } else {
+                    let path_len = context.mismatch_path.len();
+                    context.record_mismatch_member(path_len, key.clone());
                     return Err(TypeCheckFailReason::TypeNotMatch);
                 }
             }
         };
-        check_general_type_compact(
+        let path_len = context.mismatch_path.len();
+        if let Err(e) = check_general_type_compact(
             context,
             source_type,
             compact_type,
             check_guard.next_level()?,
-        )?;
+        ) {
+            context.record_mismatch_member(path_len, key.clone());
+            return Err(e);
+        }
     }
 
     Ok(())
@@ -140,7 +146,13 @@ fn check_member_value(
     member_type: &LuaType,
     check_guard: TypeCheckGuard,
 ) -> TypeCheckResult {
-    match check_general_type_compact(context, source_type, member_type, check_guard.next_level()?) {
+    let path_len = context.mismatch_path.len();
+    let result =
+        check_general_type_compact(context, source_type, member_type, check_guard.next_level()?);
+    if result.is_err() {
+        context.record_mismatch_member(path_len, key.clone());
+    }
+    match result {
         Ok(_) => Ok(()),
         Err(TypeCheckFailReason::TypeNotMatch) => {
             let mut key_display = key.to_path();
@@ -193,6 +205,8 @@ fn check_object_type_compact_table_const(
                 if source_type.is_nullable() || source_type.is_any() {
                     continue;
                 } else {
+                    let path_len = context.mismatch_path.len();
+                    context.record_mismatch_member(path_len, key.clone());
                     return Err(TypeCheckFailReason::TypeNotMatchWithReason(
                         t!("missing member %{key}", key = key.to_path().to_string()).to_string(),
                     ));
@@ -288,6 +302,8 @@ fn check_object_type_compact_type_ref(
                 continue;
             }
 
+            let path_len = context.mismatch_path.len();
+            context.record_mismatch_member(path_len, key.clone());
             return Err(TypeCheckFailReason::TypeNotMatchWithReason(
                 t!("missing member %{key}", key = key.to_path().to_string()).to_string(),
             ));
@@ -95,6 +95,11 @@ fn check_tuple_types_compact_tuple_types(
             if source_tuple_member_type.is_optional() {
                 continue;
             } else {
+                let path_len = context.mismatch_path.len();
+                context.record_mismatch_member(
+                    path_len,
+                    LuaMemberKey::Integer((i + source_start + 1) as i64),
+                );
                 return Err(TypeCheckFailReason::TypeNotMatchWithReason(
                     t!("missing tuple member %{idx}", idx = i + source_start + 1).to_string(),
                 ));
@@ -141,6 +146,7 @@ fn check_tuple_types_compact_tuple_types(
                 }
             }
             _ => {
+                let path_len = context.mismatch_path.len();
                 match check_general_type_compact(
                     context,
                     source_tuple_member_type,
@@ -149,6 +155,10 @@ fn check_tuple_types_compact_tuple_types(
                 ) {
                     Ok(_) => {}
                     Err(TypeCheckFailReason::TypeNotMatch) => {
+                        context.record_mismatch_member(
+                            path_len,
+                            LuaMemberKey::Integer((i + source_start + 1) as i64),
+                        );
                         return Err(TypeCheckFailReason::TypeNotMatchWithReason(
                             t!(
                                 "tuple member %{idx} not match, expect %{typ}, but got %{got}",
@@ -168,6 +178,10 @@ fn check_tuple_types_compact_tuple_types(
                         ));
                     }
                     Err(e) => {
+                        context.record_mismatch_member(
+                            path_len,
+                            LuaMemberKey::Integer((i + source_start + 1) as i64),
+                        );
                         return Err(e);
                     }
                 }
@@ -192,6 +206,7 @@ fn check_tuple_type_compact_table(
             let member_type = member_item
                 .resolve_type(context.db)
                 .map_err(|_| TypeCheckFailReason::TypeNotMatch)?;
+            let path_len = context.mismatch_path.len();
             match check_general_type_compact(
                 context,
                 source_tuple_member_type,
@@ -200,6 +215,7 @@ fn check_tuple_type_compact_table(
             ) {
                 Ok(_) => {}
                 Err(TypeCheckFailReason::TypeNotMatch) => {
+                    context.record_mismatch_member(path_len, key.clone());
                     return Err(TypeCheckFailReason::TypeNotMatchWithReason(
                         t!(
                             "tuple member %{idx} not match, expect %{typ}, but got %{got}",
@@ -215,12 +231,15 @@ fn check_tuple_type_compact_table(
                     ));
                 }
                 Err(e) => {
+                    context.record_mismatch_member(path_len, key.clone());
                     return Err(e);
                 }
             }
         } else if source_tuple_member_type.is_optional() {
             continue;
         } else {
+            let path_len = context.mismatch_path.len();
+            context.record_mismatch_member(path_len, key);
             return Err(TypeCheckFailReason::TypeNotMatchWithReason(
                 t!("missing tuple member %{idx}", idx = i + 1).to_string(),
             ));
@@ -243,6 +262,7 @@ fn check_tuple_type_compact_object_type(
     for (i, source_tuple_member_type) in tuple_members.iter().enumerate() {
         let key = LuaMemberKey::Integer((i + 1) as i64);
         if let Some(object_member_type) = object_members.get(&key) {
+            let path_len = context.mismatch_path.len();
             match check_general_type_compact(
                 context,
                 source_tuple_member_type,
@@ -251,6 +271,7 @@ fn check_tuple_type_compact_object_type(
             ) {
                 Ok(_) => {}
                 Err(TypeCheckFailReason::TypeNotMatch) => {
+                    context.record_mismatch_member(path_len, key.clone());
                     return Err(TypeCheckFailReason::TypeNotMatchWithReason(
                         t!(
                             "tuple member %{idx} not match, expect %{typ}, but got %{got}",
@@ -267,12 +288,15 @@ fn check_tuple_type_compact_object_type(
                     ));
                 }
                 Err(e) => {
+                    context.record_mismatch_member(path_len, key.clone());
                     return Err(e);
                 }
             }
         } else if source_tuple_member_type.is_nullable() || source_tuple_member_type.is_any() {
             continue;
         } else {
+            let path_len = context.mismatch_path.len();
+            context.record_mismatch_member(path_len, key);
             return Err(TypeCheckFailReason::TypeNotMatchWithReason(
                 t!("missing tuple member %{idx}", idx = i + 1).to_string(),
             ));
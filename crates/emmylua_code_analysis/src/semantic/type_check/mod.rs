@@ -17,7 +17,7 @@ use func_type::{check_doc_func_type_compact, check_sig_type_compact};
 use generic_type::check_generic_type_compact;
 use ref_type::check_ref_type_compact;
 use simple_type::check_simple_type_compact;
-pub use type_check_fail_reason::TypeCheckFailReason;
+pub use type_check_fail_reason::{TypeCheckFailReason, TypeMismatchReport};
 use type_check_guard::TypeCheckGuard;
 
 use crate::{
@@ -48,6 +48,20 @@ pub fn check_type_compact_detail(
     check_general_type_compact(&mut context, source, compact_type, guard)
 }
 
+pub fn check_type_compact_report(
+    db: &DbIndex,
+    source: &LuaType,
+    compact_type: &LuaType,
+) -> Result<(), TypeMismatchReport> {
+    let mut context = TypeCheckContext::new(db, true, TypeCheckCheckLevel::Normal);
+    check_general_type_compact(&mut context, source, compact_type, TypeCheckGuard::new()).map_err(
+        |reason| TypeMismatchReport {
+            reason,
+            path: std::mem::take(&mut context.mismatch_path),
+        },
+    )
+}
+
 pub fn check_type_compact_with_level(
     db: &DbIndex,
     source: &LuaType,
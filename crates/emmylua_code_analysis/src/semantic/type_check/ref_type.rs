@@ -283,6 +283,7 @@ fn check_ref_type_compact_table(
                     .unwrap_or(&LuaTypeCache::InferType(LuaType::Any))
                     .as_type();
 
+                let path_len = context.mismatch_path.len();
                 if let Err(err) = check_general_type_compact(
                     context,
                     source_member_type,
@@ -290,6 +291,7 @@ fn check_ref_type_compact_table(
                     check_guard.next_level()?,
                 ) && err.is_type_not_match()
                 {
+                    context.record_mismatch_member(path_len, key.clone());
                     if !context.detail {
                         return Err(TypeCheckFailReason::TypeNotMatch);
                     }
@@ -307,6 +309,8 @@ fn check_ref_type_compact_table(
                 }
             }
             None if !source_member_type.is_optional() => {
+                let path_len = context.mismatch_path.len();
+                context.record_mismatch_member(path_len, key.clone());
                 if !context.detail {
                     return Err(TypeCheckFailReason::TypeNotMatch);
                 }
@@ -363,6 +367,7 @@ fn check_ref_type_compact_object(
 
         match get_object_field_type(object_type, &key) {
             Some(field_type) => {
+                let path_len = context.mismatch_path.len();
                 if let Err(err) = check_general_type_compact(
                     context,
                     &source_member_type,
@@ -370,6 +375,7 @@ fn check_ref_type_compact_object(
                     check_guard.next_level()?,
                 ) && err.is_type_not_match()
                 {
+                    context.record_mismatch_member(path_len, key.clone());
                     if !context.detail {
                         return Err(TypeCheckFailReason::TypeNotMatch);
                     }
@@ -387,6 +393,8 @@ fn check_ref_type_compact_object(
                 }
             }
             None if !source_member_type.is_optional() => {
+                let path_len = context.mismatch_path.len();
+                context.record_mismatch_member(path_len, key.clone());
                 if !context.detail {
                     return Err(TypeCheckFailReason::TypeNotMatch);
                 }
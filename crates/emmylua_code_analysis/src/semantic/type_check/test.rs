@@ -231,4 +231,76 @@ mod test {
         assert_eq!(ws.expr_ty("AFTER_CAST"), LuaType::Unknown);
         assert_eq!(ws.expr_ty("table.__sentinel()"), ws.ty("integer"));
     }
+
+    #[test]
+    fn test_type_check_detailed_path() {
+        let mut ws = VirtualWorkspace::new();
+
+        let file_id = ws.def(
+            r#"
+            ---@class Inner
+            ---@field value string
+
+            ---@class Outer
+            ---@field inner Inner
+            "#,
+        );
+        let outer_ty = ws.ty("Outer");
+        let nested_ty = ws.ty("{ inner: { value: integer } }");
+        let matched_ty = ws.ty("{ inner: { value: string } }");
+        let tuple_ty = ws.ty("[string, integer]");
+        let tuple_compact_ty = ws.ty("[string, string]");
+        let semantic_model = ws
+            .analysis
+            .compilation
+            .get_semantic_model(file_id)
+            .expect("Semantic model must exist");
+
+        let report = semantic_model
+            .type_check_detailed(&outer_ty, &nested_ty)
+            .expect_err("nested member should not match");
+        assert_eq!(report.path_string(), "inner.value");
+
+        let report = semantic_model
+            .type_check_detailed(&tuple_ty, &tuple_compact_ty)
+            .expect_err("tuple member should not match");
+        assert_eq!(report.path_string(), "[2]");
+
+        assert!(
+            semantic_model
+                .type_check_detailed(&outer_ty, &matched_ty)
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn test_is_assignable() {
+        let mut ws = VirtualWorkspace::new();
+        let file_id = ws.def(
+            r#"
+            ---@class Animal
+            ---@class Dog: Animal
+            "#,
+        );
+        let animal_ty = ws.ty("Animal");
+        let dog_ty = ws.ty("Dog");
+        let int_ty = ws.ty("integer");
+        let number_ty = ws.ty("number");
+        let semantic_model = ws
+            .analysis
+            .compilation
+            .get_semantic_model(file_id)
+            .expect("Semantic model must exist");
+
+        assert!(semantic_model.is_assignable(&dog_ty, &animal_ty));
+        // 宽松语义: 与 `type_check` 一致, 父类的值也可以赋给子类
+        assert!(semantic_model.is_assignable(&animal_ty, &dog_ty));
+        assert!(animal_ty.is_assignable_to(semantic_model.get_db(), &dog_ty));
+        assert!(semantic_model.is_assignable(&int_ty, &number_ty));
+        assert!(!semantic_model.is_assignable(&number_ty, &int_ty));
+
+        let db = semantic_model.get_db();
+        assert!(dog_ty.is_assignable_to(db, &animal_ty));
+        assert!(!int_ty.is_assignable_to(db, &dog_ty));
+    }
 }
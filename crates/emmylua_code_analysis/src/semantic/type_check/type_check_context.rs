@@ -14,6 +14,8 @@ pub struct TypeCheckContext<'db> {
     pub db: &'db DbIndex,
     pub level: TypeCheckCheckLevel,
     pub table_member_checked: Option<HashSet<LuaMemberKey>>,
+    /// 仅在 `detail` 模式下记录, 检查失败时从外到内指向不匹配的成员
+    pub mismatch_path: Vec<LuaMemberKey>,
 }
 
 impl<'db> TypeCheckContext<'db> {
@@ -23,6 +25,7 @@ impl<'db> TypeCheckContext<'db> {
             db,
             level,
             table_member_checked: None,
+            mismatch_path: Vec::new(),
         }
     }
 
@@ -42,4 +45,18 @@ impl<'db> TypeCheckContext<'db> {
             checked.insert(key);
         }
     }
+
+    /// 成员检查失败时调用, `path_len` 为检查该成员之前的路径长度, 嵌套成员的路径保留在其后
+    pub fn record_mismatch_member(&mut self, path_len: usize, key: LuaMemberKey) {
+        if !self.detail {
+            return;
+        }
+        let idx = path_len.min(self.mismatch_path.len());
+        self.mismatch_path.insert(idx, key);
+    }
+
+    /// 丢弃被忽略的失败分支所记录的路径
+    pub fn truncate_mismatch_path(&mut self, path_len: usize) {
+        self.mismatch_path.truncate(path_len);
+    }
 }
@@ -1,3 +1,5 @@
+use crate::LuaMemberKey;
+
 #[derive(Debug)]
 pub enum TypeCheckFailReason {
     DonotCheck,
@@ -14,3 +16,29 @@ impl TypeCheckFailReason {
         )
     }
 }
+
+/// 带有不匹配位置的类型检查结果, 用于指出嵌套表/记录中具体哪个成员不匹配
+#[derive(Debug)]
+pub struct TypeMismatchReport {
+    pub reason: TypeCheckFailReason,
+    /// 从外到内的成员路径, 为空表示顶层类型本身不匹配
+    pub path: Vec<LuaMemberKey>,
+}
+
+impl TypeMismatchReport {
+    /// 形如 `a.b[1].c` 的路径字符串
+    pub fn path_string(&self) -> String {
+        let mut result = String::new();
+        for key in &self.path {
+            let segment = key.to_path();
+            if segment.is_empty() {
+                continue;
+            }
+            if !result.is_empty() && !segment.starts_with('[') {
+                result.push('.');
+            }
+            result.push_str(&segment);
+        }
+        result
+    }
+}
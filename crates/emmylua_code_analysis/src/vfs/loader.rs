@@ -1,7 +1,7 @@
 use encoding_rs::{Encoding, UTF_8};
 use std::{
     error::Error,
-    fs,
+    fs, io,
     path::{Path, PathBuf},
 };
 use wax::Pattern;
@@ -113,3 +113,24 @@ pub fn read_file_with_encoding(path: &Path, encoding: &str) -> Option<String> {
 
     Some(content.to_string())
 }
+
+/// 按照 `read_file_with_encoding` 读取时使用的编码把内容写回文件, 保留原有的 BOM
+pub fn write_file_with_encoding(path: &Path, content: &str, encoding: &str) -> io::Result<()> {
+    let origin_content = fs::read(path).unwrap_or_default();
+    let mut encoding = Encoding::for_label(encoding.as_bytes()).unwrap_or(UTF_8);
+    // 读取时解码失败会回退到 utf-8
+    let (_, has_error) = encoding.decode_with_bom_removal(&origin_content);
+    if has_error {
+        encoding = UTF_8;
+    }
+
+    let mut bytes = Vec::new();
+    let (encoded, output_encoding, _) = encoding.encode(content);
+    if let Some((bom_encoding, bom_len)) = Encoding::for_bom(&origin_content)
+        && bom_encoding == output_encoding
+    {
+        bytes.extend_from_slice(&origin_content[..bom_len]);
+    }
+    bytes.extend_from_slice(&encoded);
+    fs::write(path, bytes)
+}
@@ -11,7 +11,9 @@ use emmylua_parser::{LineIndex, LuaParseError, LuaParser, LuaSyntaxTree};
 pub use file_id::{FileId, InFiled};
 pub use file_uri_handler::{file_path_to_uri, uri_to_file_path};
 use hashbrown::HashMap;
-pub use loader::{LuaFileInfo, load_workspace_files, read_file_with_encoding};
+pub use loader::{
+    LuaFileInfo, load_workspace_files, read_file_with_encoding, write_file_with_encoding,
+};
 use lsp_types::Uri;
 use rowan::NodeCache;
 use std::path::PathBuf;
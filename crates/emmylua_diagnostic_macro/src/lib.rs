@@ -2,7 +2,10 @@ extern crate proc_macro;
 
 use proc_macro::TokenStream;
 use quote::quote;
-use syn::{Data, DeriveInput, Fields, Ident, parse_macro_input, spanned::Spanned};
+use syn::{
+    Data, DeriveInput, Expr, ExprLit, Fields, Ident, Lit, Meta, Variant, parse_macro_input,
+    spanned::Spanned,
+};
 
 // Convert enum variant names to kebab-case
 fn to_kebab_case(ident: &Ident) -> String {
@@ -19,6 +22,26 @@ fn to_kebab_case(ident: &Ident) -> String {
     result
 }
 
+// Collect the `///` doc comment of a variant into a single line
+fn doc_comment(variant: &Variant) -> String {
+    variant
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("doc"))
+        .filter_map(|attr| match &attr.meta {
+            Meta::NameValue(name_value) => match &name_value.value {
+                Expr::Lit(ExprLit {
+                    lit: Lit::Str(lit), ..
+                }) => Some(lit.value().trim().to_string()),
+                _ => None,
+            },
+            _ => None,
+        })
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
 #[proc_macro_derive(LuaDiagnosticMacro)]
 pub fn lua_diagnostic_macro(input: TokenStream) -> TokenStream {
     // Parse the input TokenStream into DeriveInput
@@ -35,9 +58,10 @@ pub fn lua_diagnostic_macro(input: TokenStream) -> TokenStream {
         }
     };
 
-    // Generate get_name / FromStr / Display / all based on variants
+    // Generate get_name / get_description / FromStr / Display / all based on variants
     let mut variant_idents = Vec::new();
     let mut variant_strings = Vec::new();
+    let mut variant_docs = Vec::new();
 
     for variant in variants.iter() {
         let variant_ident = &variant.ident;
@@ -46,6 +70,7 @@ pub fn lua_diagnostic_macro(input: TokenStream) -> TokenStream {
             let kebab_case_string = to_kebab_case(variant_ident);
             variant_idents.push(variant_ident);
             variant_strings.push(kebab_case_string);
+            variant_docs.push(doc_comment(variant));
         } else {
             // Only unit variants are supported
             return syn::Error::new(variant.ident.span(), "Only unit variants supported")
@@ -74,24 +99,46 @@ pub fn lua_diagnostic_macro(input: TokenStream) -> TokenStream {
             }
         });
 
-    // Build the all() array
-    let all_variants = variant_idents.iter().map(|ident| {
-        quote! { #name::#ident }
-    });
+    // Build match arms for get_description()
+    let get_description_arms =
+        variant_idents
+            .iter()
+            .zip(variant_docs.iter())
+            .map(|(ident, doc)| {
+                quote! {
+                    #name::#ident => #doc
+                }
+            });
+
+    // Build the all() array, `None` is the fallback of FromStr and not a real code
+    let all_variants = variant_idents
+        .iter()
+        .filter(|ident| **ident != "None")
+        .map(|ident| {
+            quote! { #name::#ident }
+        });
 
     // Generate the complete impl block
     let expanded = quote! {
         impl #name {
-            pub fn get_name(&self) -> &str {
+            pub fn get_name(&self) -> &'static str {
                 match self {
                     #(#get_name_arms),*,
                     _ => "none"
                 }
             }
 
-            // Return all variants
-            pub fn all() -> Vec<#name> {
-                vec![
+            // One-line description taken from the variant doc comment
+            pub fn get_description(&self) -> &'static str {
+                match self {
+                    #(#get_description_arms),*,
+                    _ => ""
+                }
+            }
+
+            // Return all variants in declaration order
+            pub fn all() -> &'static [#name] {
+                &[
                     #(#all_variants),*
                 ]
             }
@@ -355,22 +355,12 @@ impl Printer {
         for doc in docs {
             match doc {
                 DocIR::HardLine => return true,
-                DocIR::List(contents) | DocIR::Indent(contents) => {
-                    if self.has_hard_line(contents) {
-                        return true;
-                    }
-                }
-                DocIR::Group { contents, .. } => {
-                    if self.has_hard_line(contents) {
-                        return true;
-                    }
-                }
-                DocIR::AlignGroup(group) => {
-                    // Alignment groups with 2+ entries always produce hard lines
-                    if group.entries.len() >= 2 {
-                        return true;
-                    }
+                DocIR::List(contents) | DocIR::Indent(contents) if self.has_hard_line(contents) => {
+                    return true;
                 }
+                DocIR::Group { contents, .. } if self.has_hard_line(contents) => return true,
+                // Alignment groups with 2+ entries always produce hard lines
+                DocIR::AlignGroup(group) if group.entries.len() >= 2 => return true,
                 _ => {}
             }
         }
@@ -54,8 +54,14 @@ impl FileDiagnostic {
             tokio::select! {
                 _ = tokio::time::sleep(Duration::from_millis(interval)) => {
                     let analysis = analysis.read().await;
+                    // 等待读锁期间文件可能又被修改, 此时交给新的任务处理
+                    if cancel_token.is_cancelled() {
+                        debug!("cancel diagnostic: {:?}", file_id_clone);
+                        return;
+                    }
                     if let Some(uri) = analysis.get_uri(file_id_clone) {
-                        let diagnostics = analysis.diagnose_file(file_id_clone, cancel_token);
+                        let diagnostics =
+                            analysis.diagnose_file(file_id_clone, cancel_token.clone());
                         if let Some(diagnostics) = diagnostics {
                             let diagnostic_param = lsp_types::PublishDiagnosticsParams {
                                 uri,
@@ -67,9 +73,12 @@ impl FileDiagnostic {
                     } else {
                         info!("file not found: {:?}", file_id_clone);
                     }
-                    // After completion, remove from HashMap
+                    // After completion, remove from HashMap. A cancelled token has
+                    // already been replaced by a newer task, which must stay cancellable
                     let mut tokens = diagnostic_tokens.lock().await;
-                    tokens.remove(&file_id_clone);
+                    if !cancel_token.is_cancelled() {
+                        tokens.remove(&file_id_clone);
+                    }
                 }
                 _ = cancel_token.cancelled() => {
                     debug!("cancel diagnostic: {:?}", file_id_clone);
@@ -1,5 +1,9 @@
-use std::sync::Arc;
+use std::sync::{
+    Arc,
+    atomic::{AtomicU32, Ordering},
+};
 
+use emmylua_code_analysis::{IndexPhase, IndexProgressSink};
 use lsp_types::{
     NumberOrString, ProgressParams, ProgressParamsValue, WorkDoneProgress, WorkDoneProgressBegin,
     WorkDoneProgressCreateParams, WorkDoneProgressEnd, WorkDoneProgressReport,
@@ -10,6 +14,7 @@ use crate::util::time_cancel_token;
 
 use super::ClientProxy;
 
+#[derive(Clone)]
 pub struct StatusBar {
     client: Arc<ClientProxy>,
     supports_work_done_progress: bool,
@@ -116,6 +121,30 @@ impl StatusBar {
         )
     }
 
+    /// 将索引进度汇报到任务中, 解析和分析各占一半. 百分比不变时不重复发送
+    pub fn index_progress_sink(&self, task: ProgressTask) -> IndexProgressSink {
+        let status_bar = self.clone();
+        let last_percentage = AtomicU32::new(u32::MAX);
+        IndexProgressSink::new(move |progress| {
+            let base = match progress.phase {
+                IndexPhase::Parse => 0,
+                IndexPhase::Analyze => 50,
+            };
+            let percentage = base + (progress.done * 50 / progress.total.max(1)) as u32;
+            if last_percentage.swap(percentage, Ordering::Relaxed) == percentage {
+                return;
+            }
+
+            let message = match progress.phase {
+                IndexPhase::Parse => {
+                    format!("Parsing {}/{} files", progress.done, progress.total)
+                }
+                IndexPhase::Analyze => "Analyzing files".to_string(),
+            };
+            status_bar.update_progress_task(task, Some(percentage), Some(message));
+        })
+    }
+
     pub fn finish_progress_task(&self, task: ProgressTask, message: Option<String>) {
         if !self.supports_work_done_progress {
             return;
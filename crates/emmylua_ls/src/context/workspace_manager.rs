@@ -14,8 +14,8 @@ use crate::context::ServerContextSnapshot;
 use crate::context::lsp_features::LspFeatures;
 use crate::handlers::{ClientConfig, init_analysis, register_files_watch};
 use emmylua_code_analysis::{
-    EmmyLuaAnalysis, Emmyrc, WorkspaceFileMatcher, WorkspaceFolder, load_configs,
-    read_file_with_encoding, update_code_style, uri_to_file_path,
+    DIRECTORY_CONFIG_FILE_NAME, EmmyLuaAnalysis, Emmyrc, WorkspaceFileMatcher, WorkspaceFolder,
+    load_configs, read_file_with_encoding, update_code_style, uri_to_file_path,
 };
 use lsp_types::Uri;
 use tokio::sync::{Mutex as AsyncMutex, RwLock};
@@ -123,7 +123,11 @@ impl WorkspaceManager {
         let Some(config_root) = self.config_root() else {
             return;
         };
-        if config_path.parent() != Some(config_root.as_path()) {
+        let is_directory_config = config_path.starts_with(&config_root)
+            && config_path
+                .file_name()
+                .is_some_and(|name| name == DIRECTORY_CONFIG_FILE_NAME);
+        if config_path.parent() != Some(config_root.as_path()) && !is_directory_config {
             return;
         }
 
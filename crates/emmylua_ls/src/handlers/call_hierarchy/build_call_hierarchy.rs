@@ -1,17 +1,18 @@
 use emmylua_code_analysis::{
-    DbIndex, FileId, LuaCompilation, LuaDeclId, LuaMemberId, LuaSemanticDeclId, LuaTypeOwner,
-    SemanticModel,
+    DbIndex, FileId, LuaCompilation, LuaDeclId, LuaMemberId, LuaSemanticDeclId, LuaType,
+    LuaTypeOwner, SemanticDeclLevel, SemanticModel,
 };
 use emmylua_parser::{
-    LuaAst, LuaAstNode, LuaAstToken, LuaBlock, LuaGeneralToken, LuaStat, LuaTokenKind, LuaVarExpr,
-    PathTrait,
+    LuaAst, LuaAstNode, LuaAstToken, LuaBlock, LuaCallExpr, LuaClosureExpr, LuaGeneralToken,
+    LuaStat, LuaTokenKind, LuaVarExpr, PathTrait,
+};
+use lsp_types::{
+    CallHierarchyIncomingCall, CallHierarchyItem, CallHierarchyOutgoingCall, Location, Range,
+    SymbolKind,
 };
-use lsp_types::{CallHierarchyIncomingCall, CallHierarchyItem, Location, SymbolKind};
 use rowan::TokenAtOffset;
 use serde::{Deserialize, Serialize};
 
-use crate::handlers::references::{search_decl_references, search_member_references};
-
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct CallHierarchyItemData {
     pub semantic_decl: LuaSemanticDeclId,
@@ -97,17 +98,15 @@ pub fn build_incoming_hierarchy(
     semantic_decl: LuaSemanticDeclId,
 ) -> Option<Vec<CallHierarchyIncomingCall>> {
     let mut result = vec![];
-    let mut locations = vec![];
-    match semantic_decl {
-        LuaSemanticDeclId::LuaDecl(decl_id) => {
-            search_decl_references(semantic_model, compilation, decl_id, &mut locations);
-        }
-        LuaSemanticDeclId::Member(member_id) => {
-            search_member_references(semantic_model, compilation, member_id, &mut locations);
-        }
-        _ => return None,
+    if !matches!(
+        semantic_decl,
+        LuaSemanticDeclId::LuaDecl(_) | LuaSemanticDeclId::Member(_)
+    ) {
+        return None;
     }
 
+    let locations = semantic_model.find_references(semantic_decl, true);
+
     for location in locations {
         build_incoming_hierarchy_item(compilation, &location, &mut result);
     }
@@ -250,3 +249,68 @@ fn build_incoming_hierarchy_item(
 
     Some(())
 }
+
+pub fn build_outgoing_hierarchy(
+    compilation: &LuaCompilation,
+    semantic_decl: LuaSemanticDeclId,
+) -> Option<Vec<CallHierarchyOutgoingCall>> {
+    let db = compilation.get_db();
+    let type_owner: LuaTypeOwner = match semantic_decl {
+        LuaSemanticDeclId::LuaDecl(decl_id) => decl_id.into(),
+        LuaSemanticDeclId::Member(member_id) => member_id.into(),
+        _ => return None,
+    };
+    let LuaType::Signature(signature_id) =
+        db.get_type_index().get_type_cache(&type_owner)?.as_type()
+    else {
+        return None;
+    };
+
+    let semantic_model = compilation.get_semantic_model(signature_id.get_file_id())?;
+    let closure = semantic_model
+        .get_root()
+        .descendants::<LuaClosureExpr>()
+        .find(|closure| closure.get_position() == signature_id.get_position())?;
+    let document = semantic_model.get_document();
+
+    // 同一个被调用者的多次调用合并为一项
+    let mut callees: Vec<(LuaSemanticDeclId, Vec<Range>)> = Vec::new();
+    for call_expr in closure.descendants::<LuaCallExpr>() {
+        // 嵌套函数中的调用属于嵌套函数自身
+        if call_expr
+            .ancestors::<LuaClosureExpr>()
+            .next()
+            .is_none_or(|owner| owner != closure)
+        {
+            continue;
+        }
+        let Some(prefix_expr) = call_expr.get_prefix_expr() else {
+            continue;
+        };
+        let Some(callee) = semantic_model.find_decl(
+            prefix_expr.syntax().clone().into(),
+            SemanticDeclLevel::default(),
+        ) else {
+            continue;
+        };
+        let Some(range) = document.to_lsp_range(prefix_expr.get_range()) else {
+            continue;
+        };
+
+        match callees.iter_mut().find(|(decl, _)| *decl == callee) {
+            Some((_, ranges)) => ranges.push(range),
+            None => callees.push((callee, vec![range])),
+        }
+    }
+
+    let result = callees
+        .into_iter()
+        .filter_map(|(callee, from_ranges)| {
+            Some(CallHierarchyOutgoingCall {
+                to: build_call_hierarchy_item(&semantic_model, callee)?,
+                from_ranges,
+            })
+        })
+        .collect();
+    Some(result)
+}
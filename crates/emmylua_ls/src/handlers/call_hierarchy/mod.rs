@@ -1,5 +1,6 @@
 mod build_call_hierarchy;
 
+pub use build_call_hierarchy::build_outgoing_hierarchy;
 use build_call_hierarchy::{
     CallHierarchyItemData, build_call_hierarchy_item, build_incoming_hierarchy,
 };
@@ -76,11 +77,16 @@ pub async fn on_incoming_calls_handler(
 }
 
 pub async fn on_outgoing_calls_handler(
-    _: ServerContextSnapshot,
-    _: CallHierarchyOutgoingCallsParams,
+    context: ServerContextSnapshot,
+    params: CallHierarchyOutgoingCallsParams,
     _: CancellationToken,
 ) -> Option<Vec<CallHierarchyOutgoingCall>> {
-    None
+    let item = params.item;
+    let data = item.data.as_ref()?;
+    let data = serde_json::from_value::<CallHierarchyItemData>(data.clone()).ok()?;
+    let analysis = context.analysis().read().await;
+
+    build_outgoing_hierarchy(&analysis.compilation, data.semantic_decl)
 }
 
 pub struct CallHierarchyCapabilities;
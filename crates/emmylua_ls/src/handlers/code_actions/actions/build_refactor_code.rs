@@ -0,0 +1,812 @@
+use std::collections::{HashMap, HashSet};
+
+use emmylua_code_analysis::{
+    DeclReference, LuaDeclId, LuaMemberKey, LuaMemberOwner, LuaSignature, LuaSignatureId, LuaType,
+    RenderLevel, SemanticModel, humanize_type,
+};
+use emmylua_parser::{
+    BinaryOperator, LuaAssignStat, LuaAstNode, LuaBlock, LuaCallExpr, LuaClosureExpr,
+    LuaElseIfClauseStat, LuaExpr, LuaFuncStat, LuaIndexKey, LuaLiteralToken, LuaLocalName,
+    LuaLocalStat, LuaNameExpr, LuaStat, LuaSyntaxKind, LuaTableField,
+};
+use lsp_types::{
+    CodeAction, CodeActionDisabled, CodeActionKind, CodeActionOrCommand, Range, TextEdit,
+    WorkspaceEdit,
+};
+use rowan::{Direction, TextRange, TextSize};
+
+use crate::handlers::{command::make_dump_type_command, hover::build_function_params};
+use crate::util::format_string_key;
+
+pub fn build_extract_local_variable(
+    semantic_model: &SemanticModel,
+    actions: &mut Vec<CodeActionOrCommand>,
+    range: Range,
+) -> Option<()> {
+    let document = semantic_model.get_document();
+    let selection = trim_range(document.get_text(), document.to_rowan_range(range)?);
+    if selection.is_empty() {
+        return None;
+    }
+
+    let root = semantic_model.get_root();
+    let expr = root
+        .syntax()
+        .covering_element(selection)
+        .ancestors()
+        .filter_map(LuaExpr::cast)
+        .find(|expr| expr.get_range() == selection)?;
+    if !can_extract(&expr) {
+        return None;
+    }
+
+    let stat = expr.ancestors::<LuaStat>().next()?;
+    // 提取到语句前会改变求值次数或时机
+    if matches!(stat, LuaStat::WhileStat(_) | LuaStat::RepeatStat(_))
+        || expr.ancestors::<LuaElseIfClauseStat>().next().is_some()
+        || is_short_circuited(&expr, &stat)
+    {
+        return None;
+    }
+    // 局部变量只保留第一个值, 会丢弃调用的其余返回值
+    if is_multi_value_expr(&expr) && is_multi_value_position(&expr) {
+        return None;
+    }
+
+    let name = get_unique_name(semantic_model, &stat, &suggest_name(&expr))?;
+    let indent = get_line_indent(document.get_text(), stat.get_position());
+    let insert_position = document.to_lsp_position(stat.get_position())?;
+    let text_edits = vec![
+        TextEdit {
+            range: Range::new(insert_position, insert_position),
+            new_text: format!("local {} = {}\n{}", name, expr.syntax().text(), indent),
+        },
+        TextEdit {
+            range: document.to_lsp_range(selection)?,
+            new_text: name,
+        },
+    ];
+
+    actions.push(CodeActionOrCommand::CodeAction(CodeAction {
+        title: t!("Extract to local variable").to_string(),
+        kind: Some(CodeActionKind::REFACTOR_EXTRACT),
+        edit: Some(WorkspaceEdit {
+            changes: Some(HashMap::from([(document.get_uri(), text_edits)])),
+            ..Default::default()
+        }),
+        ..Default::default()
+    }));
+
+    Some(())
+}
+
+/// 表达式位于 `and`/`or` 的右侧, 只在左侧不短路时才会求值
+fn is_short_circuited(expr: &LuaExpr, stat: &LuaStat) -> bool {
+    for node in expr.syntax().ancestors() {
+        if node == *stat.syntax() {
+            break;
+        }
+        let Some(LuaExpr::BinaryExpr(binary_expr)) = LuaExpr::cast(node.clone()) else {
+            continue;
+        };
+        let Some((_, right)) = binary_expr.get_exprs() else {
+            continue;
+        };
+        let is_logical = binary_expr
+            .get_op_token()
+            .is_some_and(|op| matches!(op.get_op(), BinaryOperator::OpAnd | BinaryOperator::OpOr));
+        if is_logical && right.syntax().text_range().contains_range(expr.get_range()) {
+            return true;
+        }
+    }
+
+    false
+}
+
+/// 去掉选区首尾的空白字符
+fn trim_range(text: &str, range: TextRange) -> TextRange {
+    let selected = &text[range];
+    let start =
+        range.start() + TextSize::of(&selected[..selected.len() - selected.trim_start().len()]);
+    let end = range.end() - TextSize::of(&selected[selected.trim_end().len()..]);
+    if start >= end {
+        return TextRange::empty(range.start());
+    }
+
+    TextRange::new(start, end)
+}
+
+fn can_extract(expr: &LuaExpr) -> bool {
+    let Some(parent) = expr.syntax().parent() else {
+        return false;
+    };
+
+    // 赋值语句左侧和函数定义的名字不是值
+    if let Some(assign_stat) = LuaAssignStat::cast(parent.clone()) {
+        let (vars, _) = assign_stat.get_var_and_expr_list();
+        if vars.iter().any(|var| var.syntax() == expr.syntax()) {
+            return false;
+        }
+    }
+    if LuaFuncStat::can_cast(parent.kind().into()) {
+        return false;
+    }
+
+    // `obj:method` 只能作为调用的前缀出现
+    if let LuaExpr::IndexExpr(index_expr) = expr
+        && index_expr
+            .get_index_token()
+            .is_some_and(|token| token.is_colon())
+    {
+        return false;
+    }
+
+    true
+}
+
+fn suggest_name(expr: &LuaExpr) -> String {
+    let name = match expr {
+        LuaExpr::CallExpr(call_expr) => get_callee_name(call_expr),
+        LuaExpr::IndexExpr(index_expr) => match index_expr.get_index_key() {
+            Some(LuaIndexKey::Name(name_token)) => Some(name_token.get_name_text().to_string()),
+            _ => None,
+        },
+        LuaExpr::ParenExpr(paren_expr) => paren_expr.get_expr().map(|inner| suggest_name(&inner)),
+        _ => None,
+    };
+
+    name.unwrap_or_else(|| "value".to_string())
+}
+
+fn get_callee_name(call_expr: &LuaCallExpr) -> Option<String> {
+    match call_expr.get_prefix_expr()? {
+        LuaExpr::NameExpr(name_expr) => name_expr.get_name_text(),
+        LuaExpr::IndexExpr(index_expr) => {
+            Some(index_expr.get_name_token()?.get_name_text().to_string())
+        }
+        _ => None,
+    }
+}
+
+/// 新变量不能与插入位置可见的局部变量, 以及所在代码块中用到的名字冲突
+fn get_unique_name(semantic_model: &SemanticModel, stat: &LuaStat, base: &str) -> Option<String> {
+    let decl_tree = semantic_model
+        .get_db()
+        .get_decl_index()
+        .get_decl_tree(&semantic_model.get_file_id())?;
+    let block = stat.get_parent::<LuaBlock>()?;
+    let mut used_names = block
+        .descendants::<LuaNameExpr>()
+        .filter_map(|name_expr| name_expr.get_name_text())
+        .collect::<HashSet<_>>();
+    used_names.extend(
+        block
+            .descendants::<LuaLocalName>()
+            .filter_map(|local_name| {
+                Some(local_name.get_name_token()?.get_name_text().to_string())
+            }),
+    );
+
+    let is_used = |name: &str| {
+        used_names.contains(name)
+            || decl_tree
+                .find_local_decl(name, stat.get_position())
+                .is_some()
+    };
+    if !is_used(base) {
+        return Some(base.to_string());
+    }
+
+    (1..)
+        .map(|i| format!("{}{}", base, i))
+        .find(|name| !is_used(name))
+}
+
+fn get_line_indent(text: &str, offset: TextSize) -> String {
+    let offset = usize::from(offset);
+    let line_start = text[..offset].rfind('\n').map_or(0, |pos| pos + 1);
+    text[line_start..offset]
+        .chars()
+        .take_while(|c| c.is_whitespace())
+        .collect()
+}
+
+pub fn build_inline_local_variable(
+    semantic_model: &SemanticModel,
+    actions: &mut Vec<CodeActionOrCommand>,
+    range: Range,
+) -> Option<()> {
+    let document = semantic_model.get_document();
+    let root = semantic_model.get_root();
+    let local_name = find_local_name(semantic_model, range)?;
+    let local_stat = local_name.get_parent::<LuaLocalStat>()?;
+    if local_stat.get_local_name_list().count() != 1
+        || local_name
+            .get_attrib()
+            .is_some_and(|attrib| attrib.is_close())
+    {
+        return None;
+    }
+    let mut values = local_stat.get_value_exprs();
+    let value = values.next()?;
+    if values.next().is_some() {
+        return None;
+    }
+
+    let file_id = semantic_model.get_file_id();
+    let decl_id = LuaDeclId::new(file_id, local_name.get_position());
+    let decl_refs = semantic_model
+        .get_db()
+        .get_reference_index()
+        .get_decl_references(&file_id, &decl_id)?;
+    if decl_refs.cells.is_empty() {
+        return None;
+    }
+    let name = local_name.get_name_token()?.get_name_text().to_string();
+    let title = t!("Inline local variable `%{name}`", name = name).to_string();
+
+    let read_exprs = decl_refs
+        .cells
+        .iter()
+        .filter_map(|cell| {
+            root.syntax()
+                .covering_element(cell.range)
+                .ancestors()
+                .find_map(LuaNameExpr::cast)
+        })
+        .collect::<Vec<_>>();
+    if read_exprs.len() != decl_refs.cells.len() {
+        return None;
+    }
+    if let Some(reason) = check_inline(semantic_model, &local_stat, &value, decl_refs, &read_exprs)
+    {
+        actions.push(CodeActionOrCommand::CodeAction(CodeAction {
+            title,
+            kind: Some(CodeActionKind::REFACTOR_INLINE),
+            disabled: Some(CodeActionDisabled { reason }),
+            ..Default::default()
+        }));
+        return Some(());
+    }
+
+    let value_text = value.syntax().text().to_string();
+    let mut text_edits = vec![TextEdit {
+        range: document.to_lsp_range(get_stat_delete_range(
+            document.get_text(),
+            local_stat.get_range(),
+        ))?,
+        new_text: String::new(),
+    }];
+    for read_expr in &read_exprs {
+        // 局部变量只保留第一个值, 在会展开多个值的位置需要用括号截断
+        let new_text = if need_paren(&value, read_expr)
+            || (is_multi_value_expr(&value)
+                && is_multi_value_position(&LuaExpr::NameExpr(read_expr.clone())))
+        {
+            format!("({})", value_text)
+        } else {
+            value_text.clone()
+        };
+        text_edits.push(TextEdit {
+            range: document.to_lsp_range(read_expr.get_range())?,
+            new_text,
+        });
+    }
+
+    actions.push(CodeActionOrCommand::CodeAction(CodeAction {
+        title,
+        kind: Some(CodeActionKind::REFACTOR_INLINE),
+        edit: Some(WorkspaceEdit {
+            changes: Some(HashMap::from([(document.get_uri(), text_edits)])),
+            ..Default::default()
+        }),
+        ..Default::default()
+    }));
+
+    Some(())
+}
+
+/// 返回不能内联的原因
+fn check_inline(
+    semantic_model: &SemanticModel,
+    local_stat: &LuaLocalStat,
+    value: &LuaExpr,
+    decl_refs: &DeclReference,
+    read_exprs: &[LuaNameExpr],
+) -> Option<String> {
+    if decl_refs.mutable {
+        return Some(t!("Cannot inline a variable that is reassigned").to_string());
+    }
+
+    if !is_pure_expr(value) {
+        if read_exprs.len() > 1 {
+            return Some(
+                t!("Cannot inline an expression with side effects that is used more than once")
+                    .to_string(),
+            );
+        }
+        if !read_exprs
+            .iter()
+            .all(|read_expr| is_used_in_next_stat(local_stat, read_expr))
+        {
+            return Some(t!("Inlining would change evaluation order").to_string());
+        }
+    }
+
+    if is_value_shadowed(semantic_model, value, read_exprs) {
+        return Some(
+            t!("A name used in the value refers to another variable at the use site").to_string(),
+        );
+    }
+
+    let last_read_position = read_exprs
+        .iter()
+        .map(|read_expr| read_expr.get_position())
+        .max()?;
+    if is_value_reassigned(
+        semantic_model,
+        value,
+        TextRange::new(local_stat.get_range().end(), last_read_position),
+    ) {
+        return Some(t!("Inlining would change evaluation order").to_string());
+    }
+
+    None
+}
+
+/// 调用, 表构造和闭包在每次求值时都会产生副作用或新的对象, 索引可能触发 `__index`
+fn is_pure_expr(expr: &LuaExpr) -> bool {
+    !expr.descendants::<LuaExpr>().any(|expr| {
+        matches!(
+            expr,
+            LuaExpr::CallExpr(_)
+                | LuaExpr::IndexExpr(_)
+                | LuaExpr::TableExpr(_)
+                | LuaExpr::ClosureExpr(_)
+        )
+    })
+}
+
+/// 值中的名字在读取位置指向了另一个局部变量
+fn is_value_shadowed(
+    semantic_model: &SemanticModel,
+    value: &LuaExpr,
+    read_exprs: &[LuaNameExpr],
+) -> bool {
+    let file_id = semantic_model.get_file_id();
+    let db = semantic_model.get_db();
+    let Some(decl_tree) = db.get_decl_index().get_decl_tree(&file_id) else {
+        return true;
+    };
+    let reference_index = db.get_reference_index();
+    value.descendants::<LuaNameExpr>().any(|name_expr| {
+        let Some(name) = name_expr.get_name_text() else {
+            return true;
+        };
+        let value_decl_id = reference_index.get_var_reference_decl(&file_id, name_expr.get_range());
+        read_exprs.iter().any(|read_expr| {
+            decl_tree
+                .find_local_decl(&name, read_expr.get_position())
+                .map(|decl| decl.get_id())
+                != value_decl_id
+        })
+    })
+}
+
+/// 读取位置位于声明的下一条语句中, 并且不在嵌套的代码块或闭包里
+fn is_used_in_next_stat(local_stat: &LuaLocalStat, read_expr: &LuaNameExpr) -> bool {
+    let Some(next_stat) = local_stat
+        .syntax()
+        .siblings(Direction::Next)
+        .skip(1)
+        .find_map(LuaStat::cast)
+    else {
+        return false;
+    };
+
+    for node in read_expr.syntax().ancestors() {
+        if node == *next_stat.syntax() {
+            return true;
+        }
+        if LuaBlock::can_cast(node.kind().into()) || LuaClosureExpr::can_cast(node.kind().into()) {
+            return false;
+        }
+    }
+
+    false
+}
+
+/// 值中引用的局部变量在声明和最后一次读取之间被重新赋值
+fn is_value_reassigned(semantic_model: &SemanticModel, value: &LuaExpr, range: TextRange) -> bool {
+    let file_id = semantic_model.get_file_id();
+    let reference_index = semantic_model.get_db().get_reference_index();
+    value.descendants::<LuaNameExpr>().any(|name_expr| {
+        reference_index
+            .get_var_reference_decl(&file_id, name_expr.get_range())
+            .and_then(|decl_id| reference_index.get_decl_references(&file_id, &decl_id))
+            .is_some_and(|decl_refs| {
+                decl_refs
+                    .cells
+                    .iter()
+                    .any(|cell| cell.is_write && range.contains(cell.range.start()))
+            })
+    })
+}
+
+fn need_paren(value: &LuaExpr, read_expr: &LuaNameExpr) -> bool {
+    let Some(parent) = read_expr.syntax().parent() else {
+        return false;
+    };
+
+    match LuaExpr::cast(parent) {
+        Some(LuaExpr::BinaryExpr(_) | LuaExpr::UnaryExpr(_)) => {
+            matches!(value, LuaExpr::BinaryExpr(_) | LuaExpr::UnaryExpr(_))
+        }
+        Some(LuaExpr::CallExpr(call_expr)) => {
+            is_prefix_of(call_expr.get_prefix_expr(), read_expr) && !is_prefix_expr(value)
+        }
+        Some(LuaExpr::IndexExpr(index_expr)) => {
+            is_prefix_of(index_expr.get_prefix_expr(), read_expr) && !is_prefix_expr(value)
+        }
+        _ => false,
+    }
+}
+
+/// 调用和 `...` 可以产生多个值
+fn is_multi_value_expr(expr: &LuaExpr) -> bool {
+    match expr {
+        LuaExpr::CallExpr(_) => true,
+        LuaExpr::LiteralExpr(literal_expr) => {
+            matches!(literal_expr.get_literal(), Some(LuaLiteralToken::Dots(_)))
+        }
+        _ => false,
+    }
+}
+
+/// 表达式是参数列表, 返回值列表, 赋值的值列表或表构造中的最后一项, 多个值会在这里全部展开
+fn is_multi_value_position(expr: &LuaExpr) -> bool {
+    let Some(parent) = expr.syntax().parent() else {
+        return false;
+    };
+    match parent.kind().into() {
+        LuaSyntaxKind::CallArgList
+        | LuaSyntaxKind::ReturnStat
+        | LuaSyntaxKind::LocalStat
+        | LuaSyntaxKind::AssignStat => parent
+            .children()
+            .filter(|child| LuaExpr::can_cast(child.kind().into()))
+            .last()
+            .is_some_and(|last| last == *expr.syntax()),
+        LuaSyntaxKind::TableFieldValue => parent.parent().is_some_and(|table| {
+            table
+                .children()
+                .filter(|child| LuaTableField::can_cast(child.kind().into()))
+                .last()
+                .is_some_and(|last| last == parent)
+        }),
+        _ => false,
+    }
+}
+
+fn is_prefix_of(prefix: Option<LuaExpr>, read_expr: &LuaNameExpr) -> bool {
+    prefix.is_some_and(|prefix| prefix.syntax() == read_expr.syntax())
+}
+
+/// 可以直接作为调用或索引前缀的表达式
+fn is_prefix_expr(expr: &LuaExpr) -> bool {
+    matches!(
+        expr,
+        LuaExpr::NameExpr(_) | LuaExpr::IndexExpr(_) | LuaExpr::CallExpr(_) | LuaExpr::ParenExpr(_)
+    )
+}
+
+/// 语句独占一行时连同缩进和换行一起删除
+fn get_stat_delete_range(text: &str, stat_range: TextRange) -> TextRange {
+    let start = usize::from(stat_range.start());
+    let end = usize::from(stat_range.end());
+    let line_start = text[..start].rfind('\n').map_or(0, |pos| pos + 1);
+    let line_end = text[end..]
+        .find('\n')
+        .map_or(text.len(), |pos| end + pos + 1);
+    if text[line_start..start].trim().is_empty() && text[end..line_end].trim().is_empty() {
+        return TextRange::new(
+            TextSize::from(line_start as u32),
+            TextSize::from(line_end as u32),
+        );
+    }
+
+    stat_range
+}
+
+pub fn build_class_stub_from_table(
+    semantic_model: &SemanticModel,
+    actions: &mut Vec<CodeActionOrCommand>,
+    range: Range,
+) -> Option<()> {
+    let local_name = find_local_name(semantic_model, range)?;
+    let local_stat = local_name.get_parent::<LuaLocalStat>()?;
+    let LuaExpr::TableExpr(table_expr) = local_stat.get_value_exprs().next()? else {
+        return None;
+    };
+    let name = local_name.get_name_token()?.get_name_text().to_string();
+    if !is_module_return(semantic_model, &name) {
+        return None;
+    }
+
+    let db = semantic_model.get_db();
+    let decl_id = LuaDeclId::new(semantic_model.get_file_id(), local_name.get_position());
+    // 已经有类型注解
+    if db
+        .get_type_index()
+        .get_type_cache(&decl_id.into())
+        .is_some_and(|type_cache| type_cache.is_doc())
+    {
+        return None;
+    }
+
+    let LuaType::TableConst(table_range) = semantic_model
+        .infer_expr(LuaExpr::TableExpr(table_expr))
+        .ok()?
+    else {
+        return None;
+    };
+
+    let document = semantic_model.get_document();
+    let indent = get_line_indent(document.get_text(), local_stat.get_position());
+    let mut stub = format!("---@class {}\n", name);
+    for (field_name, typ) in get_table_fields(semantic_model, &LuaMemberOwner::Element(table_range))
+    {
+        stub.push_str(&format!(
+            "{}---@field {} {}\n",
+            indent,
+            field_name,
+            get_stub_type_text(semantic_model, &typ, 0)
+        ));
+    }
+    stub.push_str(&indent);
+
+    let insert_position = document.to_lsp_position(local_stat.get_position())?;
+    actions.push(CodeActionOrCommand::CodeAction(CodeAction {
+        title: t!("Generate @class annotation for `%{name}`", name = name).to_string(),
+        kind: Some(CodeActionKind::REFACTOR_REWRITE),
+        edit: Some(WorkspaceEdit {
+            changes: Some(HashMap::from([(
+                document.get_uri(),
+                vec![TextEdit {
+                    range: Range::new(insert_position, insert_position),
+                    new_text: stub,
+                }],
+            )])),
+            ..Default::default()
+        }),
+        ..Default::default()
+    }));
+
+    Some(())
+}
+
+fn find_local_name(semantic_model: &SemanticModel, range: Range) -> Option<LuaLocalName> {
+    let document = semantic_model.get_document();
+    let offset = document.get_offset(range.start.line as usize, range.start.character as usize)?;
+    let token_at_offset = semantic_model.get_root().syntax().token_at_offset(offset);
+    [
+        token_at_offset.clone().right_biased(),
+        token_at_offset.left_biased(),
+    ]
+    .into_iter()
+    .flatten()
+    .find_map(|token| LuaLocalName::cast(token.parent()?))
+}
+
+/// 文件末尾的 `return name`
+fn is_module_return(semantic_model: &SemanticModel, name: &str) -> bool {
+    let Some(LuaStat::ReturnStat(return_stat)) = semantic_model
+        .get_root()
+        .get_block()
+        .and_then(|block| block.get_stats().last())
+    else {
+        return false;
+    };
+
+    let mut exprs = return_stat.get_expr_list();
+    matches!(
+        (exprs.next(), exprs.next()),
+        (Some(LuaExpr::NameExpr(name_expr)), None)
+            if name_expr.get_name_text().as_deref() == Some(name)
+    )
+}
+
+/// 表的成员包括字面量中的字段和之后通过 `M.x = ...` 或 `function M.f()` 添加的字段
+fn get_table_fields(
+    semantic_model: &SemanticModel,
+    owner: &LuaMemberOwner,
+) -> Vec<(String, LuaType)> {
+    let db = semantic_model.get_db();
+    db.get_member_index()
+        .get_sorted_members(owner)
+        .unwrap_or_default()
+        .into_iter()
+        .filter_map(|member| {
+            let key = member.get_key();
+            let LuaMemberKey::Name(field_name) = key else {
+                return None;
+            };
+            let field_name = if key.is_identifier() {
+                field_name.to_string()
+            } else {
+                format_string_key(&field_name)
+            };
+            let typ = db
+                .get_type_index()
+                .get_type_cache(&member.get_id().into())
+                .map(|type_cache| type_cache.as_type().clone())
+                .unwrap_or(LuaType::Unknown);
+            Some((field_name, typ))
+        })
+        .collect()
+}
+
+/// 嵌套的表生成内联的结构类型
+fn get_stub_type_text(semantic_model: &SemanticModel, typ: &LuaType, depth: usize) -> String {
+    match typ {
+        LuaType::Unknown | LuaType::Nil => "any".to_string(),
+        LuaType::BooleanConst(_) | LuaType::DocBooleanConst(_) => "boolean".to_string(),
+        LuaType::IntegerConst(_) | LuaType::DocIntegerConst(_) => "integer".to_string(),
+        LuaType::FloatConst(_) => "number".to_string(),
+        LuaType::StringConst(_) | LuaType::DocStringConst(_) => "string".to_string(),
+        LuaType::Signature(_) => "function".to_string(),
+        LuaType::TableConst(table_range) => {
+            let owner = LuaMemberOwner::Element(table_range.clone());
+            let fields = get_table_fields(semantic_model, &owner);
+            if fields.is_empty() || depth >= 2 {
+                return "table".to_string();
+            }
+
+            let fields = fields
+                .iter()
+                .map(|(field_name, typ)| {
+                    format!(
+                        "{}: {}",
+                        field_name,
+                        get_stub_type_text(semantic_model, typ, depth + 1)
+                    )
+                })
+                .collect::<Vec<_>>();
+            format!("{{ {} }}", fields.join(", "))
+        }
+        _ => humanize_type(semantic_model.get_db(), typ, RenderLevel::Simple),
+    }
+}
+
+pub fn build_show_type_details(
+    semantic_model: &SemanticModel,
+    actions: &mut Vec<CodeActionOrCommand>,
+    range: Range,
+) -> Option<()> {
+    let document = semantic_model.get_document();
+    let selection = trim_range(document.get_text(), document.to_rowan_range(range)?);
+    // 只在选中表达式时提供, 避免每个位置都出现
+    if selection.is_empty() {
+        return None;
+    }
+
+    semantic_model
+        .get_root()
+        .syntax()
+        .covering_element(selection)
+        .ancestors()
+        .filter_map(LuaExpr::cast)
+        .find(|expr| expr.get_range() == selection)?;
+
+    let title = t!("Show Type Details").to_string();
+    actions.push(CodeActionOrCommand::CodeAction(CodeAction {
+        title: title.clone(),
+        kind: Some(CodeActionKind::new("emmylua.dumpType")),
+        command: Some(make_dump_type_command(
+            &title,
+            &document.get_uri(),
+            document.to_lsp_range(selection)?,
+        )),
+        ..Default::default()
+    }));
+
+    Some(())
+}
+
+pub fn build_add_overload(
+    semantic_model: &SemanticModel,
+    actions: &mut Vec<CodeActionOrCommand>,
+    range: Range,
+) -> Option<()> {
+    let document = semantic_model.get_document();
+    let offset = document.get_offset(range.start.line as usize, range.start.character as usize)?;
+    let token = semantic_model
+        .get_root()
+        .syntax()
+        .token_at_offset(offset)
+        .right_biased()?;
+    let stat = token.parent_ancestors().find_map(LuaStat::cast)?;
+    let (name, closure) = match &stat {
+        LuaStat::FuncStat(func_stat) => (
+            func_stat.get_func_name()?.syntax().text().to_string(),
+            func_stat.get_closure()?,
+        ),
+        LuaStat::LocalFuncStat(local_func_stat) => (
+            local_func_stat
+                .get_local_name()?
+                .get_name_token()?
+                .get_name_text()
+                .to_string(),
+            local_func_stat.get_closure()?,
+        ),
+        _ => return None,
+    };
+    // 只在函数头上提供
+    if closure
+        .get_block()
+        .is_some_and(|block| offset >= block.get_position())
+    {
+        return None;
+    }
+
+    let signature_id = LuaSignatureId::from_closure(semantic_model.get_file_id(), &closure);
+    let signature = semantic_model
+        .get_db()
+        .get_signature_index()
+        .get(&signature_id)?;
+    let overload = format_overload(semantic_model, signature);
+
+    let indent = get_line_indent(document.get_text(), stat.get_position());
+    let insert_position = document.to_lsp_position(stat.get_position())?;
+    actions.push(CodeActionOrCommand::CodeAction(CodeAction {
+        title: t!("Add @overload for `%{name}`", name = name).to_string(),
+        kind: Some(CodeActionKind::REFACTOR_REWRITE),
+        edit: Some(WorkspaceEdit {
+            changes: Some(HashMap::from([(
+                document.get_uri(),
+                vec![TextEdit {
+                    range: Range::new(insert_position, insert_position),
+                    new_text: format!("---@overload {}\n{}", overload, indent),
+                }],
+            )])),
+            ..Default::default()
+        }),
+        ..Default::default()
+    }));
+
+    Some(())
+}
+
+/// 按注解语法生成 `fun(a: T, ...: U): R`, 参数的格式与悬浮提示一致.
+/// 类型不能被截断, 否则生成的注解无法解析
+fn format_overload(semantic_model: &SemanticModel, signature: &LuaSignature) -> String {
+    let db = semantic_model.get_db();
+    let params = build_function_params(
+        db,
+        &signature.to_doc_func_type(),
+        false,
+        RenderLevel::Annotation,
+    )
+    .join(", ");
+
+    let returns = if signature.return_docs.is_empty() {
+        let return_type = signature.get_return_type();
+        if return_type.is_nil() || return_type.is_unknown() {
+            Vec::new()
+        } else {
+            vec![humanize_type(db, &return_type, RenderLevel::Annotation)]
+        }
+    } else {
+        signature
+            .return_docs
+            .iter()
+            .map(|ret| humanize_type(db, &ret.type_ref, RenderLevel::Annotation))
+            .collect()
+    };
+
+    if returns.is_empty() {
+        format!("fun({})", params)
+    } else {
+        format!("fun({}): {}", params, returns.join(", "))
+    }
+}
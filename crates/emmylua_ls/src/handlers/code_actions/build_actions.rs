@@ -7,13 +7,16 @@ use lsp_types::{
 };
 
 use super::actions::{
-    build_add_doc_tag, build_disable_file_changes, build_disable_next_line_changes,
-    build_need_check_nil, build_preferred_local_alias_fix,
+    build_add_doc_tag, build_add_overload, build_class_stub_from_table, build_colon_call_fix,
+    build_disable_file_changes, build_disable_next_line_changes, build_extract_local_variable,
+    build_inline_local_variable, build_need_check_nil, build_preferred_local_alias_fix,
+    build_remove_unused_require, build_show_type_details,
 };
 use crate::handlers::command::{DisableAction, make_disable_code_command};
 
 pub fn build_actions(
     semantic_model: &SemanticModel,
+    range: Range,
     diagnostics: Vec<Diagnostic>,
 ) -> Option<CodeActionResponse> {
     let mut actions = Vec::new();
@@ -50,6 +53,8 @@ pub fn build_actions(
         }
     }
 
+    add_refactor_code_action(semantic_model, &mut actions, range);
+
     if actions.is_empty() {
         return None;
     }
@@ -67,15 +72,37 @@ fn add_fix_code_action(
     data: &Option<serde_json::Value>,
 ) -> Option<()> {
     match diagnostic_code {
-        DiagnosticCode::NeedCheckNil => build_need_check_nil(semantic_model, actions, range, data),
+        DiagnosticCode::NeedCheckNil | DiagnosticCode::CallPossiblyNil => {
+            build_need_check_nil(semantic_model, actions, range, data)
+        }
         DiagnosticCode::UnknownDocTag => build_add_doc_tag(semantic_model, actions, range, data),
         DiagnosticCode::PreferredLocalAlias => {
             build_preferred_local_alias_fix(semantic_model, actions, range, data)
         }
+        DiagnosticCode::ColonCallMismatch => {
+            build_colon_call_fix(semantic_model, actions, range, data)
+        }
+        DiagnosticCode::UnusedRequire => {
+            build_remove_unused_require(semantic_model, actions, range, data)
+        }
         _ => Some(()),
     }
 }
 
+fn add_refactor_code_action(
+    semantic_model: &SemanticModel,
+    actions: &mut Vec<CodeActionOrCommand>,
+    range: Range,
+) -> Option<()> {
+    build_extract_local_variable(semantic_model, actions, range);
+    build_inline_local_variable(semantic_model, actions, range);
+    build_class_stub_from_table(semantic_model, actions, range);
+    build_add_overload(semantic_model, actions, range);
+    build_show_type_details(semantic_model, actions, range);
+
+    Some(())
+}
+
 fn add_disable_code_action(
     semantic_model: &SemanticModel,
     actions: &mut Vec<CodeActionOrCommand>,
@@ -5,7 +5,7 @@ use build_actions::build_actions;
 use emmylua_code_analysis::{EmmyLuaAnalysis, FileId};
 use lsp_types::{
     ClientCapabilities, CodeActionParams, CodeActionProviderCapability, CodeActionResponse,
-    Diagnostic, ServerCapabilities,
+    Diagnostic, Range, ServerCapabilities,
 };
 use tokio_util::sync::CancellationToken;
 
@@ -20,20 +20,22 @@ pub async fn on_code_action_handler(
     _: CancellationToken,
 ) -> Option<CodeActionResponse> {
     let uri = params.text_document.uri;
+    let range = params.range;
     let diagnostics = params.context.diagnostics;
     let analysis = context.analysis().read().await;
     let file_id = analysis.get_file_id(&uri)?;
-    code_action(&analysis, file_id, diagnostics)
+    code_action(&analysis, file_id, range, diagnostics)
 }
 
 pub fn code_action(
     analysis: &EmmyLuaAnalysis,
     file_id: FileId,
+    range: Range,
     diagnostics: Vec<Diagnostic>,
 ) -> Option<CodeActionResponse> {
     let semantic_model = analysis.compilation.get_semantic_model(file_id)?;
 
-    build_actions(&semantic_model, diagnostics)
+    build_actions(&semantic_model, range, diagnostics)
 }
 
 pub struct CodeActionsCapabilities;
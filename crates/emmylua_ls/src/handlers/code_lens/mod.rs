@@ -1,12 +1,12 @@
 mod build_code_lens;
 mod resolve_code_lens;
 
-use build_code_lens::build_code_lens;
+pub use build_code_lens::build_code_lens;
 use emmylua_code_analysis::{LuaDeclId, LuaMemberId};
 use lsp_types::{
     ClientCapabilities, CodeLens, CodeLensOptions, CodeLensParams, ServerCapabilities,
 };
-use resolve_code_lens::resolve_code_lens;
+pub use resolve_code_lens::resolve_code_lens;
 use serde::{Deserialize, Serialize};
 use tokio_util::sync::CancellationToken;
 
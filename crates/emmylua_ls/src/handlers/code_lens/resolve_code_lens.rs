@@ -1,10 +1,7 @@
-use emmylua_code_analysis::LuaCompilation;
+use emmylua_code_analysis::{LuaCompilation, LuaSemanticDeclId};
 use lsp_types::{CodeLens, Command, Location, Range, Uri};
 
-use crate::{
-    context::ClientId,
-    handlers::references::{search_decl_references, search_member_references},
-};
+use crate::context::ClientId;
 
 use super::CodeLensData;
 
@@ -25,8 +22,8 @@ pub fn resolve_code_lens(
         CodeLensData::Member(member_id) => {
             let file_id = member_id.file_id;
             let semantic_model = compilation.get_semantic_model(file_id)?;
-            let mut results = Vec::new();
-            search_member_references(&semantic_model, compilation, member_id, &mut results);
+            let results =
+                semantic_model.find_references(LuaSemanticDeclId::Member(member_id), true);
             let mut ref_count = results.len();
             ref_count = ref_count.saturating_sub(1);
             let uri = semantic_model.get_document().get_uri();
@@ -41,8 +38,8 @@ pub fn resolve_code_lens(
         CodeLensData::DeclId(decl_id) => {
             let file_id = decl_id.file_id;
             let semantic_model = compilation.get_semantic_model(file_id)?;
-            let mut results = Vec::new();
-            search_decl_references(&semantic_model, compilation, decl_id, &mut results);
+            let results =
+                semantic_model.find_references(LuaSemanticDeclId::LuaDecl(decl_id), false);
             let ref_count = results.len();
             let uri = semantic_model.get_document().get_uri();
             let command = make_usage_command(uri, code_lens.range, ref_count, client_id, results);
@@ -14,7 +14,7 @@ pub struct AutoRequireCommand;
 impl CommandSpec for AutoRequireCommand {
     const COMMAND: &str = "emmy.auto.require";
 
-    async fn handle(context: ServerContextSnapshot, args: Vec<Value>) -> Option<()> {
+    async fn handle(context: ServerContextSnapshot, args: Vec<Value>) -> Option<Value> {
         let add_to: FileId = serde_json::from_value(args.first()?.clone()).ok()?;
         let need_require_file_id: FileId = serde_json::from_value(args.get(1)?.clone()).ok()?;
         let position: Position = serde_json::from_value(args.get(2)?.clone()).ok()?;
@@ -116,7 +116,7 @@ impl CommandSpec for AutoRequireCommand {
             }
         });
 
-        Some(())
+        Some(Value::Null)
     }
 }
 
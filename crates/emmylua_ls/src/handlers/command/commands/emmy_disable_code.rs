@@ -23,7 +23,7 @@ pub struct DisableCodeCommand;
 impl CommandSpec for DisableCodeCommand {
     const COMMAND: &str = "emmy.disable.code";
 
-    async fn handle(context: ServerContextSnapshot, args: Vec<Value>) -> Option<()> {
+    async fn handle(context: ServerContextSnapshot, args: Vec<Value>) -> Option<Value> {
         let action: DisableAction = serde_json::from_value(args.first()?.clone()).ok()?;
         let code: DiagnosticCode = serde_json::from_value(args.get(3)?.clone()).ok()?;
 
@@ -31,7 +31,7 @@ impl CommandSpec for DisableCodeCommand {
             add_disable_project(context.workspace_manager(), code).await;
         }
 
-        Some(())
+        Some(Value::Null)
     }
 }
 
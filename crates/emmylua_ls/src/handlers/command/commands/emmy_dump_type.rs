@@ -0,0 +1,102 @@
+use emmylua_code_analysis::{
+    EmmyLuaAnalysis, FileId, LuaSemanticDeclId, RenderLevel, SemanticDeclLevel, TypeDescriptor,
+    humanize_type,
+};
+use emmylua_parser::{LuaAstNode, LuaExpr, LuaStat, LuaTokenKind};
+use lsp_types::{Command, Position, Range, Uri};
+use rowan::TokenAtOffset;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::context::ServerContextSnapshot;
+
+use super::CommandSpec;
+
+#[derive(Debug, Serialize)]
+pub struct TypeDump {
+    #[serde(rename = "type")]
+    pub typ: String,
+    pub descriptor: TypeDescriptor,
+}
+
+pub struct DumpTypeCommand;
+
+impl CommandSpec for DumpTypeCommand {
+    const COMMAND: &str = "emmylua.dumpType";
+
+    async fn handle(context: ServerContextSnapshot, args: Vec<Value>) -> Option<Value> {
+        let uri: Uri = serde_json::from_value(args.first()?.clone()).ok()?;
+        // 参数可以是位置, 也可以是选中的范围
+        let range = match serde_json::from_value::<Range>(args.get(1)?.clone()) {
+            Ok(range) => range,
+            Err(_) => {
+                let position: Position = serde_json::from_value(args.get(1)?.clone()).ok()?;
+                Range::new(position, position)
+            }
+        };
+
+        let analysis = context.analysis().read().await;
+        let file_id = analysis.get_file_id(&uri)?;
+        let type_dump = dump_type(&analysis, file_id, range)?;
+        // 结果作为命令的返回值, 如何展示由客户端决定
+        serde_json::to_value(&type_dump).ok()
+    }
+}
+
+pub fn make_dump_type_command(title: &str, uri: &Uri, range: Range) -> Command {
+    let args = vec![
+        serde_json::to_value(uri).unwrap(),
+        serde_json::to_value(range).unwrap(),
+    ];
+
+    Command {
+        title: title.to_string(),
+        command: DumpTypeCommand::COMMAND.to_string(),
+        arguments: Some(args),
+    }
+}
+
+pub fn dump_type(analysis: &EmmyLuaAnalysis, file_id: FileId, range: Range) -> Option<TypeDump> {
+    let semantic_model = analysis.compilation.get_semantic_model(file_id)?;
+    let root = semantic_model.get_root();
+    let text_range = semantic_model.get_document().to_rowan_range(range)?;
+    if text_range.end() > root.syntax().text_range().end() {
+        return None;
+    }
+
+    // 光标位置时优先取右侧的 token, 落在空白后的名字上也能找到声明
+    let element = if text_range.is_empty() {
+        match root.syntax().token_at_offset(text_range.start()) {
+            TokenAtOffset::Single(token) => token.into(),
+            TokenAtOffset::Between(left, right) => {
+                if right.kind() == LuaTokenKind::TkWhitespace.into() {
+                    left.into()
+                } else {
+                    right.into()
+                }
+            }
+            TokenAtOffset::None => return None,
+        }
+    } else {
+        root.syntax().covering_element(text_range)
+    };
+    // 不越过语句, 避免在函数内的局部变量上取到整个闭包的类型
+    let expr = element
+        .ancestors()
+        .take_while(|node| !LuaStat::can_cast(node.kind().into()))
+        .find_map(LuaExpr::cast);
+    let typ = match expr {
+        Some(expr) => semantic_model.infer_expr(expr).ok()?,
+        None => match semantic_model.find_decl(element, SemanticDeclLevel::default())? {
+            LuaSemanticDeclId::LuaDecl(decl_id) => semantic_model.get_type(decl_id.into()),
+            LuaSemanticDeclId::Member(member_id) => semantic_model.get_type(member_id.into()),
+            _ => return None,
+        },
+    };
+
+    let db = semantic_model.get_db();
+    Some(TypeDump {
+        typ: humanize_type(db, &typ, RenderLevel::Detailed),
+        descriptor: typ.to_descriptor(db),
+    })
+}
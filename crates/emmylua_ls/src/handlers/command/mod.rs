@@ -19,8 +19,8 @@ pub async fn on_execute_command_handler(
 ) -> Option<Value> {
     let args = params.arguments;
     let command_name = params.command.as_str();
-    commands::dispatch_command(context, command_name, args).await;
-    Some(Value::Null)
+    let result = commands::dispatch_command(context, command_name, args).await;
+    Some(result.unwrap_or(Value::Null))
 }
 
 pub struct CommandCapabilities;
@@ -7,7 +7,8 @@ use crate::handlers::completion::{
 };
 
 use super::{
-    CallDisplay, check_visibility, get_completion_kind, get_description, get_detail, is_deprecated,
+    CallDisplay, add_signature_overloads, check_visibility, get_completion_kind, get_description,
+    get_detail, is_deprecated,
 };
 
 pub fn add_decl_completion(
@@ -32,9 +33,8 @@ pub fn add_decl_completion(
         ..Default::default()
     };
 
-    if is_deprecated(builder, property_owner.clone()) {
-        completion_item.deprecated = Some(true);
-    }
+    let deprecated = is_deprecated(builder, property_owner.clone()).then_some(true);
+    completion_item.deprecated = deprecated;
 
     if builder.support_snippets(typ) {
         if let Some(snippet) = get_function_snippet(builder, name, typ, CallDisplay::None) {
@@ -44,6 +44,18 @@ pub fn add_decl_completion(
     }
 
     builder.add_completion_item(completion_item)?;
+
+    if builder.overload_snippet {
+        add_signature_overloads(
+            builder,
+            &Some(property_owner),
+            typ,
+            CallDisplay::None,
+            deprecated,
+            name.to_string(),
+            overload_count,
+        );
+    }
     Some(())
 }
 
@@ -12,6 +12,7 @@ use crate::handlers::completion::{
     add_completions::get_function_snippet, completion_builder::CompletionBuilder,
     completion_data::CompletionData, providers::get_function_remove_nil,
 };
+use crate::util::format_string_key;
 
 use super::{
     CallDisplay, check_visibility, get_completion_kind, get_description, get_detail, is_deprecated,
@@ -43,6 +44,7 @@ pub fn add_member_completion(
     let mut can_add_snippet = true;
     let label = match status {
         CompletionTriggerStatus::Dot => match member_key {
+            LuaMemberKey::Name(name) if !member_key.is_identifier() => format_string_key(name),
             LuaMemberKey::Name(name) => name.to_string(),
             LuaMemberKey::Integer(index) => format!("[{}]", index),
             LuaMemberKey::ExprType(typ) => {
@@ -68,7 +70,7 @@ pub fn add_member_completion(
             _ => return None,
         },
         CompletionTriggerStatus::Colon => match member_key {
-            LuaMemberKey::Name(name) => name.to_string(),
+            LuaMemberKey::Name(name) if member_key.is_identifier() => name.to_string(),
             _ => return None,
         },
         CompletionTriggerStatus::InString => {
@@ -129,8 +131,9 @@ pub fn add_member_completion(
         ..Default::default()
     };
 
+    // `[1]` 与 `["some-key"]` 不能跟在 `.` 后面
     if status == CompletionTriggerStatus::Dot
-        && member_key.is_integer()
+        && !member_key.is_identifier()
         && builder.trigger_token.kind() == LuaTokenKind::TkDot.into()
     {
         let document = builder.semantic_model.get_document();
@@ -184,7 +187,7 @@ pub fn add_member_completion(
     Some(())
 }
 
-fn add_signature_overloads(
+pub fn add_signature_overloads(
     builder: &mut CompletionBuilder,
     property_owner: &Option<LuaSemanticDeclId>,
     typ: &LuaType,
@@ -218,7 +221,7 @@ fn add_signature_overloads(
             } else {
                 None
             };
-            let completion_item = CompletionItem {
+            let mut completion_item = CompletionItem {
                 label: label.clone(),
                 kind: Some(get_completion_kind(&typ)),
                 data,
@@ -230,6 +233,13 @@ fn add_signature_overloads(
                 ..Default::default()
             };
 
+            if builder.overload_snippet
+                && let Some(snippet) = get_function_snippet(builder, &label, &typ, call_display)
+            {
+                completion_item.insert_text = Some(snippet);
+                completion_item.insert_text_format = Some(lsp_types::InsertTextFormat::SNIPPET);
+            }
+
             builder.add_completion_item(completion_item);
         });
     Some(())
@@ -3,6 +3,7 @@ mod add_member_completion;
 mod check_match_word;
 
 pub use add_decl_completion::add_decl_completion;
+use add_member_completion::add_signature_overloads;
 pub use add_member_completion::get_index_alias_name;
 pub use add_member_completion::{CompletionTriggerStatus, add_member_completion};
 pub use check_match_word::check_match_word;
@@ -19,6 +19,8 @@ pub struct CompletionBuilder<'a> {
     /// 是否为空格字符触发的补全(非主动触发)
     pub is_space_trigger_character: bool,
     pub position_offset: TextSize,
+    /// 是否为函数的每个重载单独提供调用代码片段
+    pub overload_snippet: bool,
 }
 
 impl<'a> CompletionBuilder<'a> {
@@ -36,6 +38,9 @@ impl<'a> CompletionBuilder<'a> {
             false
         };
 
+        let emmyrc = semantic_model.get_db().get_emmyrc();
+        let overload_snippet = emmyrc.completion.call_snippet && emmyrc.completion.overload_snippet;
+
         let mut builder = Self {
             trigger_token,
             semantic_model,
@@ -46,6 +51,7 @@ impl<'a> CompletionBuilder<'a> {
             trigger_kind,
             is_space_trigger_character,
             position_offset,
+            overload_snippet,
         };
         builder.context = CompletionContext::analyze(&builder);
         builder
@@ -34,3 +34,34 @@ pub const DOC_TAGS: &[&str] = &[
     "language",
     "attribute",
 ];
+
+/// 需要参数的标签在补全时插入的片段, 不需要参数的标签只插入名称
+pub fn get_doc_tag_snippet(tag: &str) -> Option<&'static str> {
+    let snippet = match tag {
+        "class" => "class ${1:Name}",
+        "enum" => "enum ${1:Name}",
+        "interface" => "interface ${1:Name}",
+        "alias" => "alias ${1:Name} ${2:type}",
+        "field" => "field ${1:name} ${2:type}",
+        "type" => "type ${1:type}",
+        "param" => "param ${1:name} ${2:type}",
+        "return" => "return ${1:type}",
+        "generic" => "generic ${1:T}",
+        "see" => "see ${1:name}",
+        "cast" => "cast ${1:name} ${2:type}",
+        "overload" => "overload fun(${1}): ${2:any}",
+        "diagnostic" => "diagnostic ${1|disable-next-line,disable-line,disable,enable|}: ${2:code}",
+        "version" => "version ${1:5.4}",
+        "as" => "as ${1:type}",
+        "operator" => "operator ${1:add}(${2:type}): ${3:type}",
+        "module" => "module \"${1:name}\"",
+        "namespace" => "namespace ${1:name}",
+        "using" => "using ${1:name}",
+        "source" => "source ${1:path}",
+        "return_cast" => "return_cast ${1:name} ${2:type}",
+        "language" => "language ${1:name}",
+        _ => return None,
+    };
+
+    Some(snippet)
+}
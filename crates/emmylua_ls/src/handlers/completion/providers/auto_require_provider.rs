@@ -59,7 +59,8 @@ fn complete_provider(builder: &mut CompletionBuilder) -> Option<()> {
     let file_conversion = emmyrc.completion.auto_require_naming_convention;
     let version_number = emmyrc.runtime.version.to_lua_version_number();
     let file_id = builder.semantic_model.get_file_id();
-    let module_index = builder.semantic_model.get_db().get_module_index();
+    let db = builder.semantic_model.get_db();
+    let module_index = db.get_module_index();
     let module_infos = module_index.get_module_infos();
     let range = builder.trigger_token.text_range();
     let document = builder.semantic_model.get_document();
@@ -71,6 +72,7 @@ fn complete_provider(builder: &mut CompletionBuilder) -> Option<()> {
             && module_info.file_id != file_id
             && module_info.export_type.is_some()
             && !module_index.is_std(&module_info.file_id)
+            && !db.is_ignored(&module_info.file_id)
         {
             add_module_completion_item(
                 builder,
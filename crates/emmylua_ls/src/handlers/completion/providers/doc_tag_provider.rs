@@ -1,4 +1,7 @@
-use crate::handlers::completion::{completion_builder::CompletionBuilder, data::DOC_TAGS};
+use crate::handlers::completion::{
+    completion_builder::CompletionBuilder,
+    data::{DOC_TAGS, get_doc_tag_snippet},
+};
 use crate::meta_text::meta_doc_tag;
 use emmylua_parser::{
     LuaAst, LuaAstToken, LuaComment, LuaDocTag, LuaExpr, LuaGeneralToken, LuaTokenKind,
@@ -63,6 +66,7 @@ fn complete_provider(builder: &mut CompletionBuilder) -> Option<()> {
 }
 
 fn add_tag_completion(builder: &mut CompletionBuilder, sorted_index: usize, tag: &str) {
+    let snippet = get_doc_tag_snippet(tag);
     let completion_item = CompletionItem {
         label: tag.to_string(),
         kind: Some(lsp_types::CompletionItemKind::EVENT),
@@ -71,6 +75,8 @@ fn add_tag_completion(builder: &mut CompletionBuilder, sorted_index: usize, tag:
             value: meta_doc_tag(tag),
         })),
         sort_text: Some(format!("{:03}", sorted_index)),
+        insert_text: snippet.map(str::to_string),
+        insert_text_format: snippet.map(|_| lsp_types::InsertTextFormat::SNIPPET),
         ..Default::default()
     };
 
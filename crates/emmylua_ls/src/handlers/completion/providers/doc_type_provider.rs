@@ -66,6 +66,12 @@ pub fn complete_types_by_prefix(
         {
             continue;
         }
+        // 只在 `workspace.silentGlobs` 匹配的文件中声明的类型不参与补全
+        if let Some(decl_id) = &type_decl
+            && is_silent_type(builder, decl_id)
+        {
+            continue;
+        }
         match completion_type {
             CompletionType::AttributeUse => {
                 if let Some(decl_id) = type_decl {
@@ -98,6 +104,14 @@ pub fn complete_types_by_prefix(
     Some(())
 }
 
+fn is_silent_type(builder: &CompletionBuilder, decl_id: &LuaTypeDeclId) -> bool {
+    let db = builder.semantic_model.get_db();
+    db.get_type_index()
+        .get_type_decl(decl_id)
+        .and_then(|type_decl| type_decl.get_locations().first())
+        .is_some_and(|location| db.is_ignored(&location.file_id))
+}
+
 pub enum CompletionType {
     Type,
     AttributeUse,
@@ -237,6 +237,14 @@ pub fn add_global_env(
         if duplicated_name.contains(&name) {
             continue;
         }
+        // 匹配 `workspace.silentGlobs` 的文件中定义的全局变量不参与补全
+        if builder
+            .semantic_model
+            .get_db()
+            .is_ignored(&decl.get_file_id())
+        {
+            continue;
+        }
         if !env_check_match_word(trigger_text, name.as_str()) {
             duplicated_name.insert(name.clone());
             continue;
@@ -87,10 +87,13 @@ pub fn add_modules(
     };
 
     let db = builder.semantic_model.get_db();
+    let module_index = db.get_module_index();
     let mut module_completions = Vec::new();
-    let module_info = db.get_module_index().find_module_node(&module_path)?;
+    let module_info = module_index.find_module_node(&module_path)?;
     for (name, module_id) in &module_info.children {
-        let child_module_node = db.get_module_index().get_module_node(module_id)?;
+        let Some(child_module_node) = module_index.get_module_node(module_id) else {
+            continue;
+        };
         let filter_text = format!("{}{}", prefix, name);
         let text_edit = text_edit_range.map(|text_edit_range| {
             CompletionTextEdit::Edit(TextEdit {
@@ -98,28 +101,36 @@ pub fn add_modules(
                 new_text: filter_text.clone(),
             })
         });
-        if let Some(child_file_id) = child_module_node.file_ids.first() {
-            let child_module_info = db.get_module_index().get_module(*child_file_id)?;
+        // `a.lua` 和 `a/init.lua` 等多个文件可能对应同一个模块, 只取第一个可见且未被静默的文件
+        let child_module_info = child_module_node
+            .file_ids
+            .iter()
+            .filter_map(|file_id| module_index.get_module(*file_id))
+            .find(|module_info| {
+                module_info.is_visible(&version_number) && !db.is_ignored(&module_info.file_id)
+            });
+        if let Some(child_module_info) = child_module_info {
             let data = if let Some(property_id) = &child_module_info.semantic_id {
                 CompletionData::from_property_owner_id(builder, property_id.clone(), None)
             } else {
                 None
             };
 
-            if child_module_info.is_visible(&version_number) {
-                let uri = db.get_vfs().get_uri(child_file_id)?;
-                let completion_item = CompletionItem {
-                    label: name.clone(),
-                    kind: Some(lsp_types::CompletionItemKind::FILE),
-                    filter_text: Some(filter_text),
-                    text_edit,
-                    detail: Some(uri.to_string()),
-                    data,
-                    ..Default::default()
-                };
-                module_completions.push(completion_item);
-            }
-        } else {
+            let detail = db
+                .get_vfs()
+                .get_uri(&child_module_info.file_id)
+                .map(|uri| uri.to_string());
+            let completion_item = CompletionItem {
+                label: name.clone(),
+                kind: Some(lsp_types::CompletionItemKind::FILE),
+                filter_text: Some(filter_text),
+                text_edit,
+                detail,
+                data,
+                ..Default::default()
+            };
+            module_completions.push(completion_item);
+        } else if !child_module_node.children.is_empty() {
             let completion_item = CompletionItem {
                 label: name.clone(),
                 kind: Some(lsp_types::CompletionItemKind::FOLDER),
@@ -132,7 +143,6 @@ pub fn add_modules(
         }
     }
 
-    let _ = module_info;
     for completion_item in module_completions {
         builder.add_completion_item(completion_item)?;
     }
@@ -1,5 +1,5 @@
-use emmylua_code_analysis::Emmyrc;
-use emmylua_parser::{LuaAstNode, LuaSyntaxToken, LuaTokenKind};
+use emmylua_code_analysis::{Emmyrc, RenderLevel, SemanticDeclLevel, humanize_type};
+use emmylua_parser::{LuaAstNode, LuaExpr, LuaStat, LuaSyntaxNode, LuaSyntaxToken, LuaTokenKind};
 use lsp_types::{CompletionItem, Range};
 use rowan::{TextRange, TextSize, TokenAtOffset};
 
@@ -64,6 +64,7 @@ fn complete_provider(builder: &mut CompletionBuilder) -> Option<()> {
         }
         TokenAtOffset::None => return None,
     };
+    let left_node = left_token.parent()?;
     let (text_range, replace_range) = get_left_valid_range(left_token, trigger_pos.into())?;
 
     let (left_token_text, replace_lsp_range) = {
@@ -170,9 +171,69 @@ fn complete_provider(builder: &mut CompletionBuilder) -> Option<()> {
         format!("{0} = {0} - $1", left_token_text),
     );
 
+    add_cast_postfix_completions(builder, replace_lsp_range, &left_token_text, left_node);
+
     Some(())
 }
 
+/// `expr@as` 插入 `--[[@as type]]`, 若 `expr` 是可以被 `---@cast` 的变量, 则额外提供 `expr@cast`.
+/// 类型占位符预填为 `expr` 当前推断的类型
+fn add_cast_postfix_completions(
+    builder: &mut CompletionBuilder,
+    replace_range: Range,
+    left_token_text: &str,
+    left_node: LuaSyntaxNode,
+) -> Option<()> {
+    let expr = LuaExpr::cast(left_node)?;
+    let semantic_model = &builder.semantic_model;
+    let typ = semantic_model.infer_expr(expr.clone()).ok();
+    let type_text = match typ {
+        Some(typ) if !typ.is_unknown() => escape_snippet(&humanize_type(
+            semantic_model.get_db(),
+            &typ,
+            RenderLevel::Minimal,
+        )),
+        _ => "type".to_string(),
+    };
+    let is_cast_target = matches!(expr, LuaExpr::NameExpr(_))
+        && is_stat_start(&expr)
+        && semantic_model
+            .find_decl(expr.syntax().clone().into(), SemanticDeclLevel::default())
+            .is_some();
+
+    add_postfix_completion(
+        builder,
+        replace_range,
+        "as",
+        format!("{} --[[@as ${{1:{}}}]]", left_token_text, type_text),
+    );
+
+    if is_cast_target {
+        add_postfix_completion(
+            builder,
+            replace_range,
+            "cast",
+            format!("---@cast {} ${{1:{}}}", left_token_text, type_text),
+        );
+    }
+
+    Some(())
+}
+
+/// 生成的 `---@cast` 注释占一整行, 只能替换位于语句开头的表达式
+fn is_stat_start(expr: &LuaExpr) -> bool {
+    expr.syntax().parent().is_some_and(|parent| {
+        LuaStat::can_cast(parent.kind().into())
+            && parent.text_range().start() == expr.get_position()
+    })
+}
+
+fn escape_snippet(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace('$', "\\$")
+        .replace('}', "\\}")
+}
+
 fn is_postfix_trigger(trigger_kind: LuaTokenKind, emmyrc: &Emmyrc) -> bool {
     let trigger_string = &emmyrc.completion.postfix;
     if trigger_string.is_empty() {
@@ -1,7 +1,7 @@
 use std::collections::HashSet;
 
 use emmylua_code_analysis::{
-    InferGuard, LuaMemberInfo, LuaMemberKey, LuaType, get_real_type,
+    InferGuard, LuaMemberInfo, LuaMemberKey, LuaType, RenderLevel, get_real_type, humanize_type,
     infer_table_field_value_should_be,
 };
 use emmylua_parser::{LuaAst, LuaAstNode, LuaKind, LuaTableExpr, LuaTableField, LuaTokenKind};
@@ -14,6 +14,7 @@ use crate::handlers::completion::{
     completion_data::CompletionData,
     providers::function_provider::dispatch_type,
 };
+use crate::util::format_string_key;
 
 use super::{CompletionProvider, ProviderDecision};
 
@@ -217,7 +218,8 @@ fn add_field_key_completion(
         check_visibility(builder, property_owner.clone())?;
     }
 
-    let name = match member_info.key {
+    let name = match &member_info.key {
+        LuaMemberKey::Name(name) if !member_info.key.is_identifier() => format_string_key(name),
         LuaMemberKey::Name(name) => name.to_string(),
         LuaMemberKey::Integer(index) => format!("[{}]", index),
         _ => return None,
@@ -266,6 +268,17 @@ fn add_field_key_completion(
         .as_ref()
         .map(|id| is_deprecated(builder, id.clone()));
 
+    // 显示字段声明的类型
+    let description = if typ.is_unknown() {
+        None
+    } else {
+        Some(humanize_type(
+            builder.semantic_model.get_db(),
+            &typ,
+            RenderLevel::Minimal,
+        ))
+    };
+
     let completion_item = CompletionItem {
         label,
         kind: Some(lsp_types::CompletionItemKind::PROPERTY),
@@ -273,6 +286,10 @@ fn add_field_key_completion(
         deprecated,
         insert_text: Some(insert_text),
         insert_text_format,
+        label_details: Some(lsp_types::CompletionItemLabelDetails {
+            detail: None,
+            description,
+        }),
         ..Default::default()
     };
 
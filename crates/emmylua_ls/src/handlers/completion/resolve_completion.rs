@@ -109,7 +109,10 @@ fn build_vscode_completion_item(
 
         if let Some(type_expansion) = hover_builder.type_expansion {
             for type_expansion in type_expansion {
-                result.push_str(&format!("\n```{}\n{}\n```\n", "lua", type_expansion));
+                result.push_str(&format!(
+                    "\n```{}\n{}\n```\n",
+                    hover_builder.code_block_language, type_expansion
+                ));
             }
         }
 
@@ -164,7 +167,10 @@ fn build_other_completion_item(
 
     if let Some(type_expansion) = hover_builder.type_expansion {
         for type_expansion in type_expansion {
-            result.push_str(&format!("\n```{}\n{}\n```\n", "lua", type_expansion));
+            result.push_str(&format!(
+                "\n```{}\n{}\n```\n",
+                hover_builder.code_block_language, type_expansion
+            ));
         }
     }
 
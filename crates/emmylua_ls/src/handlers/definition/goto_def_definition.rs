@@ -283,17 +283,15 @@ pub fn goto_str_tpl_ref_definition(
     let func = semantic_model.infer_call_expr_func(call_expr.clone(), None)?;
     let params = func.get_params();
 
-    let target_param = match (func.is_colon_define(), call_expr.is_colon_call()) {
-        (false, true) => params.get(string_token_idx + 1),
-        (true, false) => {
-            if string_token_idx > 0 {
-                params.get(string_token_idx - 1)
-            } else {
-                None
-            }
-        }
-        _ => params.get(string_token_idx),
-    }?;
+    let param_idx = match (func.is_colon_define(), call_expr.is_colon_call()) {
+        (false, true) => string_token_idx + 1,
+        (true, false) => string_token_idx.checked_sub(1)?,
+        _ => string_token_idx,
+    };
+    // 超出参数个数时由可变参数 `...` 接收
+    let target_param = params
+        .get(param_idx)
+        .or_else(|| params.last().filter(|(name, _)| name == "..."))?;
     // 首先尝试直接匹配StrTplRef类型
     if let Some(locations) =
         try_extract_str_tpl_ref_locations(semantic_model, &target_param.1, &name)
@@ -1,9 +1,12 @@
 use emmylua_code_analysis::{
-    LuaDeclId, LuaDocument, LuaSemanticDeclId, SemanticDeclLevel, SemanticModel,
+    LuaDeclId, LuaDocument, LuaMemberId, LuaSemanticDeclId, SemanticDeclLevel, SemanticModel,
+};
+use emmylua_parser::{
+    LuaAssignStat, LuaAstNode, LuaDocTagField, LuaFuncStat, LuaIndexExpr, LuaSyntaxKind,
+    LuaSyntaxNode, LuaSyntaxToken, LuaTableField, LuaTokenKind,
 };
-use emmylua_parser::{LuaAstNode, LuaSyntaxKind, LuaSyntaxNode, LuaSyntaxToken, LuaTokenKind};
 use lsp_types::{DocumentHighlight, DocumentHighlightKind};
-use rowan::NodeOrToken;
+use rowan::{NodeOrToken, TextRange};
 
 pub fn highlight_tokens(
     semantic_model: &SemanticModel,
@@ -18,6 +21,12 @@ pub fn highlight_tokens(
                 Some(LuaSemanticDeclId::LuaDecl(decl_id)) => {
                     highlight_decl_references(semantic_model, decl_id, token, &mut result);
                 }
+                Some(LuaSemanticDeclId::Member(member_id)) => {
+                    highlight_member_references(semantic_model, member_id, &mut result);
+                    if result.is_empty() {
+                        highlight_name(semantic_model, token, &mut result);
+                    }
+                }
                 _ => {
                     highlight_name(semantic_model, token, &mut result);
                 }
@@ -65,12 +74,114 @@ fn highlight_decl_references(
 
         return Some(());
     } else {
-        highlight_name(semantic_model, token, result);
+        let global_refs = semantic_model
+            .get_db()
+            .get_reference_index()
+            .get_global_file_references(decl.get_name(), semantic_model.get_file_id())
+            .unwrap_or_default();
+        let root = semantic_model.get_root();
+        for syntax_id in global_refs {
+            if let Some(node) = syntax_id.to_node_from_root(root.syntax()) {
+                push_reference_highlight(&document, &node, result);
+            }
+        }
+
+        if result.is_empty() {
+            highlight_name(semantic_model, token, result);
+        }
     }
 
     Some(())
 }
 
+fn highlight_member_references(
+    semantic_model: &SemanticModel,
+    member_id: LuaMemberId,
+    result: &mut Vec<DocumentHighlight>,
+) -> Option<()> {
+    let db = semantic_model.get_db();
+    let member = db.get_member_index().get_member(&member_id)?;
+    let index_refs = db
+        .get_reference_index()
+        .get_index_references(member.get_key())?;
+    let file_id = semantic_model.get_file_id();
+    let root = semantic_model.get_root();
+    let document = semantic_model.get_document();
+    let semantic_id = LuaSemanticDeclId::Member(member_id);
+    for in_filed_syntax_id in index_refs {
+        if in_filed_syntax_id.file_id != file_id {
+            continue;
+        }
+        let Some(node) = in_filed_syntax_id.value.to_node_from_root(root.syntax()) else {
+            continue;
+        };
+        if semantic_model.is_reference_to(
+            node.clone(),
+            semantic_id.clone(),
+            SemanticDeclLevel::default(),
+        ) {
+            push_reference_highlight(&document, &node, result);
+        }
+    }
+
+    Some(())
+}
+
+fn push_reference_highlight(
+    document: &LuaDocument,
+    node: &LuaSyntaxNode,
+    result: &mut Vec<DocumentHighlight>,
+) -> Option<()> {
+    let range = document.to_lsp_range(get_reference_range(node))?;
+    // 全局变量的定义同时也会被记录为引用
+    if result.iter().any(|highlight| highlight.range == range) {
+        return Some(());
+    }
+
+    result.push(DocumentHighlight {
+        range,
+        kind: get_reference_kind(node),
+    });
+    Some(())
+}
+
+/// 只高亮成员的名字, 而不是整个索引表达式
+fn get_reference_range(node: &LuaSyntaxNode) -> TextRange {
+    let key_range = if let Some(index_expr) = LuaIndexExpr::cast(node.clone()) {
+        index_expr.get_index_key().and_then(|key| key.get_range())
+    } else if let Some(table_field) = LuaTableField::cast(node.clone()) {
+        table_field.get_field_key().and_then(|key| key.get_range())
+    } else if let Some(doc_field) = LuaDocTagField::cast(node.clone()) {
+        doc_field.get_field_key_range()
+    } else {
+        None
+    };
+
+    key_range.unwrap_or(node.text_range())
+}
+
+/// 赋值语句左侧, 函数定义的名字和表字段是写入, 文档中的字段声明与局部变量的声明一样不区分读写
+fn get_reference_kind(node: &LuaSyntaxNode) -> Option<DocumentHighlightKind> {
+    if LuaDocTagField::can_cast(node.kind().into()) {
+        return None;
+    }
+    if LuaTableField::can_cast(node.kind().into()) {
+        return Some(DocumentHighlightKind::WRITE);
+    }
+
+    let parent = node.parent()?;
+    if let Some(assign_stat) = LuaAssignStat::cast(parent.clone()) {
+        let (vars, _) = assign_stat.get_var_and_expr_list();
+        if vars.iter().any(|var| var.syntax() == node) {
+            return Some(DocumentHighlightKind::WRITE);
+        }
+    } else if LuaFuncStat::can_cast(parent.kind().into()) {
+        return Some(DocumentHighlightKind::WRITE);
+    }
+
+    Some(DocumentHighlightKind::READ)
+}
+
 fn highlight_name(
     semantic_model: &SemanticModel,
     token: LuaSyntaxToken,
@@ -1,9 +1,11 @@
 mod highlight_tokens;
 
+use emmylua_code_analysis::{EmmyLuaAnalysis, FileId};
 use emmylua_parser::{LuaAstNode, LuaTokenKind};
 use highlight_tokens::highlight_tokens;
 use lsp_types::{
-    ClientCapabilities, DocumentHighlight, DocumentHighlightParams, OneOf, ServerCapabilities,
+    ClientCapabilities, DocumentHighlight, DocumentHighlightParams, OneOf, Position,
+    ServerCapabilities,
 };
 use rowan::TokenAtOffset;
 use tokio_util::sync::CancellationToken;
@@ -21,6 +23,15 @@ pub async fn on_document_highlight_handler(
     let analysis = context.analysis().read().await;
     let file_id = analysis.get_file_id(&uri)?;
     let position = params.text_document_position_params.position;
+
+    document_highlight(&analysis, file_id, position)
+}
+
+pub fn document_highlight(
+    analysis: &EmmyLuaAnalysis,
+    file_id: FileId,
+    position: Position,
+) -> Option<Vec<DocumentHighlight>> {
     let semantic_model = analysis.compilation.get_semantic_model(file_id)?;
     let root = semantic_model.get_root();
     let position_offset = {
@@ -1,11 +1,11 @@
 use super::RegisterCapabilities;
 use crate::context::ServerContextSnapshot;
 use crate::util::parse_desc;
-use emmylua_code_analysis::{SemanticModel, WorkspaceId};
-use emmylua_parser::{LuaAstNode, LuaDocDescription};
+use emmylua_code_analysis::{EmmyLuaAnalysis, FileId, SemanticModel, WorkspaceId};
+use emmylua_parser::{LuaAstNode, LuaDocDescription, LuaTokenKind};
 use lsp_types::{
-    ClientCapabilities, SelectionRange, SelectionRangeParams, SelectionRangeProviderCapability,
-    ServerCapabilities,
+    ClientCapabilities, Position, SelectionRange, SelectionRangeParams,
+    SelectionRangeProviderCapability, ServerCapabilities,
 };
 use rowan::{TextRange, TextSize, TokenAtOffset};
 use tokio_util::sync::CancellationToken;
@@ -16,19 +16,33 @@ pub async fn on_document_selection_range_handle(
     _: CancellationToken,
 ) -> Option<Vec<SelectionRange>> {
     let uri = params.text_document.uri;
-    let position = params.positions;
-
     let analysis = context.analysis().read().await;
     let file_id = analysis.get_file_id(&uri)?;
+
+    document_selection_range(&analysis, file_id, params.positions)
+}
+
+pub fn document_selection_range(
+    analysis: &EmmyLuaAnalysis,
+    file_id: FileId,
+    positions: Vec<Position>,
+) -> Option<Vec<SelectionRange>> {
     let semantic_model = analysis.compilation.get_semantic_model(file_id)?;
     let document = semantic_model.get_document();
     let root = semantic_model.get_root();
     let mut result = Vec::new();
-    for pos in position {
+    for pos in positions {
         let offset = document.get_offset(pos.line as usize, pos.character as usize)?;
         let token = match root.syntax().token_at_offset(offset) {
             TokenAtOffset::Single(token) => token,
-            TokenAtOffset::Between(_, right) => right,
+            // 光标在标识符末尾时选中左侧的标识符
+            TokenAtOffset::Between(left, right) => {
+                if left.kind() == LuaTokenKind::TkName.into() {
+                    left
+                } else {
+                    right
+                }
+            }
             TokenAtOffset::None => {
                 return None;
             }
@@ -48,6 +62,8 @@ pub async fn on_document_selection_range_handle(
             let range = ancestor.text_range();
             ranges.push(range);
         }
+        // 相同范围的节点只保留一层, 否则扩展选区时会停顿
+        ranges.dedup();
 
         let mut parent: Option<Box<SelectionRange>> = None;
         for range in ranges.into_iter().rev() {
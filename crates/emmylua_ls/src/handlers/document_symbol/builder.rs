@@ -1,7 +1,8 @@
 use std::collections::HashMap;
 
 use emmylua_code_analysis::{
-    DbIndex, FileId, LuaDecl, LuaDeclId, LuaDeclarationTree, LuaDocument, LuaType, LuaTypeOwner,
+    DbIndex, FileId, LuaDecl, LuaDeclId, LuaDeclarationTree, LuaDocument, LuaMemberId,
+    LuaMemberOwner, LuaType, LuaTypeDeclId, LuaTypeOwner,
 };
 use emmylua_parser::{LuaAstNode, LuaChunk, LuaSyntaxId, LuaSyntaxNode, LuaSyntaxToken};
 use lsp_types::{DocumentSymbol, SymbolKind};
@@ -12,6 +13,7 @@ pub struct DocumentSymbolBuilder<'a> {
     decl_tree: &'a LuaDeclarationTree,
     document: &'a LuaDocument<'a>,
     document_symbols: HashMap<LuaSyntaxId, Box<LuaSymbol>>,
+    class_symbols: HashMap<LuaTypeDeclId, LuaSyntaxId>,
 }
 
 impl<'a> DocumentSymbolBuilder<'a> {
@@ -25,6 +27,7 @@ impl<'a> DocumentSymbolBuilder<'a> {
             decl_tree,
             document,
             document_symbols: HashMap::new(),
+            class_symbols: HashMap::new(),
         }
     }
 
@@ -101,6 +104,23 @@ impl<'a> DocumentSymbolBuilder<'a> {
         syntax_id
     }
 
+    /// 记录类定义对应的符号, 之后该类的成员会挂在此符号下
+    pub fn add_class_symbol(&mut self, typ: &LuaType, symbol_id: LuaSyntaxId) {
+        if let LuaType::Def(type_id) = typ {
+            self.class_symbols
+                .entry(type_id.clone())
+                .or_insert(symbol_id);
+        }
+    }
+
+    pub fn get_member_class_symbol(&self, member_id: &LuaMemberId) -> Option<LuaSyntaxId> {
+        let owner = self.db.get_member_index().get_current_owner(member_id)?;
+        match owner {
+            LuaMemberOwner::Type(type_id) => self.class_symbols.get(type_id).copied(),
+            _ => None,
+        }
+    }
+
     pub fn contains_symbol(&self, id: &LuaSyntaxId) -> bool {
         self.document_symbols.contains_key(id)
     }
@@ -4,7 +4,7 @@ mod expr;
 mod stats;
 
 use builder::{DocumentSymbolBuilder, LuaSymbol};
-use emmylua_code_analysis::SemanticModel;
+use emmylua_code_analysis::{EmmyLuaAnalysis, FileId, SemanticModel};
 use emmylua_parser::{
     LuaAstNode, LuaBlock, LuaChunk, LuaComment, LuaExpr, LuaSingleArgExpr, LuaStat, LuaSyntaxId,
     LuaSyntaxNode,
@@ -34,6 +34,13 @@ pub async fn on_document_symbol(
     let uri = params.text_document.uri;
     let analysis = context.analysis().read().await;
     let file_id = analysis.get_file_id(&uri)?;
+    document_symbol(&analysis, file_id)
+}
+
+pub fn document_symbol(
+    analysis: &EmmyLuaAnalysis,
+    file_id: FileId,
+) -> Option<DocumentSymbolResponse> {
     let semantic_model = analysis.compilation.get_semantic_model(file_id)?;
     let document_symbol_root = build_document_symbol(&semantic_model)?;
     // remove root file symbol
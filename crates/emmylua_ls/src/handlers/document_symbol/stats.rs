@@ -1,9 +1,11 @@
-use emmylua_code_analysis::{LuaDeclId, LuaSignatureId, LuaType};
+use emmylua_code_analysis::{LuaDeclId, LuaMemberId, LuaSignatureId, LuaType};
 use emmylua_parser::{
     LuaAssignStat, LuaAstNode, LuaAstToken, LuaDoStat, LuaExpr, LuaForRangeStat, LuaForStat,
-    LuaFuncStat, LuaIfClauseStat, LuaIfStat, LuaLocalFuncStat, LuaLocalStat, LuaSyntaxId,
+    LuaFuncStat, LuaIfClauseStat, LuaIfStat, LuaIndexExpr, LuaLocalFuncStat, LuaLocalStat,
+    LuaSyntaxId, LuaVarExpr,
 };
 use lsp_types::SymbolKind;
+use rowan::TextRange;
 
 use super::builder::{DocumentSymbolBuilder, LuaSymbol};
 
@@ -43,6 +45,7 @@ pub fn build_local_stat_symbol(
         let symbol = LuaSymbol::new(decl.get_name().to_string(), desc.1, desc.0, range);
         let symbol_id =
             builder.add_node_symbol(local_name.syntax().clone(), symbol, Some(parent_id));
+        builder.add_class_symbol(&typ, symbol_id);
         let value_expr = local_values.get(index).cloned();
         bindings.push(SymbolBinding {
             symbol_id,
@@ -67,7 +70,22 @@ pub fn build_assign_stat_symbol(
         let decl_id = LuaDeclId::new(file_id, var.get_position());
         let decl = match builder.get_decl(&decl_id) {
             Some(decl) => decl,
-            None => continue,
+            None => {
+                if let LuaVarExpr::IndexExpr(index_expr) = var {
+                    let range = if simple_var {
+                        assign_stat.get_range()
+                    } else {
+                        index_expr.get_range()
+                    };
+                    if let Some(symbol_id) = build_class_member_symbol(builder, index_expr, range) {
+                        bindings.push(SymbolBinding {
+                            symbol_id,
+                            value_expr: exprs.get(index).cloned(),
+                        });
+                    }
+                }
+                continue;
+            }
         };
         let range = if simple_var {
             assign_stat.get_range()
@@ -79,6 +97,7 @@ pub fn build_assign_stat_symbol(
         let symbol = LuaSymbol::new(decl.get_name().to_string(), desc.1, desc.0, range);
 
         let symbol_id = builder.add_node_symbol(var.syntax().clone(), symbol, Some(parent_id));
+        builder.add_class_symbol(&typ, symbol_id);
         let value_expr = exprs.get(index).cloned();
         bindings.push(SymbolBinding {
             symbol_id,
@@ -189,6 +208,12 @@ pub fn build_func_stat_symbol(
 ) -> Option<LuaSyntaxId> {
     let file_id = builder.get_file_id();
     let func_name = func.get_func_name()?;
+    if let LuaVarExpr::IndexExpr(index_expr) = &func_name
+        && let Some(func_id) =
+            build_class_member_symbol(builder, index_expr.clone(), func.get_range())
+    {
+        return Some(func_id);
+    }
     let name = func_name.syntax().text().to_string();
     let closure = func.get_closure()?;
     let signature_id = LuaSignatureId::from_closure(file_id, &closure);
@@ -204,6 +229,37 @@ pub fn build_func_stat_symbol(
     Some(func_id)
 }
 
+/// 类成员 (`Foo.x = 1`, `function Foo:bar() end`) 挂在类符号下
+fn build_class_member_symbol(
+    builder: &mut DocumentSymbolBuilder,
+    index_expr: LuaIndexExpr,
+    range: TextRange,
+) -> Option<LuaSyntaxId> {
+    let member_id = LuaMemberId::new(index_expr.get_syntax_id(), builder.get_file_id());
+    let class_id = builder.get_member_class_symbol(&member_id)?;
+    let name_token = index_expr.get_name_token()?;
+    let typ = builder.get_type(member_id.into());
+    // 只有用 `:` 定义的函数才是方法, `function Foo.create()` 仍是普通函数
+    let is_colon_define = index_expr
+        .get_index_token()
+        .is_some_and(|token| token.is_colon());
+    let (kind, detail) = match builder.get_symbol_kind_and_detail(Some(&typ)) {
+        (SymbolKind::FUNCTION, detail) if is_colon_define => (SymbolKind::METHOD, detail),
+        (SymbolKind::FUNCTION, detail) => (SymbolKind::FUNCTION, detail),
+        (_, detail) => (SymbolKind::FIELD, detail),
+    };
+
+    let symbol = LuaSymbol::with_selection_range(
+        name_token.get_name_text().to_string(),
+        detail,
+        kind,
+        range,
+        name_token.get_range(),
+    );
+    let symbol_id = builder.add_node_symbol(index_expr.syntax().clone(), symbol, Some(class_id));
+    Some(symbol_id)
+}
+
 pub fn build_if_stat_symbol(
     builder: &mut DocumentSymbolBuilder,
     if_stat: LuaIfStat,
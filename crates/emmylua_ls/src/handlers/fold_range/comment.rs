@@ -1,6 +1,6 @@
-use emmylua_parser::{LuaAstNode, LuaComment, LuaTokenKind};
+use emmylua_parser::{LuaAstNode, LuaComment, LuaDocTag, LuaDocTagOther, LuaDocType, LuaTokenKind};
 use lsp_types::{FoldingRange, FoldingRangeKind};
-use rowan::NodeOrToken;
+use rowan::{NodeOrToken, TextRange};
 
 use super::builder::FoldingRangeBuilder;
 
@@ -24,15 +24,86 @@ pub fn build_comment_fold_range(
         builder.push(folding_range);
     }
 
+    build_doc_tag_fold_ranges(builder, &comment);
+
+    // `--region`/`--endregion` 与 `---@region`/`---@endregion` 都可以标记折叠区域
     for child in comment.syntax().children_with_tokens() {
-        if let NodeOrToken::Token(token) = child {
-            if token.kind() == LuaTokenKind::TkDocRegion.into() {
+        match child {
+            NodeOrToken::Token(token) if token.kind() == LuaTokenKind::TkDocRegion.into() => {
                 builder.begin_region(token.text_range());
-            } else if token.kind() == LuaTokenKind::TkDocEndRegion.into() {
+            }
+            NodeOrToken::Token(token) if token.kind() == LuaTokenKind::TkDocEndRegion.into() => {
                 builder.finish_region(token.text_range());
             }
+            NodeOrToken::Node(node) => {
+                let Some(tag) = LuaDocTagOther::cast(node) else {
+                    continue;
+                };
+                match tag.get_tag_name().as_deref() {
+                    Some("region") => builder.begin_region(tag.get_range()),
+                    Some("endregion") => {
+                        builder.finish_region(tag.get_range());
+                    }
+                    _ => {}
+                }
+            }
+            _ => {}
         }
     }
 
     Some(())
 }
+
+/// `---@class`/`---@enum` 与其后的 `---@field` 组成一个折叠块, 多行联合类型的 `---@alias` 单独折叠
+fn build_doc_tag_fold_ranges(builder: &mut FoldingRangeBuilder, comment: &LuaComment) {
+    let Some(comment_lsp_range) = builder.get_document().to_lsp_range(comment.get_range()) else {
+        return;
+    };
+    let mut ranges = Vec::new();
+    let mut group: Option<TextRange> = None;
+    for tag in comment.get_doc_tags() {
+        match &tag {
+            LuaDocTag::Class(_) | LuaDocTag::Enum(_) => {
+                ranges.extend(group.take());
+                group = Some(tag.get_range());
+            }
+            LuaDocTag::Field(_) | LuaDocTag::Overload(_) | LuaDocTag::Operator(_) => {
+                if let Some(range) = &mut group {
+                    *range = range.cover(tag.get_range());
+                }
+            }
+            LuaDocTag::Alias(alias) => {
+                ranges.extend(group.take());
+                if let Some(LuaDocType::MultiLineUnion(_)) = alias.get_type() {
+                    ranges.push(tag.get_range());
+                }
+            }
+            _ => {
+                ranges.extend(group.take());
+            }
+        }
+    }
+    ranges.extend(group);
+
+    for range in ranges {
+        let Some(lsp_range) = builder.get_document().to_lsp_range(range) else {
+            continue;
+        };
+        // 与整个注释行数相同的范围已经折叠过了
+        if lsp_range.start.line == lsp_range.end.line
+            || (lsp_range.start.line == comment_lsp_range.start.line
+                && lsp_range.end.line == comment_lsp_range.end.line)
+        {
+            continue;
+        }
+
+        builder.push(FoldingRange {
+            start_line: lsp_range.start.line,
+            start_character: Some(lsp_range.start.character),
+            end_line: lsp_range.end.line,
+            end_character: Some(lsp_range.end.character),
+            kind: Some(FoldingRangeKind::Comment),
+            collapsed_text: None,
+        });
+    }
+}
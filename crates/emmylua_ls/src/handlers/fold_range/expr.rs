@@ -1,5 +1,6 @@
 use emmylua_parser::{
-    LuaAstNode, LuaAstToken, LuaClosureExpr, LuaLiteralExpr, LuaLiteralToken, LuaTableExpr,
+    LuaAstNode, LuaAstToken, LuaClosureExpr, LuaCommentOwner, LuaDocTag, LuaLiteralExpr,
+    LuaLiteralToken, LuaStat, LuaTableExpr,
 };
 use lsp_types::{FoldingRange, FoldingRangeKind};
 
@@ -29,6 +30,42 @@ pub fn build_table_expr_fold_range(
     };
 
     builder.push(folding_range);
+    build_enum_table_fold_range(builder, table_expr);
+    Some(())
+}
+
+/// `---@enum` 标注的表从注解开始整体折叠为枚举体
+fn build_enum_table_fold_range(
+    builder: &mut FoldingRangeBuilder,
+    table_expr: LuaTableExpr,
+) -> Option<()> {
+    let stat = LuaStat::cast(table_expr.syntax().parent()?)?;
+    let enum_tag = stat.get_comments().into_iter().find_map(|comment| {
+        comment.get_doc_tags().find_map(|tag| match tag {
+            LuaDocTag::Enum(enum_tag) => Some(enum_tag),
+            _ => None,
+        })
+    })?;
+    let name = enum_tag.get_name_token()?;
+
+    let document = builder.get_document();
+    let start = document.to_lsp_range(enum_tag.get_range())?.start;
+    let end = document.to_lsp_range(table_expr.get_range())?.end;
+    let folding_lsp_range = builder.get_folding_lsp_range(
+        start.line as usize,
+        end.line as usize,
+        start.character as usize,
+        end.character as usize,
+    )?;
+
+    builder.push(FoldingRange {
+        start_line: folding_lsp_range.start.line,
+        start_character: Some(folding_lsp_range.start.character),
+        end_line: folding_lsp_range.end.line,
+        end_character: Some(folding_lsp_range.end.character),
+        kind: None,
+        collapsed_text: Some(format!("enum {}", name.get_name_text())),
+    });
     Some(())
 }
 
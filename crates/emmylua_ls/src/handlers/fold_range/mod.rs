@@ -6,7 +6,7 @@ mod stats;
 
 use builder::FoldingRangeBuilder;
 use comment::build_comment_fold_range;
-use emmylua_code_analysis::Emmyrc;
+use emmylua_code_analysis::{EmmyLuaAnalysis, Emmyrc, FileId};
 use emmylua_parser::{LuaAst, LuaAstNode};
 use expr::{build_closure_expr_fold_range, build_string_fold_range, build_table_expr_fold_range};
 use imports::build_imports_fold_range;
@@ -20,7 +20,7 @@ use stats::{
 };
 use tokio_util::sync::CancellationToken;
 
-use crate::context::ServerContextSnapshot;
+use crate::context::{ClientId, ServerContextSnapshot};
 
 use super::RegisterCapabilities;
 
@@ -38,6 +38,15 @@ pub async fn on_folding_range_handler(
         .client_config
         .client_id;
     let file_id = analysis.get_file_id(&uri)?;
+
+    folding_range(&analysis, file_id, client_id)
+}
+
+pub fn folding_range(
+    analysis: &EmmyLuaAnalysis,
+    file_id: FileId,
+    client_id: ClientId,
+) -> Option<Vec<FoldingRange>> {
     let semantic_model = analysis.compilation.get_semantic_model(file_id)?;
     let document = semantic_model.get_document();
     let root = semantic_model.get_root();
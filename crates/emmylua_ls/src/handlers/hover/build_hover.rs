@@ -15,6 +15,7 @@ use rowan::TextRange;
 use crate::handlers::hover::function::{build_function_hover, is_function};
 use crate::handlers::hover::humanize_type_decl::build_type_decl_hover;
 use crate::handlers::hover::humanize_types::hover_humanize_type;
+use crate::util::format_string_key;
 
 use super::{
     find_origin::{find_decl_origin_owners, find_member_origin_owners},
@@ -231,7 +232,9 @@ fn build_member_hover(
         find_member_origin_owners(builder.compilation, builder.semantic_model, member_id, true)
             .get_types(builder.semantic_model);
 
-    let member_name = match member.get_key() {
+    let member_key = member.get_key();
+    let member_name = match member_key {
+        LuaMemberKey::Name(name) if !member_key.is_identifier() => format_string_key(name),
         LuaMemberKey::Name(name) => name.to_string(),
         LuaMemberKey::Integer(i) => format!("[{}]", i),
         _ => return None,
@@ -112,12 +112,13 @@ fn build_function_call_hover(
         )
         .ok()?;
 
-        if !signature.return_overloads.is_empty()
-            && final_type.get_async_state() == instantiated_signature.get_async_state()
+        // 调用匹配的是签名本身而不是某个 `@overload`
+        let is_main_signature = final_type.get_async_state()
+            == instantiated_signature.get_async_state()
             && final_type.is_colon_define() == instantiated_signature.is_colon_define()
             && final_type.is_variadic() == instantiated_signature.is_variadic()
-            && final_type.get_params() == instantiated_signature.get_params()
-        {
+            && final_type.get_params() == instantiated_signature.get_params();
+        if is_main_signature && !signature.return_overloads.is_empty() {
             let return_overloads =
                 instantiate_call_return_overloads(builder, db, call_expr, signature);
             let ret_detail = build_function_return_overload_rows(builder, &return_overloads);
@@ -132,6 +133,19 @@ fn build_function_call_hover(
                 Vec::new(),
                 Some(ret_detail),
             )]
+        } else if is_main_signature {
+            let return_docs = instantiate_call_return_docs(signature, &final_type);
+            vec![hover_doc_function_type(
+                builder,
+                db,
+                final_type.as_ref(),
+                function_member,
+                function_name,
+                is_local,
+                is_field,
+                return_docs,
+                None,
+            )]
         } else {
             process_function_type(
                 builder,
@@ -428,11 +442,34 @@ fn hover_doc_function_type(
         func_name.to_string()
     };
 
+    let params = build_function_params(
+        db,
+        func,
+        is_method && !func.is_colon_define(),
+        RenderLevel::Simple,
+    );
+
+    let ret_detail = ret_detail.unwrap_or_else(|| build_function_returns(builder, return_docs));
+    format_function_type(
+        type_label,
+        async_label,
+        full_name,
+        params.join(", "),
+        ret_detail,
+    )
+}
+
+/// 渲染函数的参数列表, `skip_self` 为真时省略作为 `self` 的第一个参数
+pub fn build_function_params(
+    db: &DbIndex,
+    func: &LuaFunctionType,
+    skip_self: bool,
+    level: RenderLevel,
+) -> Vec<String> {
     let is_vararg = func.is_variadic();
     let last_idx = func.get_params().len().saturating_sub(1);
 
-    let params = func
-        .get_params()
+    func.get_params()
         .iter()
         .enumerate()
         .map(|(index, param)| {
@@ -440,25 +477,16 @@ fn hover_doc_function_type(
             if is_vararg && index == last_idx && name != "..." {
                 name = format!("...{}", name);
             }
-            if index == 0 && is_method && !func.is_colon_define() {
+            if index == 0 && skip_self {
                 "".to_string()
             } else if let Some(ty) = &param.1 {
-                format!("{}: {}", name, humanize_type(db, ty, RenderLevel::Simple))
+                format!("{}: {}", name, humanize_type(db, ty, level))
             } else {
                 name.to_string()
             }
         })
         .filter(|s| !s.is_empty())
-        .collect::<Vec<_>>();
-
-    let ret_detail = ret_detail.unwrap_or_else(|| build_function_returns(builder, return_docs));
-    format_function_type(
-        type_label,
-        async_label,
-        full_name,
-        params.join(", "),
-        ret_detail,
-    )
+        .collect()
 }
 
 fn instantiate_call_return_overloads(
@@ -505,6 +533,27 @@ fn instantiate_call_return_overloads(
         .collect()
 }
 
+/// 调用处的返回值类型以实例化后的函数为准, 同时保留 `@return` 中声明的名称与描述
+fn instantiate_call_return_docs(
+    signature: &LuaSignature,
+    func: &LuaFunctionType,
+) -> Vec<LuaDocReturnInfo> {
+    let instantiated_docs = convert_function_return_to_docs(func);
+    if signature.return_docs.len() != instantiated_docs.len() {
+        return instantiated_docs;
+    }
+
+    signature
+        .return_docs
+        .iter()
+        .zip(instantiated_docs)
+        .map(|(return_doc, instantiated_doc)| LuaDocReturnInfo {
+            type_ref: instantiated_doc.type_ref,
+            ..return_doc.clone()
+        })
+        .collect()
+}
+
 fn convert_function_return_to_docs(func: &LuaFunctionType) -> Vec<LuaDocReturnInfo> {
     match func.get_ret() {
         LuaType::Variadic(variadic) => match variadic.as_ref() {
@@ -578,46 +627,48 @@ fn build_function_returns(
     builder: &mut HoverBuilder,
     return_docs: Vec<LuaDocReturnInfo>,
 ) -> String {
-    let mut result = String::new();
-    // 如果不是补全且存在名称, 我们需要多行显示
-    let has_multiline = !builder.is_completion
-        && return_docs
-            .iter()
-            .any(|return_info| return_info.name.is_some());
+    // 只有一个 `nil` 返回值时视为没有返回值
+    if return_docs.is_empty() || (return_docs.len() == 1 && return_docs[0].type_ref.is_nil()) {
+        return String::new();
+    }
 
-    for (i, return_info) in return_docs.iter().enumerate() {
-        if i == 0 && return_info.type_ref.is_nil() {
-            continue;
-        }
-        let type_text = build_function_return_type(builder, return_info, i);
+    let returns = return_docs
+        .iter()
+        .enumerate()
+        .map(|(i, return_info)| {
+            let name = return_info.name.as_deref().filter(|name| !name.is_empty());
+            (name, build_function_return_type(builder, return_info, i))
+        })
+        .collect::<Vec<_>>();
+    let named_returns = returns
+        .iter()
+        .map(|(name, type_text)| match name {
+            Some(name) => format!("{}: {}", name, type_text),
+            None => type_text.clone(),
+        })
+        .collect::<Vec<_>>();
 
-        if has_multiline {
-            let prefix = if i == 0 {
-                result.push('\n');
-                "-> ".to_string()
-            } else {
-                format!("{}. ", i + 1)
-            };
-            let name = return_info.name.clone().unwrap_or_default();
+    // 如果不是补全且存在名称, 我们需要多行显示
+    let has_multiline = !builder.is_completion && returns.iter().any(|(name, _)| name.is_some());
+    if has_multiline {
+        if named_returns.len() == 1 {
+            return format!("\n  -> {}\n", named_returns[0]);
+        }
 
-            result.push_str(&format!(
-                "  {}{}{}\n",
-                prefix,
-                if !name.is_empty() {
-                    format!("{}: ", name)
-                } else {
-                    "".to_string()
-                },
-                type_text,
-            ));
-        } else if i == 0 {
-            result.push_str(&format!(" -> {}", type_text));
-        } else {
-            result.push_str(&format!(", {}", type_text));
+        // 多个返回值以编号列表显示
+        let mut result = String::from("\n");
+        for (i, ret) in named_returns.iter().enumerate() {
+            let prefix = if i == 0 { "  -> " } else { "     " };
+            result.push_str(&format!("{}{}. {}\n", prefix, i + 1, ret));
         }
+        return result;
     }
 
-    result
+    match returns.as_slice() {
+        // 单个返回值在行内显示时不带名称
+        [(_, type_text)] => format!(" -> {}", type_text),
+        _ => format!(" -> ({})", named_returns.join(", ")),
+    }
 }
 
 fn build_function_return_overload_rows(
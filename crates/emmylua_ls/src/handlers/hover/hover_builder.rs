@@ -1,6 +1,6 @@
 use emmylua_code_analysis::{
     GenericTplId, LuaCompilation, LuaMember, LuaMemberOwner, LuaSemanticDeclId, LuaType,
-    RenderLevel, SemanticModel, TypeSubstitutor,
+    LuaTypeDeclId, RenderLevel, SemanticModel, TypeSubstitutor,
 };
 use emmylua_parser::{
     LuaAstNode, LuaCallExpr, LuaExpr, LuaLocalName, LuaLocalStat, LuaSyntaxKind, LuaSyntaxToken,
@@ -12,6 +12,7 @@ use crate::handlers::hover::humanize_types::{
 };
 
 use super::build_hover::{add_signature_param_description, add_signature_ret_description};
+use super::see_link::build_see_content;
 
 #[derive(Debug)]
 pub struct HoverBuilder<'a> {
@@ -27,11 +28,15 @@ pub struct HoverBuilder<'a> {
     pub type_expansion: Option<Vec<String>>,
     /// For `@see` and unknown tags tags
     tag_content: Option<Vec<(String, String)>>,
+    /// Types whose defining files are listed in the footer
+    type_definitions: Vec<LuaTypeDeclId>,
 
     trigger_token: Option<LuaSyntaxToken>,
     pub semantic_model: &'a SemanticModel<'a>,
     pub compilation: &'a LuaCompilation,
     pub detail_render_level: RenderLevel,
+    /// 代码块的语言, 来自 `hover.codeBlockLanguage`
+    pub code_block_language: String,
 
     pub is_completion: bool,
     // 默认的泛型替换器
@@ -69,13 +74,20 @@ impl<'a> HoverBuilder<'a> {
             trigger_token: token,
             type_expansion: None,
             tag_content: None,
+            type_definitions: Vec::new(),
             detail_render_level,
+            code_block_language: semantic_model
+                .get_emmyrc()
+                .hover
+                .code_block_language
+                .clone(),
             substitutor,
         }
     }
 
     pub fn set_type_description(&mut self, type_description: String) {
-        self.primary = MarkedString::from_language_code("lua".to_string(), type_description);
+        self.primary =
+            MarkedString::from_language_code(self.code_block_language.clone(), type_description);
     }
 
     pub fn set_location_path(&mut self, owner_member: Option<&LuaMember>) {
@@ -85,16 +97,29 @@ impl<'a> HoverBuilder<'a> {
                 .get_db()
                 .get_member_index()
                 .get_current_owner(&owner_member.get_id());
-            if let Some(LuaMemberOwner::Type(ty)) = owner_id
-                && ty.get_name() != ty.get_simple_name()
-            {
-                self.location_path = Some(MarkedString::from_markdown(format!(
-                    "{}{} `{}`",
-                    "&nbsp;&nbsp;",
-                    "in class",
-                    ty.get_name()
-                )));
-            }
+            let Some(LuaMemberOwner::Type(ty)) = owner_id else {
+                return;
+            };
+            // 枚举成员总是显示所属的枚举, 类成员只在类名带命名空间时显示
+            let is_enum = self
+                .semantic_model
+                .get_db()
+                .get_type_index()
+                .get_type_decl(ty)
+                .is_some_and(|decl| decl.is_enum());
+            let owner_kind = if is_enum {
+                "in enum"
+            } else if ty.get_name() != ty.get_simple_name() {
+                "in class"
+            } else {
+                return;
+            };
+            self.location_path = Some(MarkedString::from_markdown(format!(
+                "{}{} `{}`",
+                "&nbsp;&nbsp;",
+                owner_kind,
+                ty.get_name()
+            )));
         }
     }
 
@@ -109,7 +134,7 @@ impl<'a> HoverBuilder<'a> {
             .as_mut()
             .unwrap()
             .push(MarkedString::from_language_code(
-                "lua".to_string(),
+                self.code_block_language.clone(),
                 signature_overload,
             ));
     }
@@ -142,6 +167,12 @@ impl<'a> HoverBuilder<'a> {
         }
     }
 
+    pub fn add_type_definition(&mut self, type_decl_id: &LuaTypeDeclId) {
+        if !self.type_definitions.contains(type_decl_id) {
+            self.type_definitions.push(type_decl_id.clone());
+        }
+    }
+
     pub fn add_annotation_description(&mut self, annotation_description: String) {
         if annotation_description.is_empty() {
             return;
@@ -226,6 +257,11 @@ impl<'a> HoverBuilder<'a> {
                     content.push_str("\n---\n");
                 }
                 for (tag_name, description) in tag_content {
+                    let description = if tag_name == "see" {
+                        build_see_content(self.semantic_model, description)
+                    } else {
+                        description.clone()
+                    };
                     content.push_str(&format!("\n@*{}* {}\n", tag_name, description));
                 }
             }
@@ -251,7 +287,10 @@ impl<'a> HoverBuilder<'a> {
 
             if let Some(type_expansion) = &self.type_expansion {
                 for type_expansion in type_expansion {
-                    expansion.push_str(&format!("\n```{}\n{}\n```\n", "lua", type_expansion));
+                    expansion.push_str(&format!(
+                        "\n```{}\n{}\n```\n",
+                        self.code_block_language, type_expansion
+                    ));
                 }
             }
             expansion
@@ -266,6 +305,12 @@ impl<'a> HoverBuilder<'a> {
         result.push_str(&description_content);
         result.push_str(&expansion);
 
+        let definition = self.build_type_definition_footer();
+        if !definition.is_empty() {
+            result.push_str("\n---\n");
+            result.push_str(&definition);
+        }
+
         // 清除空白字符
         result = result.trim().to_string();
 
@@ -278,6 +323,59 @@ impl<'a> HoverBuilder<'a> {
         })
     }
 
+    /// 列出类型定义所在的文件. 只在类型定义于其他文件时显示, 分部定义会列出所有文件
+    fn build_type_definition_footer(&self) -> String {
+        let db = self.semantic_model.get_db();
+        let file_id = self.semantic_model.get_file_id();
+        let module_index = db.get_module_index();
+        let vfs = db.get_vfs();
+
+        let mut footer = String::new();
+        for type_decl_id in &self.type_definitions {
+            let Some(type_decl) = db.get_type_index().get_type_decl(type_decl_id) else {
+                continue;
+            };
+            let locations = type_decl
+                .get_locations()
+                .iter()
+                .filter(|location| !module_index.is_std(&location.file_id))
+                .collect::<Vec<_>>();
+            if locations.iter().all(|location| location.file_id == file_id) {
+                continue;
+            }
+
+            let mut paths: Vec<String> = Vec::new();
+            for location in locations {
+                let Some(path) = vfs.get_file_path(&location.file_id) else {
+                    continue;
+                };
+                let path = module_index
+                    .get_workspace_relative_path(path)
+                    .unwrap_or(path)
+                    .to_string_lossy()
+                    .replace('\\', "/");
+                if !paths.contains(&path) {
+                    paths.push(path);
+                }
+            }
+            if paths.is_empty() {
+                continue;
+            }
+
+            let paths = paths
+                .iter()
+                .map(|path| format!("`{}`", path))
+                .collect::<Vec<_>>()
+                .join(", ");
+            footer.push_str(&format!(
+                "\n`{}` defined in {}\n",
+                type_decl.get_full_name(),
+                paths
+            ));
+        }
+        footer
+    }
+
     pub fn get_trigger_token(&self) -> Option<LuaSyntaxToken> {
         self.trigger_token.clone()
     }
@@ -33,6 +33,7 @@ pub fn hover_humanize_type(
     let db = builder.semantic_model.get_db();
     match ty {
         LuaType::Ref(type_decl_id) => {
+            builder.add_type_definition(type_decl_id);
             if let Some(type_decl) = db.get_type_index().get_type_decl(type_decl_id)
                 && let Some(LuaType::MultiLineUnion(multi_union)) =
                     type_decl.get_alias_origin(db, None)
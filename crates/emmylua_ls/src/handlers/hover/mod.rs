@@ -5,6 +5,7 @@ mod hover_builder;
 mod humanize_type_decl;
 mod humanize_types;
 mod keyword_hover;
+mod see_link;
 
 use super::RegisterCapabilities;
 use crate::context::ServerContextSnapshot;
@@ -15,6 +16,7 @@ use emmylua_code_analysis::{EmmyLuaAnalysis, FileId, WorkspaceId};
 use emmylua_parser::{LuaAstNode, LuaDocDescription, LuaTokenKind};
 use emmylua_parser_desc::parse_ref_target;
 pub use find_origin::{find_all_same_named_members, find_member_origin_owner};
+pub use function::build_function_params;
 pub use hover_builder::HoverBuilder;
 pub use humanize_types::infer_prefix_global_name;
 use keyword_hover::{hover_keyword, is_keyword};
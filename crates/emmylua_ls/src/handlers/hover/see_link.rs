@@ -0,0 +1,103 @@
+use emmylua_code_analysis::{FileId, LuaMemberKey, LuaSemanticDeclId, LuaType, SemanticModel};
+use lsp_types::Location;
+use rowan::TextRange;
+
+/// 将 `@see` 的引用转换为指向定义位置的 markdown 链接, 无法解析时保持原文
+pub fn build_see_content(semantic_model: &SemanticModel, content: &str) -> String {
+    let (reference, rest) = match content.split_once(char::is_whitespace) {
+        Some((reference, rest)) => (reference, Some(rest.trim_start())),
+        None => (content, None),
+    };
+    let Some(location) = resolve_see_location(semantic_model, reference) else {
+        return content.to_string();
+    };
+
+    let link = format!(
+        "[{}]({}#L{})",
+        reference,
+        location.uri.as_str(),
+        location.range.start.line + 1
+    );
+    match rest {
+        Some(rest) if !rest.is_empty() => format!("{} {}", link, rest),
+        _ => link,
+    }
+}
+
+/// 支持 `Foo`, `Foo.bar` 以及旧格式 `Foo#bar`
+fn resolve_see_location(semantic_model: &SemanticModel, reference: &str) -> Option<Location> {
+    let names = reference.split(['.', '#']).collect::<Vec<_>>();
+    if names.iter().any(|name| name.is_empty()) {
+        return None;
+    }
+
+    let db = semantic_model.get_db();
+    let file_id = semantic_model.get_file_id();
+    // 类型名本身可能包含 `.`, 优先匹配最长的类型名
+    for i in (1..=names.len()).rev() {
+        let type_name = names[..i].join(".");
+        if let Some(type_decl) = db.get_type_index().find_type_decl(file_id, &type_name) {
+            if i == names.len() {
+                let location = type_decl.get_locations().first()?;
+                return to_lsp_location(semantic_model, location.file_id, location.range);
+            }
+            return resolve_member_location(
+                semantic_model,
+                LuaType::Ref(type_decl.get_id()),
+                &names[i..],
+            );
+        }
+    }
+
+    let decl_id = *db
+        .get_global_index()
+        .get_global_decl_ids(names[0])?
+        .first()?;
+    if names.len() == 1 {
+        let decl = db.get_decl_index().get_decl(&decl_id)?;
+        return to_lsp_location(semantic_model, decl.get_file_id(), decl.get_range());
+    }
+    resolve_member_location(
+        semantic_model,
+        semantic_model.get_type(decl_id.into()),
+        &names[1..],
+    )
+}
+
+fn resolve_member_location(
+    semantic_model: &SemanticModel,
+    mut typ: LuaType,
+    names: &[&str],
+) -> Option<Location> {
+    for (i, name) in names.iter().enumerate() {
+        let member_map = semantic_model.get_member_info_map(&typ)?;
+        let member_info = member_map
+            .get(&LuaMemberKey::Name((*name).into()))?
+            .first()?;
+        if i + 1 < names.len() {
+            typ = member_info.typ.clone();
+            continue;
+        }
+
+        let Some(LuaSemanticDeclId::Member(member_id)) = &member_info.property_owner_id else {
+            return None;
+        };
+        return to_lsp_location(
+            semantic_model,
+            member_id.file_id,
+            member_id.get_syntax_id().get_range(),
+        );
+    }
+
+    None
+}
+
+fn to_lsp_location(
+    semantic_model: &SemanticModel,
+    file_id: FileId,
+    range: TextRange,
+) -> Option<Location> {
+    semantic_model
+        .get_document_by_file_id(file_id)?
+        .to_lsp_location(range)
+}
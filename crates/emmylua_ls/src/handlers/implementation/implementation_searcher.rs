@@ -1,8 +1,8 @@
 use std::collections::HashMap;
 
 use emmylua_code_analysis::{
-    LuaCompilation, LuaDeclId, LuaMemberId, LuaSemanticDeclId, LuaType, LuaTypeDeclId,
-    SemanticDeclLevel, SemanticModel,
+    LuaCompilation, LuaDeclId, LuaMemberId, LuaMemberOwner, LuaSemanticDeclId, LuaType,
+    LuaTypeDeclId, SemanticDeclLevel, SemanticModel,
 };
 use emmylua_parser::{
     LuaAstNode, LuaDocTagField, LuaExpr, LuaIndexExpr, LuaStat, LuaSyntaxNode, LuaSyntaxToken,
@@ -27,6 +27,7 @@ pub fn search_implementations(
             }
             LuaSemanticDeclId::Member(member_id) => {
                 search_member_implementations(semantic_model, compilation, member_id, &mut result);
+                search_sub_type_member_implementations(semantic_model, member_id, &mut result);
             }
             LuaSemanticDeclId::LuaDecl(decl_id) => {
                 search_decl_implementations(semantic_model, compilation, decl_id, &mut result);
@@ -93,6 +94,42 @@ pub fn search_member_implementations(
     Some(())
 }
 
+/// 查找子类中重写了该成员的实现
+pub fn search_sub_type_member_implementations(
+    semantic_model: &SemanticModel,
+    member_id: LuaMemberId,
+    result: &mut Vec<Location>,
+) -> Option<()> {
+    let db = semantic_model.get_db();
+    let member_index = db.get_member_index();
+    let member_key = member_index.get_member(&member_id)?.get_key();
+    let LuaMemberOwner::Type(type_decl_id) = member_index.get_current_owner(&member_id)? else {
+        return None;
+    };
+
+    for sub_type in db.get_type_index().get_all_sub_types(type_decl_id) {
+        let owner = LuaMemberOwner::Type(sub_type.get_id());
+        let Some(member_item) = member_index.get_member_item(&owner, member_key) else {
+            continue;
+        };
+        for sub_member_id in member_item.get_member_ids() {
+            let Some(document) = db.get_vfs().get_document(&sub_member_id.file_id) else {
+                continue;
+            };
+            let Some(location) =
+                document.to_lsp_location(sub_member_id.get_syntax_id().get_range())
+            else {
+                continue;
+            };
+            if !result.contains(&location) {
+                result.push(location);
+            }
+        }
+    }
+
+    Some(())
+}
+
 /// 检查成员引用是否符合实现
 fn check_member_reference(semantic_model: &SemanticModel, node: LuaSyntaxNode) -> Option<bool> {
     match &node {
@@ -19,8 +19,8 @@ use crate::{
 pub use client_config::{ClientConfig, get_client_config};
 use codestyle::load_editorconfig;
 use emmylua_code_analysis::{
-    EmmyLuaAnalysis, Emmyrc, WorkspaceFolder, build_workspace_folders, collect_workspace_files,
-    uri_to_file_path,
+    DIRECTORY_CONFIG_FILE_NAME, EmmyLuaAnalysis, Emmyrc, WorkspaceFolder, build_workspace_folders,
+    collect_workspace_files, is_directory_config_file, load_directory_configs, uri_to_file_path,
 };
 use lsp_types::InitializeParams;
 use tokio::sync::RwLock;
@@ -145,9 +145,33 @@ pub async fn init_analysis(
     );
 
     // load files
-    let files = collect_workspace_files(&workspace_folders, &emmyrc, None, None);
-    let files: Vec<(PathBuf, Option<String>)> =
-        files.into_iter().map(|file| file.into_tuple()).collect();
+    let files = collect_workspace_files(
+        &workspace_folders,
+        &emmyrc,
+        Some(vec![format!("**/{}", DIRECTORY_CONFIG_FILE_NAME)]),
+        None,
+    );
+    let (config_files, files): (Vec<_>, Vec<_>) = files
+        .into_iter()
+        .map(|file| file.into_tuple())
+        .partition(|(path, _)| is_directory_config_file(path));
+
+    // 子目录中的 `.emmyrc.json` 覆盖其下文件的诊断配置
+    let main_roots = workspace_folders
+        .iter()
+        .filter(|workspace| !workspace.is_library)
+        .map(|workspace| workspace.root.clone())
+        .collect::<Vec<_>>();
+    let directory_configs = load_directory_configs(
+        &main_roots,
+        config_files.into_iter().map(|(path, _)| path).collect(),
+        &emmyrc,
+    )
+    .into_iter()
+    .map(|(dir, config)| (dir, Arc::new(config)))
+    .collect();
+    mut_analysis.update_directory_configs(directory_configs);
+
     let file_count = files.len();
     if file_count != 0 {
         status_bar.update_progress_task(
@@ -156,7 +180,11 @@ pub async fn init_analysis(
             Some(format!("Indexing {} files", file_count)),
         );
     }
+    mut_analysis.compilation.set_index_progress(Some(
+        status_bar.index_progress_sink(ProgressTask::LoadWorkspace),
+    ));
     let removed_uris = mut_analysis.reload_workspace_files(files, open_files);
+    mut_analysis.compilation.set_index_progress(None);
 
     status_bar.update_progress_task(
         ProgressTask::LoadWorkspace,
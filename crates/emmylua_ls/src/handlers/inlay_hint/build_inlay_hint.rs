@@ -7,9 +7,9 @@ use emmylua_code_analysis::{
     SemanticModel,
 };
 use emmylua_parser::{
-    LuaAst, LuaAstNode, LuaCallExpr, LuaExpr, LuaFuncStat, LuaIndexExpr, LuaIndexKey,
-    LuaLiteralToken, LuaLocalFuncStat, LuaLocalName, LuaLocalStat, LuaStat, LuaSyntaxId,
-    LuaVarExpr,
+    LuaAst, LuaAstNode, LuaCallExpr, LuaCommentOwner, LuaDocTag, LuaExpr, LuaFuncStat,
+    LuaIndexExpr, LuaIndexKey, LuaLiteralToken, LuaLocalFuncStat, LuaLocalName, LuaLocalStat,
+    LuaStat, LuaSyntaxId, LuaVarExpr,
 };
 use emmylua_parser::{LuaAstToken, LuaTokenKind};
 use lsp_types::{InlayHint, InlayHintKind, InlayHintLabel, InlayHintLabelPart, Location};
@@ -263,11 +263,8 @@ fn build_call_args_for_func_type(
         }
 
         let arg = &call_args[idx];
-        if let LuaExpr::NameExpr(name_expr) = arg
-            && let Some(param_name) = name_expr.get_name_text()
-            // optimize like rust analyzer
-            && &param_name == name
-        {
+        // optimize like rust analyzer
+        if is_arg_named_as_param(arg, name) {
             continue;
         }
 
@@ -305,14 +302,31 @@ fn build_call_args_for_func_type(
     Some(())
 }
 
+/// 参数为同名变量或同名字段 (如 `obj.name`) 时不显示参数名
+fn is_arg_named_as_param(arg: &LuaExpr, param_name: &str) -> bool {
+    match arg {
+        LuaExpr::NameExpr(name_expr) => name_expr
+            .get_name_text()
+            .is_some_and(|name| name == param_name),
+        LuaExpr::IndexExpr(index_expr) => index_expr
+            .get_name_token()
+            .is_some_and(|token| token.get_name_text() == param_name),
+        _ => false,
+    }
+}
+
 fn build_local_name_hint(
     semantic_model: &SemanticModel,
     result: &mut Vec<InlayHint>,
     local_name: LuaLocalName,
 ) -> Option<()> {
-    if !semantic_model.get_emmyrc().hint.local_hint {
+    let hint_config = &semantic_model.get_emmyrc().hint;
+    if !hint_config.local_hint {
         return Some(());
     }
+    let mut has_value = false;
+    let mut has_annotation = false;
+    let mut value_expr = None;
     // local function 不显示
     if let Some(parent) = local_name.syntax().parent() {
         if LuaLocalFuncStat::can_cast(parent.kind().into()) {
@@ -322,13 +336,22 @@ fn build_local_name_hint(
             let local_stat = LuaLocalStat::cast(parent)?;
             let local_names = local_stat.get_local_name_list();
             for (i, ln) in local_names.enumerate() {
-                if local_name == ln
-                    && let Some(value_expr) = local_stat.get_value_exprs().nth(i)
-                    && let LuaExpr::ClosureExpr(_) = value_expr
-                {
-                    return Some(());
+                if local_name == ln {
+                    value_expr = local_stat.get_value_exprs().nth(i);
                 }
             }
+            if let Some(LuaExpr::ClosureExpr(_)) = value_expr {
+                return Some(());
+            }
+            has_value = local_stat.get_value_exprs().next().is_some();
+            has_annotation = local_stat.get_left_comment().is_some_and(|comment| {
+                comment.get_doc_tags().any(|tag| {
+                    matches!(
+                        tag,
+                        LuaDocTag::Type(_) | LuaDocTag::Class(_) | LuaDocTag::Enum(_)
+                    )
+                })
+            });
         }
     }
 
@@ -338,13 +361,22 @@ fn build_local_name_hint(
         ))?
         .typ;
 
-    // 目前没时间完善结合 ast 的类型过滤, 所以只允许一些类型显示
-    match typ {
-        LuaType::Ref(_) | LuaType::Generic(_) => {}
-        _ => {
-            return Some(());
-        }
-    }
+    let typ = match typ {
+        LuaType::Ref(_) | LuaType::Generic(_) => typ,
+        // 显式注解或没有初始值时不显示推断类型
+        _ if has_annotation || !has_value => return Some(()),
+        LuaType::Unknown | LuaType::Any | LuaType::Nil | LuaType::Def(_) => return Some(()),
+        _ if typ.is_function() => return Some(()),
+        _ => match value_expr {
+            // 表构造的类型即其字面结构, 显示出来只会增加干扰
+            Some(LuaExpr::TableExpr(_)) => return Some(()),
+            Some(LuaExpr::LiteralExpr(_)) if !hint_config.local_literal_hint => {
+                return Some(());
+            }
+            Some(LuaExpr::LiteralExpr(_)) => decay_literal_type(typ),
+            _ => typ,
+        },
+    };
 
     let document = semantic_model.get_document();
     let range = local_name.get_range();
@@ -366,6 +398,16 @@ fn build_local_name_hint(
     Some(())
 }
 
+fn decay_literal_type(typ: LuaType) -> LuaType {
+    match typ {
+        LuaType::IntegerConst(_) => LuaType::Integer,
+        LuaType::FloatConst(_) => LuaType::Number,
+        LuaType::StringConst(_) => LuaType::String,
+        LuaType::BooleanConst(_) => LuaType::Boolean,
+        _ => typ,
+    }
+}
+
 fn build_func_stat_override_hint(
     semantic_model: &SemanticModel,
     result: &mut Vec<InlayHint>,
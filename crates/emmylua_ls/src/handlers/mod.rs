@@ -31,6 +31,7 @@ mod response_handler;
 mod semantic_token;
 mod signature_helper;
 mod text_document;
+mod type_definition;
 mod workspace;
 mod workspace_symbol;
 
@@ -84,6 +85,7 @@ capabilities!(modules: {
     inlay_hint => InlayHintCapabilities,
     definition => DefinitionCapabilities,
     implementation => ImplementationCapabilities,
+    type_definition => TypeDefinitionCapabilities,
     references => ReferencesCapabilities,
     rename => RenameCapabilities,
     code_lens => CodeLensCapabilities,
@@ -26,13 +26,13 @@ use super::RegisterCapabilities;
 pub async fn on_rename_handler(
     context: ServerContextSnapshot,
     params: RenameParams,
-    _: CancellationToken,
+    cancel_token: CancellationToken,
 ) -> Option<WorkspaceEdit> {
     let uri = params.text_document_position.text_document.uri;
     let analysis = context.analysis().read().await;
     let file_id = analysis.get_file_id(&uri)?;
     let position = params.text_document_position.position;
-    rename(&analysis, file_id, position, params.new_name)
+    rename(&analysis, file_id, position, params.new_name, cancel_token)
 }
 
 pub async fn on_prepare_rename_handler(
@@ -43,7 +43,14 @@ pub async fn on_prepare_rename_handler(
     let uri = params.text_document.uri;
     let analysis = context.analysis().read().await;
     let file_id = analysis.get_file_id(&uri)?;
-    let position = params.position;
+    prepare_rename(&analysis, file_id, params.position)
+}
+
+pub fn prepare_rename(
+    analysis: &emmylua_code_analysis::EmmyLuaAnalysis,
+    file_id: emmylua_code_analysis::FileId,
+    position: lsp_types::Position,
+) -> Option<PrepareRenameResponse> {
     let semantic_model = analysis.compilation.get_semantic_model(file_id)?;
     let root = semantic_model.get_root();
     let document = semantic_model.get_document();
@@ -69,15 +76,49 @@ pub async fn on_prepare_rename_handler(
             return None;
         }
     };
-    if matches!(
+    // 关键字等其他 token 不允许重命名
+    if !matches!(
         token.kind().into(),
         LuaTokenKind::TkName | LuaTokenKind::TkInt | LuaTokenKind::TkString
     ) {
-        let range = document.to_lsp_range(token.text_range())?;
-        let placeholder = token.text().to_string();
-        Some(PrepareRenameResponse::RangeWithPlaceholder { range, placeholder })
-    } else {
-        None
+        return None;
+    }
+
+    // 找不到定义时(如 `t[1]`, `t["k"]` 这类动态键)仍然允许重命名, 只拒绝标准库中的定义
+    let semantic_decl = match get_target_node(token.clone()) {
+        Some(node) => semantic_model.find_decl(node.into(), SemanticDeclLevel::NoTrace),
+        None => semantic_model.find_decl(token.clone().into(), SemanticDeclLevel::NoTrace),
+    };
+    if let Some(semantic_decl) = semantic_decl
+        && !is_renamable_decl(&semantic_model, &semantic_decl)
+    {
+        return None;
+    }
+
+    let range = document.to_lsp_range(token.text_range())?;
+    let placeholder = token.text().to_string();
+    Some(PrepareRenameResponse::RangeWithPlaceholder { range, placeholder })
+}
+
+/// 标准库中的定义不可重命名
+fn is_renamable_decl(semantic_model: &SemanticModel, semantic_decl: &LuaSemanticDeclId) -> bool {
+    let db = semantic_model.get_db();
+    let module_index = db.get_module_index();
+    match semantic_decl {
+        LuaSemanticDeclId::LuaDecl(decl_id) => !module_index.is_std(&decl_id.file_id),
+        LuaSemanticDeclId::Member(member_id) => !module_index.is_std(&member_id.file_id),
+        LuaSemanticDeclId::TypeDecl(type_decl_id) => {
+            let Some(type_decl) = db.get_type_index().get_type_decl(type_decl_id) else {
+                return false;
+            };
+            !type_decl
+                .get_locations()
+                .iter()
+                .any(|location| module_index.is_std(&location.file_id))
+        }
+        LuaSemanticDeclId::Signature(signature_id) => {
+            !module_index.is_std(&signature_id.get_file_id())
+        }
     }
 }
 
@@ -86,6 +127,7 @@ pub fn rename(
     file_id: emmylua_code_analysis::FileId,
     position: lsp_types::Position,
     new_name: String,
+    cancel_token: CancellationToken,
 ) -> Option<WorkspaceEdit> {
     let semantic_model = analysis.compilation.get_semantic_model(file_id)?;
     let root = semantic_model.get_root();
@@ -112,7 +154,13 @@ pub fn rename(
         }
     };
 
-    rename_references(&semantic_model, &analysis.compilation, token, new_name)
+    rename_references(
+        &semantic_model,
+        &analysis.compilation,
+        token,
+        new_name,
+        &cancel_token,
+    )
 }
 
 #[allow(clippy::mutable_key_type)]
@@ -121,6 +169,7 @@ fn rename_references(
     compilation: &LuaCompilation,
     token: LuaSyntaxToken,
     new_name: String,
+    cancel_token: &CancellationToken,
 ) -> Option<WorkspaceEdit> {
     let mut result = HashMap::new();
     let semantic_decl = match get_target_node(token.clone()) {
@@ -142,7 +191,14 @@ fn rename_references(
             );
         }
         LuaSemanticDeclId::TypeDecl(type_decl_id) => {
-            rename_type_references(semantic_model, type_decl_id, new_name, &mut result);
+            rename_type_references(
+                semantic_model,
+                compilation,
+                type_decl_id,
+                new_name,
+                cancel_token,
+                &mut result,
+            );
         }
         _ => {}
     }
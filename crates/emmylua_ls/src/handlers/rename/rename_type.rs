@@ -1,13 +1,17 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
-use emmylua_code_analysis::{LuaTypeDeclId, SemanticModel};
+use emmylua_code_analysis::{FileId, LuaCompilation, LuaType, LuaTypeDeclId, SemanticModel};
+use emmylua_parser::{LuaAstNode, LuaAstToken, LuaCallExpr, LuaExpr, LuaLiteralToken};
 use lsp_types::Uri;
+use tokio_util::sync::CancellationToken;
 
 #[allow(clippy::mutable_key_type)]
 pub fn rename_type_references(
     semantic_model: &SemanticModel,
+    compilation: &LuaCompilation,
     type_decl_id: LuaTypeDeclId,
     new_name: String,
+    cancel_token: &CancellationToken,
     result: &mut HashMap<Uri, HashMap<lsp_types::Range, String>>,
 ) -> Option<()> {
     let type_index = semantic_model.get_db().get_type_index();
@@ -43,6 +47,13 @@ pub fn rename_type_references(
             .insert(range, new_name.clone());
     }
 
+    let new_full_name = if reserved_namespace.is_empty() {
+        new_name.clone()
+    } else {
+        format!("{}.{}", reserved_namespace, new_name)
+    };
+    rename_str_tpl_references(compilation, full_name, &new_full_name, cancel_token, result);
+
     let refs = semantic_model
         .get_db()
         .get_reference_index()
@@ -77,6 +88,107 @@ pub fn rename_type_references(
     Some(())
 }
 
+/// 通过 `` `T` `` 字符串模板引用类型的调用参数, 例如 `meta("MyClass")`.
+/// 开启 `shortStringSearch` 时从字符串引用索引取候选文件, 否则只解析文本中包含类型名的文件
+#[allow(clippy::mutable_key_type)]
+fn rename_str_tpl_references(
+    compilation: &LuaCompilation,
+    full_name: &str,
+    new_full_name: &str,
+    cancel_token: &CancellationToken,
+    result: &mut HashMap<Uri, HashMap<lsp_types::Range, String>>,
+) {
+    let db = compilation.get_db();
+    let module_index = db.get_module_index();
+    // 字符串引用索引只记录不超过 64 字节的字符串
+    let file_ids: HashSet<FileId> =
+        if db.get_emmyrc().references.short_string_search && full_name.len() <= 64 {
+            db.get_reference_index()
+                .get_string_references(full_name)
+                .into_iter()
+                .map(|reference| reference.file_id)
+                .filter(|file_id| module_index.is_main(file_id))
+                .collect()
+        } else {
+            let vfs = db.get_vfs();
+            module_index
+                .get_main_workspace_file_ids()
+                .into_iter()
+                .filter(|file_id| {
+                    vfs.get_file_content(file_id)
+                        .is_some_and(|content| content.contains(full_name))
+                })
+                .collect()
+        };
+
+    for file_id in file_ids {
+        if cancel_token.is_cancelled() {
+            return;
+        }
+        let Some(semantic_model) = compilation.get_semantic_model(file_id) else {
+            continue;
+        };
+        let document = semantic_model.get_document();
+        for call_expr in semantic_model.get_root().descendants::<LuaCallExpr>() {
+            let Some(args_list) = call_expr.get_args_list() else {
+                continue;
+            };
+            let args = args_list.get_args().collect::<Vec<_>>();
+            for (idx, arg) in args.iter().enumerate() {
+                let LuaExpr::LiteralExpr(literal_expr) = arg else {
+                    continue;
+                };
+                let Some(LuaLiteralToken::String(string_token)) = literal_expr.get_literal() else {
+                    continue;
+                };
+                if string_token.get_value() != full_name
+                    || !is_str_tpl_param(&semantic_model, &call_expr, idx, args.len())
+                {
+                    continue;
+                }
+
+                let text = string_token.get_text();
+                let quote = &text[..1];
+                if quote != "\"" && quote != "'" {
+                    continue;
+                }
+                let Some(range) = document.to_lsp_range(string_token.get_range()) else {
+                    continue;
+                };
+                result
+                    .entry(document.get_uri())
+                    .or_default()
+                    .insert(range, format!("{}{}{}", quote, new_full_name, quote));
+            }
+        }
+    }
+}
+
+fn is_str_tpl_param(
+    semantic_model: &SemanticModel,
+    call_expr: &LuaCallExpr,
+    arg_idx: usize,
+    arg_count: usize,
+) -> bool {
+    let Some(func) = semantic_model.infer_call_expr_func(call_expr.clone(), Some(arg_count)) else {
+        return false;
+    };
+    let param_idx = match (call_expr.is_colon_call(), func.is_colon_define()) {
+        (true, false) => arg_idx + 1,
+        (false, true) => match arg_idx.checked_sub(1) {
+            Some(idx) => idx,
+            None => return false,
+        },
+        _ => arg_idx,
+    };
+
+    matches!(
+        func.get_params().get(param_idx),
+        Some((_, Some(LuaType::StrTplRef(tpl))))
+            if tpl.get_prefix().is_empty() && tpl.get_suffix().is_empty()
+    )
+}
+
 /// 根据引用文件的命名空间上下文决定使用简名还是全名
 fn determine_type_name_for_file(
     semantic_model: &SemanticModel,
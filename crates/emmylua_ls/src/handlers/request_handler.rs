@@ -7,11 +7,11 @@ use lsp_types::request::{
     CodeActionRequest, CodeLensRequest, CodeLensResolve, ColorPresentationRequest, Completion,
     DocumentColor, DocumentDiagnosticRequest, DocumentHighlightRequest, DocumentLinkRequest,
     DocumentLinkResolve, DocumentSymbolRequest, ExecuteCommand, FoldingRangeRequest, Formatting,
-    GotoDefinition, GotoImplementation, HoverRequest, InlayHintRequest, InlayHintResolveRequest,
-    InlineValueRequest, OnTypeFormatting, PrepareRenameRequest, RangeFormatting, References,
-    Rename, Request as LspRequest, ResolveCompletionItem, SelectionRangeRequest,
-    SemanticTokensFullRequest, SignatureHelpRequest, WorkspaceDiagnosticRequest,
-    WorkspaceSymbolRequest,
+    GotoDefinition, GotoImplementation, GotoTypeDefinition, HoverRequest, InlayHintRequest,
+    InlayHintResolveRequest, InlineValueRequest, OnTypeFormatting, PrepareRenameRequest,
+    RangeFormatting, References, Rename, Request as LspRequest, ResolveCompletionItem,
+    SelectionRangeRequest, SemanticTokensFullRequest, SignatureHelpRequest,
+    WorkspaceDiagnosticRequest, WorkspaceSymbolRequest,
 };
 
 use crate::{
@@ -53,6 +53,7 @@ use super::{
     rename::{on_prepare_rename_handler, on_rename_handler},
     semantic_token::on_semantic_token_handler,
     signature_helper::on_signature_helper_handler,
+    type_definition::on_type_definition_handler,
     workspace_symbol::on_workspace_symbol_handler,
 };
 
@@ -109,6 +110,7 @@ pub async fn on_request_handler(
         InlayHintResolveRequest => on_resolve_inlay_hint,
         GotoDefinition => on_goto_definition_handler,
         GotoImplementation => on_implementation_handler,
+        GotoTypeDefinition => on_type_definition_handler,
         References => on_references_handler,
         Rename => on_rename_handler,
         PrepareRenameRequest => on_prepare_rename_handler,
@@ -6,8 +6,9 @@ use crate::handlers::semantic_token::semantic_token_builder::{
 use crate::util::parse_desc;
 use crate::{context::ClientId, handlers::semantic_token::language_injector::inject_language};
 use emmylua_code_analysis::{
-    Emmyrc, LuaDecl, LuaDeclExtra, LuaMemberId, LuaMemberOwner, LuaSemanticDeclId, LuaType,
-    LuaTypeDeclId, SemanticDeclLevel, SemanticModel, WorkspaceId, parse_require_module_info,
+    Emmyrc, LocalAttribute, LuaDecl, LuaDeclExtra, LuaMemberId, LuaMemberOwner, LuaSemanticDeclId,
+    LuaType, LuaTypeDeclId, SemanticDeclLevel, SemanticModel, WorkspaceId,
+    parse_require_module_info,
 };
 use emmylua_parser::{
     LuaAst, LuaAstNode, LuaAstToken, LuaCallArgList, LuaCallExpr, LuaComment, LuaDocFieldKey,
@@ -521,6 +522,10 @@ fn build_node_semantic_token(
         LuaAst::LuaCallExpr(call_expr) => {
             let prefix = call_expr.get_prefix_expr()?;
             let prefix_type = semantic_model.infer_expr(prefix.clone()).ok();
+            let deprecated = semantic_model
+                .find_decl(prefix.syntax().clone().into(), SemanticDeclLevel::default())
+                .map(|id| deprecated_modifier(semantic_model, &id))
+                .unwrap_or(SemanticTokenModifierKind::empty());
 
             match prefix {
                 LuaExpr::NameExpr(name_expr) => {
@@ -536,7 +541,7 @@ fn build_node_semantic_token(
                                     builder.push_with_modifier(
                                         name.syntax(),
                                         SemanticTokenTypeKind::Function,
-                                        SemanticTokenModifierKind::DEFAULT_LIBRARY,
+                                        SemanticTokenModifierKind::DEFAULT_LIBRARY | deprecated,
                                     );
                                     return Some(());
                                 }
@@ -549,15 +554,27 @@ fn build_node_semantic_token(
                         }
                     }
 
-                    builder.push(name.syntax(), SemanticTokenTypeKind::Function);
+                    builder.push_with_modifier(
+                        name.syntax(),
+                        SemanticTokenTypeKind::Function,
+                        deprecated,
+                    );
                 }
                 LuaExpr::IndexExpr(index_expr) => {
                     let name = index_expr.get_index_name_token()?;
                     // 改进：区分方法调用和属性访问
                     if call_expr.get_args_list().is_some() {
-                        builder.push(&name, SemanticTokenTypeKind::Method);
+                        builder.push_with_modifier(
+                            &name,
+                            SemanticTokenTypeKind::Method,
+                            deprecated,
+                        );
                     } else {
-                        builder.push(&name, SemanticTokenTypeKind::Function);
+                        builder.push_with_modifier(
+                            &name,
+                            SemanticTokenTypeKind::Function,
+                            deprecated,
+                        );
                     }
                 }
                 _ => {}
@@ -642,19 +659,27 @@ fn build_node_semantic_token(
             }
             let semantic_decl = semantic_model
                 .find_decl(name.syntax().clone().into(), SemanticDeclLevel::default());
+            let deprecated = semantic_decl
+                .as_ref()
+                .map(|id| deprecated_modifier(semantic_model, id))
+                .unwrap_or(SemanticTokenModifierKind::empty());
             if let Some(property_owner) = semantic_decl
                 && let LuaSemanticDeclId::Member(member_id) = property_owner
             {
                 let decl_type = semantic_model.get_type(member_id.into());
                 if decl_type.is_function() {
-                    builder.push(name.syntax(), SemanticTokenTypeKind::Method);
+                    builder.push_with_modifier(
+                        name.syntax(),
+                        SemanticTokenTypeKind::Method,
+                        deprecated,
+                    );
                     return Some(());
                 }
                 if decl_type.is_def() {
                     builder.push_with_modifier(
                         name.syntax(),
                         SemanticTokenTypeKind::Class,
-                        SemanticTokenModifierKind::READONLY,
+                        SemanticTokenModifierKind::READONLY | deprecated,
                     );
                     return Some(());
                 }
@@ -673,7 +698,7 @@ fn build_node_semantic_token(
                     builder.push_with_modifier(
                         name.syntax(),
                         SemanticTokenTypeKind::EnumMember,
-                        SemanticTokenModifierKind::READONLY,
+                        SemanticTokenModifierKind::READONLY | deprecated,
                     );
                     return Some(());
                 }
@@ -685,9 +710,17 @@ fn build_node_semantic_token(
                 .parent()
                 .is_some_and(|p| p.kind() == LuaSyntaxKind::CallExpr.into())
             {
-                builder.push(name.syntax(), SemanticTokenTypeKind::Method);
+                builder.push_with_modifier(
+                    name.syntax(),
+                    SemanticTokenTypeKind::Method,
+                    deprecated,
+                );
             } else {
-                builder.push(name.syntax(), SemanticTokenTypeKind::Property);
+                builder.push_with_modifier(
+                    name.syntax(),
+                    SemanticTokenTypeKind::Property,
+                    deprecated,
+                );
             }
         }
         LuaAst::LuaTableField(table_field) => {
@@ -923,7 +956,11 @@ fn handle_name_node(
         LuaSemanticDeclId::Member(member_id) => {
             let decl_type = semantic_model.get_type(member_id.into());
             if matches!(decl_type, LuaType::Signature(_)) {
-                builder.push(name_token.syntax(), SemanticTokenTypeKind::Function);
+                builder.push_with_modifier(
+                    name_token.syntax(),
+                    SemanticTokenTypeKind::Function,
+                    deprecated_modifier(semantic_model, &semantic_decl),
+                );
                 return Some(());
             }
         }
@@ -1006,7 +1043,17 @@ fn handle_name_node(
             };
 
             // 检查是否只读
-            if modifier.is_none() {
+            if matches!(
+                decl.extra,
+                LuaDeclExtra::Local {
+                    attrib: Some(LocalAttribute::Const | LocalAttribute::IterConst),
+                    ..
+                }
+            ) {
+                modifier = Some(modifier.map_or(SemanticTokenModifierKind::READONLY, |m| {
+                    m | SemanticTokenModifierKind::READONLY
+                }));
+            } else if modifier.is_none() {
                 let file_id = semantic_model.get_file_id();
                 let ref_decl = semantic_model
                     .get_db()
@@ -1038,6 +1085,7 @@ fn handle_name_node(
             if let Some(modifier) = modifier {
                 modifiers |= modifier;
             }
+            modifiers |= deprecated_modifier(semantic_model, &semantic_decl);
 
             builder.push_with_modifier(name_token.syntax(), token_type, modifiers);
             return Some(());
@@ -1054,6 +1102,22 @@ fn handle_name_node(
     Some(())
 }
 
+fn deprecated_modifier(
+    semantic_model: &SemanticModel,
+    semantic_decl: &LuaSemanticDeclId,
+) -> SemanticTokenModifierKind {
+    let is_deprecated = semantic_model
+        .get_db()
+        .get_property_index()
+        .get_property(semantic_decl)
+        .is_some_and(|property| property.deprecated().is_some());
+    if is_deprecated {
+        SemanticTokenModifierKind::DEPRECATED
+    } else {
+        SemanticTokenModifierKind::empty()
+    }
+}
+
 fn default_identifier_token_type(name_text: &str) -> SemanticTokenTypeKind {
     if name_text.chars().next().is_some_and(|c| c.is_uppercase()) {
         // 首字母大写可能是类或常量
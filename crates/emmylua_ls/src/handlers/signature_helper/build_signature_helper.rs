@@ -129,11 +129,12 @@ fn build_doc_function_signature_help(
         _ => {}
     }
 
+    // 可变参数需要按照补充或移除 `self` 之后的参数列表计算
     if let Some((name, _)) = params.last()
         && name == "..."
-        && current_idx >= params.len()
+        && current_idx >= param_infos.len()
     {
-        current_idx = params.len() - 1;
+        current_idx = param_infos.len().saturating_sub(1);
     }
 
     let label = build_function_label(
@@ -232,11 +233,12 @@ fn build_sig_id_signature_help(
         _ => {}
     }
 
+    // 可变参数需要按照补充或移除 `self` 之后的参数列表计算
     if let Some((name, _)) = params.last()
         && name == "..."
-        && current_idx >= params.len()
+        && current_idx >= param_infos.len()
     {
-        current_idx = params.len() - 1;
+        current_idx = param_infos.len().saturating_sub(1);
     }
 
     let label = build_function_label(
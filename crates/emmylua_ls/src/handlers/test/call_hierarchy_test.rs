@@ -0,0 +1,63 @@
+#[cfg(test)]
+mod tests {
+    use emmylua_code_analysis::LuaSemanticDeclId;
+    use googletest::prelude::*;
+
+    use crate::handlers::{
+        call_hierarchy::build_outgoing_hierarchy, test_lib::ProviderVirtualWorkspace,
+    };
+
+    #[gtest]
+    fn test_outgoing_calls() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new();
+        let file_id = ws.def(
+            r#"
+                ---@class Worker
+                local Worker = {}
+
+                function Worker:step()
+                end
+
+                local function log()
+                end
+
+                function run()
+                    Worker:step()
+                    log()
+                    log()
+                    local function later()
+                        Worker:step()
+                        log()
+                    end
+                    later()
+                end
+            "#,
+        );
+
+        let compilation = &ws.analysis.compilation;
+        let decl_id = compilation
+            .get_db()
+            .get_decl_index()
+            .get_decl_tree(&file_id)
+            .and_then(|tree| {
+                tree.get_decls()
+                    .values()
+                    .find(|decl| decl.get_name() == "run")
+            })
+            .map(|decl| decl.get_id())
+            .ok_or("failed to find decl")
+            .or_fail()?;
+        let calls = build_outgoing_hierarchy(compilation, LuaSemanticDeclId::LuaDecl(decl_id))
+            .ok_or("failed to get outgoing calls")
+            .or_fail()?;
+
+        let calls = calls
+            .iter()
+            .map(|call| (call.to.name.as_str(), call.from_ranges.len()))
+            .collect::<Vec<_>>();
+        verify_that!(
+            calls,
+            elements_are![eq(&("step", 1)), eq(&("log", 2)), eq(&("later", 1))]
+        )
+    }
+}
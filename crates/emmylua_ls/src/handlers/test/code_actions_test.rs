@@ -3,6 +3,7 @@ mod tests {
     use crate::handlers::test_lib::{ProviderVirtualWorkspace, VirtualCodeAction, check};
     use emmylua_code_analysis::{DiagnosticCode, Emmyrc};
     use googletest::prelude::*;
+    use lsp_types::{Command, Position, Range, TextEdit};
 
     #[gtest]
     fn test_1() -> Result<()> {
@@ -87,4 +88,414 @@ mod tests {
 
         Ok(())
     }
+
+    #[gtest]
+    fn test_colon_call_fix() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new();
+        ws.analysis
+            .diagnostic
+            .enable_only(DiagnosticCode::ColonCallMismatch);
+        check!(ws.check_code_action(
+            r#"
+                ---@class ColonFix
+                local A = {}
+
+                function A:method() end
+
+                A.method(A)
+            "#,
+            vec![
+                VirtualCodeAction {
+                    title: "Use `:` to call method".to_string()
+                },
+                VirtualCodeAction {
+                    title: "Disable current line diagnostic (colon-call-mismatch)".to_string()
+                },
+                VirtualCodeAction {
+                    title: "Disable all diagnostics in current file (colon-call-mismatch)"
+                        .to_string()
+                },
+                VirtualCodeAction {
+                    title: "Disable all diagnostics in current project (colon-call-mismatch)"
+                        .to_string()
+                },
+            ]
+        ));
+
+        Ok(())
+    }
+
+    #[gtest]
+    fn test_remove_unused_require() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new();
+        ws.analysis
+            .diagnostic
+            .enable_only(DiagnosticCode::UnusedRequire);
+        ws.def_file("unused_mod.lua", "return {}");
+        check!(ws.check_code_action(
+            r#"
+                local unused_mod = require("unused_mod")
+            "#,
+            vec![
+                VirtualCodeAction {
+                    title: "Remove unused require".to_string()
+                },
+                VirtualCodeAction {
+                    title: "Disable current line diagnostic (unused-require)".to_string()
+                },
+                VirtualCodeAction {
+                    title: "Disable all diagnostics in current file (unused-require)".to_string()
+                },
+                VirtualCodeAction {
+                    title:
+                        "Disable all diagnostics in current project (unused-require)".to_string()
+                },
+            ]
+        ));
+
+        Ok(())
+    }
+
+    #[gtest]
+    fn test_remove_unused_require_same_line() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new();
+        ws.analysis
+            .diagnostic
+            .enable_only(DiagnosticCode::UnusedRequire);
+        ws.def_file("unused_mod.lua", "return {}");
+        // 只删除语句和它的分号, 同一行中的其他语句保留
+        check!(ws.check_quick_fix(
+            "local unused_mod = require(\"unused_mod\"); print(1)\n",
+            "Remove unused require",
+            Some(vec![TextEdit {
+                range: Range::new(Position::new(0, 0), Position::new(0, 41)),
+                new_text: String::new(),
+            }]),
+        ));
+        check!(ws.check_quick_fix(
+            "local unused_mod = require(\"unused_mod\")\nprint(1)\n",
+            "Remove unused require",
+            Some(vec![TextEdit {
+                range: Range::new(Position::new(0, 0), Position::new(1, 0)),
+                new_text: String::new(),
+            }]),
+        ));
+
+        Ok(())
+    }
+
+    #[gtest]
+    fn test_extract_local_variable() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new();
+        check!(ws.check_refactor_action(
+            r#"
+                local t = {}
+                if t then
+                    print(<??>t:get_name()<??>, 1)
+                end
+            "#,
+            "Extract to local variable",
+            Some(vec![
+                TextEdit {
+                    range: Range::new(Position::new(3, 20), Position::new(3, 20)),
+                    new_text: "local get_name = t:get_name()\n                    ".to_string(),
+                },
+                TextEdit {
+                    range: Range::new(Position::new(3, 26), Position::new(3, 38)),
+                    new_text: "get_name".to_string(),
+                },
+            ]),
+        ));
+
+        check!(ws.check_refactor_action(
+            r#"
+                local value = 1
+                print(<??>value + 2<??>)
+            "#,
+            "Extract to local variable",
+            Some(vec![
+                TextEdit {
+                    range: Range::new(Position::new(2, 16), Position::new(2, 16)),
+                    new_text: "local value1 = value + 2\n                ".to_string(),
+                },
+                TextEdit {
+                    range: Range::new(Position::new(2, 22), Position::new(2, 31)),
+                    new_text: "value1".to_string(),
+                },
+            ]),
+        ));
+
+        check!(ws.check_refactor_action(
+            r#"
+                local a = {}
+                <??>a.b<??> = 1
+            "#,
+            "Extract to local variable",
+            None,
+        ));
+
+        // 提取后只保留第一个返回值
+        check!(ws.check_refactor_action(
+            r#"
+                local function f() return 1, 2 end
+                print(1, <??>f()<??>)
+            "#,
+            "Extract to local variable",
+            None,
+        ));
+
+        // `or` 右侧只在左侧为假时求值
+        check!(ws.check_refactor_action(
+            r#"
+                local t = {}
+                local v = t.cached or <??>t:load()<??>
+            "#,
+            "Extract to local variable",
+            None,
+        ));
+
+        Ok(())
+    }
+
+    #[gtest]
+    fn test_inline_local_variable() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new();
+        check!(ws.check_refactor_action(
+            r#"
+                local a = 1
+                local <??>x = a + 2
+                print(x * 3, x)
+            "#,
+            "Inline local variable `x`",
+            Some(vec![
+                TextEdit {
+                    range: Range::new(Position::new(2, 0), Position::new(3, 0)),
+                    new_text: "".to_string(),
+                },
+                TextEdit {
+                    range: Range::new(Position::new(3, 22), Position::new(3, 23)),
+                    new_text: "(a + 2)".to_string(),
+                },
+                TextEdit {
+                    range: Range::new(Position::new(3, 29), Position::new(3, 30)),
+                    new_text: "a + 2".to_string(),
+                },
+            ]),
+        ));
+
+        check!(ws.check_refactor_action(
+            r#"
+                local <??>t = {}
+                print(t.a)
+            "#,
+            "Inline local variable `t`",
+            Some(vec![
+                TextEdit {
+                    range: Range::new(Position::new(1, 0), Position::new(2, 0)),
+                    new_text: "".to_string(),
+                },
+                TextEdit {
+                    range: Range::new(Position::new(2, 22), Position::new(2, 23)),
+                    new_text: "({})".to_string(),
+                },
+            ]),
+        ));
+
+        check!(ws.check_refactor_action(
+            r#"
+                local <??>y = tostring(1)
+                print(y, y)
+            "#,
+            "Inline local variable `y`",
+            None,
+        ));
+
+        // 只保留调用的第一个返回值
+        check!(ws.check_refactor_action(
+            r#"
+                local function f() return 1, 2 end
+                local <??>v = f()
+                print(v)
+            "#,
+            "Inline local variable `v`",
+            Some(vec![
+                TextEdit {
+                    range: Range::new(Position::new(2, 0), Position::new(3, 0)),
+                    new_text: "".to_string(),
+                },
+                TextEdit {
+                    range: Range::new(Position::new(3, 22), Position::new(3, 23)),
+                    new_text: "(f())".to_string(),
+                },
+            ]),
+        ));
+
+        // 索引可能触发 `__index`
+        check!(ws.check_refactor_action(
+            r#"
+                local t = {}
+                local <??>n = t.name
+                print(n, n)
+            "#,
+            "Inline local variable `n`",
+            None,
+        ));
+
+        // 使用处的 `a` 是另一个局部变量
+        check!(ws.check_refactor_action(
+            r#"
+                local a = 1
+                local <??>x = a
+                do
+                    local a = 2
+                    print(x)
+                end
+            "#,
+            "Inline local variable `x`",
+            None,
+        ));
+
+        Ok(())
+    }
+
+    #[gtest]
+    fn test_class_stub_from_table() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new();
+        check!(ws.check_refactor_action(
+            r#"
+                local <??>M = {
+                    name = "mod",
+                    opts = { debug = true },
+                    ["end"] = true,
+                }
+
+                function M.run() end
+
+                return M
+            "#,
+            "Generate @class annotation for `M`",
+            Some(vec![TextEdit {
+                range: Range::new(Position::new(1, 16), Position::new(1, 16)),
+                new_text: [
+                    "---@class M\n",
+                    "                ---@field name string\n",
+                    "                ---@field opts { debug: boolean }\n",
+                    "                ---@field [\"end\"] boolean\n",
+                    "                ---@field run function\n",
+                    "                ",
+                ]
+                .concat(),
+            }]),
+        ));
+
+        check!(ws.check_refactor_action(
+            r#"
+                local <??>M = { name = "mod" }
+                print(M)
+            "#,
+            "Generate @class annotation for `M`",
+            None,
+        ));
+
+        Ok(())
+    }
+
+    #[gtest]
+    fn test_add_overload() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new();
+        check!(ws.check_refactor_action(
+            r#"
+                ---@param name string
+                ---@return integer
+                local function <??>parse(name, ...)
+                    return 0
+                end
+            "#,
+            "Add @overload for `parse`",
+            Some(vec![TextEdit {
+                range: Range::new(Position::new(3, 16), Position::new(3, 16)),
+                new_text:
+                    "---@overload fun(name: string, ...): integer\n                ".to_string(),
+            }]),
+        ));
+
+        check!(ws.check_refactor_action(
+            r#"
+                ---@param name string
+                ---@param opts? { strict: boolean }
+                ---@param ... string
+                ---@return integer count
+                ---@return string? err
+                function M.<??>parse(name, opts, ...)
+                    return 0
+                end
+            "#,
+            "Add @overload for `M.parse`",
+            Some(vec![TextEdit {
+                range: Range::new(Position::new(6, 16), Position::new(6, 16)),
+                new_text: "---@overload fun(name: string, opts: { strict: boolean }?, ...: string): integer, string?\n                "
+                    .to_string(),
+            }]),
+        ));
+
+        // 大的类型不能被截断, 否则生成的注解无法解析
+        check!(ws.check_refactor_action(
+            r#"
+                ---@param mode "a"|"b"|"c"|"d"|"e"|"f"|"g"|"h"
+                ---@return "a"|"b"|"c"|"d"|"e"|"f"|"g"|"h"
+                local function <??>pick(mode)
+                    return mode
+                end
+            "#,
+            "Add @overload for `pick`",
+            Some(vec![TextEdit {
+                range: Range::new(Position::new(3, 16), Position::new(3, 16)),
+                new_text: "---@overload fun(mode: (\"a\"|\"b\"|\"c\"|\"d\"|\"e\"|\"f\"|\"g\"|\"h\")): (\"a\"|\"b\"|\"c\"|\"d\"|\"e\"|\"f\"|\"g\"|\"h\")\n                "
+                    .to_string(),
+            }]),
+        ));
+
+        check!(ws.check_refactor_action(
+            r#"
+                local function parse(name)
+                    <??>return 0
+                end
+            "#,
+            "Add @overload for `parse`",
+            None,
+        ));
+        Ok(())
+    }
+
+    #[gtest]
+    fn test_show_type_details() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new();
+        let uri = ws.virtual_url_generator.new_uri("virtual_0.lua");
+        let range = Range::new(Position::new(2, 22), Position::new(2, 26));
+        check!(ws.check_refactor_command(
+            r#"
+                local list = { 1, 2 }
+                print(<??>list<??>)
+            "#,
+            "Show Type Details",
+            Some(Command {
+                title: "Show Type Details".to_string(),
+                command: "emmylua.dumpType".to_string(),
+                arguments: Some(vec![
+                    serde_json::to_value(&uri).unwrap(),
+                    serde_json::to_value(range).unwrap(),
+                ]),
+            }),
+        ));
+
+        check!(ws.check_refactor_command(
+            r#"
+                local list = { 1, 2 }
+                print(li<??>st)
+            "#,
+            "Show Type Details",
+            None,
+        ));
+        Ok(())
+    }
 }
@@ -0,0 +1,41 @@
+#[cfg(test)]
+mod tests {
+    use crate::handlers::test_lib::ProviderVirtualWorkspace;
+    use googletest::prelude::*;
+
+    #[gtest]
+    fn test_local_function_usage_count() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new();
+        let titles = ws.get_code_lens_titles(
+            r#"
+            local function unused()
+            end
+
+            local function used()
+            end
+
+            used()
+            "#,
+        )?;
+        verify_eq!(titles, vec!["0 usages", "1 usage"])
+    }
+
+    #[gtest]
+    fn test_member_function_usage_count() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new();
+        let titles = ws.get_code_lens_titles(
+            r#"
+            local M = {}
+
+            function M.unused()
+            end
+
+            function M.used()
+            end
+
+            M.used()
+            "#,
+        )?;
+        verify_eq!(titles, vec!["0 usages", "1 usage"])
+    }
+}
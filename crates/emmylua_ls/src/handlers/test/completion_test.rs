@@ -2,9 +2,13 @@
 mod tests {
     use emmylua_code_analysis::{DocSyntax, Emmyrc, EmmyrcFilenameConvention};
     use googletest::prelude::*;
-    use lsp_types::{CompletionItemKind, CompletionTriggerKind};
+    use lsp_types::{CompletionItemKind, CompletionResponse, CompletionTriggerKind};
+    use tokio_util::sync::CancellationToken;
 
-    use crate::handlers::test_lib::{ProviderVirtualWorkspace, VirtualCompletionItem, check};
+    use crate::handlers::{
+        completion::completion,
+        test_lib::{ProviderVirtualWorkspace, VirtualCompletionItem, check},
+    };
 
     #[gtest]
     fn test_1() -> Result<()> {
@@ -1254,6 +1258,107 @@ mod tests {
         Ok(())
     }
 
+    #[gtest]
+    fn test_field_literal_key() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new();
+        ws.def(
+            r#"
+                ---@class Headers
+                ---@field [1] string
+                ---@field ["content-type"] string
+                ---@field ["end"] integer
+                ---@field host string
+            "#,
+        );
+        check!(ws.check_completion(
+            r#"
+                ---@type Headers
+                local headers
+                headers.<??>
+            "#,
+            vec![
+                VirtualCompletionItem {
+                    label: "[\"content-type\"]".to_string(),
+                    kind: CompletionItemKind::VARIABLE,
+                    label_detail: None,
+                },
+                VirtualCompletionItem {
+                    label: "[\"end\"]".to_string(),
+                    kind: CompletionItemKind::VARIABLE,
+                    label_detail: None,
+                },
+                VirtualCompletionItem {
+                    label: "[1]".to_string(),
+                    kind: CompletionItemKind::VARIABLE,
+                    label_detail: None,
+                },
+                VirtualCompletionItem {
+                    label: "host".to_string(),
+                    kind: CompletionItemKind::VARIABLE,
+                    label_detail: None,
+                },
+            ],
+        ));
+        Ok(())
+    }
+
+    #[gtest]
+    fn test_field_literal_key_escape() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new();
+        ws.def(
+            r#"
+                ---@class EscapedKeys
+                ---@field ["c\\d"] string
+            "#,
+        );
+        check!(ws.check_completion(
+            r#"
+                ---@type EscapedKeys
+                local keys
+                keys.<??>
+            "#,
+            vec![VirtualCompletionItem {
+                label: r#"["c\\d"]"#.to_string(),
+                kind: CompletionItemKind::VARIABLE,
+                label_detail: None,
+            }],
+        ));
+        check!(ws.check_completion(
+            r#"
+                local keys = { ["a\"b"] = 1 }
+                keys.<??>
+            "#,
+            vec![VirtualCompletionItem {
+                label: r#"["a\"b"]"#.to_string(),
+                kind: CompletionItemKind::CONSTANT,
+                label_detail: None,
+            }],
+        ));
+        check!(ws.check_completion(
+            r#"
+                local keys = { ["a\0011"] = 1 }
+                keys.<??>
+            "#,
+            vec![VirtualCompletionItem {
+                label: r#"["a\0011"]"#.to_string(),
+                kind: CompletionItemKind::CONSTANT,
+                label_detail: None,
+            }],
+        ));
+        check!(ws.check_completion(
+            r#"
+                local keys = { ["a\u{85}"] = 1 }
+                keys.<??>
+            "#,
+            vec![VirtualCompletionItem {
+                label: r#"["a\194\133"]"#.to_string(),
+                kind: CompletionItemKind::CONSTANT,
+                label_detail: None,
+            }],
+        ));
+        Ok(())
+    }
+
     #[gtest]
     fn test_private_config() -> Result<()> {
         let mut ws = ProviderVirtualWorkspace::new();
@@ -1296,6 +1401,59 @@ mod tests {
         Ok(())
     }
 
+    #[gtest]
+    fn test_visibility_tags() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new();
+        ws.def(
+            r#"
+                ---@class CompletionBase
+                ---@field protected secret integer
+                ---@field private hidden integer
+                ---@field name string
+            "#,
+        );
+        check!(ws.check_completion(
+            r#"
+                ---@type CompletionBase
+                local base
+                base.<??>
+            "#,
+            vec![VirtualCompletionItem {
+                label: "name".to_string(),
+                kind: CompletionItemKind::VARIABLE,
+                label_detail: None,
+            }],
+        ));
+        check!(ws.check_completion(
+            r#"
+                ---@class CompletionDerived: CompletionBase
+                local CompletionDerived = {}
+
+                function CompletionDerived:run()
+                    self.<??>
+                end
+            "#,
+            vec![
+                VirtualCompletionItem {
+                    label: "name".to_string(),
+                    kind: CompletionItemKind::VARIABLE,
+                    label_detail: None,
+                },
+                VirtualCompletionItem {
+                    label: "run".to_string(),
+                    kind: CompletionItemKind::FUNCTION,
+                    label_detail: Some("(self) -> nil".to_string()),
+                },
+                VirtualCompletionItem {
+                    label: "secret".to_string(),
+                    kind: CompletionItemKind::VARIABLE,
+                    label_detail: None,
+                },
+            ],
+        ));
+        Ok(())
+    }
+
     #[gtest]
     fn test_require_private() -> Result<()> {
         let mut ws = ProviderVirtualWorkspace::new();
@@ -2417,4 +2575,290 @@ mod tests {
 
         Ok(())
     }
+
+    #[gtest]
+    fn test_overload_snippet() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new();
+        let mut emmyrc = ws.get_emmyrc();
+        emmyrc.completion.call_snippet = true;
+        emmyrc.completion.overload_snippet = true;
+        ws.update_emmyrc(emmyrc);
+
+        let (content, position) = ProviderVirtualWorkspace::handle_file_content(
+            r#"
+            ---@param fmt string
+            ---@overload fun(fmt: string, value: integer)
+            local function format(fmt) end
+
+            form<??>
+            "#,
+        )?;
+        let file_id = ws.def(&content);
+        let result = completion(
+            &ws.analysis,
+            file_id,
+            position,
+            CompletionTriggerKind::INVOKED,
+            CancellationToken::new(),
+        )
+        .ok_or("failed to get completion")
+        .or_fail()?;
+        let items = match result {
+            CompletionResponse::Array(items) => items,
+            CompletionResponse::List(list) => list.items,
+        };
+        let snippets = items
+            .into_iter()
+            .filter(|item| item.label == "format")
+            .filter_map(|item| item.insert_text)
+            .collect::<Vec<_>>();
+
+        verify_that!(
+            snippets,
+            unordered_elements_are![eq("format(${1:fmt})"), eq("format(${1:fmt}, ${2:value})")]
+        )
+    }
+
+    #[gtest]
+    fn test_doc_tag_snippet() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new();
+
+        let items = ws.get_completion_items(
+            r#"
+            ---@<??>
+            local t
+            "#,
+            CompletionTriggerKind::TRIGGER_CHARACTER,
+        )?;
+        let get_insert_text = |label: &str| {
+            items
+                .iter()
+                .find(|item| item.label == label)
+                .map(|item| item.insert_text.clone())
+        };
+
+        verify_that!(
+            get_insert_text("field"),
+            some(some(eq("field ${1:name} ${2:type}")))
+        )?;
+        verify_that!(get_insert_text("class"), some(some(eq("class ${1:Name}"))))?;
+        verify_that!(get_insert_text("nodiscard"), some(none()))
+    }
+
+    #[gtest]
+    fn test_postfix_cast_and_as() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new();
+
+        let get_insert_text = |ws: &mut ProviderVirtualWorkspace, block: &str, label: &str| {
+            let (content, position) = ProviderVirtualWorkspace::handle_file_content(block).ok()?;
+            let file_id = ws.def(&content);
+            let result = completion(
+                &ws.analysis,
+                file_id,
+                position,
+                CompletionTriggerKind::TRIGGER_CHARACTER,
+                CancellationToken::new(),
+            )?;
+            let items = match result {
+                CompletionResponse::Array(items) => items,
+                CompletionResponse::List(list) => list.items,
+            };
+            items
+                .into_iter()
+                .find(|item| item.label == label)
+                .and_then(|item| item.insert_text)
+        };
+
+        let block = r#"
+            ---@type string|number
+            local value
+            value@<??>
+            "#;
+        verify_that!(
+            get_insert_text(&mut ws, block, "cast"),
+            some(eq("---@cast value ${1:(string|number)}"))
+        )?;
+        verify_that!(
+            get_insert_text(&mut ws, block, "as"),
+            some(eq("value --[[@as ${1:(string|number)}]]"))
+        )?;
+
+        // `---@cast` 只能作为独立的一行插入
+        let block = r#"
+            ---@type string|number
+            local value
+            print(value@<??>)
+            "#;
+        verify_that!(get_insert_text(&mut ws, block, "cast"), none())?;
+        verify_that!(
+            get_insert_text(&mut ws, block, "as"),
+            some(eq("value --[[@as ${1:(string|number)}]]"))
+        )?;
+
+        // 索引表达式不是可以 `---@cast` 的变量
+        let block = r#"
+            ---@class PostfixCast
+            ---@field name string
+
+            ---@type PostfixCast
+            local t
+            t.name@<??>
+            "#;
+        verify_that!(get_insert_text(&mut ws, block, "cast"), none())?;
+        verify_that!(
+            get_insert_text(&mut ws, block, "as"),
+            some(eq("t.name --[[@as ${1:string}]]"))
+        )
+    }
+
+    #[gtest]
+    fn test_table_field_key_shows_type() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new();
+
+        let items = ws.get_completion_items(
+            r#"
+            ---@class TableFieldConfig
+            ---@field name string
+            ---@field port integer
+
+            ---@type TableFieldConfig
+            local config = { name = "a", <??> }
+            "#,
+            CompletionTriggerKind::INVOKED,
+        )?;
+        let fields = items
+            .into_iter()
+            .filter(|item| item.kind == Some(CompletionItemKind::PROPERTY))
+            .map(|item| {
+                let description = item.label_details.and_then(|details| details.description);
+                (item.label, description)
+            })
+            .collect::<Vec<_>>();
+
+        verify_that!(
+            fields,
+            elements_are![eq(&("port = ".to_string(), Some("integer".to_string())))]
+        )
+    }
+
+    #[gtest]
+    fn test_setmetatable_inherited_method() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new_with_init_std_lib();
+        ws.def(
+            r#"
+            ---@class Animal
+            Animal = {}
+            Animal.__index = Animal
+
+            function Animal:speak()
+            end
+            "#,
+        );
+
+        let items = ws.get_completion_items(
+            r#"
+            ---@class Dog
+            local Dog = setmetatable({}, { __index = Animal })
+
+            Dog:<??>
+            "#,
+            CompletionTriggerKind::TRIGGER_CHARACTER,
+        )?;
+        let labels = items.into_iter().map(|item| item.label).collect::<Vec<_>>();
+
+        verify_that!(labels, contains(eq("speak")))
+    }
+
+    #[gtest]
+    fn test_require_path_completion() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new();
+        ws.def_files(vec![
+            ("net.lua", "return {}"),
+            ("net/init.lua", "return {}"),
+            ("net/http.lua", "return {}"),
+            ("util/str.lua", "return {}"),
+        ]);
+
+        let items = ws.get_completion_items(
+            r#"
+            require("<??>")
+            "#,
+            CompletionTriggerKind::INVOKED,
+        )?;
+        let get_kind = |label: &str| {
+            items
+                .iter()
+                .filter(|item| item.label == label)
+                .map(|item| item.kind)
+                .collect::<Vec<_>>()
+        };
+        verify_that!(
+            get_kind("net"),
+            elements_are![eq(&Some(CompletionItemKind::FILE))]
+        )?;
+        verify_that!(
+            get_kind("util"),
+            elements_are![eq(&Some(CompletionItemKind::FOLDER))]
+        )?;
+
+        let items = ws.get_completion_items(
+            r#"
+            require("net/<??>")
+            "#,
+            CompletionTriggerKind::TRIGGER_CHARACTER,
+        )?;
+        let new_texts = items
+            .into_iter()
+            .filter_map(|item| match item.text_edit {
+                Some(lsp_types::CompletionTextEdit::Edit(edit)) => Some(edit.new_text),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        verify_that!(new_texts, elements_are![eq("net/http")])
+    }
+
+    #[gtest]
+    fn test_silent_globs_excluded_from_completion() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new();
+        let mut emmyrc = ws.get_emmyrc();
+        emmyrc.workspace.silent_globs = vec!["vendor/**".to_string()];
+        ws.update_emmyrc(emmyrc);
+        ws.def_file(
+            "vendor/silentlib.lua",
+            r#"
+            ---@class SilentVendorClass
+            SilentVendorGlobal = {}
+            return {}
+            "#,
+        );
+        ws.def_file(
+            "shown/shownlib.lua",
+            r#"
+            ---@class ShownVendorClass
+            ShownVendorGlobal = {}
+            return {}
+            "#,
+        );
+
+        let mut labels = |block_str: &str| -> Result<Vec<String>> {
+            Ok(ws
+                .get_completion_items(block_str, CompletionTriggerKind::INVOKED)?
+                .into_iter()
+                .map(|item| item.label)
+                .collect())
+        };
+        let globals = labels("local a = S<??>")?;
+        verify_that!(globals, contains(eq("ShownVendorGlobal")))?;
+        verify_that!(globals, not(contains(eq("SilentVendorGlobal"))))?;
+
+        let types = labels("---@type S<??>")?;
+        verify_that!(types, contains(eq("ShownVendorClass")))?;
+        verify_that!(types, not(contains(eq("SilentVendorClass"))))?;
+
+        verify_that!(labels("require(\"shown.<??>\")")?, contains(eq("shownlib")))?;
+        verify_that!(
+            labels("require(\"vendor.<??>\")")?,
+            not(contains(eq("silentlib")))
+        )
+    }
 }
@@ -30,6 +30,30 @@ mod tests {
         Ok(())
     }
 
+    #[gtest]
+    fn test_str_tpl_variadic_definition() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new();
+        check!(ws.check_definition(
+            r#"
+                ---@generic T
+                ---@param ... `T`
+                ---@return T
+                local function import(...)
+                end
+
+                ---@class Ability
+                ---@class Buff
+
+                import("Ability", "<??>Buff")
+            "#,
+            vec![Expected {
+                file: "".to_string(),
+                line: 8
+            }]
+        ));
+        Ok(())
+    }
+
     #[gtest]
     fn test_table_field_definition_1() -> Result<()> {
         let mut ws = ProviderVirtualWorkspace::new();
@@ -538,6 +562,30 @@ mod tests {
         Ok(())
     }
 
+    #[gtest]
+    fn test_goto_source_path_line() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new();
+        ws.def_file(
+            "api.lua",
+            r#"
+                ---@source native/api.c:10
+                function native_call()
+                end
+            "#,
+        );
+
+        check!(ws.check_definition(
+            r#"
+                native_<??>call()
+            "#,
+            vec![Expected {
+                file: "api.c".to_string(),
+                line: 9,
+            }],
+        ));
+        Ok(())
+    }
+
     #[gtest]
     fn test_accessors() -> Result<()> {
         let mut ws = ProviderVirtualWorkspace::new_with_init_std_lib();
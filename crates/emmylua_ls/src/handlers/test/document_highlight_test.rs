@@ -0,0 +1,103 @@
+#[cfg(test)]
+mod tests {
+    use crate::handlers::test_lib::{ProviderVirtualWorkspace, VirtualDocumentHighlight, check};
+    use googletest::prelude::*;
+    use lsp_types::DocumentHighlightKind;
+
+    #[gtest]
+    fn test_local() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new();
+        check!(ws.check_document_highlight(
+            r#"
+                local a = 1
+                a = 2
+                print(<??>a)
+            "#,
+            vec![
+                VirtualDocumentHighlight {
+                    line: 1,
+                    character: 22,
+                    kind: None,
+                },
+                VirtualDocumentHighlight {
+                    line: 2,
+                    character: 16,
+                    kind: Some(DocumentHighlightKind::WRITE),
+                },
+                VirtualDocumentHighlight {
+                    line: 3,
+                    character: 22,
+                    kind: Some(DocumentHighlightKind::READ),
+                },
+            ],
+        ));
+        Ok(())
+    }
+
+    #[gtest]
+    fn test_global() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new();
+        check!(ws.check_document_highlight(
+            r#"
+                Count = 1
+                Count = Count + 1
+                print(Cou<??>nt)
+            "#,
+            vec![
+                VirtualDocumentHighlight {
+                    line: 1,
+                    character: 16,
+                    kind: Some(DocumentHighlightKind::WRITE),
+                },
+                VirtualDocumentHighlight {
+                    line: 2,
+                    character: 16,
+                    kind: Some(DocumentHighlightKind::WRITE),
+                },
+                VirtualDocumentHighlight {
+                    line: 2,
+                    character: 24,
+                    kind: Some(DocumentHighlightKind::READ),
+                },
+                VirtualDocumentHighlight {
+                    line: 3,
+                    character: 22,
+                    kind: Some(DocumentHighlightKind::READ),
+                },
+            ],
+        ));
+        Ok(())
+    }
+
+    #[gtest]
+    fn test_member() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new();
+        check!(ws.check_document_highlight(
+            r#"
+                local t = {
+                    name = "a",
+                }
+                t.name = "b"
+                print(t.na<??>me)
+            "#,
+            vec![
+                VirtualDocumentHighlight {
+                    line: 2,
+                    character: 20,
+                    kind: Some(DocumentHighlightKind::WRITE),
+                },
+                VirtualDocumentHighlight {
+                    line: 4,
+                    character: 18,
+                    kind: Some(DocumentHighlightKind::WRITE),
+                },
+                VirtualDocumentHighlight {
+                    line: 5,
+                    character: 24,
+                    kind: Some(DocumentHighlightKind::READ),
+                },
+            ],
+        ));
+        Ok(())
+    }
+}
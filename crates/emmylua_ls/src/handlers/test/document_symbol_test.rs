@@ -0,0 +1,56 @@
+#[cfg(test)]
+mod tests {
+    use googletest::prelude::*;
+    use lsp_types::SymbolKind;
+
+    use crate::handlers::test_lib::{ProviderVirtualWorkspace, check};
+
+    #[gtest]
+    fn test_class_members_nested() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new();
+        let symbols = check!(ws.get_document_symbols(
+            r#"
+                ---@class Foo
+                local Foo = {}
+
+                Foo.count = 1
+
+                function Foo:bar()
+                end
+
+                function Foo.create()
+                end
+
+                local function helper()
+                end
+            "#,
+        ));
+
+        let outline = symbols
+            .iter()
+            .map(|symbol| (symbol.name.as_str(), symbol.kind))
+            .collect::<Vec<_>>();
+        verify_that!(
+            outline,
+            elements_are![
+                eq(&("Foo", SymbolKind::CLASS)),
+                eq(&("helper", SymbolKind::FUNCTION))
+            ]
+        )?;
+
+        let members = symbols[0]
+            .children
+            .iter()
+            .flatten()
+            .map(|symbol| (symbol.name.as_str(), symbol.kind))
+            .collect::<Vec<_>>();
+        verify_that!(
+            members,
+            elements_are![
+                eq(&("count", SymbolKind::FIELD)),
+                eq(&("bar", SymbolKind::METHOD)),
+                eq(&("create", SymbolKind::FUNCTION))
+            ]
+        )
+    }
+}
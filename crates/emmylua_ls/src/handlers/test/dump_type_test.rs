@@ -0,0 +1,39 @@
+#[cfg(test)]
+mod tests {
+    use crate::handlers::test_lib::{ProviderVirtualWorkspace, check};
+    use emmylua_code_analysis::TypeDescriptor;
+    use googletest::prelude::*;
+
+    #[gtest]
+    fn test_dump_expr_type() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new();
+        check!(ws.check_dump_type(
+            r#"
+                ---@type string[]
+                local list = {}
+                print(<??>list<??>)
+            "#,
+            "string[]",
+            TypeDescriptor::Array {
+                base: Box::new(TypeDescriptor::String),
+            },
+        ));
+        Ok(())
+    }
+
+    #[gtest]
+    fn test_dump_decl_type() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new();
+        check!(ws.check_dump_type(
+            r#"
+                local function f()
+                    ---@type integer
+                    local <??>n = 1
+                end
+            "#,
+            "integer",
+            TypeDescriptor::Integer,
+        ));
+        Ok(())
+    }
+}
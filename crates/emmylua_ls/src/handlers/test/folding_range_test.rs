@@ -0,0 +1,96 @@
+#[cfg(test)]
+mod tests {
+    use crate::handlers::test_lib::{ProviderVirtualWorkspace, check};
+    use googletest::prelude::*;
+    use lsp_types::FoldingRangeKind;
+
+    #[gtest]
+    fn test_nested_region() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new();
+        check!(ws.check_region_folding_range(
+            r#"--region outer
+local a = 1
+--region inner
+local b = 2
+--endregion
+local c = 3
+--endregion"#,
+            vec![(0, 6), (2, 4)],
+        ));
+        Ok(())
+    }
+
+    #[gtest]
+    fn test_doc_tag_region() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new();
+        check!(ws.check_region_folding_range(
+            r#"---@region helpers
+local a = 1
+local b = 2
+---@endregion"#,
+            vec![(0, 3)],
+        ));
+        Ok(())
+    }
+
+    #[gtest]
+    fn test_unpaired_region() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new();
+        check!(ws.check_region_folding_range(
+            r#"--endregion
+local a = 1
+--region
+local b = 2
+--endregion
+--region never closed
+local c = 3"#,
+            vec![(2, 4)],
+        ));
+        Ok(())
+    }
+
+    #[gtest]
+    fn test_doc_tag_fold() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new();
+        check!(ws.check_folding_range(
+            r#"--- A point
+---@class Point
+---@field x number
+---@field y number
+local Point = {}
+
+--- Drawing mode
+---@alias DrawMode
+---| "fill"
+---| "line"
+local mode"#,
+            Some(FoldingRangeKind::Comment),
+            vec![(0, 3), (1, 3), (6, 9), (7, 9)],
+        ));
+        Ok(())
+    }
+
+    #[gtest]
+    fn test_enum_table_fold() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new();
+        // 从 `---@enum` 注释到表结束的折叠不是区域, 表本身的折叠保持不变
+        check!(ws.check_folding_range(
+            r#"---@enum Color
+local Color = {
+    Red = 1,
+    Green = 2,
+}"#,
+            None,
+            vec![(0, 3)],
+        ));
+        check!(ws.check_region_folding_range(
+            r#"---@enum Color
+local Color = {
+    Red = 1,
+    Green = 2,
+}"#,
+            vec![(1, 3)],
+        ));
+        Ok(())
+    }
+}
@@ -773,4 +773,95 @@ mod tests {
         ));
         Ok(())
     }
+
+    #[gtest]
+    fn test_local_function_overload_call_hover() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new();
+        check!(ws.check_hover(
+            r#"
+                ---@overload fun(a: integer)
+                ---@overload fun(a: string, b: string)
+                ---@param a boolean
+                local function f(a)
+                end
+
+                <??>f("x", "y")
+            "#,
+            VirtualHoverResult {
+                value: "```lua\nlocal function f(a: string, b: string)\n```".to_string(),
+            },
+        ));
+        Ok(())
+    }
+
+    #[gtest]
+    fn test_call_generic_named_return() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new();
+        check!(ws.check_hover(
+            r#"
+                ---@class A
+                local A
+                ---@class B
+                local B
+
+                ---@generic T
+                ---@param x T
+                ---@return T result
+                function A.identity(x)
+                end
+
+                A.iden<??>tity(B)
+            "#,
+            VirtualHoverResult {
+                value: "```lua\nfunction A.identity(x: B)\n  -> result: B\n\n```".to_string(),
+            },
+        ));
+        Ok(())
+    }
+
+    #[gtest]
+    fn test_multi_return() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new();
+        check!(ws.check_hover(
+            r#"
+                ---@return boolean
+                ---@return string
+                local function <??>parse()
+                end
+            "#,
+            VirtualHoverResult {
+                value: "```lua\nlocal function parse() -> (boolean, string)\n```".to_string(),
+            },
+        ));
+
+        check!(ws.check_hover(
+            r#"
+                ---@return boolean ok
+                ---@return string err
+                local function <??>parse()
+                end
+            "#,
+            VirtualHoverResult {
+                value: "```lua\nlocal function parse()\n  -> 1. ok: boolean\n     2. err: string\n\n```".to_string(),
+            },
+        ));
+        Ok(())
+    }
+
+    #[gtest]
+    fn test_legacy_vararg_tag() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new();
+        check!(ws.check_hover(
+            r#"
+                ---@param name string
+                ---@vararg integer
+                local function <??>sum(name, ...)
+                end
+            "#,
+            VirtualHoverResult {
+                value: "```lua\nlocal function sum(name: string, ...: integer)\n```".to_string(),
+            },
+        ));
+        Ok(())
+    }
 }
@@ -437,12 +437,13 @@ mod tests {
             "#,
         );
 
+        // `Node` 定义在 virtual_0.lua, 在其他文件中悬浮时末尾会列出定义文件
         check!(ws.check_hover(
             r#"
                 node<??>.field = nil
             "#,
             VirtualHoverResult {
-                value: "```lua\n(global) node: Node {\n    field: number?,\n    method: function,\n}\n```".to_string(),
+                value: "```lua\n(global) node: Node {\n    field: number?,\n    method: function,\n}\n```\n\n---\n\n`Node` defined in `virtual_0.lua`".to_string(),
             },
         ));
 
@@ -451,7 +452,7 @@ mod tests {
                 node<??>:method()
             "#,
             VirtualHoverResult {
-                value: "```lua\n(global) node: Node {\n    field: number?,\n    method: function,\n}\n```".to_string(),
+                value: "```lua\n(global) node: Node {\n    field: number?,\n    method: function,\n}\n```\n\n---\n\n`Node` defined in `virtual_0.lua`".to_string(),
             },
         ));
 
@@ -460,7 +461,7 @@ mod tests {
                 node<??>["key"] = "value"
             "#,
             VirtualHoverResult {
-                value: "```lua\n(global) node: Node {\n    field: number?,\n    method: function,\n}\n```".to_string(),
+                value: "```lua\n(global) node: Node {\n    field: number?,\n    method: function,\n}\n```\n\n---\n\n`Node` defined in `virtual_0.lua`".to_string(),
             },
         ));
 
@@ -506,6 +507,37 @@ mod tests {
         Ok(())
     }
 
+    #[gtest]
+    fn test_see_tag_link() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new();
+        ws.def_file(
+            "see.lua",
+            r#"
+                ---@class SeeTarget
+                local SeeTarget = {}
+
+                function SeeTarget.run() end
+            "#,
+        );
+        let uri = ws.virtual_url_generator.new_uri("see.lua");
+        check!(ws.check_hover(
+            r#"
+                --- Description
+                ---
+                --- @see SeeTarget.run see description
+                local function te<??>st() end
+            "#,
+            VirtualHoverResult {
+                value: format!(
+                    "```lua\nlocal function test()\n```\n\n---\n\nDescription\n\n---\n\n@*see* [SeeTarget.run]({}#L5) see description",
+                    uri.as_str()
+                ),
+            },
+        ));
+
+        Ok(())
+    }
+
     #[gtest]
     fn test_other_tag() -> Result<()> {
         let mut ws = ProviderVirtualWorkspace::new();
@@ -551,4 +583,143 @@ mod tests {
 
         Ok(())
     }
+
+    #[gtest]
+    fn test_type_call_narrow() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new();
+        check!(ws.check_hover(
+            r#"
+            ---@type string|number
+            local x
+            if type(x) == "string" then
+                local y = <??>x
+            end
+            "#,
+            VirtualHoverResult {
+                value: "```lua\nlocal x: string\n```".to_string(),
+            },
+        ));
+        check!(ws.check_hover(
+            r#"
+            ---@type string|number
+            local x
+            if type(x) == "string" then
+            else
+                local y = <??>x
+            end
+            "#,
+            VirtualHoverResult {
+                value: "```lua\nlocal x: number\n```".to_string(),
+            },
+        ));
+
+        Ok(())
+    }
+
+    #[gtest]
+    fn test_type_definition_location() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new();
+        ws.def_file(
+            "player/player.lua",
+            r#"
+                ---@class Player
+                ---@field name string
+            "#,
+        );
+        ws.def_file(
+            "player/player_ext.lua",
+            r#"
+                ---@class Player
+                ---@field title string
+            "#,
+        );
+        check!(ws.check_hover(
+            r#"
+                ---@type Player
+                local <??>player
+            "#,
+            VirtualHoverResult {
+                value: "```lua\nlocal player: Player {\n    name: string,\n    title: string,\n}\n```\n\n---\n\n`Player` defined in `player/player.lua`, `player/player_ext.lua`".to_string(),
+            },
+        ));
+
+        // 同一文件中定义的类型不显示位置
+        check!(ws.check_hover(
+            r#"
+                ---@class Enemy
+                ---@field name string
+
+                ---@type Enemy
+                local <??>enemy
+            "#,
+            VirtualHoverResult {
+                value: "```lua\nlocal enemy: Enemy {\n    name: string,\n}\n```".to_string(),
+            },
+        ));
+        Ok(())
+    }
+
+    #[gtest]
+    fn test_code_block_language() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new();
+        let mut emmyrc = ws.get_emmyrc();
+        emmyrc.hover.code_block_language = "luau".to_string();
+        ws.update_emmyrc(emmyrc);
+        check!(ws.check_hover(
+            r#"
+                ---@class Enemy
+                ---@field name string
+
+                ---@type Enemy
+                local <??>enemy
+            "#,
+            VirtualHoverResult {
+                value: "```luau\nlocal enemy: Enemy {\n    name: string,\n}\n```".to_string(),
+            },
+        ));
+        Ok(())
+    }
+
+    #[gtest]
+    fn test_enum_member() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new();
+        check!(
+            ws.check_hover(
+                r#"
+                ---@enum Color
+                local Color = {
+                    Red = 1,
+                    Green = 2,
+                }
+
+                local c = Color.<??>Red
+            "#,
+                VirtualHoverResult {
+                    value: "```lua\n(field) Red: integer = 1\n```\n\n&nbsp;&nbsp;in enum `Color`"
+                        .to_string(),
+                },
+            )
+        );
+        Ok(())
+    }
+
+    #[gtest]
+    fn test_self_field_of_generic_class() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new();
+        check!(ws.check_hover(
+            r#"
+                ---@class HoverBox<T>
+                ---@field parent self
+
+                ---@type HoverBox<integer>
+                local box
+
+                local <??>parent = box.parent
+            "#,
+            VirtualHoverResult {
+                value: "```lua\nlocal parent: HoverBox<integer>\n```".to_string(),
+            },
+        ));
+        Ok(())
+    }
 }
@@ -213,4 +213,45 @@ mod tests {
         ));
         Ok(())
     }
+
+    #[gtest]
+    fn test_sub_class_member_implementation() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new();
+        ws.def_file(
+            "1.lua",
+            r#"
+                ---@class Shape
+                ---@field area fun(self: Shape): number
+            "#,
+        );
+        ws.def_file(
+            "2.lua",
+            r#"
+                ---@class Circle : Shape
+                local Circle = {}
+
+                function Circle:area()
+                    return 1
+                end
+            "#,
+        );
+        check!(ws.check_implementation(
+            r#"
+                ---@type Shape
+                local s
+                s:ar<??>ea()
+            "#,
+            vec![
+                VirtualLocation {
+                    file: "1.lua".to_string(),
+                    line: 2,
+                },
+                VirtualLocation {
+                    file: "2.lua".to_string(),
+                    line: 4,
+                },
+            ],
+        ));
+        Ok(())
+    }
 }
@@ -85,6 +85,105 @@ mod tests {
         Ok(())
     }
 
+    #[gtest]
+    fn test_local_hint_infer() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new_with_init_std_lib();
+        ws.def(
+            r#"
+                ---@return number
+                function get_number() end
+            "#,
+        );
+        check!(ws.check_inlay_hint(
+            r#"
+                local n = get_number()
+            "#,
+            vec![VirtualInlayHint {
+                label: ": number".to_string(),
+                line: 1,
+                pos: 23,
+                ref_file: Some("builtin.lua".to_string()),
+            }]
+        ));
+        check!(ws.check_inlay_hint(
+            r#"
+                ---@type number
+                local n = get_number()
+            "#,
+            vec![]
+        ));
+        Ok(())
+    }
+
+    #[gtest]
+    fn test_local_literal_hint() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new_with_init_std_lib();
+        let mut emmyrc = ws.get_emmyrc();
+        emmyrc.hint.local_literal_hint = true;
+        ws.update_emmyrc(emmyrc);
+        check!(ws.check_inlay_hint(
+            r#"
+                local a = 1
+            "#,
+            vec![VirtualInlayHint {
+                label: ": integer".to_string(),
+                line: 1,
+                pos: 23,
+                ref_file: Some("builtin.lua".to_string()),
+            }]
+        ));
+        Ok(())
+    }
+
+    #[gtest]
+    fn test_param_hint() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new();
+        ws.def(
+            r#"
+                ---@param name string
+                ---@param age integer
+                function greet(name, age)
+                end
+
+                ---@class Greeter
+                Greeter = {}
+
+                ---@param name string
+                function Greeter:greet(name)
+                end
+            "#,
+        );
+        check!(ws.check_inlay_hint(
+            r#"
+                local info = { name = "a", age = 1 }
+                greet(info.name, info.age)
+                greet("b", 2)
+                Greeter:greet("c")
+            "#,
+            vec![
+                VirtualInlayHint {
+                    label: "name:".to_string(),
+                    line: 3,
+                    pos: 22,
+                    ref_file: Some("".to_string()),
+                },
+                VirtualInlayHint {
+                    label: "age:".to_string(),
+                    line: 3,
+                    pos: 27,
+                    ref_file: Some("".to_string()),
+                },
+                VirtualInlayHint {
+                    label: "name:".to_string(),
+                    line: 4,
+                    pos: 30,
+                    ref_file: Some("".to_string()),
+                },
+            ]
+        ));
+        Ok(())
+    }
+
     #[gtest]
     fn test_meta_call_hint() -> Result<()> {
         let mut ws = ProviderVirtualWorkspace::new();
@@ -1,12 +1,21 @@
+mod call_hierarchy_test;
 mod code_actions_test;
+mod code_lens_test;
 mod completion_resolve_test;
 mod completion_test;
 mod definition_test;
+mod document_highlight_test;
+mod document_symbol_test;
+mod dump_type_test;
+mod folding_range_test;
 mod hover_function_test;
 mod hover_test;
 mod implementation_test;
 mod inlay_hint_test;
 mod references_test;
 mod rename_test;
+mod selection_range_test;
 mod semantic_token_test;
 mod signature_helper_test;
+mod type_definition_test;
+mod workspace_symbol_test;
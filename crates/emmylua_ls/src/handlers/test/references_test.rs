@@ -37,10 +37,6 @@ mod tests {
                     file: "1.lua".to_string(),
                     line: 1,
                 },
-                VirtualLocation {
-                    file: "1.lua".to_string(),
-                    line: 1,
-                },
                 VirtualLocation {
                     file: "1.lua".to_string(),
                     line: 2,
@@ -89,10 +85,6 @@ mod tests {
                     file: "1.lua".to_string(),
                     line: 1,
                 },
-                VirtualLocation {
-                    file: "1.lua".to_string(),
-                    line: 1,
-                },
                 VirtualLocation {
                     file: "1.lua".to_string(),
                     line: 2,
@@ -183,6 +175,46 @@ mod tests {
         Ok(())
     }
 
+    #[gtest]
+    fn test_require_alias_references() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new();
+        ws.def_file(
+            "a.lua",
+            r#"
+            local function helper()
+            end
+            return helper
+        "#,
+        );
+
+        check!(ws.check_references(
+            r#"
+                local h = require("a")
+                h<??>()
+            "#,
+            vec![],
+            vec![
+                VirtualLocation {
+                    file: "a.lua".to_string(),
+                    line: 1,
+                },
+                VirtualLocation {
+                    file: "virtual_0.lua".to_string(),
+                    line: 1,
+                },
+                VirtualLocation {
+                    file: "virtual_0.lua".to_string(),
+                    line: 2,
+                },
+                VirtualLocation {
+                    file: "a.lua".to_string(),
+                    line: 3,
+                },
+            ],
+        ));
+        Ok(())
+    }
+
     #[gtest]
     fn test_member_references_alias_cycle_does_not_stack_overflow() -> Result<()> {
         let mut ws = ProviderVirtualWorkspace::new();
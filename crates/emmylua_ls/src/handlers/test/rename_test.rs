@@ -307,4 +307,255 @@ mod tests {
         ));
         Ok(())
     }
+
+    #[gtest]
+    fn test_rename_class_str_tpl_reference() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new();
+        check!(ws.check_rename(
+            r#"
+                ---@generic T
+                ---@param name `T`
+                ---@return T
+                function meta(name) end
+
+                ---@class My<??>Class
+                local A = meta("MyClass")
+            "#,
+            "NewClass".to_string(),
+            vec![(
+                "virtual_0.lua".to_string(),
+                vec![
+                    TextEdit {
+                        range: Range::new(Position::new(6, 26), Position::new(6, 33)),
+                        new_text: "NewClass".to_string(),
+                    },
+                    TextEdit {
+                        range: Range::new(Position::new(7, 31), Position::new(7, 40)),
+                        new_text: "\"NewClass\"".to_string(),
+                    },
+                ],
+            )]
+        ));
+        Ok(())
+    }
+
+    #[gtest]
+    fn test_rename_class_str_tpl_reference_with_string_index() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new();
+        let mut emmyrc = ws.get_emmyrc();
+        emmyrc.references.short_string_search = true;
+        ws.update_emmyrc(emmyrc);
+        ws.def_file(
+            "meta.lua",
+            r#"
+                ---@generic T
+                ---@param name `T`
+                ---@return T
+                function meta(name) end
+            "#,
+        );
+        check!(ws.check_rename(
+            r#"
+                ---@class My<??>Class
+                local A = meta("MyClass")
+            "#,
+            "NewClass".to_string(),
+            vec![(
+                "virtual_0.lua".to_string(),
+                vec![
+                    TextEdit {
+                        range: Range::new(Position::new(1, 26), Position::new(1, 33)),
+                        new_text: "NewClass".to_string(),
+                    },
+                    TextEdit {
+                        range: Range::new(Position::new(2, 31), Position::new(2, 40)),
+                        new_text: "\"NewClass\"".to_string(),
+                    },
+                ],
+            )]
+        ));
+        Ok(())
+    }
+
+    #[gtest]
+    fn test_rename_param_updates_doc_param() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new();
+        check!(ws.check_rename(
+            r#"
+                ---@class ParamOwner
+                local M = {}
+
+                ---@param value number
+                ---@return number
+                function M:scale(val<??>ue)
+                    return value * 2
+                end
+            "#,
+            "factor".to_string(),
+            vec![(
+                "virtual_0.lua".to_string(),
+                vec![
+                    TextEdit {
+                        range: Range::new(Position::new(4, 26), Position::new(4, 31)),
+                        new_text: "factor".to_string(),
+                    },
+                    TextEdit {
+                        range: Range::new(Position::new(6, 33), Position::new(6, 38)),
+                        new_text: "factor".to_string(),
+                    },
+                    TextEdit {
+                        range: Range::new(Position::new(7, 27), Position::new(7, 32)),
+                        new_text: "factor".to_string(),
+                    },
+                ],
+            )]
+        ));
+        check!(ws.check_rename(
+            r#"
+                local M = {
+                    ---@param value number
+                    scale = function(val<??>ue)
+                        return value * 2
+                    end,
+                }
+
+                ---@param value number
+                local f = function(value) end
+            "#,
+            "factor".to_string(),
+            vec![(
+                "virtual_1.lua".to_string(),
+                vec![
+                    TextEdit {
+                        range: Range::new(Position::new(2, 30), Position::new(2, 35)),
+                        new_text: "factor".to_string(),
+                    },
+                    TextEdit {
+                        range: Range::new(Position::new(3, 37), Position::new(3, 42)),
+                        new_text: "factor".to_string(),
+                    },
+                    TextEdit {
+                        range: Range::new(Position::new(4, 31), Position::new(4, 36)),
+                        new_text: "factor".to_string(),
+                    },
+                ],
+            )]
+        ));
+        Ok(())
+    }
+
+    #[gtest]
+    fn test_rename_field_updates_doc_field() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new();
+        check!(ws.check_rename(
+            r#"
+                ---@class FieldOwner
+                ---@field count integer
+                local A = {}
+
+                function A:inc()
+                    self.co<??>unt = self.count + 1
+                end
+            "#,
+            "total".to_string(),
+            vec![(
+                "virtual_0.lua".to_string(),
+                vec![
+                    TextEdit {
+                        range: Range::new(Position::new(2, 26), Position::new(2, 31)),
+                        new_text: "total".to_string(),
+                    },
+                    TextEdit {
+                        range: Range::new(Position::new(6, 25), Position::new(6, 30)),
+                        new_text: "total".to_string(),
+                    },
+                    TextEdit {
+                        range: Range::new(Position::new(6, 38), Position::new(6, 43)),
+                        new_text: "total".to_string(),
+                    },
+                ],
+            )]
+        ));
+        check!(ws.check_rename(
+            r#"
+                ---@class FieldOwner2
+                ---@field co<??>unt integer
+                local B = {}
+
+                ---@param b FieldOwner2
+                local function inc(b)
+                    b.count = b.count + 1
+                end
+            "#,
+            "total".to_string(),
+            vec![(
+                "virtual_1.lua".to_string(),
+                vec![
+                    TextEdit {
+                        range: Range::new(Position::new(2, 26), Position::new(2, 31)),
+                        new_text: "total".to_string(),
+                    },
+                    TextEdit {
+                        range: Range::new(Position::new(7, 22), Position::new(7, 27)),
+                        new_text: "total".to_string(),
+                    },
+                    TextEdit {
+                        range: Range::new(Position::new(7, 32), Position::new(7, 37)),
+                        new_text: "total".to_string(),
+                    },
+                ],
+            )]
+        ));
+        Ok(())
+    }
+
+    #[gtest]
+    fn test_prepare_rename() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new_with_init_std_lib();
+        check!(ws.check_prepare_rename(
+            r#"
+                local a<??>bc = 1
+            "#,
+            Some(Range::new(Position::new(1, 22), Position::new(1, 25))),
+        ));
+        check!(ws.check_prepare_rename(
+            r#"
+                lo<??>cal abc = 1
+            "#,
+            None,
+        ));
+        check!(ws.check_prepare_rename(
+            r#"
+                pr<??>int("hello")
+            "#,
+            None,
+        ));
+        check!(ws.check_prepare_rename(
+            r#"
+                local s = string.up<??>per("hello")
+            "#,
+            None,
+        ));
+        Ok(())
+    }
+
+    #[gtest]
+    fn test_prepare_rename_index_key() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new_with_init_std_lib();
+        check!(ws.check_prepare_rename(
+            r#"
+                local t = {}
+                t[1<??>] = 2
+            "#,
+            Some(Range::new(Position::new(2, 18), Position::new(2, 19))),
+        ));
+        check!(ws.check_prepare_rename(
+            r#"
+                local t = {}
+                local v = t["k<??>"]
+            "#,
+            Some(Range::new(Position::new(2, 28), Position::new(2, 31))),
+        ));
+        Ok(())
+    }
 }
@@ -0,0 +1,34 @@
+#[cfg(test)]
+mod tests {
+    use crate::handlers::test_lib::{ProviderVirtualWorkspace, check};
+    use googletest::prelude::*;
+    use lsp_types::{Position, Range};
+
+    #[gtest]
+    fn test_expand_from_name() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new();
+        check!(ws.check_selection_range(
+            "local t = foo(a<??>, b)",
+            vec![
+                Range::new(Position::new(0, 14), Position::new(0, 15)),
+                Range::new(Position::new(0, 13), Position::new(0, 19)),
+                Range::new(Position::new(0, 10), Position::new(0, 19)),
+            ],
+        ));
+        Ok(())
+    }
+
+    #[gtest]
+    fn test_expand_to_statement() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new();
+        check!(ws.check_selection_range(
+            "if ok then\n    print(<??>x)\nend",
+            vec![
+                Range::new(Position::new(1, 10), Position::new(1, 11)),
+                Range::new(Position::new(1, 9), Position::new(1, 12)),
+                Range::new(Position::new(1, 4), Position::new(1, 12)),
+            ],
+        ));
+        Ok(())
+    }
+}
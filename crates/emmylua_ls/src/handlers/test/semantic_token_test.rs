@@ -117,4 +117,44 @@ m.foo()
         )?;
         Ok(())
     }
+
+    #[gtest]
+    fn test_const_local_and_deprecated_modifiers() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new();
+        let data = ws.get_semantic_token_data(concat!(
+            "local a <const> = 1\n",
+            "a = 2\n",
+            "---@deprecated\n",
+            "local function old() end\n",
+            "old()\n",
+        ))?;
+        let tokens = decode(&data);
+        let variable = SemanticTokenTypeKind::Variable.to_u32();
+        let function = SemanticTokenTypeKind::Function.to_u32();
+        let readonly = SemanticTokenModifierKind::READONLY.to_u32();
+        let deprecated = SemanticTokenModifierKind::DEPRECATED.to_u32();
+
+        verify_that!(
+            &tokens,
+            all![
+                contains(eq(&(1, 0, 1, variable, readonly))),
+                contains(eq(&(4, 0, 3, function, deprecated))),
+            ]
+        )?;
+        Ok(())
+    }
+
+    #[gtest]
+    fn test_const_local_keeps_default_library_modifier() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new_with_init_std_lib();
+        let data = ws.get_semantic_token_data("local p <const> = print\n")?;
+        let tokens = decode(&data);
+        let function = SemanticTokenTypeKind::Function.to_u32();
+        let modifiers = (SemanticTokenModifierKind::READONLY
+            | SemanticTokenModifierKind::DEFAULT_LIBRARY)
+            .to_u32();
+
+        verify_that!(&tokens, contains(eq(&(0, 6, 1, function, modifiers))))?;
+        Ok(())
+    }
 }
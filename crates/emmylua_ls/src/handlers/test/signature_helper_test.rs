@@ -44,4 +44,28 @@ mod tests {
         ));
         Ok(())
     }
+
+    #[gtest]
+    fn test_colon_call_variadic() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new();
+        check!(ws.check_signature_helper(
+            r#"
+                ---@class Foo
+                local Foo = {}
+
+                ---@param a Foo
+                ---@param ... string
+                function Foo.log(a, ...)
+                end
+
+                Foo:log("x", "y", <??>)
+            "#,
+            VirtualSignatureHelp {
+                target_label: "Foo.log(...: string)".to_string(),
+                active_signature: 0,
+                active_parameter: 0,
+            },
+        ));
+        Ok(())
+    }
 }
@@ -0,0 +1,82 @@
+#[cfg(test)]
+mod tests {
+    use crate::handlers::test_lib::{ProviderVirtualWorkspace, VirtualLocation, check};
+    use googletest::prelude::*;
+
+    #[gtest]
+    fn test_local_type() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new();
+        ws.def_file(
+            "player.lua",
+            r#"
+                ---@class Player
+                ---@field name string
+            "#,
+        );
+        check!(ws.check_type_definition(
+            r#"
+                ---@type Player
+                local pla<??>yer
+            "#,
+            vec![VirtualLocation {
+                file: "player.lua".to_string(),
+                line: 1,
+            }],
+        ));
+        Ok(())
+    }
+
+    #[gtest]
+    fn test_union_type() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new();
+        ws.def_file(
+            "shapes.lua",
+            r#"
+                ---@class Circle
+                ---@field radius number
+
+                ---@class Square
+                ---@field size number
+            "#,
+        );
+        check!(ws.check_type_definition(
+            r#"
+                ---@type Circle|Square
+                local shape
+
+                print(sha<??>pe)
+            "#,
+            vec![
+                VirtualLocation {
+                    file: "shapes.lua".to_string(),
+                    line: 1,
+                },
+                VirtualLocation {
+                    file: "shapes.lua".to_string(),
+                    line: 4,
+                },
+            ],
+        ));
+        Ok(())
+    }
+
+    #[gtest]
+    fn test_alias_type() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new();
+        check!(ws.check_type_definition(
+            r#"
+                ---@alias Mode "read"|"write"
+
+                ---@param mode Mode
+                local function open(mode)
+                    print(mo<??>de)
+                end
+            "#,
+            vec![VirtualLocation {
+                file: "".to_string(),
+                line: 1,
+            }],
+        ));
+        Ok(())
+    }
+}
@@ -0,0 +1,60 @@
+#[cfg(test)]
+mod tests {
+    use googletest::prelude::*;
+    use lsp_types::{SymbolKind, WorkspaceSymbolResponse};
+    use tokio_util::sync::CancellationToken;
+
+    use crate::handlers::{
+        test_lib::ProviderVirtualWorkspace, workspace_symbol::build_workspace_symbols,
+    };
+
+    #[gtest]
+    fn test_workspace_symbol_ranking() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new();
+        ws.def(
+            r#"
+                ---@class MyRender
+                MyRender = {}
+
+                function MyRender:draw()
+                end
+
+                ---@class Render
+                Render = {}
+
+                function Render:update()
+                end
+            "#,
+        );
+
+        let Some(WorkspaceSymbolResponse::Nested(symbols)) = build_workspace_symbols(
+            &ws.analysis.compilation,
+            "Render".to_string(),
+            CancellationToken::new(),
+        ) else {
+            return fail!("failed to get workspace symbols");
+        };
+        let names = symbols
+            .iter()
+            .map(|symbol| (symbol.name.as_str(), symbol.kind))
+            .collect::<Vec<_>>();
+        verify_that!(
+            names,
+            elements_are![
+                eq(&("Render", SymbolKind::CLASS)),
+                eq(&("MyRender", SymbolKind::CLASS))
+            ]
+        )?;
+
+        let Some(WorkspaceSymbolResponse::Nested(symbols)) = build_workspace_symbols(
+            &ws.analysis.compilation,
+            "draw".to_string(),
+            CancellationToken::new(),
+        ) else {
+            return fail!("failed to get workspace symbols");
+        };
+        let draw = symbols.first().ok_or("no symbols").or_fail()?;
+        verify_eq!(draw.kind, SymbolKind::METHOD)?;
+        verify_eq!(draw.container_name.as_deref(), Some("MyRender"))
+    }
+}
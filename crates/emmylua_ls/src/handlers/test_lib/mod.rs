@@ -1,11 +1,13 @@
-use emmylua_code_analysis::{EmmyLuaAnalysis, Emmyrc, FileId, VirtualUrlGenerator};
+use emmylua_code_analysis::{EmmyLuaAnalysis, Emmyrc, FileId, TypeDescriptor, VirtualUrlGenerator};
 use googletest::prelude::*;
 use itertools::Itertools;
 use lsp_types::{
-    CodeActionOrCommand, CompletionItem, CompletionItemKind, CompletionResponse,
-    CompletionTriggerKind, Documentation, GotoDefinitionResponse, Hover, HoverContents,
-    InlayHintLabel, Location, MarkupContent, Position, SemanticToken, SemanticTokensResult,
-    SignatureHelpContext, SignatureHelpTriggerKind, SignatureInformation, TextEdit,
+    CodeActionOrCommand, Command, CompletionItem, CompletionItemKind, CompletionResponse,
+    CompletionTriggerKind, DocumentHighlightKind, DocumentSymbol, DocumentSymbolResponse,
+    Documentation, FoldingRange, FoldingRangeKind, GotoDefinitionResponse, Hover, HoverContents,
+    InlayHintLabel, Location, MarkupContent, Position, PrepareRenameResponse, Range, SemanticToken,
+    SemanticTokensResult, SignatureHelpContext, SignatureHelpTriggerKind, SignatureInformation,
+    TextEdit,
 };
 use std::collections::HashSet;
 use std::{ops::Deref, sync::Arc};
@@ -15,15 +17,24 @@ use crate::{
     context::ClientId,
     handlers::{
         code_actions::code_action,
+        code_lens::{build_code_lens, resolve_code_lens},
+        command::dump_type,
         completion::{completion, completion_resolve},
+        document_highlight::document_highlight,
+        document_selection_range::document_selection_range,
+        document_symbol::document_symbol,
+        fold_range::folding_range,
         inlay_hint::inlay_hint,
-        rename::rename,
+        rename::{prepare_rename, rename},
         semantic_token::semantic_token,
         signature_helper::signature_help,
     },
 };
 
-use super::{hover::hover, implementation::implementation, references::references};
+use super::{
+    hover::hover, implementation::implementation, references::references,
+    type_definition::type_definition,
+};
 
 /// Calling this macro on a [`Result`] is equivalent to `result?`,
 /// but adds info about current location to the error message.
@@ -95,6 +106,13 @@ pub struct VirtualInlayHint {
     pub ref_file: Option<String>,
 }
 
+#[derive(Debug, PartialEq)]
+pub struct VirtualDocumentHighlight {
+    pub line: u32,
+    pub character: u32,
+    pub kind: Option<DocumentHighlightKind>,
+}
+
 #[derive(Debug)]
 pub struct VirtualCodeAction {
     pub title: String,
@@ -215,6 +233,21 @@ impl ProviderVirtualWorkspace {
         Ok((new_content, Some(Position::new(line as u32, column as u32))))
     }
 
+    /// 处理带选区的文件内容, 两个 `<??>` 分别标记选区的起止位置, 只有一个时选区为空
+    pub fn handle_file_selection(content: &str) -> Result<(String, Range)> {
+        let start_byte_pos = content
+            .find("<??>")
+            .ok_or("module content should include <??>")
+            .or_fail()?;
+        let end_byte_pos = start_byte_pos + "<??>".len();
+        let (_, start) = Self::handle_file_content(&content[..end_byte_pos])?;
+        let rest = format!("{}{}", &content[..start_byte_pos], &content[end_byte_pos..]);
+        match Self::handle_file_content_option(&rest)? {
+            (new_content, Some(end)) => Ok((new_content, Range::new(start, end))),
+            (new_content, None) => Ok((new_content, Range::new(start, start))),
+        }
+    }
+
     pub fn check_hover(&mut self, block_str: &str, expected: VirtualHoverResult) -> Result<()> {
         let (content, position) = Self::handle_file_content(block_str)?;
         let file_id = self.def(&content);
@@ -280,6 +313,28 @@ impl ProviderVirtualWorkspace {
         )
     }
 
+    pub fn get_completion_items(
+        &mut self,
+        block_str: &str,
+        trigger_kind: CompletionTriggerKind,
+    ) -> Result<Vec<CompletionItem>> {
+        let (content, position) = Self::handle_file_content(block_str)?;
+        let file_id = self.def(&content);
+        let result = completion(
+            &self.analysis,
+            file_id,
+            position,
+            trigger_kind,
+            CancellationToken::new(),
+        )
+        .ok_or("failed to get completion")
+        .or_fail()?;
+        Ok(match result {
+            CompletionResponse::Array(items) => items,
+            CompletionResponse::List(list) => list.items,
+        })
+    }
+
     pub fn check_completion_resolve(
         &mut self,
         block_str: &str,
@@ -334,6 +389,45 @@ impl ProviderVirtualWorkspace {
         Self::assert_definition(result, expected)
     }
 
+    pub fn check_type_definition(
+        &mut self,
+        block_str: &str,
+        expected: Vec<VirtualLocation>,
+    ) -> Result<()> {
+        let (content, position) = Self::handle_file_content(block_str)?;
+        let file_id = self.def(&content);
+        let result = type_definition(&self.analysis, file_id, position)
+            .ok_or("failed to get go to type definition response")
+            .or_fail()?;
+
+        Self::assert_definition(result, expected)
+    }
+
+    pub fn check_document_highlight(
+        &mut self,
+        block_str: &str,
+        mut expected: Vec<VirtualDocumentHighlight>,
+    ) -> Result<()> {
+        let (content, position) = Self::handle_file_content(block_str)?;
+        let file_id = self.def(&content);
+        let result = document_highlight(&self.analysis, file_id, position)
+            .ok_or("failed to get document highlight")
+            .or_fail()?;
+
+        let mut items = result
+            .into_iter()
+            .map(|item| VirtualDocumentHighlight {
+                line: item.range.start.line,
+                character: item.range.start.character,
+                kind: item.kind,
+            })
+            .collect::<Vec<_>>();
+        items.sort_by_key(|item| (item.line, item.character));
+        expected.sort_by_key(|item| (item.line, item.character));
+
+        verify_eq!(items, expected)
+    }
+
     pub fn check_definition(
         &mut self,
         block_str: &str,
@@ -519,7 +613,7 @@ impl ProviderVirtualWorkspace {
             .diagnose_file(file_id, CancellationToken::new())
             .ok_or("failed to diagnose file")
             .or_fail()?;
-        let result = code_action(&self.analysis, file_id, result)
+        let result = code_action(&self.analysis, file_id, Range::default(), result)
             .ok_or("failed to generate code action")
             .or_fail()?;
 
@@ -539,6 +633,148 @@ impl ProviderVirtualWorkspace {
         )
     }
 
+    /// 比较诊断的快速修复产生的编辑
+    pub fn check_quick_fix(
+        &mut self,
+        block_str: &str,
+        title: &str,
+        expected: Option<Vec<TextEdit>>,
+    ) -> Result<()> {
+        let file_id = self.def(block_str);
+        let diagnostics = self
+            .analysis
+            .diagnose_file(file_id, CancellationToken::new())
+            .ok_or("failed to diagnose file")
+            .or_fail()?;
+        let result =
+            code_action(&self.analysis, file_id, Range::default(), diagnostics).unwrap_or_default();
+        let edits = result.into_iter().find_map(|action| match action {
+            CodeActionOrCommand::CodeAction(action) if action.title == title => {
+                action.edit?.changes?.into_values().next()
+            }
+            _ => None,
+        });
+        verify_eq!(edits, expected)
+    }
+
+    pub fn check_refactor_action(
+        &mut self,
+        block_str: &str,
+        title: &str,
+        expected: Option<Vec<TextEdit>>,
+    ) -> Result<()> {
+        let (content, range) = Self::handle_file_selection(block_str)?;
+        let file_id = self.def(&content);
+        let result = code_action(&self.analysis, file_id, range, Vec::new()).unwrap_or_default();
+        let edits = result.into_iter().find_map(|action| match action {
+            CodeActionOrCommand::CodeAction(action) if action.title == title => {
+                action.edit?.changes?.into_values().next()
+            }
+            _ => None,
+        });
+        verify_eq!(edits, expected)
+    }
+
+    pub fn check_refactor_command(
+        &mut self,
+        block_str: &str,
+        title: &str,
+        expected: Option<Command>,
+    ) -> Result<()> {
+        let (content, range) = Self::handle_file_selection(block_str)?;
+        let file_id = self.def(&content);
+        let result = code_action(&self.analysis, file_id, range, Vec::new()).unwrap_or_default();
+        let command = result.into_iter().find_map(|action| match action {
+            CodeActionOrCommand::CodeAction(action) if action.title == title => action.command,
+            _ => None,
+        });
+        verify_eq!(command, expected)
+    }
+
+    pub fn check_dump_type(
+        &mut self,
+        block_str: &str,
+        expected_type: &str,
+        expected_descriptor: TypeDescriptor,
+    ) -> Result<()> {
+        let (content, range) = Self::handle_file_selection(block_str)?;
+        let file_id = self.def(&content);
+        let result = dump_type(&self.analysis, file_id, range)
+            .ok_or("failed to dump type")
+            .or_fail()?;
+        verify_eq!(result.typ, expected_type)?;
+        verify_eq!(result.descriptor, expected_descriptor)
+    }
+
+    /// 只比较从内到外的前几层选区
+    pub fn check_selection_range(&mut self, block_str: &str, expected: Vec<Range>) -> Result<()> {
+        let (content, position) = Self::handle_file_content(block_str)?;
+        let file_id = self.def(&content);
+        let result = document_selection_range(&self.analysis, file_id, vec![position])
+            .ok_or("failed to get selection range")
+            .or_fail()?;
+        let mut ranges = Vec::new();
+        let mut selection_range = result.into_iter().next();
+        while let Some(current) = selection_range {
+            ranges.push(current.range);
+            selection_range = current.parent.map(|parent| *parent);
+        }
+        ranges.truncate(expected.len());
+        verify_eq!(ranges, expected)
+    }
+
+    /// 只比较 `region` 类型的折叠范围, 结果为 `(start_line, end_line)`
+    pub fn check_region_folding_range(
+        &mut self,
+        block_str: &str,
+        expected: Vec<(u32, u32)>,
+    ) -> Result<()> {
+        self.check_folding_range(block_str, Some(FoldingRangeKind::Region), expected)
+    }
+
+    /// 只比较指定类型的折叠范围的起止行, `None` 表示没有设置类型的折叠范围
+    pub fn check_folding_range(
+        &mut self,
+        block_str: &str,
+        kind: Option<FoldingRangeKind>,
+        expected: Vec<(u32, u32)>,
+    ) -> Result<()> {
+        let file_id = self.def(block_str);
+        let result = folding_range(&self.analysis, file_id, ClientId::VSCode)
+            .ok_or("failed to get folding range")
+            .or_fail()?;
+        let mut ranges = result
+            .into_iter()
+            .filter(|range: &FoldingRange| range.kind == kind)
+            .map(|range| (range.start_line, range.end_line))
+            .collect::<Vec<_>>();
+        ranges.sort();
+        verify_eq!(ranges, expected)
+    }
+
+    /// 解析后的每个 code lens 的标题, 按位置排序
+    pub fn get_code_lens_titles(&mut self, block_str: &str) -> Result<Vec<String>> {
+        let file_id = self.def(block_str);
+        let semantic_model = self
+            .analysis
+            .compilation
+            .get_semantic_model(file_id)
+            .ok_or("failed to get semantic model")
+            .or_fail()?;
+        let code_lenses = build_code_lens(&semantic_model)
+            .ok_or("failed to get code lens")
+            .or_fail()?;
+        let titles = code_lenses
+            .into_iter()
+            .filter_map(|code_lens| {
+                resolve_code_lens(&self.analysis.compilation, code_lens, ClientId::VSCode)
+            })
+            .sorted_by_key(|code_lens| code_lens.range.start)
+            .filter_map(|code_lens| code_lens.command.map(|command| command.title))
+            .collect();
+        Ok(titles)
+    }
+
     pub fn check_semantic_token(&mut self, block_str: &str, expected: Vec<u32>) -> Result<()> {
         let result_data = self.get_semantic_token_data(block_str)?;
         verify_eq!(result_data, expected)
@@ -585,9 +821,15 @@ impl ProviderVirtualWorkspace {
     ) -> Result<()> {
         let (content, position) = Self::handle_file_content(block_str)?;
         let file_id = self.def(&content);
-        let result = rename(&self.analysis, file_id, position, new_name.clone())
-            .ok_or("failed to rename")
-            .or_fail()?;
+        let result = rename(
+            &self.analysis,
+            file_id,
+            position,
+            new_name.clone(),
+            CancellationToken::new(),
+        )
+        .ok_or("failed to rename")
+        .or_fail()?;
         let mut items = result
             .changes
             .or_fail()?
@@ -615,6 +857,29 @@ impl ProviderVirtualWorkspace {
         verify_eq!(items, expected)
     }
 
+    pub fn check_prepare_rename(&mut self, block_str: &str, expected: Option<Range>) -> Result<()> {
+        let (content, position) = Self::handle_file_content(block_str)?;
+        let file_id = self.def(&content);
+        let result =
+            prepare_rename(&self.analysis, file_id, position).and_then(|response| match response {
+                PrepareRenameResponse::Range(range)
+                | PrepareRenameResponse::RangeWithPlaceholder { range, .. } => Some(range),
+                PrepareRenameResponse::DefaultBehavior { .. } => None,
+            });
+        verify_eq!(result, expected)
+    }
+
+    pub fn get_document_symbols(&mut self, block_str: &str) -> Result<Vec<DocumentSymbol>> {
+        let file_id = self.def(block_str);
+        let result = document_symbol(&self.analysis, file_id)
+            .ok_or("failed to get document symbols")
+            .or_fail()?;
+        match result {
+            DocumentSymbolResponse::Nested(symbols) => Ok(symbols),
+            DocumentSymbolResponse::Flat(_) => Err("expected nested document symbols").or_fail(),
+        }
+    }
+
     pub fn check_references(
         &mut self,
         main_block_str: &str,
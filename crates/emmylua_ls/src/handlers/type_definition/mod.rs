@@ -0,0 +1,133 @@
+use crate::context::ServerContextSnapshot;
+use emmylua_code_analysis::{EmmyLuaAnalysis, FileId, LuaType, LuaTypeDeclId, SemanticModel};
+use emmylua_parser::{LuaAstNode, LuaTokenKind};
+use lsp_types::{
+    ClientCapabilities, GotoDefinitionResponse, Position, ServerCapabilities,
+    TypeDefinitionProviderCapability, request::GotoTypeDefinitionParams,
+};
+use rowan::TokenAtOffset;
+use tokio_util::sync::CancellationToken;
+
+use super::RegisterCapabilities;
+
+pub async fn on_type_definition_handler(
+    context: ServerContextSnapshot,
+    params: GotoTypeDefinitionParams,
+    _: CancellationToken,
+) -> Option<GotoDefinitionResponse> {
+    let uri = params.text_document_position_params.text_document.uri;
+    let analysis = context.analysis().read().await;
+    let file_id = analysis.get_file_id(&uri)?;
+    let position = params.text_document_position_params.position;
+
+    type_definition(&analysis, file_id, position)
+}
+
+pub fn type_definition(
+    analysis: &EmmyLuaAnalysis,
+    file_id: FileId,
+    position: Position,
+) -> Option<GotoDefinitionResponse> {
+    let semantic_model = analysis.compilation.get_semantic_model(file_id)?;
+    let root = semantic_model.get_root();
+    let position_offset = {
+        let document = semantic_model.get_document();
+        document.get_offset(position.line as usize, position.character as usize)?
+    };
+
+    if position_offset > root.syntax().text_range().end() {
+        return None;
+    }
+
+    let token = match root.syntax().token_at_offset(position_offset) {
+        TokenAtOffset::Single(token) => token,
+        TokenAtOffset::Between(left, right) => {
+            if left.kind() == LuaTokenKind::TkName.into() {
+                left
+            } else {
+                right
+            }
+        }
+        TokenAtOffset::None => return None,
+    };
+
+    let semantic_info = semantic_model.get_semantic_info(token.into())?;
+    let mut type_decl_ids = Vec::new();
+    collect_type_decl_ids(&semantic_info.typ, &mut type_decl_ids);
+
+    let locations = type_decl_ids
+        .iter()
+        .flat_map(|type_decl_id| get_type_decl_locations(&semantic_model, type_decl_id))
+        .collect::<Vec<_>>();
+    if locations.is_empty() {
+        return None;
+    }
+
+    Some(GotoDefinitionResponse::Array(locations))
+}
+
+/// 收集类型中引用的所有类型声明, 联合类型的每个分支都会被收集
+fn collect_type_decl_ids(typ: &LuaType, result: &mut Vec<LuaTypeDeclId>) {
+    match typ {
+        LuaType::Ref(type_decl_id) | LuaType::Def(type_decl_id) => {
+            if !result.contains(type_decl_id) {
+                result.push(type_decl_id.clone());
+            }
+        }
+        LuaType::Generic(generic) => {
+            let base_id = generic.get_base_type_id();
+            if !result.contains(&base_id) {
+                result.push(base_id);
+            }
+        }
+        LuaType::Array(array) => collect_type_decl_ids(array.get_base(), result),
+        LuaType::Union(union) => {
+            for typ in union.into_vec() {
+                collect_type_decl_ids(&typ, result);
+            }
+        }
+        LuaType::MultiLineUnion(multi_union) => {
+            for (typ, _) in multi_union.get_unions() {
+                collect_type_decl_ids(typ, result);
+            }
+        }
+        LuaType::Intersection(intersection) => {
+            for typ in intersection.get_types() {
+                collect_type_decl_ids(typ, result);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn get_type_decl_locations(
+    semantic_model: &SemanticModel,
+    type_decl_id: &LuaTypeDeclId,
+) -> Vec<lsp_types::Location> {
+    let Some(type_decl) = semantic_model
+        .get_db()
+        .get_type_index()
+        .get_type_decl(type_decl_id)
+    else {
+        return Vec::new();
+    };
+
+    type_decl
+        .get_locations()
+        .iter()
+        .filter_map(|location| {
+            semantic_model
+                .get_document_by_file_id(location.file_id)?
+                .to_lsp_location(location.range)
+        })
+        .collect()
+}
+
+pub struct TypeDefinitionCapabilities;
+
+impl RegisterCapabilities for TypeDefinitionCapabilities {
+    fn register_capabilities(server_capabilities: &mut ServerCapabilities, _: &ClientCapabilities) {
+        server_capabilities.type_definition_provider =
+            Some(TypeDefinitionProviderCapability::Simple(true));
+    }
+}
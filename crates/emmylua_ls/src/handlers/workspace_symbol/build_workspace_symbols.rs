@@ -1,13 +1,36 @@
-use emmylua_code_analysis::{DbIndex, LuaCompilation, LuaSemanticDeclId, LuaType};
+use emmylua_code_analysis::{
+    DbIndex, LuaCompilation, LuaMemberKey, LuaMemberOwner, LuaSemanticDeclId, LuaType,
+    LuaTypeDeclId,
+};
 use lsp_types::{OneOf, SymbolKind, SymbolTag, WorkspaceSymbol, WorkspaceSymbolResponse};
 use tokio_util::sync::CancellationToken;
 
+const MAX_WORKSPACE_SYMBOLS: usize = 500;
+
+/// 匹配程度, 越小越靠前
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum SymbolMatch {
+    Exact,
+    Prefix,
+    Substring,
+}
+
 /// if query contains uppercase, do case-sensitive match; otherwise, ignore case
-fn match_symbol(text: &str, query: &str) -> bool {
-    if query.chars().any(|c| c.is_uppercase()) {
-        text.contains(query)
+fn match_symbol(text: &str, query: &str) -> Option<SymbolMatch> {
+    let (text, query) = if query.chars().any(|c| c.is_uppercase()) {
+        (text.to_string(), query.to_string())
+    } else {
+        (text.to_lowercase(), query.to_lowercase())
+    };
+
+    if text == query {
+        Some(SymbolMatch::Exact)
+    } else if text.starts_with(&query) {
+        Some(SymbolMatch::Prefix)
+    } else if text.contains(&query) {
+        Some(SymbolMatch::Substring)
     } else {
-        text.to_lowercase().contains(&query.to_lowercase())
+        None
     }
 }
 
@@ -19,11 +42,19 @@ pub fn build_workspace_symbols(
     let mut symbols = Vec::new();
     add_global_variable_symbols(&mut symbols, compilation, &query, &cancel_token)?;
     add_type_symbols(&mut symbols, compilation, &query, &cancel_token)?;
+    symbols.sort_by(|(a_match, a), (b_match, b)| {
+        a_match.cmp(b_match).then_with(|| a.name.cmp(&b.name))
+    });
+    let symbols = symbols
+        .into_iter()
+        .take(MAX_WORKSPACE_SYMBOLS)
+        .map(|(_, symbol)| symbol)
+        .collect();
     Some(WorkspaceSymbolResponse::Nested(symbols))
 }
 
 fn add_global_variable_symbols(
-    symbols: &mut Vec<WorkspaceSymbol>,
+    symbols: &mut Vec<(SymbolMatch, WorkspaceSymbol)>,
     compilation: &LuaCompilation,
     query: &str,
     cancel_token: &CancellationToken,
@@ -40,13 +71,22 @@ fn add_global_variable_symbols(
         if cancel_token.is_cancelled() {
             return None;
         }
+        if db.is_ignored(&decl.get_file_id()) {
+            continue;
+        }
 
-        if match_symbol(decl.get_name(), query) {
+        if let Some(symbol_match) = match_symbol(decl.get_name(), query) {
             let typ = db
                 .get_type_index()
                 .get_type_cache(&decl_id.into())
                 .map(|cache| cache.as_type())
                 .unwrap_or(&LuaType::Unknown);
+            // 与类同名的全局变量由 `add_type_symbols` 给出, 避免重复
+            if let LuaType::Def(type_id) = typ
+                && type_id.get_name() == decl.get_name()
+            {
+                continue;
+            }
             let property_owner_id = LuaSemanticDeclId::LuaDecl(decl_id);
             let document = db.get_vfs().get_document(&decl.get_file_id())?;
             let location = document.to_lsp_location(decl.get_range())?;
@@ -62,7 +102,7 @@ fn add_global_variable_symbols(
                 location: OneOf::Left(location),
                 data: None,
             };
-            symbols.push(symbol);
+            symbols.push((symbol_match, symbol));
         }
     }
 
@@ -70,7 +110,7 @@ fn add_global_variable_symbols(
 }
 
 fn add_type_symbols(
-    symbols: &mut Vec<WorkspaceSymbol>,
+    symbols: &mut Vec<(SymbolMatch, WorkspaceSymbol)>,
     compilation: &LuaCompilation,
     query: &str,
     cancel_token: &CancellationToken,
@@ -86,8 +126,16 @@ fn add_type_symbols(
         if cancel_token.is_cancelled() {
             return None;
         }
+        if typ
+            .get_locations()
+            .first()
+            .is_some_and(|location| db.is_ignored(&location.file_id))
+        {
+            continue;
+        }
 
-        if match_symbol(typ.get_full_name(), query) {
+        add_type_member_symbols(symbols, db, typ.get_id(), typ.get_full_name(), query);
+        if let Some(symbol_match) = match_symbol(typ.get_full_name(), query) {
             let property_owner_id = LuaSemanticDeclId::TypeDecl(typ.get_id());
             let location = typ.get_locations().first()?;
             let document = db.get_vfs().get_document(&location.file_id)?;
@@ -104,8 +152,64 @@ fn add_type_symbols(
                 location: OneOf::Left(location),
                 data: None,
             };
-            symbols.push(symbol);
+            symbols.push((symbol_match, symbol));
+        }
+    }
+
+    Some(())
+}
+
+fn add_type_member_symbols(
+    symbols: &mut Vec<(SymbolMatch, WorkspaceSymbol)>,
+    db: &DbIndex,
+    type_id: LuaTypeDeclId,
+    type_name: &str,
+    query: &str,
+) -> Option<()> {
+    let members = db
+        .get_member_index()
+        .get_sorted_members(&LuaMemberOwner::Type(type_id))?;
+    for member in members {
+        let LuaMemberKey::Name(name) = member.get_key() else {
+            continue;
+        };
+        if db.is_ignored(&member.get_file_id()) {
+            continue;
         }
+        let Some(symbol_match) = match_symbol(name, query) else {
+            continue;
+        };
+
+        let member_id = member.get_id();
+        let typ = db
+            .get_type_index()
+            .get_type_cache(&member_id.into())
+            .map(|cache| cache.as_type())
+            .unwrap_or(&LuaType::Unknown);
+        let kind = if typ.is_function() {
+            SymbolKind::METHOD
+        } else {
+            SymbolKind::FIELD
+        };
+        let Some(document) = db.get_vfs().get_document(&member.get_file_id()) else {
+            continue;
+        };
+        let Some(location) = document.to_lsp_location(member.get_range()) else {
+            continue;
+        };
+        let symbol = WorkspaceSymbol {
+            name: name.to_string(),
+            kind,
+            tags: if is_deprecated(db, LuaSemanticDeclId::Member(member_id)) {
+                Some(vec![SymbolTag::DEPRECATED])
+            } else {
+                None
+            },
+            container_name: Some(type_name.to_string()),
+            location: OneOf::Left(location),
+            data: None,
+        };
+        symbols.push((symbol_match, symbol));
     }
 
     Some(())
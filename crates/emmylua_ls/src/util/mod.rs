@@ -1,9 +1,11 @@
 mod desc;
 mod module_name_convert;
+mod string_key;
 mod time_cancel_token;
 
 pub use desc::*;
 pub use module_name_convert::{
     file_name_convert, module_name_convert, to_camel_case, to_pascal_case, to_snake_case,
 };
+pub use string_key::format_string_key;
 pub use time_cancel_token::time_cancel_token;
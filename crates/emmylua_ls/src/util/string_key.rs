@@ -0,0 +1,26 @@
+use std::fmt::Write;
+
+/// 不是合法标识符的字符串键, 写成 `["key"]` 形式, 并转义引号、反斜杠和控制字符.
+/// 控制字符按 UTF-8 字节写成三位十进制转义, 避免与后面的数字连在一起
+pub fn format_string_key(name: &str) -> String {
+    let mut result = String::with_capacity(name.len() + 4);
+    result.push_str("[\"");
+    for c in name.chars() {
+        match c {
+            '"' => result.push_str("\\\""),
+            '\\' => result.push_str("\\\\"),
+            '\n' => result.push_str("\\n"),
+            '\r' => result.push_str("\\r"),
+            '\t' => result.push_str("\\t"),
+            c if c.is_control() => {
+                let mut buf = [0; 4];
+                for byte in c.encode_utf8(&mut buf).bytes() {
+                    let _ = write!(result, "\\{:03}", byte);
+                }
+            }
+            c => result.push(c),
+        }
+    }
+    result.push_str("\"]");
+    result
+}
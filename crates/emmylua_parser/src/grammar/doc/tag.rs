@@ -319,23 +319,28 @@ fn parse_tag_type(p: &mut LuaDocParser) -> DocParseResult {
 // ---@param a number
 // ---@param a? number
 // ---@param ... string
+// ---@vararg string
 fn parse_tag_param(p: &mut LuaDocParser) -> DocParseResult {
     p.set_lexer_state(LuaDocLexerState::Normal);
     let m = p.mark(LuaSyntaxKind::DocTagParam);
+    // 旧写法 `---@vararg` 没有参数名
+    let is_legacy_vararg = p.current_token_text() == "vararg";
     p.bump();
-    if matches!(
-        p.current_token(),
-        LuaTokenKind::TkName | LuaTokenKind::TkDots
-    ) {
-        p.bump();
-    } else {
-        return Err(LuaParseError::doc_error_from(
-            &t!(
-                "expect param name or '...', but get %{current}",
-                current = p.current_token()
-            ),
-            p.current_token_range(),
-        ));
+    if !is_legacy_vararg {
+        if matches!(
+            p.current_token(),
+            LuaTokenKind::TkName | LuaTokenKind::TkDots
+        ) {
+            p.bump();
+        } else {
+            return Err(LuaParseError::doc_error_from(
+                &t!(
+                    "expect param name or '...', but get %{current}",
+                    current = p.current_token()
+                ),
+                p.current_token_range(),
+            ));
+        }
     }
 
     if_token_bump(p, LuaTokenKind::TkDocQuestion);
@@ -246,6 +246,35 @@ impl LuaTokenKind {
         })
     }
 
+    /// The keyword token for `name`, `None` if it lexes as a plain name
+    pub fn from_keyword(name: &str) -> Option<LuaTokenKind> {
+        Some(match name {
+            "and" => LuaTokenKind::TkAnd,
+            "break" => LuaTokenKind::TkBreak,
+            "do" => LuaTokenKind::TkDo,
+            "else" => LuaTokenKind::TkElse,
+            "elseif" => LuaTokenKind::TkElseIf,
+            "end" => LuaTokenKind::TkEnd,
+            "false" => LuaTokenKind::TkFalse,
+            "for" => LuaTokenKind::TkFor,
+            "function" => LuaTokenKind::TkFunction,
+            "goto" => LuaTokenKind::TkGoto,
+            "if" => LuaTokenKind::TkIf,
+            "in" => LuaTokenKind::TkIn,
+            "local" => LuaTokenKind::TkLocal,
+            "nil" => LuaTokenKind::TkNil,
+            "not" => LuaTokenKind::TkNot,
+            "or" => LuaTokenKind::TkOr,
+            "repeat" => LuaTokenKind::TkRepeat,
+            "return" => LuaTokenKind::TkReturn,
+            "then" => LuaTokenKind::TkThen,
+            "true" => LuaTokenKind::TkTrue,
+            "until" => LuaTokenKind::TkUntil,
+            "while" => LuaTokenKind::TkWhile,
+            _ => return None,
+        })
+    }
+
     pub fn is_keyword(self) -> bool {
         matches!(
             self,
@@ -709,7 +709,8 @@ fn to_tag(text: &str) -> LuaTokenKind {
         "module" => LuaTokenKind::TkTagModule,
         "field" => LuaTokenKind::TkTagField,
         "type" => LuaTokenKind::TkTagType,
-        "param" => LuaTokenKind::TkTagParam,
+        // `vararg` 是旧版本的写法, 等价于 `param ...`
+        "param" | "vararg" => LuaTokenKind::TkTagParam,
         "return" => LuaTokenKind::TkTagReturn,
         "return_cast" => LuaTokenKind::TkTagReturnCast,
         "return_overload" => LuaTokenKind::TkTagReturnOverload,
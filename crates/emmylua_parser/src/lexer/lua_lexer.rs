@@ -81,15 +81,6 @@ impl<'a> LuaLexer<'a> {
 
     fn name_to_kind(&self, name: &str) -> LuaTokenKind {
         match name {
-            "and" => LuaTokenKind::TkAnd,
-            "break" => LuaTokenKind::TkBreak,
-            "do" => LuaTokenKind::TkDo,
-            "else" => LuaTokenKind::TkElse,
-            "elseif" => LuaTokenKind::TkElseIf,
-            "end" => LuaTokenKind::TkEnd,
-            "false" => LuaTokenKind::TkFalse,
-            "for" => LuaTokenKind::TkFor,
-            "function" => LuaTokenKind::TkFunction,
             "goto" => {
                 if self.lexer_config.support_goto() {
                     LuaTokenKind::TkGoto
@@ -97,18 +88,6 @@ impl<'a> LuaLexer<'a> {
                     LuaTokenKind::TkName
                 }
             }
-            "if" => LuaTokenKind::TkIf,
-            "in" => LuaTokenKind::TkIn,
-            "local" => LuaTokenKind::TkLocal,
-            "nil" => LuaTokenKind::TkNil,
-            "not" => LuaTokenKind::TkNot,
-            "or" => LuaTokenKind::TkOr,
-            "repeat" => LuaTokenKind::TkRepeat,
-            "return" => LuaTokenKind::TkReturn,
-            "then" => LuaTokenKind::TkThen,
-            "true" => LuaTokenKind::TkTrue,
-            "until" => LuaTokenKind::TkUntil,
-            "while" => LuaTokenKind::TkWhile,
             "continue" => {
                 if self.support_non_std_symbol(LuaNonStdSymbol::Continue) {
                     LuaTokenKind::TkBreak
@@ -116,7 +95,7 @@ impl<'a> LuaLexer<'a> {
                     LuaTokenKind::TkName
                 }
             }
-            _ => LuaTokenKind::TkName,
+            _ => LuaTokenKind::from_keyword(name).unwrap_or(LuaTokenKind::TkName),
         }
     }
 
@@ -1,8 +1,8 @@
 use crate::{
-    BinaryOperator, LuaAstChildren, LuaAstToken, LuaAstTokenChildren, LuaBinaryOpToken,
-    LuaDocAttributeUse, LuaDocVersionNumberToken, LuaDocVisibilityToken, LuaExpr, LuaGeneralToken,
-    LuaKind, LuaNameToken, LuaNumberToken, LuaPathToken, LuaStringToken, LuaSyntaxNode,
-    LuaTokenKind, LuaVersionCondition,
+    BinaryOperator, LuaAstChildren, LuaAstToken, LuaAstTokenChildren, LuaBinaryOpToken, LuaBlock,
+    LuaComment, LuaDocAttributeUse, LuaDocVersionNumberToken, LuaDocVisibilityToken, LuaExpr,
+    LuaGeneralToken, LuaKind, LuaNameToken, LuaNumberToken, LuaPathToken, LuaStringToken,
+    LuaSyntaxNode, LuaTokenKind, LuaVersionCondition,
     kind::LuaSyntaxKind,
     syntax::{LuaDocDescriptionOwner, traits::LuaAstNode},
 };
@@ -517,8 +517,12 @@ impl LuaDocTagParam {
         self.token()
     }
 
+    /// `---@param ...` 或旧写法 `---@vararg`
     pub fn is_vararg(&self) -> bool {
         self.token_by_kind(LuaTokenKind::TkDots).is_some()
+            || self
+                .token_by_kind(LuaTokenKind::TkTagParam)
+                .is_some_and(|token| token.get_text() == "vararg")
     }
 
     pub fn is_nullable(&self) -> bool {
@@ -1522,6 +1526,25 @@ impl LuaDocTagAs {
     pub fn get_type(&self) -> Option<LuaDocType> {
         self.child()
     }
+
+    /// `@as` 作用于所在注释前面的表达式
+    pub fn get_target_expr(&self) -> Option<LuaExpr> {
+        let comment = self.ancestors::<LuaComment>().next()?;
+        let mut left_token = comment.syntax().first_token()?.prev_token()?;
+        if left_token.kind() == LuaTokenKind::TkWhitespace.into() {
+            left_token = left_token.prev_token()?;
+        }
+
+        for node in left_token.parent_ancestors() {
+            if let Some(expr) = LuaExpr::cast(node.clone()) {
+                return Some(expr);
+            } else if LuaBlock::can_cast(node.kind().into()) {
+                return None;
+            }
+        }
+
+        None
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
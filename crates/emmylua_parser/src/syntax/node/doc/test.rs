@@ -1,6 +1,8 @@
 #[cfg(test)]
 mod test {
-    use crate::{LuaAstNode, LuaComment, LuaKind, LuaParser, LuaTokenKind, ParserConfig};
+    use crate::{
+        LuaAstNode, LuaComment, LuaDocTagParam, LuaKind, LuaParser, LuaTokenKind, ParserConfig,
+    };
 
     #[allow(unused)]
     fn print_ast(lua_code: &str) {
@@ -121,4 +123,23 @@ mod test {
             ]
         );
     }
+
+    #[test]
+    fn test_legacy_vararg_tag() {
+        let code = r#"
+        ---@vararg string
+        ---@param ... integer
+        function f(...) end
+        "#;
+
+        let tree = LuaParser::parse(code, ParserConfig::default());
+        let root = tree.get_chunk_node();
+        let params = root.descendants::<LuaDocTagParam>().collect::<Vec<_>>();
+        assert_eq!(params.len(), 2);
+        for param in params {
+            assert!(param.is_vararg());
+            assert!(param.get_name_token().is_none());
+            assert!(param.get_type().is_some());
+        }
+    }
 }
@@ -1,8 +1,12 @@
-use emmylua_code_analysis::Emmyrc;
+use emmylua_code_analysis::{DiagnosticCode, Emmyrc};
+use lsp_types::DiagnosticSeverity;
+use schemars::Schema;
+use serde_json::{json, Value};
 use std::fs;
 
 fn main() {
-    let schema = schemars::schema_for!(Emmyrc);
+    let mut schema = schemars::schema_for!(Emmyrc);
+    fill_diagnostic_codes(&mut schema);
     let mut schema_json = serde_json::to_string_pretty(&schema).unwrap();
     if !schema_json.ends_with('\n') {
         schema_json.push('\n');
@@ -12,3 +16,46 @@ fn main() {
     println!("Output path: {:?}", output_path);
     fs::write(output_path, schema_json).expect("Unable to write file");
 }
+
+// Rebuild the `DiagnosticCode` variants from the code metadata so each entry shows its
+// default severity and whether it is enabled by default
+fn fill_diagnostic_codes(schema: &mut Schema) {
+    let Some(one_of) = schema
+        .pointer_mut("/$defs/DiagnosticCode/oneOf")
+        .and_then(Value::as_array_mut)
+    else {
+        return;
+    };
+
+    // keep the `none` fallback which is not part of `DiagnosticCode::all()`
+    one_of.retain(|variant| variant.get("const").is_none());
+    let level = Emmyrc::default().get_language_level();
+    for code in DiagnosticCode::all() {
+        let metadata = code.metadata(level);
+        let enabled = if metadata.default_enabled {
+            "enabled"
+        } else {
+            "disabled"
+        };
+        one_of.push(json!({
+            "description": format!(
+                "{} (default severity: {}, {} by default)",
+                metadata.description,
+                severity_name(metadata.default_severity),
+                enabled
+            ),
+            "type": "string",
+            "const": metadata.name,
+        }));
+    }
+}
+
+fn severity_name(severity: DiagnosticSeverity) -> &'static str {
+    match severity {
+        DiagnosticSeverity::ERROR => "error",
+        DiagnosticSeverity::WARNING => "warning",
+        DiagnosticSeverity::INFORMATION => "information",
+        DiagnosticSeverity::HINT => "hint",
+        _ => "warning",
+    }
+}